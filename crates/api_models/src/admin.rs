@@ -269,6 +269,66 @@ pub struct MerchantDetails {
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum RoutingAlgorithm {
     Single(api_enums::RoutableConnectors),
+    Priority(Vec<api_enums::RoutableConnectors>),
+    Rules(RoutingRules),
+    VolumeSplit(Vec<ConnectorVolumeSplit>),
+}
+
+/// Rule based routing. The rules are evaluated in the order in which they are configured and the
+/// connectors of the first matching rule are used. If none of the rules match, the default
+/// connectors are used.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRules {
+    pub rules: Vec<RoutingRule>,
+    pub default_connectors: Vec<api_enums::RoutableConnectors>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRule {
+    /// Name of the rule, used to identify the rule in error messages
+    pub name: String,
+    /// All the conditions have to be satisfied for the rule to match
+    pub conditions: Vec<RoutingCondition>,
+    /// Connectors to be used if the rule matches, in the order of preference
+    pub connectors: Vec<api_enums::RoutableConnectors>,
+}
+
+/// Share of the traffic to be routed through a connector in volume split routing
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorVolumeSplit {
+    pub connector: api_enums::RoutableConnectors,
+    /// Percentage of the traffic, the splits of all the connectors should add up to 100
+    pub split: u8,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "field", rename_all = "snake_case")]
+pub enum RoutingCondition {
+    /// Matches if the amount lies within the range, both the bounds are inclusive
+    Amount {
+        min: Option<i64>,
+        max: Option<i64>,
+    },
+    Currency {
+        values: Vec<api_enums::Currency>,
+    },
+    CardNetwork {
+        values: Vec<api_enums::CardNetwork>,
+    },
+    PaymentMethodType {
+        values: Vec<api_enums::PaymentMethodType>,
+    },
+    BillingCountry {
+        values: Vec<api_enums::CountryAlpha2>,
+    },
+    /// Matches if the metadata of the payment contains the key with the given value
+    Metadata {
+        key: String,
+        value: serde_json::Value,
+    },
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -281,12 +341,18 @@ pub enum RoutingAlgorithm {
 )]
 pub enum StraightThroughAlgorithm {
     Single(api_enums::RoutableConnectors),
+    Priority(Vec<api_enums::RoutableConnectors>),
+    Rules(RoutingRules),
+    VolumeSplit(Vec<ConnectorVolumeSplit>),
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum StraightThroughAlgorithmInner {
     Single(api_enums::RoutableConnectors),
+    Priority(Vec<api_enums::RoutableConnectors>),
+    Rules(RoutingRules),
+    VolumeSplit(Vec<ConnectorVolumeSplit>),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -307,6 +373,9 @@ impl From<StraightThroughAlgorithmSerde> for StraightThroughAlgorithm {
 
         match inner {
             StraightThroughAlgorithmInner::Single(conn) => Self::Single(conn),
+            StraightThroughAlgorithmInner::Priority(conns) => Self::Priority(conns),
+            StraightThroughAlgorithmInner::Rules(rules) => Self::Rules(rules),
+            StraightThroughAlgorithmInner::VolumeSplit(splits) => Self::VolumeSplit(splits),
         }
     }
 }
@@ -315,12 +384,41 @@ impl From<StraightThroughAlgorithm> for StraightThroughAlgorithmSerde {
     fn from(value: StraightThroughAlgorithm) -> Self {
         let inner = match value {
             StraightThroughAlgorithm::Single(conn) => StraightThroughAlgorithmInner::Single(conn),
+            StraightThroughAlgorithm::Priority(conns) => {
+                StraightThroughAlgorithmInner::Priority(conns)
+            }
+            StraightThroughAlgorithm::Rules(rules) => StraightThroughAlgorithmInner::Rules(rules),
+            StraightThroughAlgorithm::VolumeSplit(splits) => {
+                StraightThroughAlgorithmInner::VolumeSplit(splits)
+            }
         };
 
         Self::Nested { algorithm: inner }
     }
 }
 
+impl From<RoutingAlgorithm> for StraightThroughAlgorithm {
+    fn from(value: RoutingAlgorithm) -> Self {
+        match value {
+            RoutingAlgorithm::Single(conn) => Self::Single(conn),
+            RoutingAlgorithm::Priority(conns) => Self::Priority(conns),
+            RoutingAlgorithm::Rules(rules) => Self::Rules(rules),
+            RoutingAlgorithm::VolumeSplit(splits) => Self::VolumeSplit(splits),
+        }
+    }
+}
+
+impl From<StraightThroughAlgorithm> for RoutingAlgorithm {
+    fn from(value: StraightThroughAlgorithm) -> Self {
+        match value {
+            StraightThroughAlgorithm::Single(conn) => Self::Single(conn),
+            StraightThroughAlgorithm::Priority(conns) => Self::Priority(conns),
+            StraightThroughAlgorithm::Rules(rules) => Self::Rules(rules),
+            StraightThroughAlgorithm::VolumeSplit(splits) => Self::VolumeSplit(splits),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct PrimaryBusinessDetails {
@@ -720,6 +818,38 @@ pub struct ToggleKVRequest {
     pub kv_enabled: bool,
 }
 
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MerchantKeyRotationResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// Version of the key with which the data of the merchant is encrypted
+    #[schema(example = 2)]
+    pub key_version: i32,
+    /// Status of the re-encryption of the data encrypted with previous versions of the key
+    pub status: KeyRotationStatus,
+    /// Number of records which have been re-encrypted with the latest version of the key
+    pub reencrypted_records: ReencryptedRecords,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyRotationStatus {
+    /// Data encrypted with previous versions of the key is being re-encrypted
+    InProgress,
+    /// All the data is encrypted with the latest version of the key
+    Completed,
+    /// The re-encryption failed, the key has to be rotated again to resume it
+    Failed,
+}
+
+#[derive(Debug, Clone, Default, Serialize, ToSchema)]
+pub struct ReencryptedRecords {
+    pub merchant_connector_accounts: usize,
+    pub customers: usize,
+    pub addresses: usize,
+}
+
 #[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct MerchantConnectorDetailsWrap {
     /// Creds Identifier is to uniquely identify the credentials. Do not send any sensitive info in this field. And do not send the string "null".
@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
+use crate::enums as api_enums;
+
 /// The request body for creating an API Key.
 #[derive(Debug, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
@@ -23,6 +25,11 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permissions to restrict the API Key to. If not specified, the API Key has full access
+    /// to the merchant account.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["payments_read", "refunds_read"]))]
+    pub scopes: Option<Vec<api_enums::ApiKeyScope>>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +67,12 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permissions the API Key is restricted to. Absent if the API Key has full access to the
+    /// merchant account.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["payments_read", "refunds_read"]))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<api_enums::ApiKeyScope>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,12 +115,18 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
-    /*
-    /// The date and time indicating when the API Key was last used.
+
+    /// The permissions the API Key is restricted to. Absent if the API Key has full access to the
+    /// merchant account.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["payments_read", "refunds_read"]))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<api_enums::ApiKeyScope>>,
+
+    /// The date and time indicating when the API Key was last used. This is updated
+    /// periodically, so it may lag behind the actual use by a few minutes.
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub last_used: Option<PrimitiveDateTime>,
-    */
 }
 
 /// The request body for updating an API Key.
@@ -129,6 +148,10 @@ pub struct UpdateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
+
+    /// The permissions to restrict the API Key to, replacing the existing ones.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["payments_read", "refunds_read"]))]
+    pub scopes: Option<Vec<api_enums::ApiKeyScope>>,
 }
 
 /// The response body for revoking an API Key.
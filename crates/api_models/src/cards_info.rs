@@ -29,3 +29,95 @@ pub struct CardInfoResponse {
     #[schema(example = "INDIA")]
     pub card_issuing_country: Option<String>,
 }
+
+/// Format of a BIN data file imported into the cards info table
+#[derive(
+    Clone, Copy, Debug, Default, serde::Deserialize, serde::Serialize, strum::EnumString, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardsInfoImportFormat {
+    /// An array of [`CardInfoImportRecord`] objects
+    #[default]
+    Json,
+    /// A comma separated file with a header row naming the [`CardInfoImportRecord`] fields
+    Csv,
+}
+
+#[derive(serde::Deserialize, ToSchema)]
+pub struct CardsInfoImportParams {
+    /// Format of the request body, defaults to `json`
+    pub format: Option<CardsInfoImportFormat>,
+    /// Validate the file and report the changes without writing them
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Name of the provider of the BIN data, stored along with the imported records
+    #[schema(example = "visa_bin_file")]
+    pub provider: Option<String>,
+}
+
+pub struct CardsInfoImportRequest {
+    pub format: CardsInfoImportFormat,
+    pub dry_run: bool,
+    pub provider: Option<String>,
+    pub data: String,
+}
+
+// BIN data files can be several megabytes large, only their size is logged
+impl Debug for CardsInfoImportRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CardsInfoImportRequest")
+            .field("format", &self.format)
+            .field("dry_run", &self.dry_run)
+            .field("provider", &self.provider)
+            .field("data_length", &self.data.len())
+            .finish()
+    }
+}
+
+/// A BIN entry of an import file. The `card_iin` is a 6 to 8 digit prefix, lookups use the
+/// longest prefix matching the card number.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct CardInfoImportRecord {
+    #[schema(example = "42424242")]
+    pub card_iin: String,
+    #[schema(example = "JP MORGAN CHASE")]
+    pub card_issuer: Option<String>,
+    #[schema(example = "VISA")]
+    pub card_network: Option<String>,
+    #[schema(example = "CREDIT")]
+    pub card_type: Option<String>,
+    #[serde(alias = "card_subtype")]
+    #[schema(example = "CLASSIC")]
+    pub card_sub_type: Option<String>,
+    #[schema(example = "UNITEDSTATES")]
+    pub card_issuing_country: Option<String>,
+    pub bank_code_id: Option<String>,
+    pub bank_code: Option<String>,
+    #[schema(example = "US")]
+    pub country_code: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct CardsInfoImportResponse {
+    /// Whether the import was only validated, without writing any records
+    pub dry_run: bool,
+    /// Number of records found in the file
+    pub total_records: usize,
+    /// Number of BIN entries which are (or would be) created
+    pub created: usize,
+    /// Number of existing BIN entries which are (or would be) updated
+    pub updated: usize,
+    /// Number of records matching the stored BIN entries
+    pub unchanged: usize,
+    /// Records which failed validation. When present, no record of the file is written.
+    pub errors: Vec<CardsInfoImportError>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct CardsInfoImportError {
+    /// Position of the record in the file, starting at 1 and not counting the CSV header
+    pub record_number: usize,
+    pub card_iin: Option<String>,
+    pub message: String,
+}
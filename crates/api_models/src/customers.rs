@@ -112,6 +112,10 @@ pub struct CustomerDeleteResponse {
     /// Whether payment methods deleted or not
     #[schema(example = false)]
     pub payment_methods_deleted: bool,
+    /// The identifier of the redaction of the customer's data linked to its payments, mandates and
+    /// disputes, which is completed in the background
+    #[schema(example = "redact_1Ma2aNbS3vK1rKdYQXLk")]
+    pub redaction_id: String,
 }
 
 pub fn generate_customer_id() -> String {
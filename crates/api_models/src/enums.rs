@@ -13,6 +13,7 @@ use utoipa::ToSchema;
     strum::Display,
     strum::EnumString,
     frunk::LabelledGeneric,
+    ToSchema,
 )]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
@@ -95,6 +96,34 @@ pub enum CaptureMethod {
     Scheduled,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CaptureStatus {
+    /// The capture has been created but not yet sent to the connector
+    #[default]
+    Started,
+    /// The connector has settled the capture
+    Charged,
+    /// The connector has accepted the capture but not yet settled it
+    Pending,
+    /// The capture was declined or could not be processed
+    Failed,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -263,6 +292,53 @@ pub enum Currency {
     strum::Display,
     strum::EnumString,
     frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EventClass {
+    Payments,
+    Refunds,
+    Disputes,
+    Payouts,
+    Mandates,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EventObjectType {
+    PaymentDetails,
+    RefundDetails,
+    DisputeDetails,
+    PayoutDetails,
+    MandateDetails,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
 )]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
@@ -270,6 +346,7 @@ pub enum EventType {
     PaymentSucceeded,
     PaymentFailed,
     PaymentProcessing,
+    PaymentCancelled,
     ActionRequired,
     RefundSucceeded,
     RefundFailed,
@@ -280,6 +357,17 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    DisputeEvidenceDue,
+    PayoutInitiated,
+    PayoutProcessing,
+    PayoutSuccess,
+    PayoutFailed,
+    PayoutCancelled,
+    PayoutReversed,
+    MandateExpired,
+    MandateUsed,
+    MandateActive,
+    MandateRevoked,
 }
 
 #[derive(
@@ -563,6 +651,10 @@ pub enum MandateStatus {
     Inactive,
     Pending,
     Revoked,
+    /// The mandate is past its end date
+    Expired,
+    /// The usage or amount limits of the mandate have been exhausted
+    Used,
 }
 
 #[derive(
@@ -969,9 +1061,140 @@ pub enum FrmPreferredFlowTypes {
     Pre,
     Post,
 }
+
+/// Outcome of a fraud check performed by an FRM connector
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum FraudCheckStatus {
+    Fraud,
+    ManualReview,
+    #[default]
+    Pending,
+    Legit,
+    TransactionFailure,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmConnectors {
+    #[cfg(feature = "dummy_connector")]
+    #[serde(rename = "dummyfrm")]
+    #[strum(serialize = "dummyfrm")]
+    DummyFrm,
+}
 #[derive(Debug, Eq, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct UnresolvedResponseReason {
     pub code: String,
     /// A message to merchant to give hint on next action he/she should do to resolve
     pub message: String,
 }
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutStatus {
+    #[default]
+    RequiresCreation,
+    RequiresFulfillment,
+    Pending,
+    Success,
+    Failed,
+    Cancelled,
+    Reversed,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutType {
+    #[default]
+    Card,
+    Bank,
+}
+
+/// The permissions an API key can be restricted to. Write scopes do not imply the corresponding
+/// read scopes.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ApiKeyScope {
+    PaymentsRead,
+    PaymentsWrite,
+    RefundsRead,
+    RefundsWrite,
+    CustomersRead,
+    CustomersWrite,
+    PaymentMethodsRead,
+    PaymentMethodsWrite,
+    MandatesRead,
+    MandatesWrite,
+    DisputesRead,
+    DisputesWrite,
+    PayoutsRead,
+    PayoutsWrite,
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::enums::FraudCheckStatus;
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FrmFulfillmentRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: String,
+    /// The identifier of the fulfillment (such as a shipment) of the order the payment was made for
+    #[schema(example = "shipment_1234")]
+    pub fulfillment_id: String,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct FrmFulfillmentResponse {
+    /// The identifier for the payment
+    pub payment_id: String,
+    /// The identifier of the fulfillment reported to the FRM connector
+    pub fulfillment_id: String,
+    /// Reference of the fraud check at the FRM connector
+    pub frm_reference_id: String,
+    /// Outcome of the fraud check after the fulfillment was reported
+    #[schema(value_type = FraudCheckStatus)]
+    pub status: FraudCheckStatus,
+}
+
+/// Decision of the merchant on a payment left for manual review by the FRM connector
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FrmReviewDecision {
+    /// The payment is let through, as if the FRM connector had considered it legitimate
+    Approve,
+    /// The payment is reversed, by voiding an authorized payment or refunding a captured one
+    Reject,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FrmReviewRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: String,
+    /// Decision of the merchant on the payment
+    pub decision: FrmReviewDecision,
+}
@@ -0,0 +1,32 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Healthy,
+    Unhealthy,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RouterHealthCheckResponse {
+    /// Healthy only if all the components are healthy
+    pub status: HealthStatus,
+    pub database_master: ComponentHealth,
+    pub database_replica: ComponentHealth,
+    pub redis: ComponentHealth,
+    pub locker: ComponentHealth,
+    /// Not present if the scheduler is not configured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduler_stream: Option<ComponentHealth>,
+    pub drainer_streams: ComponentHealth,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ComponentHealth {
+    pub status: HealthStatus,
+    /// Time taken to check the component, in milliseconds
+    pub latency_ms: u128,
+    /// Number of entries in the stream(s) yet to be processed, for stream components
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_entries: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
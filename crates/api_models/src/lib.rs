@@ -10,9 +10,12 @@ pub mod ephemeral_key;
 #[cfg(feature = "errors")]
 pub mod errors;
 pub mod files;
+pub mod fraud_check;
+pub mod health_check;
 pub mod mandates;
 pub mod payment_methods;
 pub mod payments;
 pub mod payouts;
 pub mod refunds;
+pub mod webhook_events;
 pub mod webhooks;
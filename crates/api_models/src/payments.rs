@@ -391,6 +391,12 @@ pub struct MandateAmountData {
     #[schema(example = "2023-09-10T23:59:59Z")]
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub end_date: Option<PrimitiveDateTime>,
+    /// The maximum amount that can be debited in a single payment using the mandate
+    #[schema(example = 1000)]
+    pub max_amount_per_payment: Option<i64>,
+    /// The maximum number of payments that can be made using the mandate
+    #[schema(example = 12)]
+    pub usage_limit: Option<i32>,
     /// Additional details required by mandate
     #[schema(value_type = Option<Object>, example = r#"{
         "frequency": "DAILY"
@@ -1169,6 +1175,83 @@ pub struct ReceiverDetails {
     amount_remaining: Option<i64>,
 }
 
+#[derive(Clone, Debug, PartialEq, serde::Serialize, ToSchema)]
+pub struct PaymentAttemptResponse {
+    /// Unique identifier for the attempt
+    #[schema(example = "2a1b4c8d9e0f4a6b8c2d4e6f8a0b2c4d")]
+    pub attempt_id: String,
+
+    /// The status of the attempt
+    #[schema(value_type = AttemptStatus, example = "charged")]
+    pub status: api_enums::AttemptStatus,
+
+    /// The attempt amount, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: i64,
+
+    /// The connector used for the attempt
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+
+    /// The connector's reference for the attempt
+    pub connector_transaction_id: Option<String>,
+
+    /// Error code received from the connector, if the attempt failed
+    pub error_code: Option<String>,
+
+    /// Error message received from the connector, if the attempt failed
+    pub error_message: Option<String>,
+
+    /// Time at which the attempt was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, ToSchema)]
+pub struct CaptureResponse {
+    /// Unique identifier for the capture
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1_capture_1")]
+    pub capture_id: String,
+
+    /// The status of the capture
+    #[schema(value_type = CaptureStatus, example = "charged")]
+    pub status: api_enums::CaptureStatus,
+
+    /// The capture amount, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: i64,
+
+    /// The currency of the capture
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// The connector used for the capture
+    #[schema(example = "checkout")]
+    pub connector: String,
+
+    /// The attempt whose authorization was captured
+    pub authorized_attempt_id: String,
+
+    /// The position of this capture among the captures made against the authorization
+    #[schema(example = 1)]
+    pub capture_sequence: i16,
+
+    /// The connector's reference for the capture
+    pub connector_capture_id: Option<String>,
+
+    /// Error code received from the connector, if the capture failed
+    pub error_code: Option<String>,
+
+    /// Error message received from the connector, if the capture failed
+    pub error_message: Option<String>,
+
+    /// Time at which the capture was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
 #[derive(Setter, Clone, Default, Debug, PartialEq, serde::Serialize, ToSchema)]
 pub struct PaymentsResponse {
     /// Unique identifier for the payment. This ensures idempotency for multiple payments
@@ -1234,6 +1317,14 @@ pub struct PaymentsResponse {
     #[schema(value_type = Option<Vec<DisputeResponsePaymentsRetrieve>>)]
     pub disputes: Option<Vec<disputes::DisputeResponsePaymentsRetrieve>>,
 
+    /// List of attempts made for this payment, including the ones retried on fallback connectors
+    #[schema(value_type = Option<Vec<PaymentAttemptResponse>>)]
+    pub attempts: Option<Vec<PaymentAttemptResponse>>,
+
+    /// List of captures made against the authorization, when the capture method is `manual_multiple`
+    #[schema(value_type = Option<Vec<CaptureResponse>>)]
+    pub captures: Option<Vec<CaptureResponse>>,
+
     /// A unique identifier to link the payment to a mandate, can be use instead of payment_method_data
     #[schema(max_length = 255, example = "mandate_iwer89rnjef349dni3")]
     pub mandate_id: Option<String>,
@@ -1587,6 +1678,9 @@ pub struct PaymentsRetrieveRequest {
     /// Merchant connector details used to make payments.
     #[schema(value_type = Option<MerchantConnectorDetailsWrap>)]
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
+    /// The identifier of the capture to retrieve, for payments captured in several parts
+    #[serde(skip)]
+    pub capture_id: Option<String>,
 }
 
 #[derive(Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -1 +1,232 @@
+use cards::CardNumber;
+use common_utils::{
+    custom_serde,
+    pii::{self, Email},
+};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
 
+use crate::{enums as api_enums, payments};
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutCreateRequest {
+    /// Unique identifier for the payout. This ensures idempotency for multiple payouts that have been done by a single merchant. If the identifier is not defined by the merchant, it will be auto generated and provided in the API response.
+    #[schema(
+        max_length = 30,
+        min_length = 30,
+        example = "payout_mbabizu24mvu3mela5njyhpit4"
+    )]
+    pub payout_id: Option<String>,
+
+    /// The payout amount. Amount for the payout in lowest denomination of the currency. (i.e) in cents for USD denomination, in paisa for INR denomination etc.
+    #[schema(minimum = 1, example = 1000)]
+    pub amount: i64,
+
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The identifier for the customer object who is receiving the payout
+    #[schema(max_length = 255, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<String>,
+
+    /// The connector through which the payout is to be processed. If not passed, the connector configured in the merchant's routing algorithm is used
+    #[schema(value_type = Option<Connector>, example = "adyen")]
+    pub connector: Option<api_enums::Connector>,
+
+    /// The type of the payout
+    #[schema(value_type = PayoutType, example = "card")]
+    pub payout_type: api_enums::PayoutType,
+
+    /// The details of the card or bank account the funds are paid out to
+    pub payout_method_data: PayoutMethodData,
+
+    /// The billing address of the recipient
+    pub billing: Option<payments::Address>,
+
+    /// Set to true to fulfill the payout right after it is created at the connector. If false, the payout has to be fulfilled explicitly
+    #[schema(default = false, example = true)]
+    pub auto_fulfill: Option<bool>,
+
+    /// The recipient's email address
+    #[schema(value_type = Option<String>, max_length = 255, example = "johntest@test.com")]
+    pub email: Option<Email>,
+
+    /// The recipient's name
+    #[schema(value_type = Option<String>, max_length = 255, example = "John Test")]
+    pub name: Option<Secret<String>>,
+
+    /// A description of the payout
+    #[schema(max_length = 255, example = "Seller payout for order 42")]
+    pub description: Option<String>,
+
+    /// The URL to redirect the recipient to after the payout is processed
+    #[schema(example = "https://hyperswitch.io")]
+    pub return_url: Option<String>,
+
+    /// The business country of merchant for this payout
+    #[schema(value_type = Option<CountryAlpha2>, example = "US")]
+    pub business_country: Option<api_enums::CountryAlpha2>,
+
+    /// The business label of merchant for this payout
+    #[schema(example = "food")]
+    pub business_label: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutMethodData {
+    Card(CardPayout),
+    Bank(BankPayout),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CardPayout {
+    /// The card number
+    #[schema(value_type = String, example = "4111111111111111")]
+    pub card_number: CardNumber,
+
+    /// The card's expiry month
+    #[schema(value_type = String, example = "03")]
+    pub expiry_month: Secret<String>,
+
+    /// The card's expiry year
+    #[schema(value_type = String, example = "2030")]
+    pub expiry_year: Secret<String>,
+
+    /// The card holder's name
+    #[schema(value_type = String, example = "John Test")]
+    pub card_holder_name: Secret<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct BankPayout {
+    /// International Bank Account Number (iban)
+    #[schema(value_type = String, example = "NL46TEST0136169112")]
+    pub iban: Secret<String>,
+
+    /// Bank Identifier Code (bic)
+    #[schema(value_type = Option<String>, example = "ABNANL2A")]
+    pub bic: Option<Secret<String>>,
+
+    /// The name of the account holder
+    #[schema(value_type = String, example = "John Test")]
+    pub account_holder_name: Secret<String>,
+
+    /// The name of the bank
+    #[schema(example = "Deutsche Bank")]
+    pub bank_name: Option<String>,
+
+    /// The country in which the bank account is held
+    #[schema(value_type = Option<CountryAlpha2>, example = "NL")]
+    pub bank_country_code: Option<api_enums::CountryAlpha2>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutUpdateRequest {
+    /// A description of the payout
+    #[schema(max_length = 255, example = "Seller payout for order 42")]
+    pub description: Option<String>,
+
+    /// The URL to redirect the recipient to after the payout is processed
+    #[schema(example = "https://hyperswitch.io")]
+    pub return_url: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize)]
+pub struct PayoutRetrieveBody {
+    pub force_sync: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize, ToSchema)]
+pub struct PayoutRetrieveRequest {
+    /// The identifier for the payout
+    pub payout_id: String,
+
+    /// `force_sync` with the connector to get the latest status of the payout
+    /// (defaults to false)
+    pub force_sync: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize, ToSchema)]
+pub struct PayoutActionRequest {
+    /// The identifier for the payout
+    pub payout_id: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, ToSchema)]
+pub struct PayoutResponse {
+    /// The identifier for the payout
+    pub payout_id: String,
+
+    /// The identifier for the merchant account
+    pub merchant_id: String,
+
+    /// The payout amount in the lowest denomination of the currency
+    pub amount: i64,
+
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The identifier for the customer receiving the payout
+    pub customer_id: Option<String>,
+
+    /// The connector used for the payout
+    #[schema(example = "adyen")]
+    pub connector: Option<String>,
+
+    /// The identifier of the payout at the connector
+    pub connector_payout_id: Option<String>,
+
+    /// The type of the payout
+    #[schema(value_type = PayoutType)]
+    pub payout_type: api_enums::PayoutType,
+
+    /// The current status of the payout
+    #[schema(value_type = PayoutStatus)]
+    pub status: api_enums::PayoutStatus,
+
+    /// Whether the payout is fulfilled right after it is created
+    pub auto_fulfill: bool,
+
+    /// A description of the payout
+    pub description: Option<String>,
+
+    /// The URL to redirect the recipient to after the payout is processed
+    pub return_url: Option<String>,
+
+    /// The business country of merchant for this payout
+    #[schema(value_type = Option<CountryAlpha2>)]
+    pub business_country: Option<api_enums::CountryAlpha2>,
+
+    /// The business label of merchant for this payout
+    pub business_label: Option<String>,
+
+    /// Additional information attached to the payout
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// The error code returned by the connector, if the payout failed
+    pub error_code: Option<String>,
+
+    /// The error message returned by the connector, if the payout failed
+    pub error_message: Option<String>,
+
+    /// The timestamp at which the payout was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums::{EventClass, EventObjectType, EventType};
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EventListConstraints {
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// type of the event
+    pub event_type: Option<EventType>,
+    /// class of the event
+    pub event_class: Option<EventClass>,
+    /// identifier of the object (payment, refund, dispute or payout) the event was raised for
+    pub object_id: Option<String>,
+    /// whether the merchant has acknowledged the webhook of the event
+    pub is_webhook_notified: Option<bool>,
+    /// Time less than the event creation time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(rename = "created.lt")]
+    pub created_lt: Option<PrimitiveDateTime>,
+    /// Time greater than the event creation time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(rename = "created.gt")]
+    pub created_gt: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventResponse {
+    /// The identifier for the event
+    pub event_id: String,
+    /// The identifier for the merchant account
+    pub merchant_id: Option<String>,
+    /// Type of the event
+    pub event_type: EventType,
+    /// Class of the event
+    pub event_class: EventClass,
+    /// Whether the merchant has acknowledged the webhook of the event
+    pub is_webhook_notified: bool,
+    /// Identifier of the object (payment, refund, dispute or payout) the event was raised for
+    pub object_id: String,
+    /// Type of the object the event was raised for
+    pub object_type: EventObjectType,
+    /// Identifier of the payment the object belongs to
+    pub intent_reference_id: Option<String>,
+    /// Time at which the event was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The attempts made at delivering the webhook of the event to the merchant
+    pub delivery_attempts: Option<Vec<EventDeliveryAttemptResponse>>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventDeliveryAttemptResponse {
+    /// The identifier for the delivery attempt
+    pub attempt_id: String,
+    /// The body of the webhook sent to the merchant
+    pub request_body: String,
+    /// HTTP status code returned by the merchant endpoint
+    pub response_status_code: Option<u16>,
+    /// Excerpt of the body returned by the merchant endpoint
+    pub response_body: Option<String>,
+    /// Time taken by the merchant endpoint to respond, in milliseconds
+    pub latency_ms: Option<i64>,
+    /// Reason the webhook could not be delivered, if the merchant endpoint could not be reached
+    pub error_message: Option<String>,
+    /// Whether the attempt was triggered manually
+    pub is_manual_retry: bool,
+    /// Time at which the attempt was made
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
@@ -2,7 +2,7 @@ use common_utils::custom_serde;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
-use crate::{disputes, enums as api_enums, payments, refunds};
+use crate::{disputes, enums as api_enums, mandates, payments, payouts, refunds};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -26,6 +26,12 @@ pub enum IncomingWebhookEvent {
     DisputeWon,
     // dispute has been unsuccessfully challenged
     DisputeLost,
+    MandateActive,
+    MandateRevoked,
+    PayoutSuccess,
+    PayoutFailure,
+    PayoutCancelled,
+    PayoutReversed,
     EndpointVerification,
 }
 
@@ -36,6 +42,8 @@ pub enum WebhookFlow {
     Subscription,
     ReturnResponse,
     BankTransfer,
+    Mandate,
+    Payout,
 }
 
 impl From<IncomingWebhookEvent> for WebhookFlow {
@@ -56,6 +64,12 @@ impl From<IncomingWebhookEvent> for WebhookFlow {
             IncomingWebhookEvent::DisputeChallenged => Self::Dispute,
             IncomingWebhookEvent::DisputeWon => Self::Dispute,
             IncomingWebhookEvent::DisputeLost => Self::Dispute,
+            IncomingWebhookEvent::MandateActive => Self::Mandate,
+            IncomingWebhookEvent::MandateRevoked => Self::Mandate,
+            IncomingWebhookEvent::PayoutSuccess
+            | IncomingWebhookEvent::PayoutFailure
+            | IncomingWebhookEvent::PayoutCancelled
+            | IncomingWebhookEvent::PayoutReversed => Self::Payout,
             IncomingWebhookEvent::EndpointVerification => Self::ReturnResponse,
             IncomingWebhookEvent::SourceChargeable
             | IncomingWebhookEvent::SourceTransactionCreated => Self::BankTransfer,
@@ -70,9 +84,16 @@ pub enum RefundIdType {
     ConnectorRefundId(String),
 }
 
+pub enum MandateIdType {
+    MandateId(String),
+    ConnectorMandateId(String),
+}
+
 pub enum ObjectReferenceId {
     PaymentId(payments::PaymentIdType),
     RefundId(RefundIdType),
+    MandateId(MandateIdType),
+    PayoutId(String),
 }
 
 pub struct IncomingWebhookDetails {
@@ -96,6 +117,8 @@ pub enum OutgoingWebhookContent {
     PaymentDetails(payments::PaymentsResponse),
     RefundDetails(refunds::RefundResponse),
     DisputeDetails(Box<disputes::DisputeResponse>),
+    PayoutDetails(payouts::PayoutResponse),
+    MandateDetails(Box<mandates::MandateResponse>),
 }
 
 pub trait OutgoingWebhookType:
@@ -408,6 +408,20 @@ impl<T: Clone> Encryptable<T> {
     pub fn into_encrypted(self) -> Vec<u8> {
         self.encrypted
     }
+
+    ///
+    /// Get the inner encrypted data
+    ///
+    pub fn get_encrypted(&self) -> &[u8] {
+        &self.encrypted
+    }
+
+    ///
+    /// Get the inner data and the encrypted data while consuming self
+    ///
+    pub fn deconstruct(self) -> (T, Vec<u8>) {
+        (self.inner, self.encrypted)
+    }
 }
 
 impl<T: Clone> Deref for Encryptable<masking::Secret<T>> {
@@ -1,7 +1,7 @@
 use bb8::PooledConnection;
 use diesel::PgConnection;
-#[cfg(feature = "kms")]
-use external_services::kms;
+#[cfg(feature = "secrets_management")]
+use external_services::secrets_management;
 
 use crate::settings::Database;
 
@@ -20,16 +20,18 @@ pub async fn redis_connection(
 pub async fn diesel_make_pg_pool(
     database: &Database,
     _test_transaction: bool,
-    #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
+    #[cfg(feature = "secrets_management")]
+    secrets_management_config: &secrets_management::SecretsManagementConfig,
 ) -> PgPool {
-    #[cfg(feature = "kms")]
-    let password = kms::get_kms_client(kms_config)
+    #[cfg(feature = "secrets_management")]
+    let password = secrets_management::get_secret_manager(secrets_management_config)
         .await
+        .expect("Failed to initialize secret manager")
         .decrypt(&database.kms_encrypted_password)
         .await
-        .expect("Failed to KMS decrypt database password");
+        .expect("Failed to decrypt database password");
 
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "secrets_management"))]
     let password = &database.password;
 
     let database_url = format!(
@@ -0,0 +1,133 @@
+//! Stream entries whose DB operation could not be executed are moved to a dead letter stream
+//! per shard, instead of being dropped when the drainer stream is trimmed. The entries can later
+//! be inspected and moved back to the drainer stream of the shard.
+
+use std::sync::Arc;
+
+use error_stack::IntoReport;
+use redis_interface as redis;
+
+use crate::{
+    errors::{self, DrainerError},
+    logger, metrics, services, utils,
+};
+
+pub const TYPED_SQL_FIELD: &str = "typed_sql";
+const REASON_FIELD: &str = "reason";
+const RETRY_COUNT_FIELD: &str = "retry_count";
+const SOURCE_STREAM_FIELD: &str = "source_stream";
+const SOURCE_ENTRY_ID_FIELD: &str = "source_entry_id";
+const FAILED_AT_FIELD: &str = "failed_at";
+
+#[derive(Debug)]
+pub struct FailedEntry {
+    pub reason: String,
+    pub retry_count: u8,
+}
+
+pub async fn push_to_dead_letter_stream(
+    redis: &redis::RedisConnectionPool,
+    dead_letter_stream_name: &str,
+    stream_name: &str,
+    entry_id: &str,
+    typed_sql: String,
+    failure: FailedEntry,
+) -> errors::DrainerResult<()> {
+    let fields = vec![
+        (TYPED_SQL_FIELD, typed_sql),
+        (REASON_FIELD, failure.reason),
+        (RETRY_COUNT_FIELD, failure.retry_count.to_string()),
+        (SOURCE_STREAM_FIELD, stream_name.to_owned()),
+        (SOURCE_ENTRY_ID_FIELD, entry_id.to_owned()),
+        (
+            FAILED_AT_FIELD,
+            common_utils::date_time::now_unix_timestamp().to_string(),
+        ),
+    ];
+
+    redis
+        .stream_append_entry(
+            dead_letter_stream_name,
+            &redis::RedisEntryId::AutoGeneratedID,
+            fields,
+        )
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+
+    metrics::ENTRIES_DEAD_LETTERED.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::KeyValue::new("stream", stream_name.to_owned())],
+    );
+
+    Ok(())
+}
+
+/// Returns up to `count` entries from the dead letter stream of the shard, oldest first
+pub async fn list_dead_letters(
+    store: Arc<services::Store>,
+    stream_index: u8,
+    count: u64,
+) -> errors::DrainerResult<utils::StreamEntries> {
+    let dead_letter_stream_name =
+        utils::get_drainer_dead_letter_stream_name(store.clone(), stream_index);
+    let mut stream_read = utils::read_from_stream(
+        dead_letter_stream_name.as_str(),
+        count,
+        store.redis_conn.as_ref(),
+    )
+    .await?;
+
+    Ok(stream_read
+        .remove(dead_letter_stream_name.as_str())
+        .unwrap_or_default())
+}
+
+/// Moves entries from the dead letter stream of the shard back to its drainer stream, so that the
+/// drainer picks them up again. Only the oldest `count` dead letters are considered, optionally
+/// narrowed down to the given entry ids. Returns the number of entries moved.
+pub async fn requeue_dead_letters(
+    store: Arc<services::Store>,
+    stream_index: u8,
+    entry_ids: &[String],
+    count: u64,
+) -> errors::DrainerResult<usize> {
+    let stream_name = utils::get_drainer_stream_name(store.clone(), stream_index);
+    let dead_letter_stream_name =
+        utils::get_drainer_dead_letter_stream_name(store.clone(), stream_index);
+    let dead_letters = list_dead_letters(store.clone(), stream_index, count).await?;
+
+    let mut requeued_count = 0;
+    for (entry_id, entry) in dead_letters
+        .iter()
+        .filter(|(entry_id, _)| entry_ids.is_empty() || entry_ids.contains(entry_id))
+    {
+        let typed_sql = entry
+            .get(TYPED_SQL_FIELD)
+            .map_or(String::new(), Clone::clone);
+
+        store
+            .redis_conn
+            .stream_append_entry(
+                stream_name.as_str(),
+                &redis::RedisEntryId::AutoGeneratedID,
+                vec![(TYPED_SQL_FIELD, typed_sql)],
+            )
+            .await
+            .map_err(DrainerError::from)
+            .into_report()?;
+
+        store
+            .redis_conn
+            .stream_delete_entries(dead_letter_stream_name.as_str(), entry_id.as_str())
+            .await
+            .map_err(DrainerError::from)
+            .into_report()?;
+
+        logger::info!(stream = %stream_name, %entry_id, "Requeued dead letter entry");
+        requeued_count += 1;
+    }
+
+    Ok(requeued_count)
+}
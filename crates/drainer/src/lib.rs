@@ -1,4 +1,5 @@
 mod connection;
+pub mod dead_letter;
 pub mod env;
 pub mod errors;
 pub(crate) mod metrics;
@@ -99,7 +100,17 @@ async fn drainer_handler(
     active_tasks.fetch_add(1, atomic::Ordering::Release);
 
     let stream_name = utils::get_drainer_stream_name(store.clone(), stream_index);
-    let drainer_result = drainer(store.clone(), max_read_count, stream_name.as_str()).await;
+    let dead_letter_stream_name =
+        utils::get_drainer_dead_letter_stream_name(store.clone(), stream_index);
+    let retry_counts_key = utils::get_drainer_retry_counts_key(store.clone(), stream_index);
+    let drainer_result = drainer(
+        store.clone(),
+        max_read_count,
+        stream_name.as_str(),
+        dead_letter_stream_name.as_str(),
+        retry_counts_key.as_str(),
+    )
+    .await;
 
     if let Err(error) = drainer_result {
         logger::error!(?error)
@@ -117,12 +128,14 @@ async fn drainer(
     store: Arc<Store>,
     max_read_count: u64,
     stream_name: &str,
+    dead_letter_stream_name: &str,
+    retry_counts_key: &str,
 ) -> errors::DrainerResult<()> {
     let stream_read =
         utils::read_from_stream(stream_name, max_read_count, store.redis_conn.as_ref()).await?; // this returns the error.
 
     // parse_stream_entries returns error if no entries is found, handle it
-    let (entries, last_entry_id) = utils::parse_stream_entries(&stream_read, stream_name)?;
+    let (entries, _) = utils::parse_stream_entries(&stream_read, stream_name)?;
     let read_count = entries.len();
 
     metrics::JOBS_PICKED_PER_STREAM.add(
@@ -134,113 +147,375 @@ async fn drainer(
         }],
     );
 
-    // TODO: Handle errors when deserialization fails and when DB error occurs
-    for entry in entries {
-        let typed_sql = entry.1.get("typed_sql").map_or(String::new(), Clone::clone);
-        let result = serde_json::from_str::<kv::DBOperation>(&typed_sql);
-        let db_op = match result {
-            Ok(f) => f,
-            Err(_err) => continue, // TODO: handle error
-        };
+    let mut processed_count = 0;
+    let mut last_processed_entry_id = None;
+    for (entry_id, entry) in entries {
+        let typed_sql = entry
+            .get(dead_letter::TYPED_SQL_FIELD)
+            .map_or(String::new(), Clone::clone);
 
-        let conn = pg_connection(&store.master_pool).await;
-        let insert_op = "insert";
-        let update_op = "update";
-        let payment_intent = "payment_intent";
-        let payment_attempt = "payment_attempt";
-        let refund = "refund";
-        match db_op {
-            // TODO: Handle errors
-            kv::DBOperation::Insert { insertable } => {
-                let (_, execution_time) = common_utils::date_time::time_it(|| async {
-                    match insertable {
-                        kv::Insertable::PaymentIntent(a) => {
-                            macro_util::handle_resp!(
-                                a.insert(&conn).await,
-                                insert_op,
-                                payment_intent
-                            )
-                        }
-                        kv::Insertable::PaymentAttempt(a) => {
-                            macro_util::handle_resp!(
-                                a.insert(&conn).await,
-                                insert_op,
-                                payment_attempt
-                            )
-                        }
-                        kv::Insertable::Refund(a) => {
-                            macro_util::handle_resp!(a.insert(&conn).await, insert_op, refund)
-                        }
-                    }
-                })
-                .await;
-                metrics::QUERY_EXECUTION_TIME.record(
-                    &metrics::CONTEXT,
-                    execution_time,
-                    &[metrics::KeyValue {
-                        key: "operation".into(),
-                        value: insert_op.into(),
-                    }],
-                );
-            }
-            kv::DBOperation::Update { updatable } => {
-                let (_, execution_time) = common_utils::date_time::time_it(|| async {
-                    match updatable {
-                        kv::Updateable::PaymentIntentUpdate(a) => {
-                            macro_util::handle_resp!(
-                                a.orig.update(&conn, a.update_data).await,
-                                update_op,
-                                payment_intent
-                            )
-                        }
-                        kv::Updateable::PaymentAttemptUpdate(a) => {
-                            macro_util::handle_resp!(
-                                a.orig.update_with_attempt_id(&conn, a.update_data).await,
-                                update_op,
-                                payment_attempt
-                            )
-                        }
-                        kv::Updateable::RefundUpdate(a) => {
-                            macro_util::handle_resp!(
-                                a.orig.update(&conn, a.update_data).await,
-                                update_op,
-                                refund
-                            )
-                        }
-                    }
-                })
-                .await;
-                metrics::QUERY_EXECUTION_TIME.record(
-                    &metrics::CONTEXT,
-                    execution_time,
-                    &[metrics::KeyValue {
-                        key: "operation".into(),
-                        value: update_op.into(),
-                    }],
-                );
+        if let Err(failure) =
+            execute_db_operation_with_retries(&store, retry_counts_key, entry_id, &typed_sql).await
+        {
+            logger::error!(
+                stream = %stream_name,
+                %entry_id,
+                reason = %failure.reason,
+                retry_count = failure.retry_count,
+                "Moving entry to the dead letter stream"
+            );
+
+            let was_retried = failure.retry_count > 0;
+            // The entry must not be trimmed from the stream unless it is safely dead lettered
+            if let Err(error) = dead_letter::push_to_dead_letter_stream(
+                store.redis_conn.as_ref(),
+                dead_letter_stream_name,
+                stream_name,
+                entry_id,
+                typed_sql,
+                failure,
+            )
+            .await
+            {
+                logger::error!(?error, "Failed to move entry to the dead letter stream");
+                break;
             }
-            kv::DBOperation::Delete => {
-                // [#224]: Implement this
-                logger::error!("Not implemented!");
+
+            if was_retried {
+                utils::clear_retry_count(store.redis_conn.as_ref(), retry_counts_key, entry_id)
+                    .await;
             }
-        };
+        }
+
+        processed_count += 1;
+        last_processed_entry_id = Some(entry_id);
     }
 
-    let entries_trimmed =
-        utils::trim_from_stream(stream_name, last_entry_id.as_str(), &store.redis_conn).await?;
+    let last_processed_entry_id = match last_processed_entry_id {
+        Some(entry_id) => entry_id,
+        None => return Ok(()),
+    };
 
-    if read_count != entries_trimmed {
+    let entries_trimmed = utils::trim_from_stream(
+        stream_name,
+        last_processed_entry_id.as_str(),
+        &store.redis_conn,
+    )
+    .await?;
+
+    if processed_count != entries_trimmed {
         logger::error!(
             read_entries = %read_count,
+            processed_entries = %processed_count,
             trimmed_entries = %entries_trimmed,
             ?entries,
-            "Assertion Failed no. of entries read from the stream doesn't match no. of entries trimmed"
+            "Assertion Failed no. of entries processed from the stream doesn't match no. of entries trimmed"
         );
     }
 
     Ok(())
 }
 
+/// Executes the DB operation of a stream entry, retrying failures which could be transient up to
+/// the configured number of times with an exponential backoff. The number of retries made is
+/// stored against the entry id, so that a restart of the drainer does not reset it.
+async fn execute_db_operation_with_retries(
+    store: &Store,
+    retry_counts_key: &str,
+    entry_id: &str,
+    typed_sql: &str,
+) -> Result<(), dead_letter::FailedEntry> {
+    // The stored retry count is only looked up once the entry fails, to spare a round trip to
+    // Redis for every entry which is executed successfully at the first attempt
+    let mut retry_count = None;
+    loop {
+        let db_op = serde_json::from_str::<kv::DBOperation>(typed_sql).map_err(|error| {
+            dead_letter::FailedEntry {
+                reason: format!("Failed to deserialize the stream entry: {error}"),
+                retry_count: retry_count.unwrap_or_default(),
+            }
+        })?;
+
+        let error = match execute_db_operation(store, db_op).await {
+            Ok(()) => {
+                if retry_count.is_some() {
+                    utils::clear_retry_count(store.redis_conn.as_ref(), retry_counts_key, entry_id)
+                        .await;
+                }
+                return Ok(());
+            }
+            Err(error) => error,
+        };
+
+        let count = match retry_count {
+            Some(count) => count,
+            None => {
+                utils::get_retry_count(store.redis_conn.as_ref(), retry_counts_key, entry_id).await
+            }
+        };
+
+        if !is_retryable(error.current_context()) || count >= store.config.drainer_max_retries {
+            return Err(dead_letter::FailedEntry {
+                reason: error.current_context().to_string(),
+                retry_count: count,
+            });
+        }
+
+        let count = count + 1;
+        retry_count = Some(count);
+        utils::set_retry_count(store.redis_conn.as_ref(), retry_counts_key, entry_id, count).await;
+
+        let backoff = retry_backoff(&store.config, count);
+        logger::warn!(
+            ?error,
+            retry_count = count,
+            backoff_ms = backoff.as_millis(),
+            "Retrying failed DB operation"
+        );
+        metrics::QUERY_EXECUTION_RETRIES.add(&metrics::CONTEXT, 1, &[]);
+        tokio::time::sleep(backoff).await;
+    }
+}
+
+/// The delay before the given retry, doubled for every retry made before it
+fn retry_backoff(config: &services::StoreConfig, retry_count: u8) -> std::time::Duration {
+    let exponent = u32::from(retry_count.saturating_sub(1));
+    let backoff = 2u32
+        .checked_pow(exponent)
+        .and_then(|multiplier| config.drainer_retry_backoff.checked_mul(multiplier))
+        .unwrap_or(u32::MAX)
+        .min(config.drainer_max_retry_backoff);
+
+    std::time::Duration::from_millis(backoff.into())
+}
+
+/// Unique violations and query generation failures fail the same way every time they are executed
+fn is_retryable(error: &storage_models::errors::DatabaseError) -> bool {
+    !matches!(
+        error,
+        storage_models::errors::DatabaseError::UniqueViolation
+            | storage_models::errors::DatabaseError::QueryGenerationFailed
+    )
+}
+
+async fn execute_db_operation(
+    store: &Store,
+    db_op: kv::DBOperation,
+) -> storage_models::StorageResult<()> {
+    let conn = pg_connection(&store.master_pool).await;
+    let insert_op = "insert";
+    let update_op = "update";
+    let delete_op = "delete";
+    let payment_intent = "payment_intent";
+    let payment_attempt = "payment_attempt";
+    let refund = "refund";
+    let connector_response = "connector_response";
+    let address = "address";
+    let dispute = "dispute";
+    let reverse_lookup = "reverse_lookup";
+    match db_op {
+        kv::DBOperation::Insert { insertable } => {
+            let (result, execution_time) = common_utils::date_time::time_it(|| async {
+                match insertable {
+                    kv::Insertable::PaymentIntent(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, payment_intent)
+                    }
+                    kv::Insertable::PaymentAttempt(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, payment_attempt)
+                    }
+                    kv::Insertable::Refund(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, refund)
+                    }
+                    kv::Insertable::ConnectorResponse(a) => {
+                        macro_util::handle_resp!(
+                            a.insert(&conn).await,
+                            insert_op,
+                            connector_response
+                        )
+                    }
+                    kv::Insertable::Address(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, address)
+                    }
+                    kv::Insertable::Dispute(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, dispute)
+                    }
+                    kv::Insertable::ReverseLookup(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, reverse_lookup)
+                    }
+                }
+            })
+            .await;
+            metrics::QUERY_EXECUTION_TIME.record(
+                &metrics::CONTEXT,
+                execution_time,
+                &[metrics::KeyValue {
+                    key: "operation".into(),
+                    value: insert_op.into(),
+                }],
+            );
+            result
+        }
+        kv::DBOperation::Update { updatable } => {
+            let (result, execution_time) = common_utils::date_time::time_it(|| async {
+                match updatable {
+                    kv::Updateable::PaymentIntentUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update(&conn, a.update_data).await,
+                            update_op,
+                            payment_intent
+                        )
+                    }
+                    kv::Updateable::PaymentAttemptUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update_with_attempt_id(&conn, a.update_data).await,
+                            update_op,
+                            payment_attempt
+                        )
+                    }
+                    kv::Updateable::RefundUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update(&conn, a.update_data).await,
+                            update_op,
+                            refund
+                        )
+                    }
+                    kv::Updateable::ConnectorResponseUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update(&conn, a.update_data).await,
+                            update_op,
+                            connector_response
+                        )
+                    }
+                    kv::Updateable::AddressUpdate(a) => {
+                        macro_util::handle_resp!(
+                            storage_models::address::Address::update_by_address_id(
+                                &conn,
+                                a.orig.address_id,
+                                a.update_data
+                            )
+                            .await,
+                            update_op,
+                            address
+                        )
+                    }
+                    kv::Updateable::DisputeUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update(&conn, a.update_data).await,
+                            update_op,
+                            dispute
+                        )
+                    }
+                }
+            })
+            .await;
+            metrics::QUERY_EXECUTION_TIME.record(
+                &metrics::CONTEXT,
+                execution_time,
+                &[metrics::KeyValue {
+                    key: "operation".into(),
+                    value: update_op.into(),
+                }],
+            );
+            result
+        }
+        kv::DBOperation::Delete { deletable } => {
+            let (result, execution_time) = common_utils::date_time::time_it(|| async {
+                match deletable {
+                    kv::Deletable::ConnectorResponse {
+                        merchant_id,
+                        payment_id,
+                        attempt_id,
+                    } => {
+                        macro_util::handle_resp!(
+                            allow_deleted(
+                                storage_models::connector_response::ConnectorResponse::delete_by_payment_id_merchant_id_attempt_id(
+                                    &conn,
+                                    &payment_id,
+                                    &merchant_id,
+                                    &attempt_id,
+                                )
+                                .await
+                            ),
+                            delete_op,
+                            connector_response
+                        )
+                    }
+                    kv::Deletable::Address { address_id } => {
+                        macro_util::handle_resp!(
+                            allow_deleted(
+                                storage_models::address::Address::delete_by_address_id(
+                                    &conn,
+                                    &address_id
+                                )
+                                .await
+                            ),
+                            delete_op,
+                            address
+                        )
+                    }
+                    kv::Deletable::Dispute {
+                        merchant_id,
+                        dispute_id,
+                    } => {
+                        macro_util::handle_resp!(
+                            allow_deleted(
+                                storage_models::dispute::Dispute::delete_by_merchant_id_dispute_id(
+                                    &conn,
+                                    &merchant_id,
+                                    &dispute_id
+                                )
+                                .await
+                            ),
+                            delete_op,
+                            dispute
+                        )
+                    }
+                    kv::Deletable::ReverseLookup { lookup_id } => {
+                        macro_util::handle_resp!(
+                            allow_deleted(
+                                storage_models::reverse_lookup::ReverseLookup::delete_by_lookup_id(
+                                    &lookup_id, &conn
+                                )
+                                .await
+                            ),
+                            delete_op,
+                            reverse_lookup
+                        )
+                    }
+                }
+            })
+            .await;
+            metrics::QUERY_EXECUTION_TIME.record(
+                &metrics::CONTEXT,
+                execution_time,
+                &[metrics::KeyValue {
+                    key: "operation".into(),
+                    value: delete_op.into(),
+                }],
+            );
+            result
+        }
+    }
+}
+
+/// A row which no longer exists, such as one deleted by an earlier execution of the same entry
+/// which was not trimmed from the stream, is considered deleted
+fn allow_deleted(
+    result: storage_models::StorageResult<bool>,
+) -> storage_models::StorageResult<bool> {
+    match result {
+        Err(error)
+            if matches!(
+                error.current_context(),
+                storage_models::errors::DatabaseError::NotFound
+            ) =>
+        {
+            Ok(false)
+        }
+        result => result,
+    }
+}
+
 mod macro_util {
 
     macro_rules! handle_resp {
@@ -254,6 +529,7 @@ mod macro_util {
                             value: $table.into(),
                         }
                     ]);
+                    Ok(())
                 }
                 Err(err) => {
                     logger::error!(operation = %$op_type, table = %$table, ?err);
@@ -263,9 +539,56 @@ mod macro_util {
                             value: $table.into(),
                         }
                     ]);
+                    Err(err)
                 }
             }
         };
     }
     pub(crate) use handle_resp;
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_delete_operation_is_read_from_typed_sql() {
+        let typed_sql = kv::TypedSql {
+            op: kv::DBOperation::Delete {
+                deletable: kv::Deletable::Dispute {
+                    merchant_id: "merchant_1".to_string(),
+                    dispute_id: "dp_1".to_string(),
+                },
+            },
+        };
+        let field_value_pairs = typed_sql.to_field_value_pairs().unwrap();
+        let (field, value) = field_value_pairs.first().unwrap();
+        assert_eq!(*field, dead_letter::TYPED_SQL_FIELD);
+
+        let db_op = serde_json::from_str::<kv::DBOperation>(value).unwrap();
+        assert!(matches!(
+            db_op,
+            kv::DBOperation::Delete {
+                deletable: kv::Deletable::Dispute {
+                    merchant_id,
+                    dispute_id,
+                },
+            } if merchant_id == "merchant_1" && dispute_id == "dp_1"
+        ));
+    }
+
+    #[test]
+    fn test_deleting_a_deleted_row_succeeds() {
+        let not_found: storage_models::StorageResult<bool> = Err(error_stack::report!(
+            storage_models::errors::DatabaseError::NotFound
+        ));
+        assert!(!allow_deleted(not_found).unwrap());
+
+        let failed: storage_models::StorageResult<bool> = Err(error_stack::report!(
+            storage_models::errors::DatabaseError::Others
+        ));
+        assert!(allow_deleted(failed).is_err());
+    }
+}
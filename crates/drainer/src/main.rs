@@ -1,4 +1,6 @@
-use drainer::{errors::DrainerResult, logger::logger, services, settings, start_drainer};
+use drainer::{
+    dead_letter, errors::DrainerResult, logger::logger, services, settings, start_drainer,
+};
 
 #[tokio::main]
 async fn main() -> DrainerResult<()> {
@@ -22,6 +24,10 @@ async fn main() -> DrainerResult<()> {
 
     let _guard = logger::setup(&conf.log);
 
+    if let Some(subcommand) = cmd_line.subcommand {
+        return run_subcommand(store, subcommand).await;
+    }
+
     logger::info!("Drainer started [{:?}] [{:?}]", conf.drainer, conf.log);
 
     start_drainer(
@@ -35,3 +41,31 @@ async fn main() -> DrainerResult<()> {
 
     Ok(())
 }
+
+async fn run_subcommand(
+    store: std::sync::Arc<services::Store>,
+    subcommand: settings::Subcommand,
+) -> DrainerResult<()> {
+    match subcommand {
+        settings::Subcommand::ListDeadLetters { shard, count } => {
+            let dead_letters = dead_letter::list_dead_letters(store, shard, count).await?;
+            for (entry_id, entry) in dead_letters {
+                println!(
+                    "{}",
+                    serde_json::json!({ "entry_id": entry_id, "fields": entry })
+                );
+            }
+        }
+        settings::Subcommand::RequeueDeadLetters {
+            shard,
+            count,
+            entry_ids,
+        } => {
+            let requeued_count =
+                dead_letter::requeue_dead_letters(store, shard, &entry_ids, count).await?;
+            println!("Requeued {requeued_count} dead letter entries of shard {shard}");
+        }
+    }
+
+    Ok(())
+}
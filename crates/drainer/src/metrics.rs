@@ -9,6 +9,8 @@ counter_metric!(CYCLES_COMPLETED_SUCCESSFULLY, DRAINER_METER);
 counter_metric!(CYCLES_COMPLETED_UNSUCCESSFULLY, DRAINER_METER);
 counter_metric!(ERRORS_WHILE_QUERY_EXECUTION, DRAINER_METER);
 counter_metric!(SUCCESSFUL_QUERY_EXECUTION, DRAINER_METER);
+counter_metric!(QUERY_EXECUTION_RETRIES, DRAINER_METER);
+counter_metric!(ENTRIES_DEAD_LETTERED, DRAINER_METER);
 counter_metric!(SHUTDOWN_SIGNAL_RECEIVED, DRAINER_METER);
 counter_metric!(SUCCESSFUL_SHUTDOWN, DRAINER_METER);
 
@@ -13,6 +13,9 @@ pub struct Store {
 pub struct StoreConfig {
     pub drainer_stream_name: String,
     pub drainer_num_partitions: u8,
+    pub drainer_max_retries: u8,
+    pub drainer_retry_backoff: u32,
+    pub drainer_max_retry_backoff: u32,
 }
 
 impl Store {
@@ -21,14 +24,17 @@ impl Store {
             master_pool: diesel_make_pg_pool(
                 &config.master_database,
                 test_transaction,
-                #[cfg(feature = "kms")]
-                &config.kms,
+                #[cfg(feature = "secrets_management")]
+                &config.secrets_management,
             )
             .await,
             redis_conn: Arc::new(crate::connection::redis_connection(config).await),
             config: StoreConfig {
                 drainer_stream_name: config.drainer.stream_name.clone(),
                 drainer_num_partitions: config.drainer.num_partitions,
+                drainer_max_retries: config.drainer.max_retries,
+                drainer_retry_backoff: config.drainer.retry_backoff,
+                drainer_max_retry_backoff: config.drainer.max_retry_backoff,
             },
         }
     }
@@ -37,4 +43,14 @@ impl Store {
         // Example: {shard_5}_drainer_stream
         format!("{{{}}}_{}", shard_key, self.config.drainer_stream_name,)
     }
+
+    pub fn drainer_dead_letter_stream(&self, shard_key: &str) -> String {
+        // Example: {shard_5}_drainer_stream_dead_letter
+        format!("{}_dead_letter", self.drainer_stream(shard_key))
+    }
+
+    pub fn drainer_retry_counts(&self, shard_key: &str) -> String {
+        // Example: {shard_5}_drainer_stream_retry_counts
+        format!("{}_retry_counts", self.drainer_stream(shard_key))
+    }
 }
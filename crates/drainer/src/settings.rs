@@ -4,6 +4,8 @@ use common_utils::ext_traits::ConfigExt;
 use config::{Environment, File};
 #[cfg(feature = "kms")]
 use external_services::kms;
+#[cfg(feature = "secrets_management")]
+use external_services::secrets_management;
 use redis_interface as redis;
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use router_env::{env, logger};
@@ -18,6 +20,34 @@ pub struct CmdLineConf {
     /// Application will look for "config/config.toml" if this option isn't specified.
     #[arg(short = 'f', long, value_name = "FILE")]
     pub config_path: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub subcommand: Option<Subcommand>,
+}
+
+#[derive(clap::Parser)]
+pub enum Subcommand {
+    /// List the entries in the dead letter stream of a shard.
+    ListDeadLetters {
+        /// Index of the shard.
+        #[arg(long)]
+        shard: u8,
+        /// Maximum number of entries to list.
+        #[arg(long, default_value_t = 100)]
+        count: u64,
+    },
+    /// Move entries from the dead letter stream of a shard back to its drainer stream.
+    RequeueDeadLetters {
+        /// Index of the shard.
+        #[arg(long)]
+        shard: u8,
+        /// Number of the oldest dead letters to consider.
+        #[arg(long, default_value_t = 100)]
+        count: u64,
+        /// Requeue only the entries with these ids, all considered entries are requeued otherwise.
+        #[arg(long = "entry-id")]
+        entry_ids: Vec<String>,
+    },
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -27,22 +57,26 @@ pub struct Settings {
     pub redis: redis::RedisSettings,
     pub log: Log,
     pub drainer: DrainerSettings,
+    /// Deprecated AWS KMS configuration, used when the AWS KMS backend is not configured in
+    /// `secrets_management`
     #[cfg(feature = "kms")]
     pub kms: kms::KmsConfig,
+    #[cfg(feature = "secrets_management")]
+    pub secrets_management: secrets_management::SecretsManagementConfig,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct Database {
     pub username: String,
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "secrets_management"))]
     pub password: String,
     pub host: String,
     pub port: u16,
     pub dbname: String,
     pub pool_size: u32,
     pub connection_timeout: u64,
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "secrets_management")]
     pub kms_encrypted_password: String,
 }
 
@@ -54,20 +88,23 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    pub max_retries: u8,
+    pub retry_backoff: u32,     // in milliseconds
+    pub max_retry_backoff: u32, // in milliseconds
 }
 
 impl Default for Database {
     fn default() -> Self {
         Self {
             username: String::new(),
-            #[cfg(not(feature = "kms"))]
+            #[cfg(not(feature = "secrets_management"))]
             password: String::new(),
             host: "localhost".into(),
             port: 5432,
             dbname: String::new(),
             pool_size: 5,
             connection_timeout: 10,
-            #[cfg(feature = "kms")]
+            #[cfg(feature = "secrets_management")]
             kms_encrypted_password: String::new(),
         }
     }
@@ -81,6 +118,9 @@ impl Default for DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000, // in milliseconds
             loop_interval: 500,      // in milliseconds
+            max_retries: 3,
+            retry_backoff: 100,      // in milliseconds
+            max_retry_backoff: 5000, // in milliseconds
         }
     }
 }
@@ -107,7 +147,7 @@ impl Database {
             ))
         })?;
 
-        #[cfg(not(feature = "kms"))]
+        #[cfg(not(feature = "secrets_management"))]
         {
             when(self.password.is_default_or_empty(), || {
                 Err(errors::DrainerError::ConfigParsingError(
@@ -116,7 +156,7 @@ impl Database {
             })
         }
 
-        #[cfg(feature = "kms")]
+        #[cfg(feature = "secrets_management")]
         {
             when(self.kms_encrypted_password.is_default_or_empty(), || {
                 Err(errors::DrainerError::ConfigParsingError(
@@ -170,11 +210,21 @@ impl Settings {
             )
             .build()?;
 
-        serde_path_to_error::deserialize(config).map_err(|error| {
+        #[allow(unused_mut)]
+        let mut settings: Self = serde_path_to_error::deserialize(config).map_err(|error| {
             logger::error!(%error, "Unable to deserialize application configuration");
             eprintln!("Unable to deserialize application configuration: {error}");
             errors::DrainerError::from(error.into_inner())
-        })
+        })?;
+
+        #[cfg(feature = "kms")]
+        {
+            settings.secrets_management = settings
+                .secrets_management
+                .with_legacy_kms_config(&settings.kms);
+        }
+
+        Ok(settings)
     }
 
     pub fn validate(&self) -> Result<(), errors::DrainerError> {
@@ -148,3 +148,56 @@ pub(crate) fn get_stream_key_flag(store: Arc<services::Store>, stream_index: u8)
 pub(crate) fn get_drainer_stream_name(store: Arc<services::Store>, stream_index: u8) -> String {
     store.drainer_stream(format!("shard_{stream_index}").as_str())
 }
+
+pub(crate) fn get_drainer_dead_letter_stream_name(
+    store: Arc<services::Store>,
+    stream_index: u8,
+) -> String {
+    store.drainer_dead_letter_stream(format!("shard_{stream_index}").as_str())
+}
+
+pub(crate) fn get_drainer_retry_counts_key(
+    store: Arc<services::Store>,
+    stream_index: u8,
+) -> String {
+    store.drainer_retry_counts(format!("shard_{stream_index}").as_str())
+}
+
+/// Returns the number of retries already made for the stream entry, zero if none were recorded
+pub async fn get_retry_count(
+    redis: &redis::RedisConnectionPool,
+    retry_counts_key: &str,
+    entry_id: &str,
+) -> u8 {
+    redis
+        .get_hash_field::<Option<u8>>(retry_counts_key, entry_id)
+        .await
+        .map_err(|error| logger::error!(?error, %entry_id, "Failed to get the retry count"))
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+pub async fn set_retry_count(
+    redis: &redis::RedisConnectionPool,
+    retry_counts_key: &str,
+    entry_id: &str,
+    retry_count: u8,
+) {
+    if let Err(error) = redis
+        .set_hash_fields(retry_counts_key, (entry_id, retry_count))
+        .await
+    {
+        logger::error!(?error, %entry_id, "Failed to store the retry count");
+    }
+}
+
+pub async fn clear_retry_count(
+    redis: &redis::RedisConnectionPool,
+    retry_counts_key: &str,
+    entry_id: &str,
+) {
+    if let Err(error) = redis.delete_hash_field(retry_counts_key, entry_id).await {
+        logger::error!(?error, %entry_id, "Failed to clear the retry count");
+    }
+}
@@ -1,4 +1,6 @@
-//! Interactions with the AWS SES SDK
+//! Interactions with the AWS SES SDK and SMTP servers for sending emails
+
+pub mod smtp;
 
 use aws_config::meta::region::RegionProviderChain;
 use aws_sdk_sesv2::{
@@ -40,6 +42,26 @@ pub struct EmailSettings {
 
     /// Base-url used when adding links that should redirect to self
     pub base_url: String,
+
+    /// The email client to send emails with.
+    #[serde(default)]
+    pub active_email_client: ActiveEmailClient,
+
+    /// The SMTP server to send emails with, when the active email client is SMTP.
+    #[serde(default)]
+    pub smtp: smtp::SmtpServerConfig,
+}
+
+/// The email clients which can be selected to send emails.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ActiveEmailClient {
+    /// AWS Simple Email Service.
+    #[default]
+    AwsSes,
+
+    /// Any SMTP server, for example an on-premise relay or a local SMTP sink.
+    Smtp,
 }
 
 /// Client for AWS SES operation
@@ -112,6 +134,10 @@ pub enum EmailError {
     /// An error occurred when sending email
     #[error("Error sending email to recipient")]
     EmailSendingFailure,
+
+    /// The email could not be built from the given sender, recipient or contents.
+    #[error("Error building email")]
+    EmailBuildingFailure,
 }
 
 /// Errors that could occur during SES operations.
@@ -0,0 +1,162 @@
+//! Interactions with SMTP servers
+
+use std::time::Duration;
+
+use common_utils::pii;
+use error_stack::{IntoReport, ResultExt};
+use lettre::{
+    message::{header::ContentType, Mailbox},
+    transport::smtp::{self, authentication::Credentials},
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+use masking::{PeekInterface, Secret};
+use serde::Deserialize;
+
+use super::{EmailClient, EmailError, EmailResult, EmailSettings};
+
+/// Struct that contains the settings required to connect to an SMTP server.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SmtpServerConfig {
+    /// Hostname of the SMTP server.
+    pub host: String,
+
+    /// Port of the SMTP server.
+    pub port: u16,
+
+    /// Username to authenticate with, if the SMTP server requires authentication.
+    pub username: Option<String>,
+
+    /// Password to authenticate with, if the SMTP server requires authentication.
+    pub password: Option<Secret<String>>,
+
+    /// How the connection to the SMTP server is secured.
+    pub connection: SmtpConnection,
+
+    /// Timeout for the commands sent to the SMTP server, in seconds.
+    pub timeout: u64,
+}
+
+impl Default for SmtpServerConfig {
+    fn default() -> Self {
+        Self {
+            host: "localhost".to_string(),
+            port: 25,
+            username: None,
+            password: None,
+            connection: SmtpConnection::StartTls,
+            timeout: 10,
+        }
+    }
+}
+
+/// How the connection to the SMTP server is secured.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpConnection {
+    /// The connection is upgraded to TLS with the `STARTTLS` command.
+    #[default]
+    StartTls,
+
+    /// The connection is encrypted with TLS from the start.
+    Tls,
+
+    /// The connection is not encrypted, only meant for local SMTP servers used in development.
+    Plaintext,
+}
+
+/// Client for sending emails through an SMTP server
+#[derive(Debug, Clone)]
+pub struct SmtpServer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from_email: String,
+}
+
+impl SmtpServer {
+    /// Constructs a new SmtpServer client
+    pub fn new(conf: &EmailSettings) -> EmailResult<Self> {
+        let smtp_config = &conf.smtp;
+        let mut builder = match smtp_config.connection {
+            SmtpConnection::StartTls => {
+                AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&smtp_config.host)
+                    .map_err(SmtpError::ConnectionFailure)
+                    .into_report()
+                    .change_context(EmailError::ClientBuildingFailure)?
+            }
+            SmtpConnection::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&smtp_config.host)
+                .map_err(SmtpError::ConnectionFailure)
+                .into_report()
+                .change_context(EmailError::ClientBuildingFailure)?,
+            SmtpConnection::Plaintext => {
+                AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&smtp_config.host)
+            }
+        }
+        .port(smtp_config.port)
+        .timeout(Some(Duration::from_secs(smtp_config.timeout)));
+
+        if let (Some(username), Some(password)) = (&smtp_config.username, &smtp_config.password) {
+            builder = builder.credentials(Credentials::new(
+                username.to_owned(),
+                password.peek().to_owned(),
+            ));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+            from_email: conf.from_email.clone(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailClient for SmtpServer {
+    async fn send_email(
+        &self,
+        recipient: pii::Email,
+        subject: String,
+        body: String,
+    ) -> EmailResult<()> {
+        let from = self
+            .from_email
+            .parse::<Mailbox>()
+            .into_report()
+            .change_context(EmailError::EmailBuildingFailure)
+            .attach_printable("Invalid sender email")?;
+        let to = recipient
+            .peek()
+            .parse::<Mailbox>()
+            .into_report()
+            .change_context(EmailError::EmailBuildingFailure)
+            .attach_printable("Invalid recipient email")?;
+
+        let message = Message::builder()
+            .from(from)
+            .to(to)
+            .subject(subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(body)
+            .into_report()
+            .change_context(EmailError::EmailBuildingFailure)?;
+
+        self.transport
+            .send(message)
+            .await
+            .map_err(SmtpError::SendingFailure)
+            .into_report()
+            .change_context(EmailError::EmailSendingFailure)?;
+
+        Ok(())
+    }
+}
+
+/// Errors that could occur during SMTP operations.
+#[derive(Debug, thiserror::Error)]
+pub enum SmtpError {
+    /// The connection to the SMTP server could not be set up.
+    #[error("Failed to set up connection to SMTP server {0:?}")]
+    ConnectionFailure(smtp::Error),
+
+    /// An error occurred while sending email to the SMTP server.
+    #[error("Failed to Send Email {0:?}")]
+    SendingFailure(smtp::Error),
+}
@@ -9,8 +9,11 @@ pub mod email;
 #[cfg(feature = "kms")]
 pub mod kms;
 
+#[cfg(feature = "secrets_management")]
+pub mod secrets_management;
+
 /// Crate specific constants
-#[cfg(feature = "kms")]
+#[cfg(feature = "secrets_management")]
 pub mod consts {
     /// General purpose base64 engine
     pub(crate) const BASE64_ENGINE: base64::engine::GeneralPurpose =
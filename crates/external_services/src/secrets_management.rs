@@ -0,0 +1,144 @@
+//! Decryption of the secrets in the application configuration, using one of the supported
+//! secret management backends selected at runtime.
+
+pub mod local;
+pub mod vault;
+
+use common_utils::errors::CustomResult;
+#[cfg(feature = "kms")]
+use error_stack::ResultExt;
+
+#[cfg(feature = "kms")]
+use crate::kms::{KmsClient, KmsConfig};
+
+static SECRET_MANAGER: tokio::sync::OnceCell<Box<dyn SecretManager>> =
+    tokio::sync::OnceCell::const_new();
+
+/// Returns the shared secret manager of the configured backend, or initializes a new one if not
+/// previously initialized.
+#[inline]
+pub async fn get_secret_manager(
+    config: &SecretsManagementConfig,
+) -> CustomResult<&'static dyn SecretManager, SecretsManagementError> {
+    SECRET_MANAGER
+        .get_or_try_init(|| config.create_secret_manager())
+        .await
+        .map(|secret_manager| secret_manager.as_ref())
+}
+
+/// Decryption of secrets stored encrypted in the application configuration.
+#[async_trait::async_trait]
+pub trait SecretManager: Send + Sync + std::fmt::Debug {
+    /// Decrypts the provided encrypted data, in the format produced by the backend of the secret
+    /// manager.
+    async fn decrypt(&self, data: &str) -> CustomResult<String, SecretsManagementError>;
+}
+
+/// The backends secrets can be managed with.
+#[derive(Clone, Copy, Debug, Default, serde::Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretsManagementBackend {
+    /// Secrets are encrypted with AWS KMS. Only available when the `kms` feature is enabled.
+    #[cfg(feature = "kms")]
+    #[default]
+    AwsKms,
+
+    /// Secrets are envelope encrypted with a master key read from a local file.
+    #[cfg_attr(not(feature = "kms"), default)]
+    Local,
+
+    /// Secrets are encrypted with the transit secrets engine of HashiCorp Vault, or a server
+    /// compatible with its API.
+    Vault,
+}
+
+/// Configuration parameters required for constructing a [`SecretManager`].
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct SecretsManagementConfig {
+    /// The backend the secrets are encrypted with.
+    pub backend: SecretsManagementBackend,
+
+    /// Configuration of the AWS KMS backend.
+    #[cfg(feature = "kms")]
+    pub aws_kms: KmsConfig,
+
+    /// Configuration of the local backend.
+    pub local: local::LocalConfig,
+
+    /// Configuration of the HashiCorp Vault backend.
+    pub vault: vault::VaultConfig,
+}
+
+impl SecretsManagementConfig {
+    /// Constructs the secret manager of the configured backend.
+    pub async fn create_secret_manager(
+        &self,
+    ) -> CustomResult<Box<dyn SecretManager>, SecretsManagementError> {
+        let secret_manager: Box<dyn SecretManager> = match self.backend {
+            #[cfg(feature = "kms")]
+            SecretsManagementBackend::AwsKms => Box::new(KmsClient::new(&self.aws_kms).await),
+            SecretsManagementBackend::Local => {
+                Box::new(local::LocalSecretManager::new(&self.local)?)
+            }
+            SecretsManagementBackend::Vault => Box::new(vault::VaultClient::new(&self.vault)?),
+        };
+
+        Ok(secret_manager)
+    }
+
+    /// Verifies that the configuration of the selected backend is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self.backend {
+            #[cfg(feature = "kms")]
+            SecretsManagementBackend::AwsKms => self.aws_kms.validate(),
+            SecretsManagementBackend::Local => self.local.validate(),
+            SecretsManagementBackend::Vault => self.vault.validate(),
+        }
+    }
+
+    /// Falls back to the AWS KMS configuration of the deprecated `[kms]` section, if the AWS KMS
+    /// backend is not configured in the secrets management configuration.
+    #[cfg(feature = "kms")]
+    pub fn with_legacy_kms_config(mut self, kms_config: &KmsConfig) -> Self {
+        if self.aws_kms.key_id.is_empty() && self.aws_kms.region.is_empty() {
+            self.aws_kms = kms_config.clone();
+        }
+
+        self
+    }
+}
+
+#[cfg(feature = "kms")]
+#[async_trait::async_trait]
+impl SecretManager for KmsClient {
+    async fn decrypt(&self, data: &str) -> CustomResult<String, SecretsManagementError> {
+        Self::decrypt(self, data)
+            .await
+            .change_context(SecretsManagementError::DecryptionFailed)
+    }
+}
+
+/// Errors that could occur during secret management operations.
+#[derive(Debug, thiserror::Error)]
+pub enum SecretsManagementError {
+    /// The secret manager of the configured backend could not be constructed.
+    #[error("Failed to construct the secret manager")]
+    ClientCreationFailed,
+
+    /// An error occurred when base64 decoding input data.
+    #[error("Failed to base64 decode input data")]
+    Base64DecodingFailed,
+
+    /// An error occurred when decrypting input data.
+    #[error("Failed to decrypt input data")]
+    DecryptionFailed,
+
+    /// An error occurred when encrypting input data.
+    #[error("Failed to encrypt input data")]
+    EncryptionFailed,
+
+    /// An error occurred UTF-8 decoding decrypted output.
+    #[error("Failed to UTF-8 decode decryption output")]
+    Utf8DecodingFailed,
+}
@@ -0,0 +1,164 @@
+//! Envelope encryption of secrets with a master key read from a local file
+//!
+//! Each secret is encrypted with its own randomly generated data key, which is in turn encrypted
+//! with the master key. Both are encrypted with AES-256-GCM, and the encrypted secret is stored as
+//! `<base64 encrypted data key>.<base64 encrypted data>`.
+
+use std::path::PathBuf;
+
+use base64::Engine;
+use common_utils::{
+    crypto::{self, DecodeMessage, EncodeMessage, GcmAes256},
+    errors::CustomResult,
+};
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, StrongSecret};
+
+use super::{SecretManager, SecretsManagementError};
+use crate::consts;
+
+/// Length of the master key and of the data keys, in bytes.
+const KEY_LENGTH: usize = 32;
+
+/// Configuration parameters required for constructing a [`LocalSecretManager`].
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct LocalConfig {
+    /// Path of the file containing the hex-encoded 256-bit master key.
+    pub master_key_file: PathBuf,
+}
+
+impl LocalConfig {
+    /// Verifies that the [`LocalSecretManager`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        common_utils::fp_utils::when(self.master_key_file.as_os_str().is_empty(), || {
+            Err("Local secret management master key file must not be empty")
+        })
+    }
+}
+
+/// Secret manager decrypting envelope encrypted secrets with a local master key.
+#[derive(Debug)]
+pub struct LocalSecretManager {
+    master_key: StrongSecret<[u8; KEY_LENGTH]>,
+}
+
+impl LocalSecretManager {
+    /// Constructs a new local secret manager, reading the master key from the configured file.
+    pub fn new(config: &LocalConfig) -> CustomResult<Self, SecretsManagementError> {
+        let master_key = std::fs::read_to_string(&config.master_key_file)
+            .into_report()
+            .change_context(SecretsManagementError::ClientCreationFailed)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed to read master key file {}",
+                    config.master_key_file.display()
+                )
+            })?;
+
+        Self::from_hex_master_key(master_key.trim())
+    }
+
+    fn from_hex_master_key(master_key: &str) -> CustomResult<Self, SecretsManagementError> {
+        let master_key = hex::decode(master_key)
+            .into_report()
+            .change_context(SecretsManagementError::ClientCreationFailed)
+            .attach_printable("Master key is not valid hexadecimal data")?;
+        let master_key = <[u8; KEY_LENGTH]>::try_from(master_key.as_slice())
+            .into_report()
+            .change_context(SecretsManagementError::ClientCreationFailed)
+            .attach_printable("Master key must be 32 bytes long")?;
+
+        Ok(Self {
+            master_key: master_key.into(),
+        })
+    }
+
+    /// Envelope encrypts the provided data, in the format expected by
+    /// [`SecretManager::decrypt`].
+    pub fn encrypt(&self, data: &str) -> CustomResult<String, SecretsManagementError> {
+        let data_key = crypto::generate_cryptographically_secure_random_bytes::<KEY_LENGTH>();
+        let encrypted_data_key = GcmAes256
+            .encode_message(self.master_key.peek(), &data_key)
+            .change_context(SecretsManagementError::EncryptionFailed)?;
+        let encrypted_data = GcmAes256
+            .encode_message(&data_key, data.as_bytes())
+            .change_context(SecretsManagementError::EncryptionFailed)?;
+
+        Ok(format!(
+            "{}.{}",
+            consts::BASE64_ENGINE.encode(encrypted_data_key),
+            consts::BASE64_ENGINE.encode(encrypted_data)
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretManager for LocalSecretManager {
+    async fn decrypt(&self, data: &str) -> CustomResult<String, SecretsManagementError> {
+        let (encrypted_data_key, encrypted_data) = data
+            .split_once('.')
+            .ok_or(SecretsManagementError::DecryptionFailed)
+            .into_report()
+            .attach_printable("Encrypted data is missing the encrypted data key")?;
+        let decode = |data: &str| {
+            consts::BASE64_ENGINE
+                .decode(data)
+                .into_report()
+                .change_context(SecretsManagementError::Base64DecodingFailed)
+        };
+
+        let data_key = GcmAes256
+            .decode_message(self.master_key.peek(), decode(encrypted_data_key)?)
+            .change_context(SecretsManagementError::DecryptionFailed)
+            .attach_printable("Failed to decrypt data key with the master key")?;
+        let data = GcmAes256
+            .decode_message(&data_key, decode(encrypted_data)?)
+            .change_context(SecretsManagementError::DecryptionFailed)?;
+
+        String::from_utf8(data)
+            .into_report()
+            .change_context(SecretsManagementError::Utf8DecodingFailed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+
+    const MASTER_KEY: &str = "2d5ba8ef0b9fbd86a9d9b2b6b2e2bd8d7a0a7c5e2f1d4c3b2a19080706050403";
+
+    #[tokio::test]
+    async fn test_local_secret_manager_roundtrip() {
+        let secret_manager =
+            LocalSecretManager::from_hex_master_key(MASTER_KEY).expect("Invalid master key");
+
+        let encrypted = secret_manager
+            .encrypt("admin_api_key")
+            .expect("Failed to encrypt data");
+
+        assert_eq!(
+            secret_manager
+                .decrypt(&encrypted)
+                .await
+                .expect("Failed to decrypt data"),
+            "admin_api_key"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_local_secret_manager_rejects_other_master_key() {
+        let secret_manager =
+            LocalSecretManager::from_hex_master_key(MASTER_KEY).expect("Invalid master key");
+        let other_secret_manager =
+            LocalSecretManager::from_hex_master_key(&"ab".repeat(32)).expect("Invalid master key");
+
+        let encrypted = secret_manager
+            .encrypt("admin_api_key")
+            .expect("Failed to encrypt data");
+
+        assert!(other_secret_manager.decrypt(&encrypted).await.is_err());
+    }
+}
@@ -0,0 +1,144 @@
+//! Interactions with the transit secrets engine of HashiCorp Vault
+
+use std::time::Duration;
+
+use base64::Engine;
+use common_utils::errors::CustomResult;
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::logger;
+
+use super::{SecretManager, SecretsManagementError};
+use crate::consts;
+
+/// Configuration parameters required for constructing a [`VaultClient`].
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(default)]
+pub struct VaultConfig {
+    /// Base URL of the Vault server, for example `https://vault.example.com:8200`.
+    pub url: String,
+
+    /// Path the transit secrets engine is mounted at.
+    pub mount_path: String,
+
+    /// Name of the transit key used to encrypt or decrypt data.
+    pub key_name: String,
+
+    /// Token to authenticate with against the Vault server.
+    pub token: Secret<String>,
+
+    /// Timeout for the requests sent to the Vault server, in seconds.
+    pub timeout: u64,
+}
+
+impl Default for VaultConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            mount_path: "transit".to_string(),
+            key_name: String::new(),
+            token: Secret::default(),
+            timeout: 10,
+        }
+    }
+}
+
+impl VaultConfig {
+    /// Verifies that the [`VaultClient`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.url.is_default_or_empty(), || {
+            Err("Vault server URL must not be empty")
+        })?;
+
+        when(self.key_name.is_default_or_empty(), || {
+            Err("Vault transit key name must not be empty")
+        })?;
+
+        when(self.token.peek().is_default_or_empty(), || {
+            Err("Vault token must not be empty")
+        })
+    }
+}
+
+/// Client for the transit secrets engine of HashiCorp Vault.
+#[derive(Debug)]
+pub struct VaultClient {
+    inner_client: reqwest::Client,
+    decrypt_url: String,
+    token: Secret<String>,
+}
+
+#[derive(serde::Serialize)]
+struct DecryptRequest<'a> {
+    ciphertext: &'a str,
+}
+
+#[derive(serde::Deserialize)]
+struct DecryptResponse {
+    data: DecryptResponseData,
+}
+
+#[derive(serde::Deserialize)]
+struct DecryptResponseData {
+    plaintext: Secret<String>,
+}
+
+impl VaultClient {
+    /// Constructs a new Vault client.
+    pub fn new(config: &VaultConfig) -> CustomResult<Self, SecretsManagementError> {
+        let inner_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout))
+            .build()
+            .into_report()
+            .change_context(SecretsManagementError::ClientCreationFailed)?;
+
+        Ok(Self {
+            inner_client,
+            decrypt_url: format!(
+                "{}/v1/{}/decrypt/{}",
+                config.url.trim_end_matches('/'),
+                config.mount_path.trim_matches('/'),
+                config.key_name
+            ),
+            token: config.token.clone(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretManager for VaultClient {
+    /// Decrypts the provided ciphertext, as returned by the transit secrets engine (for example
+    /// `vault:v1:...`).
+    async fn decrypt(&self, data: &str) -> CustomResult<String, SecretsManagementError> {
+        let response = self
+            .inner_client
+            .post(&self.decrypt_url)
+            .header("X-Vault-Token", self.token.peek())
+            .json(&DecryptRequest { ciphertext: data })
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|error| {
+                logger::error!(vault_error=?error, "Failed to Vault decrypt data");
+                error
+            })
+            .into_report()
+            .change_context(SecretsManagementError::DecryptionFailed)?
+            .json::<DecryptResponse>()
+            .await
+            .into_report()
+            .change_context(SecretsManagementError::DecryptionFailed)
+            .attach_printable("Unexpected response from Vault")?;
+
+        let plaintext = consts::BASE64_ENGINE
+            .decode(response.data.plaintext.peek())
+            .into_report()
+            .change_context(SecretsManagementError::Base64DecodingFailed)?;
+
+        String::from_utf8(plaintext)
+            .into_report()
+            .change_context(SecretsManagementError::Utf8DecodingFailed)
+    }
+}
@@ -48,12 +48,16 @@ pub enum RedisError {
     SetHashFieldFailed,
     #[error("Failed to get hash field in Redis")]
     GetHashFieldFailed,
+    #[error("Failed to delete hash field in Redis")]
+    DeleteHashFieldFailed,
     #[error("The requested value was not found in Redis")]
     NotFound,
     #[error("Invalid RedisEntryId provided")]
     InvalidRedisEntryId,
     #[error("Failed to establish Redis connection")]
     RedisConnectionError,
+    #[error("Failed to ping Redis")]
+    PingFailed,
     #[error("Failed to subscribe to a channel")]
     SubscribeError,
     #[error("Failed to publish to a channel")]
@@ -1,5 +1,8 @@
+use std::path::PathBuf;
+
+use api_models::cards_info::{CardsInfoImportFormat, CardsInfoImportRequest};
 use router::{
-    configs::settings::{CmdLineConf, Settings},
+    configs::settings::{CmdLineConf, Settings, Subcommand},
     core::errors::{ApplicationError, ApplicationResult},
     logger,
 };
@@ -11,7 +14,6 @@ async fn main() -> ApplicationResult<()> {
 
     #[cfg(feature = "openapi")]
     {
-        use router::configs::settings::Subcommand;
         if let Some(Subcommand::GenerateOpenapiSpec) = cmd_line.subcommand {
             let file_path = "openapi/openapi_spec.json";
             #[allow(clippy::expect_used)]
@@ -36,6 +38,16 @@ async fn main() -> ApplicationResult<()> {
 
     let _guard = logger::setup(&conf.log);
 
+    if let Some(Subcommand::ImportCardsInfo {
+        file,
+        format,
+        provider,
+        dry_run,
+    }) = cmd_line.subcommand
+    {
+        return import_cards_info(conf, file, format, provider, dry_run).await;
+    }
+
     logger::info!("Application started [{:?}] [{:?}]", conf.server, conf.log);
 
     #[allow(clippy::expect_used)]
@@ -49,3 +61,45 @@ async fn main() -> ApplicationResult<()> {
         "Server shut down",
     )))
 }
+
+async fn import_cards_info(
+    conf: Settings,
+    file: PathBuf,
+    format: Option<CardsInfoImportFormat>,
+    provider: Option<String>,
+    dry_run: bool,
+) -> ApplicationResult<()> {
+    let format = match (format, file.extension().and_then(std::ffi::OsStr::to_str)) {
+        (Some(format), _) => format,
+        (None, Some(extension)) if extension.eq_ignore_ascii_case("csv") => {
+            CardsInfoImportFormat::Csv
+        }
+        (None, _) => CardsInfoImportFormat::Json,
+    };
+    let data = std::fs::read_to_string(&file)?;
+
+    let (redis_shutdown_signal_tx, _redis_shutdown_signal_rx) = tokio::sync::oneshot::channel();
+    let state = router::routes::AppState::new(conf, redis_shutdown_signal_tx).await;
+
+    let request = CardsInfoImportRequest {
+        format,
+        dry_run,
+        provider,
+        data,
+    };
+    let response = router::core::cards_info::import_cards_info_records(&*state.store, request)
+        .await
+        .map_err(|error| {
+            ApplicationError::from(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("Failed to import card information: {error:?}"),
+            ))
+        })?;
+
+    #[allow(clippy::expect_used)]
+    let report =
+        serde_json::to_string_pretty(&response).expect("Failed to serialize the import report");
+    println!("{report}");
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ pub mod types;
 
 use actix_web::{web, HttpRequest, HttpResponse};
 use error_stack::report;
-use router_env::{instrument, tracing};
+use router_env::{instrument, tracing, Flow};
 
 use crate::{
     compatibility::{stripe::errors, wrap},
@@ -12,7 +12,7 @@ use crate::{
     types::api::customers as customer_types,
 };
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersCreate))]
 pub async fn customer_create(
     state: web::Data<routes::AppState>,
     qs_config: web::Data<serde_qs::Config>,
@@ -28,6 +28,7 @@ pub async fn customer_create(
 
     let create_cust_req: customer_types::CustomerRequest = payload.into();
 
+    let flow = Flow::CustomersCreate;
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -38,6 +39,7 @@ pub async fn customer_create(
         types::CreateCustomerResponse,
         errors::StripeErrorCode,
     >(
+        flow,
         state.get_ref(),
         &req,
         create_cust_req,
@@ -49,7 +51,7 @@ pub async fn customer_create(
     .await
 }
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersRetrieve))]
 pub async fn customer_retrieve(
     state: web::Data<routes::AppState>,
     req: HttpRequest,
@@ -59,6 +61,7 @@ pub async fn customer_retrieve(
         customer_id: path.into_inner(),
     };
 
+    let flow = Flow::CustomersRetrieve;
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -69,6 +72,7 @@ pub async fn customer_retrieve(
         types::CustomerRetrieveResponse,
         errors::StripeErrorCode,
     >(
+        flow,
         state.get_ref(),
         &req,
         payload,
@@ -80,7 +84,7 @@ pub async fn customer_retrieve(
     .await
 }
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersUpdate))]
 pub async fn customer_update(
     state: web::Data<routes::AppState>,
     qs_config: web::Data<serde_qs::Config>,
@@ -99,6 +103,7 @@ pub async fn customer_update(
     let mut cust_update_req: customer_types::CustomerRequest = payload.into();
     cust_update_req.customer_id = customer_id;
 
+    let flow = Flow::CustomersUpdate;
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -109,6 +114,7 @@ pub async fn customer_update(
         types::CustomerUpdateResponse,
         errors::StripeErrorCode,
     >(
+        flow,
         state.get_ref(),
         &req,
         cust_update_req,
@@ -120,7 +126,7 @@ pub async fn customer_update(
     .await
 }
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersDelete))]
 pub async fn customer_delete(
     state: web::Data<routes::AppState>,
     req: HttpRequest,
@@ -130,6 +136,7 @@ pub async fn customer_delete(
         customer_id: path.into_inner(),
     };
 
+    let flow = Flow::CustomersDelete;
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -140,6 +147,7 @@ pub async fn customer_delete(
         types::CustomerDeleteResponse,
         errors::StripeErrorCode,
     >(
+        flow,
         state.get_ref(),
         &req,
         payload,
@@ -149,7 +157,7 @@ pub async fn customer_delete(
     .await
 }
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPaymentMethodsList))]
 pub async fn list_customer_payment_method_api(
     state: web::Data<routes::AppState>,
     req: HttpRequest,
@@ -157,6 +165,7 @@ pub async fn list_customer_payment_method_api(
 ) -> HttpResponse {
     let customer_id = path.into_inner();
 
+    let flow = Flow::CustomerPaymentMethodsList;
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -167,6 +176,7 @@ pub async fn list_customer_payment_method_api(
         types::CustomerPaymentMethodListResponse,
         errors::StripeErrorCode,
     >(
+        flow,
         state.get_ref(),
         &req,
         customer_id.as_ref(),
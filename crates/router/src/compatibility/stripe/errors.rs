@@ -43,6 +43,9 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::ApiError, code = "dispute_failure", message = "Dispute failed while processing with connector. Retry operation.")]
     DisputeFailed { data: Option<serde_json::Value> },
 
+    #[error(error_type = StripeErrorType::ApiError, code = "payout_failure", message = "Payout failed while processing with connector. Retry payout.")]
+    PayoutFailed { data: Option<serde_json::Value> },
+
     #[error(error_type = StripeErrorType::CardError, code = "expired_card", message = "Card Expired. Please use another card")]
     ExpiredCard,
 
@@ -173,6 +176,9 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The payment with the specified payment_id '{payment_id}' already exists in our records.")]
     DuplicatePayment { payment_id: String },
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The payout with the specified payout_id '{payout_id}' already exists in our records.")]
+    DuplicatePayout { payout_id: String },
+
     #[error(error_type = StripeErrorType::ConnectorError, code = "", message = "{code}: {message}")]
     ExternalConnectorError {
         code: String,
@@ -201,6 +207,12 @@ pub enum StripeErrorCode {
     FileNotAvailable,
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "There was an issue with processing webhooks")]
     WebhookProcessingError,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_key_in_use", message = "There is currently another in-progress request using this Idempotent Key (that probably means you submitted twice, and the other request is still going through). Please try again later.")]
+    IdempotencyKeyInUse,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_error", message = "Keys for idempotent requests can only be used with the same parameters they were first used with. Try using a key other than the one used for the original request.")]
+    IdempotencyKeyMismatch,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "insufficient_permissions", message = "{message}")]
+    InsufficientApiKeyScope { message: String },
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "payment_method_unactivated", message = "The operation cannot be performed as the payment method used has not been activated. Activate the payment method in the Dashboard, then try again.")]
     PaymentMethodUnactivated,
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
@@ -245,7 +257,6 @@ pub enum StripeErrorCode {
         DebitNotAuthorized,
         EmailInvalid,
         ExpiredCard,
-        IdempotencyKeyInUse,
         IncorrectAddress,
         IncorrectCvc,
         IncorrectNumber,
@@ -409,6 +420,7 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 Self::PaymentIntentPaymentAttemptFailed { data }
             }
             errors::ApiErrorResponse::DisputeFailed { data } => Self::DisputeFailed { data },
+            errors::ApiErrorResponse::PayoutFailed { data } => Self::PayoutFailed { data },
             errors::ApiErrorResponse::InvalidCardData { data } => Self::InvalidCardType, // Maybe it is better to de generalize this router error
             errors::ApiErrorResponse::CardExpired { data } => Self::ExpiredCard,
             errors::ApiErrorResponse::RefundNotPossible { connector } => Self::RefundFailed,
@@ -495,6 +507,17 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 object: "dispute".to_owned(),
                 id: dispute_id,
             },
+            errors::ApiErrorResponse::PayoutNotFound => Self::ResourceIdNotFound,
+            errors::ApiErrorResponse::EventNotFound => Self::ResourceIdNotFound,
+            errors::ApiErrorResponse::CaptureNotFound => Self::ResourceIdNotFound,
+            errors::ApiErrorResponse::IdempotencyKeyInUse => Self::IdempotencyKeyInUse,
+            errors::ApiErrorResponse::IdempotencyKeyMismatch => Self::IdempotencyKeyMismatch,
+            errors::ApiErrorResponse::InsufficientApiKeyScope { message } => {
+                Self::InsufficientApiKeyScope { message }
+            }
+            errors::ApiErrorResponse::DuplicatePayout { payout_id } => {
+                Self::DuplicatePayout { payout_id }
+            }
             errors::ApiErrorResponse::DisputeStatusValidationFailed { reason } => {
                 Self::InternalServerError
             }
@@ -527,6 +550,7 @@ impl actix_web::ResponseError for StripeErrorCode {
 
         match self {
             Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::InsufficientApiKeyScope { .. } => StatusCode::FORBIDDEN,
             Self::InvalidRequestUrl => StatusCode::NOT_FOUND,
             Self::ParameterUnknown { .. } => StatusCode::UNPROCESSABLE_ENTITY,
             Self::ParameterMissing { .. }
@@ -553,6 +577,8 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::PaymentFailed
             | Self::VerificationFailed { .. }
             | Self::DisputeFailed { .. }
+            | Self::PayoutFailed { .. }
+            | Self::DuplicatePayout { .. }
             | Self::MaximumRefundCount
             | Self::PaymentIntentInvalidParameter { .. }
             | Self::SerdeQsError { .. }
@@ -574,7 +600,9 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::MissingDisputeId
             | Self::FileNotFound
             | Self::FileNotAvailable
-            | Self::PaymentMethodUnactivated => StatusCode::BAD_REQUEST,
+            | Self::PaymentMethodUnactivated
+            | Self::IdempotencyKeyMismatch => StatusCode::BAD_REQUEST,
+            Self::IdempotencyKeyInUse => StatusCode::CONFLICT,
             Self::RefundFailed
             | Self::InternalServerError
             | Self::MandateActive
@@ -3,7 +3,7 @@ pub mod types;
 use actix_web::{web, HttpRequest, HttpResponse};
 use api_models::payments as payment_types;
 use error_stack::report;
-use router_env::{instrument, tracing};
+use router_env::{instrument, tracing, Flow};
 
 use crate::{
     compatibility::{stripe::errors, wrap},
@@ -13,7 +13,7 @@ use crate::{
     types::api::{self as api_types},
 };
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCreate))]
 pub async fn payment_intents_create(
     state: web::Data<routes::AppState>,
     qs_config: web::Data<serde_qs::Config>,
@@ -33,6 +33,7 @@ pub async fn payment_intents_create(
         Err(err) => return api::log_and_return_error_response(err),
     };
 
+    let flow = Flow::PaymentsCreate;
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -43,6 +44,7 @@ pub async fn payment_intents_create(
         types::StripePaymentIntentResponse,
         errors::StripeErrorCode,
     >(
+        flow,
         state.get_ref(),
         &req,
         create_payment_req,
@@ -61,7 +63,7 @@ pub async fn payment_intents_create(
     .await
 }
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsRetrieve))]
 pub async fn payment_intents_retrieve(
     state: web::Data<routes::AppState>,
     req: HttpRequest,
@@ -74,6 +76,7 @@ pub async fn payment_intents_retrieve(
         connector: None,
         param: None,
         merchant_connector_details: None,
+        capture_id: None,
     };
 
     let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
@@ -81,6 +84,7 @@ pub async fn payment_intents_retrieve(
         Err(err) => return api::log_and_return_error_response(report!(err)),
     };
 
+    let flow = Flow::PaymentsRetrieve;
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -91,6 +95,7 @@ pub async fn payment_intents_retrieve(
         types::StripePaymentIntentResponse,
         errors::StripeErrorCode,
     >(
+        flow,
         state.get_ref(),
         &req,
         payload,
@@ -109,7 +114,7 @@ pub async fn payment_intents_retrieve(
     .await
 }
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsRetrieve))]
 pub async fn payment_intents_retrieve_with_gateway_creds(
     state: web::Data<routes::AppState>,
     qs_config: web::Data<serde_qs::Config>,
@@ -138,6 +143,7 @@ pub async fn payment_intents_retrieve_with_gateway_creds(
         Err(err) => return api::log_and_return_error_response(report!(err)),
     };
 
+    let flow = Flow::PaymentsRetrieve;
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -148,6 +154,7 @@ pub async fn payment_intents_retrieve_with_gateway_creds(
         types::StripePaymentIntentResponse,
         errors::StripeErrorCode,
     >(
+        flow,
         state.get_ref(),
         &req,
         payload,
@@ -166,7 +173,7 @@ pub async fn payment_intents_retrieve_with_gateway_creds(
     .await
 }
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsUpdate))]
 pub async fn payment_intents_update(
     state: web::Data<routes::AppState>,
     qs_config: web::Data<serde_qs::Config>,
@@ -196,6 +203,7 @@ pub async fn payment_intents_update(
         Err(err) => return api::log_and_return_error_response(report!(err)),
     };
 
+    let flow = Flow::PaymentsUpdate;
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -206,6 +214,7 @@ pub async fn payment_intents_update(
         types::StripePaymentIntentResponse,
         errors::StripeErrorCode,
     >(
+        flow,
         state.get_ref(),
         &req,
         payload,
@@ -224,7 +233,7 @@ pub async fn payment_intents_update(
     .await
 }
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsConfirm))]
 pub async fn payment_intents_confirm(
     state: web::Data<routes::AppState>,
     qs_config: web::Data<serde_qs::Config>,
@@ -256,6 +265,7 @@ pub async fn payment_intents_confirm(
             Err(err) => return api::log_and_return_error_response(err),
         };
 
+    let flow = Flow::PaymentsConfirm;
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -266,6 +276,7 @@ pub async fn payment_intents_confirm(
         types::StripePaymentIntentResponse,
         errors::StripeErrorCode,
     >(
+        flow,
         state.get_ref(),
         &req,
         payload,
@@ -284,6 +295,7 @@ pub async fn payment_intents_confirm(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCapture))]
 pub async fn payment_intents_capture(
     state: web::Data<routes::AppState>,
     qs_config: web::Data<serde_qs::Config>,
@@ -305,6 +317,7 @@ pub async fn payment_intents_capture(
         ..stripe_payload
     };
 
+    let flow = Flow::PaymentsCapture;
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -315,6 +328,7 @@ pub async fn payment_intents_capture(
         types::StripePaymentIntentResponse,
         errors::StripeErrorCode,
     >(
+        flow,
         state.get_ref(),
         &req,
         capture_payload,
@@ -333,7 +347,7 @@ pub async fn payment_intents_capture(
     .await
 }
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCancel))]
 pub async fn payment_intents_cancel(
     state: web::Data<routes::AppState>,
     qs_config: web::Data<serde_qs::Config>,
@@ -359,6 +373,7 @@ pub async fn payment_intents_cancel(
         Err(err) => return api::log_and_return_error_response(report!(err)),
     };
 
+    let flow = Flow::PaymentsCancel;
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -369,6 +384,7 @@ pub async fn payment_intents_cancel(
         types::StripePaymentIntentResponse,
         errors::StripeErrorCode,
     >(
+        flow,
         state.get_ref(),
         &req,
         payload,
@@ -387,7 +403,7 @@ pub async fn payment_intents_cancel(
     .await
 }
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsList))]
 #[cfg(feature = "olap")]
 pub async fn payment_intent_list(
     state: web::Data<routes::AppState>,
@@ -398,6 +414,7 @@ pub async fn payment_intent_list(
         Ok(p) => p,
         Err(err) => return api::log_and_return_error_response(err),
     };
+    let flow = Flow::PaymentsList;
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -408,6 +425,7 @@ pub async fn payment_intent_list(
         types::StripePaymentIntentListResponse,
         errors::StripeErrorCode,
     >(
+        flow,
         state.get_ref(),
         &req,
         payload,
@@ -605,6 +605,8 @@ impl ForeignTryFrom<(Option<MandateOption>, Option<String>)> for Option<payments
                             currency,
                             start_date: mandate.start_date,
                             end_date: mandate.end_date,
+                            max_amount_per_payment: None,
+                            usage_limit: None,
                             metadata: None,
                         },
                     )),
@@ -2,7 +2,7 @@ pub mod types;
 
 use actix_web::{web, HttpRequest, HttpResponse};
 use error_stack::report;
-use router_env::{instrument, tracing};
+use router_env::{instrument, tracing, Flow};
 
 use crate::{
     compatibility::{stripe::errors, wrap},
@@ -12,7 +12,7 @@ use crate::{
     types::api::refunds as refund_types,
 };
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsCreate))]
 pub async fn refund_create(
     state: web::Data<routes::AppState>,
     qs_config: web::Data<serde_qs::Config>,
@@ -29,6 +29,7 @@ pub async fn refund_create(
 
     let create_refund_req: refund_types::RefundRequest = payload.into();
 
+    let flow = Flow::RefundsCreate;
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -39,6 +40,7 @@ pub async fn refund_create(
         types::StripeRefundResponse,
         errors::StripeErrorCode,
     >(
+        flow,
         state.get_ref(),
         &req,
         create_refund_req,
@@ -48,7 +50,7 @@ pub async fn refund_create(
     .await
 }
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsRetrieve))]
 pub async fn refund_retrieve_with_gateway_creds(
     state: web::Data<routes::AppState>,
     qs_config: web::Data<serde_qs::Config>,
@@ -62,6 +64,7 @@ pub async fn refund_retrieve_with_gateway_creds(
         Ok(payload) => payload,
         Err(err) => return api::log_and_return_error_response(err),
     };
+    let flow = Flow::RefundsRetrieve;
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -72,6 +75,7 @@ pub async fn refund_retrieve_with_gateway_creds(
         types::StripeRefundResponse,
         errors::StripeErrorCode,
     >(
+        flow,
         state.get_ref(),
         &req,
         refund_request,
@@ -88,7 +92,7 @@ pub async fn refund_retrieve_with_gateway_creds(
     .await
 }
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsRetrieve))]
 pub async fn refund_retrieve(
     state: web::Data<routes::AppState>,
     req: HttpRequest,
@@ -99,6 +103,7 @@ pub async fn refund_retrieve(
         force_sync: Some(true),
         merchant_connector_details: None,
     };
+    let flow = Flow::RefundsRetrieve;
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -109,6 +114,7 @@ pub async fn refund_retrieve(
         types::StripeRefundResponse,
         errors::StripeErrorCode,
     >(
+        flow,
         state.get_ref(),
         &req,
         refund_request,
@@ -125,7 +131,7 @@ pub async fn refund_retrieve(
     .await
 }
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsUpdate))]
 pub async fn refund_update(
     state: web::Data<routes::AppState>,
     req: HttpRequest,
@@ -136,6 +142,7 @@ pub async fn refund_update(
     let payload = form_payload.into_inner();
     let create_refund_update_req: refund_types::RefundUpdateRequest = payload.into();
 
+    let flow = Flow::RefundsUpdate;
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -146,6 +153,7 @@ pub async fn refund_update(
         types::StripeRefundResponse,
         errors::StripeErrorCode,
     >(
+        flow,
         state.get_ref(),
         &req,
         create_refund_update_req,
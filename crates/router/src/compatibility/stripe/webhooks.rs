@@ -1,5 +1,5 @@
 use api_models::{
-    enums::DisputeStatus,
+    enums::{DisputeStatus, MandateStatus, PayoutStatus},
     webhooks::{self as api},
 };
 use serde::Serialize;
@@ -24,6 +24,8 @@ pub enum StripeWebhookObject {
     PaymentIntent(StripePaymentIntentResponse),
     Refund(StripeRefundResponse),
     Dispute(StripeDisputeResponse),
+    Payout(StripePayoutResponse),
+    Mandate(StripeMandateResponse),
 }
 
 #[derive(Serialize, Debug)]
@@ -76,6 +78,97 @@ impl From<DisputeStatus> for StripeDisputeStatus {
     }
 }
 
+#[derive(Serialize, Debug)]
+pub struct StripePayoutResponse {
+    pub id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub description: Option<String>,
+    pub status: StripePayoutStatus,
+    pub failure_code: Option<String>,
+    pub failure_message: Option<String>,
+    pub metadata: Option<common_utils::pii::SecretSerdeValue>,
+    pub created: i64,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum StripePayoutStatus {
+    Pending,
+    InTransit,
+    Paid,
+    Failed,
+    Canceled,
+}
+
+impl From<api_models::payouts::PayoutResponse> for StripePayoutResponse {
+    fn from(res: api_models::payouts::PayoutResponse) -> Self {
+        Self {
+            id: res.payout_id,
+            amount: res.amount,
+            currency: res.currency.to_string(),
+            description: res.description,
+            status: StripePayoutStatus::from(res.status),
+            failure_code: res.error_code,
+            failure_message: res.error_message,
+            metadata: res.metadata,
+            created: res.created_at.assume_utc().unix_timestamp(),
+        }
+    }
+}
+
+impl From<PayoutStatus> for StripePayoutStatus {
+    fn from(status: PayoutStatus) -> Self {
+        match status {
+            PayoutStatus::RequiresCreation | PayoutStatus::RequiresFulfillment => Self::Pending,
+            PayoutStatus::Pending => Self::InTransit,
+            PayoutStatus::Success => Self::Paid,
+            PayoutStatus::Failed => Self::Failed,
+            PayoutStatus::Cancelled => Self::Canceled,
+            // Stripe keeps a reversed payout as paid, the reversal being a payout of its own
+            PayoutStatus::Reversed => Self::Paid,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct StripeMandateResponse {
+    pub id: String,
+    pub payment_method: String,
+    pub status: StripeMandateStatus,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeMandateStatus {
+    Active,
+    Inactive,
+    Pending,
+}
+
+impl From<api_models::mandates::MandateResponse> for StripeMandateResponse {
+    fn from(res: api_models::mandates::MandateResponse) -> Self {
+        Self {
+            id: res.mandate_id,
+            payment_method: res.payment_method_id,
+            status: StripeMandateStatus::from(res.status),
+        }
+    }
+}
+
+impl From<MandateStatus> for StripeMandateStatus {
+    fn from(status: MandateStatus) -> Self {
+        match status {
+            MandateStatus::Active => Self::Active,
+            MandateStatus::Pending => Self::Pending,
+            MandateStatus::Inactive
+            | MandateStatus::Revoked
+            | MandateStatus::Expired
+            | MandateStatus::Used => Self::Inactive,
+        }
+    }
+}
+
 impl From<api::OutgoingWebhook> for StripeOutgoingWebhook {
     fn from(value: api::OutgoingWebhook) -> Self {
         let data: StripeWebhookObject = value.content.into();
@@ -97,6 +190,10 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api::OutgoingWebhookContent::DisputeDetails(dispute) => {
                 Self::Dispute((*dispute).into())
             }
+            api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout(payout.into()),
+            api::OutgoingWebhookContent::MandateDetails(mandate) => {
+                Self::Mandate((*mandate).into())
+            }
         }
     }
 }
@@ -107,6 +204,8 @@ impl StripeWebhookObject {
             Self::PaymentIntent(p) => p.id.to_owned(),
             Self::Refund(r) => Some(r.id.to_owned()),
             Self::Dispute(d) => Some(d.id.to_owned()),
+            Self::Payout(p) => Some(p.id.to_owned()),
+            Self::Mandate(m) => Some(m.id.to_owned()),
         }
     }
 }
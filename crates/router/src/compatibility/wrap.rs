@@ -13,6 +13,7 @@ use crate::{
 
 #[instrument(skip(request, payload, state, func, api_authentication))]
 pub async fn compatibility_api_wrap<'a, 'b, A, U, T, Q, F, Fut, S, E>(
+    flow: router_env::Flow,
     state: &'b A,
     request: &'a HttpRequest,
     payload: T,
@@ -29,18 +30,12 @@ where
     error_stack::Report<E>: services::EmbedError,
     errors::ApiErrorResponse: ErrorSwitch<E>,
     T: std::fmt::Debug,
-    A: AppStateInfo,
+    A: AppStateInfo + Sync,
 {
-    let resp: common_utils::errors::CustomResult<_, E> = api::server_wrap_util(
-        &router_env::Flow::CompatibilityLayerRequest,
-        state,
-        request,
-        payload,
-        func,
-        api_authentication,
-    )
-    .await;
-    match resp {
+    let resp: common_utils::errors::CustomResult<_, E> =
+        api::server_wrap_util(&flow, state, request, payload, func, api_authentication).await;
+    let mut is_rejected = false;
+    let res = match resp {
         Ok(api::ApplicationResponse::Json(router_resp)) => {
             let pg_resp = S::try_from(router_resp);
             match pg_resp {
@@ -88,10 +83,16 @@ where
         )
         .respond_to(request)
         .map_into_boxed_body(),
+        Ok(api::ApplicationResponse::IdempotentReplay(response)) => {
+            api::idempotency::http_response_replayed(response)
+        }
 
         Err(error) => {
             logger::error!(api_response_error=?error);
+            is_rejected = api::idempotency::is_rejected_request(&error);
             api::log_and_return_error_response(error)
         }
-    }
+    };
+
+    api::idempotency::store_idempotent_response(state, request, res, is_rejected).await
 }
@@ -1,5 +1,5 @@
 mod defaults;
-#[cfg(feature = "kms")]
+#[cfg(feature = "secrets_management")]
 pub mod kms;
 pub mod settings;
 mod validations;
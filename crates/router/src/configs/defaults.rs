@@ -15,14 +15,14 @@ impl Default for super::settings::Database {
     fn default() -> Self {
         Self {
             username: String::new(),
-            #[cfg(not(feature = "kms"))]
+            #[cfg(not(feature = "secrets_management"))]
             password: String::new(),
             host: "localhost".into(),
             port: 5432,
             dbname: String::new(),
             pool_size: 5,
             connection_timeout: 10,
-            #[cfg(feature = "kms")]
+            #[cfg(feature = "secrets_management")]
             kms_encrypted_password: String::new(),
         }
     }
@@ -31,14 +31,14 @@ impl Default for super::settings::Database {
 impl Default for super::settings::Secrets {
     fn default() -> Self {
         Self {
-            #[cfg(not(feature = "kms"))]
+            #[cfg(not(feature = "secrets_management"))]
             jwt_secret: "secret".into(),
-            #[cfg(not(feature = "kms"))]
+            #[cfg(not(feature = "secrets_management"))]
             admin_api_key: "test_admin".into(),
             master_enc_key: "".into(),
-            #[cfg(feature = "kms")]
+            #[cfg(feature = "secrets_management")]
             kms_encrypted_jwt_secret: "".into(),
-            #[cfg(feature = "kms")]
+            #[cfg(feature = "secrets_management")]
             kms_encrypted_admin_api_key: "".into(),
         }
     }
@@ -91,6 +91,19 @@ impl Default for super::settings::EphemeralConfig {
     }
 }
 
+impl Default for super::settings::ApiKeys {
+    fn default() -> Self {
+        Self {
+            #[cfg(feature = "secrets_management")]
+            kms_encrypted_hash_key: String::new(),
+            #[cfg(not(feature = "secrets_management"))]
+            hash_key: String::new(),
+            rotation_grace_period: 24 * 60 * 60, // 1 day
+            last_used_flush_interval: 5 * 60,    // 5 minutes
+        }
+    }
+}
+
 impl Default for super::settings::SchedulerSettings {
     fn default() -> Self {
         Self {
@@ -115,6 +128,15 @@ impl Default for super::settings::ProducerSettings {
     }
 }
 
+impl Default for super::settings::HealthCheckSettings {
+    fn default() -> Self {
+        Self {
+            timeout: 5,
+            max_drainer_pending_entries: 10_000,
+        }
+    }
+}
+
 impl Default for super::settings::ConsumerSettings {
     fn default() -> Self {
         Self {
@@ -1,5 +1,5 @@
 use common_utils::errors::CustomResult;
-use external_services::kms;
+use external_services::secrets_management;
 use masking::ExposeInterface;
 
 use crate::configs::settings;
@@ -7,15 +7,21 @@ use crate::configs::settings;
 #[async_trait::async_trait]
 // This trait performs inplace decryption of the structure on which this is implemented
 pub trait KmsDecrypt {
-    async fn decrypt_inner(self, kms_config: &kms::KmsConfig) -> CustomResult<Self, kms::KmsError>
+    async fn decrypt_inner(
+        self,
+        secrets_management_config: &secrets_management::SecretsManagementConfig,
+    ) -> CustomResult<Self, secrets_management::SecretsManagementError>
     where
         Self: Sized;
 }
 
 #[async_trait::async_trait]
 impl KmsDecrypt for settings::Jwekey {
-    async fn decrypt_inner(self, kms_config: &kms::KmsConfig) -> CustomResult<Self, kms::KmsError> {
-        let client = kms::get_kms_client(kms_config).await;
+    async fn decrypt_inner(
+        self,
+        secrets_management_config: &secrets_management::SecretsManagementConfig,
+    ) -> CustomResult<Self, secrets_management::SecretsManagementError> {
+        let client = secrets_management::get_secret_manager(secrets_management_config).await?;
 
         // If this pattern required repetition, a macro approach needs to be deviced
         let (
@@ -27,13 +33,13 @@ impl KmsDecrypt for settings::Jwekey {
             vault_private_key,
             tunnel_private_key,
         ) = tokio::try_join!(
-            client.decrypt(self.locker_encryption_key1),
-            client.decrypt(self.locker_encryption_key2),
-            client.decrypt(self.locker_decryption_key1),
-            client.decrypt(self.locker_decryption_key2),
-            client.decrypt(self.vault_encryption_key),
-            client.decrypt(self.vault_private_key),
-            client.decrypt(self.tunnel_private_key),
+            client.decrypt(&self.locker_encryption_key1),
+            client.decrypt(&self.locker_encryption_key2),
+            client.decrypt(&self.locker_decryption_key1),
+            client.decrypt(&self.locker_decryption_key2),
+            client.decrypt(&self.vault_encryption_key),
+            client.decrypt(&self.vault_private_key),
+            client.decrypt(&self.tunnel_private_key),
         )?;
 
         Ok(Self {
@@ -52,9 +58,17 @@ impl KmsDecrypt for settings::Jwekey {
 
 #[async_trait::async_trait]
 impl KmsDecrypt for settings::ActiveKmsSecrets {
-    async fn decrypt_inner(self, kms_config: &kms::KmsConfig) -> CustomResult<Self, kms::KmsError> {
+    async fn decrypt_inner(
+        self,
+        secrets_management_config: &secrets_management::SecretsManagementConfig,
+    ) -> CustomResult<Self, secrets_management::SecretsManagementError> {
         Ok(Self {
-            jwekey: self.jwekey.expose().decrypt_inner(kms_config).await?.into(),
+            jwekey: self
+                .jwekey
+                .expose()
+                .decrypt_inner(secrets_management_config)
+                .await?
+                .into(),
         })
     }
 }
@@ -11,6 +11,8 @@ use config::{Environment, File};
 use external_services::email::EmailSettings;
 #[cfg(feature = "kms")]
 use external_services::kms;
+#[cfg(feature = "secrets_management")]
+use external_services::secrets_management;
 use redis_interface::RedisSettings;
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use serde::{de::Error, Deserialize, Deserializer};
@@ -37,9 +39,24 @@ pub enum Subcommand {
     #[cfg(feature = "openapi")]
     /// Generate the OpenAPI specification file from code.
     GenerateOpenapiSpec,
-}
-
-#[cfg(feature = "kms")]
+    /// Import a BIN data file into the card information used by the card IIN lookups.
+    ImportCardsInfo {
+        /// JSON or CSV file of BIN entries.
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+        /// Format of the file, inferred from its extension if not specified.
+        #[arg(long)]
+        format: Option<api_models::cards_info::CardsInfoImportFormat>,
+        /// Name of the provider of the BIN data, stored along with the imported entries.
+        #[arg(long)]
+        provider: Option<String>,
+        /// Validate the file and report the changes without writing them.
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[cfg(feature = "secrets_management")]
 /// Store the decrypted kms secret values for active use in the application
 /// Currently using `StrongSecret` won't have any effect as this struct have smart pointers to heap
 /// allocations.
@@ -70,11 +87,16 @@ pub struct Settings {
     pub drainer: DrainerSettings,
     pub jwekey: Jwekey,
     pub webhooks: WebhooksSettings,
+    pub health_check: HealthCheckSettings,
     pub pm_filters: ConnectorFilters,
     pub bank_config: BankRedirectConfig,
     pub api_keys: ApiKeys,
+    /// Deprecated AWS KMS configuration, used when the AWS KMS backend is not configured in
+    /// `secrets_management`
     #[cfg(feature = "kms")]
     pub kms: kms::KmsConfig,
+    #[cfg(feature = "secrets_management")]
+    pub secrets_management: secrets_management::SecretsManagementConfig,
     #[cfg(feature = "s3")]
     pub file_upload_config: FileUploadConfig,
     pub tokenization: TokenizationConfig,
@@ -277,14 +299,14 @@ where
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct Secrets {
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "secrets_management"))]
     pub jwt_secret: String,
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "secrets_management"))]
     pub admin_api_key: String,
     pub master_enc_key: String,
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "secrets_management")]
     pub kms_encrypted_jwt_secret: String,
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "secrets_management")]
     pub kms_encrypted_admin_api_key: String,
 }
 
@@ -355,14 +377,14 @@ pub struct Server {
 #[serde(default)]
 pub struct Database {
     pub username: String,
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "secrets_management"))]
     pub password: String,
     pub host: String,
     pub port: u16,
     pub dbname: String,
     pub pool_size: u32,
     pub connection_timeout: u64,
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "secrets_management")]
     pub kms_encrypted_password: String,
 }
 
@@ -483,18 +505,36 @@ pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
 }
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct HealthCheckSettings {
+    /// Time after which a component is considered unreachable (in seconds)
+    pub timeout: u64,
+    /// Number of entries pending in the drainer streams above which the drainer is considered
+    /// to be lagging and the application unhealthy
+    pub max_drainer_pending_entries: usize,
+}
+
+#[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct ApiKeys {
     /// Base64-encoded (KMS encrypted) ciphertext of the key used for calculating hashes of API
     /// keys
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "secrets_management")]
     pub kms_encrypted_hash_key: String,
 
     /// Hex-encoded 32-byte long (64 characters long when hex-encoded) key used for calculating
     /// hashes of API keys
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "secrets_management"))]
     pub hash_key: String,
+
+    /// Time in seconds for which the previous secret of a rotated API key continues to be
+    /// accepted
+    pub rotation_grace_period: i64,
+
+    /// Interval in seconds at which the last used times of API keys, collected in Redis, are
+    /// written to the database
+    pub last_used_flush_interval: i64,
 }
 
 #[cfg(feature = "s3")]
@@ -541,11 +581,21 @@ impl Settings {
             )
             .build()?;
 
-        serde_path_to_error::deserialize(config).map_err(|error| {
+        #[allow(unused_mut)]
+        let mut settings: Self = serde_path_to_error::deserialize(config).map_err(|error| {
             logger::error!(%error, "Unable to deserialize application configuration");
             eprintln!("Unable to deserialize application configuration: {error}");
             ApplicationError::from(error.into_inner())
-        })
+        })?;
+
+        #[cfg(feature = "kms")]
+        {
+            settings.secrets_management = settings
+                .secrets_management
+                .with_legacy_kms_config(&settings.kms);
+        }
+
+        Ok(settings)
     }
 
     pub fn validate(&self) -> ApplicationResult<()> {
@@ -581,8 +631,8 @@ impl Settings {
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
         self.api_keys.validate()?;
-        #[cfg(feature = "kms")]
-        self.kms
+        #[cfg(feature = "secrets_management")]
+        self.secrets_management
             .validate()
             .map_err(|error| ApplicationError::InvalidConfigurationValueError(error.into()))?;
         #[cfg(feature = "s3")]
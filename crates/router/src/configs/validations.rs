@@ -6,7 +6,7 @@ impl super::settings::Secrets {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
 
-        #[cfg(not(feature = "kms"))]
+        #[cfg(not(feature = "secrets_management"))]
         {
             when(self.jwt_secret.is_default_or_empty(), || {
                 Err(ApplicationError::InvalidConfigurationValueError(
@@ -21,7 +21,7 @@ impl super::settings::Secrets {
             })?;
         }
 
-        #[cfg(feature = "kms")]
+        #[cfg(feature = "secrets_management")]
         {
             when(self.kms_encrypted_jwt_secret.is_default_or_empty(), || {
                 Err(ApplicationError::InvalidConfigurationValueError(
@@ -99,7 +99,7 @@ impl super::settings::Database {
             ))
         })?;
 
-        #[cfg(not(feature = "kms"))]
+        #[cfg(not(feature = "secrets_management"))]
         {
             when(self.password.is_default_or_empty(), || {
                 Err(ApplicationError::InvalidConfigurationValueError(
@@ -108,7 +108,7 @@ impl super::settings::Database {
             })
         }
 
-        #[cfg(feature = "kms")]
+        #[cfg(feature = "secrets_management")]
         {
             when(self.kms_encrypted_password.is_default_or_empty(), || {
                 Err(ApplicationError::InvalidConfigurationValueError(
@@ -241,14 +241,26 @@ impl super::settings::ApiKeys {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
 
-        #[cfg(feature = "kms")]
+        when(self.rotation_grace_period < 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "API key rotation grace period must not be negative".into(),
+            ))
+        })?;
+
+        when(self.last_used_flush_interval <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "API key last used flush interval must be positive".into(),
+            ))
+        })?;
+
+        #[cfg(feature = "secrets_management")]
         return when(self.kms_encrypted_hash_key.is_default_or_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "API key hashing key must not be empty when KMS feature is enabled".into(),
             ))
         });
 
-        #[cfg(not(feature = "kms"))]
+        #[cfg(not(feature = "secrets_management"))]
         when(self.hash_key.is_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "API key hashing key must not be empty".into(),
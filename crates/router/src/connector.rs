@@ -12,6 +12,8 @@ pub mod cybersource;
 pub mod dlocal;
 #[cfg(feature = "dummy_connector")]
 pub mod dummyconnector;
+#[cfg(feature = "dummy_connector")]
+pub mod dummyfrm;
 pub mod fiserv;
 pub mod forte;
 pub mod globalpay;
@@ -38,6 +40,8 @@ pub mod zen;
 
 #[cfg(feature = "dummy_connector")]
 pub use self::dummyconnector::DummyConnector;
+#[cfg(feature = "dummy_connector")]
+pub use self::dummyfrm::DummyFrm;
 pub use self::{
     aci::Aci, adyen::Adyen, airwallex::Airwallex, authorizedotnet::Authorizedotnet,
     bambora::Bambora, bitpay::Bitpay, bluesnap::Bluesnap, braintree::Braintree, checkout::Checkout,
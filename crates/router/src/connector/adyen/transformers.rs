@@ -1995,6 +1995,10 @@ pub enum WebhookEventCode {
     SecondChargeback,
     PrearbitrationWon,
     PrearbitrationLost,
+    PayoutThirdparty,
+    PayoutDecline,
+    PayoutExpire,
+    PaidoutReversed,
     #[serde(other)]
     Unknown,
 }
@@ -2024,6 +2028,35 @@ pub fn is_chargeback_event(event_code: &WebhookEventCode) -> bool {
     )
 }
 
+pub fn is_payout_event(event_code: &WebhookEventCode) -> bool {
+    matches!(
+        event_code,
+        WebhookEventCode::PayoutThirdparty
+            | WebhookEventCode::PayoutDecline
+            | WebhookEventCode::PayoutExpire
+            | WebhookEventCode::PaidoutReversed
+    )
+}
+
+/// The outcome of a confirmed payout is only known from the `success` flag of its notification
+pub fn get_payout_webhook_event(
+    event_code: &WebhookEventCode,
+    success: &str,
+) -> webhooks::IncomingWebhookEvent {
+    match (event_code, success) {
+        (WebhookEventCode::PayoutThirdparty, "true") => {
+            webhooks::IncomingWebhookEvent::PayoutSuccess
+        }
+        (WebhookEventCode::PayoutThirdparty, _) => webhooks::IncomingWebhookEvent::PayoutFailure,
+        (WebhookEventCode::PayoutDecline, _) => webhooks::IncomingWebhookEvent::PayoutCancelled,
+        (WebhookEventCode::PayoutExpire, _) => webhooks::IncomingWebhookEvent::PayoutFailure,
+        (WebhookEventCode::PaidoutReversed, "true") => {
+            webhooks::IncomingWebhookEvent::PayoutReversed
+        }
+        _ => webhooks::IncomingWebhookEvent::EventNotSupported,
+    }
+}
+
 impl ForeignFrom<(WebhookEventCode, Option<DisputeStatus>)> for webhooks::IncomingWebhookEvent {
     fn foreign_from((code, status): (WebhookEventCode, Option<DisputeStatus>)) -> Self {
         match (code, status) {
@@ -2046,7 +2079,12 @@ impl ForeignFrom<(WebhookEventCode, Option<DisputeStatus>)> for webhooks::Incomi
             }
             (WebhookEventCode::PrearbitrationWon, _) => Self::DisputeWon,
             (WebhookEventCode::PrearbitrationLost, _) => Self::DisputeLost,
-            (WebhookEventCode::Unknown, _) => Self::EventNotSupported,
+            // Payout events are mapped along with their success flag, see get_payout_webhook_event
+            (WebhookEventCode::PayoutThirdparty, _)
+            | (WebhookEventCode::PayoutDecline, _)
+            | (WebhookEventCode::PayoutExpire, _)
+            | (WebhookEventCode::PaidoutReversed, _)
+            | (WebhookEventCode::Unknown, _) => Self::EventNotSupported,
         }
     }
 }
@@ -2110,3 +2148,215 @@ impl From<AdyenNotificationRequestItemWH> for AdyenResponse {
         }
     }
 }
+
+// Payouts Request and Response Types
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutCreateRequest {
+    amount: Amount,
+    merchant_account: String,
+    reference: String,
+    recurring: AdyenPayoutRecurring,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    card: Option<AdyenPayoutCard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bank: Option<AdyenPayoutBank>,
+    billing_address: Option<Address>,
+    shopper_email: Option<Email>,
+    shopper_reference: String,
+    shopper_name: Option<ShopperName>,
+    entity_type: AdyenPayoutEntityType,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutRecurring {
+    contract: AdyenPayoutContract,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AdyenPayoutContract {
+    Payout,
+}
+
+#[derive(Debug, Serialize)]
+pub enum AdyenPayoutEntityType {
+    NaturalPerson,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutCard {
+    number: CardNumber,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    holder_name: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutBank {
+    iban: Secret<String>,
+    bic: Option<Secret<String>>,
+    owner_name: Secret<String>,
+    bank_name: Option<String>,
+    country_code: Option<api_enums::CountryAlpha2>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutActionRequest {
+    merchant_account: String,
+    original_reference: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutCreateResponse {
+    psp_reference: String,
+    result_code: String,
+    refusal_reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutActionResponse {
+    psp_reference: String,
+    response: String,
+}
+
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenPayoutCreateRequest {
+    type Error = Error;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        let payout_method_data = item.request.payout_method_data.clone().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "payout_method_data",
+            },
+        )?;
+        let (card, bank) = match payout_method_data {
+            api::PayoutMethodData::Card(card) => (
+                Some(AdyenPayoutCard {
+                    number: card.card_number,
+                    expiry_month: card.expiry_month,
+                    expiry_year: card.expiry_year,
+                    holder_name: card.card_holder_name,
+                }),
+                None,
+            ),
+            api::PayoutMethodData::Bank(bank) => (
+                None,
+                Some(AdyenPayoutBank {
+                    iban: bank.iban,
+                    bic: bank.bic,
+                    owner_name: bank.account_holder_name,
+                    bank_name: bank.bank_name,
+                    country_code: bank.bank_country_code,
+                }),
+            ),
+        };
+        let shopper_reference = item
+            .customer_id
+            .clone()
+            .unwrap_or_else(|| format!("{}_{}", item.merchant_id, item.request.payout_id));
+        let shopper_name = item
+            .address
+            .billing
+            .as_ref()
+            .and_then(|billing| billing.address.as_ref())
+            .map(|address| ShopperName {
+                first_name: address.first_name.clone(),
+                last_name: address.last_name.clone(),
+            });
+        Ok(Self {
+            amount: Amount {
+                currency: item.request.currency.to_string(),
+                value: item.request.amount,
+            },
+            merchant_account: auth_type.merchant_account,
+            reference: item.request.payout_id.clone(),
+            recurring: AdyenPayoutRecurring {
+                contract: AdyenPayoutContract::Payout,
+            },
+            card,
+            bank,
+            billing_address: get_address_info(item.address.billing.as_ref()),
+            shopper_email: item.request.email.clone(),
+            shopper_reference,
+            shopper_name,
+            entity_type: AdyenPayoutEntityType::NaturalPerson,
+        })
+    }
+}
+
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenPayoutActionRequest {
+    type Error = Error;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            original_reference: item.request.connector_payout_id.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "connector_payout_id",
+                },
+            )?,
+        })
+    }
+}
+
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, AdyenPayoutCreateResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, AdyenPayoutCreateResponse>,
+    ) -> Result<Self, Self::Error> {
+        // Adyen only acknowledges the stored payout details here, the payout has to be confirmed
+        // before any funds are moved
+        // For more info: https://docs.adyen.com/online-payments/online-payouts
+        let response = match item.response.result_code.as_str() {
+            "[payout-submit-received]" => Ok(types::PayoutsResponseData {
+                connector_payout_id: item.response.psp_reference,
+                status: storage_enums::PayoutStatus::RequiresFulfillment,
+            }),
+            _ => Err(types::ErrorResponse {
+                code: item.response.result_code,
+                message: item
+                    .response
+                    .refusal_reason
+                    .clone()
+                    .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                reason: item.response.refusal_reason,
+                status_code: item.http_code,
+            }),
+        };
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
+
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, AdyenPayoutActionResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, AdyenPayoutActionResponse>,
+    ) -> Result<Self, Self::Error> {
+        // Confirming a payout only submits it, its outcome is notified through webhooks
+        let status = match item.response.response.as_str() {
+            "[payout-confirm-received]" => storage_enums::PayoutStatus::Pending,
+            "[payout-decline-received]" => storage_enums::PayoutStatus::Cancelled,
+            _ => storage_enums::PayoutStatus::Failed,
+        };
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                connector_payout_id: item.response.psp_reference,
+                status,
+            }),
+            ..item.data
+        })
+    }
+}
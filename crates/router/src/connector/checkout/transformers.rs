@@ -450,6 +450,7 @@ pub struct PaymentCaptureRequest {
     pub amount: Option<i64>,
     pub capture_type: Option<CaptureType>,
     pub processing_channel_id: String,
+    pub reference: Option<String>,
 }
 
 impl TryFrom<&types::PaymentsCaptureRouterData> for PaymentCaptureRequest {
@@ -458,10 +459,19 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for PaymentCaptureRequest {
         let connector_auth = &item.connector_auth_type;
         let auth_type: CheckoutAuthType = connector_auth.try_into()?;
         let processing_channel_id = auth_type.processing_channel_id;
+        // A non final capture leaves the rest of the authorization available for further captures
+        let (capture_type, reference) = match &item.request.multiple_capture_data {
+            Some(multiple_capture_data) => (
+                CaptureType::NonFinal,
+                Some(multiple_capture_data.capture_reference.clone()),
+            ),
+            None => (CaptureType::Final, None),
+        };
         Ok(Self {
             amount: Some(item.request.amount_to_capture),
-            capture_type: Some(CaptureType::Final),
+            capture_type: Some(capture_type),
             processing_channel_id,
+            reference,
         })
     }
 }
@@ -478,6 +488,27 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<PaymentCaptureResponse>>
     fn try_from(
         item: types::PaymentsCaptureResponseRouterData<PaymentCaptureResponse>,
     ) -> Result<Self, Self::Error> {
+        // Each of the multiple captures is tracked with its own action id, and is settled
+        // asynchronously by the connector
+        if item.data.request.multiple_capture_data.is_some() {
+            let status = if item.http_code == 202 {
+                enums::AttemptStatus::CaptureInitiated
+            } else {
+                enums::AttemptStatus::CaptureFailed
+            };
+            return Ok(Self {
+                response: Ok(types::PaymentsResponseData::TransactionResponse {
+                    resource_id: types::ResponseId::ConnectorTransactionId(item.response.action_id),
+                    redirection_data: None,
+                    mandate_reference: None,
+                    connector_metadata: None,
+                    network_txn_id: None,
+                }),
+                status,
+                ..item.data
+            });
+        }
+
         let (status, amount_captured) = if item.http_code == 202 {
             (
                 enums::AttemptStatus::Charged,
@@ -586,7 +617,7 @@ pub struct ErrorResponse {
     pub error_codes: Option<Vec<String>>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, PartialEq)]
 pub enum ActionType {
     Authorization,
     Void,
@@ -607,6 +638,8 @@ pub struct ActionResponse {
     pub action_type: ActionType,
     pub approved: Option<bool>,
     pub reference: Option<String>,
+    pub response_code: Option<String>,
+    pub response_summary: Option<String>,
 }
 
 impl From<&ActionResponse> for enums::RefundStatus {
@@ -619,6 +652,51 @@ impl From<&ActionResponse> for enums::RefundStatus {
     }
 }
 
+impl From<&ActionResponse> for types::CaptureSyncResponse {
+    fn from(item: &ActionResponse) -> Self {
+        let status = match item.approved {
+            Some(true) => enums::CaptureStatus::Charged,
+            Some(false) => enums::CaptureStatus::Failed,
+            None => enums::CaptureStatus::Pending,
+        };
+        Self {
+            status,
+            error_code: item.response_code.clone(),
+            error_message: item.response_summary.clone(),
+        }
+    }
+}
+
+impl TryFrom<types::PaymentsSyncResponseRouterData<Vec<ActionResponse>>>
+    for types::PaymentsSyncRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PaymentsSyncResponseRouterData<Vec<ActionResponse>>,
+    ) -> Result<Self, Self::Error> {
+        // The actions of all the captures of the payment are listed, only the captures requested
+        // to be synced are reported
+        let capture_ids = match &item.data.request.sync_type {
+            types::SyncRequestType::MultipleCaptureSync(capture_ids) => capture_ids.as_slice(),
+            types::SyncRequestType::SinglePaymentSync => &[],
+        };
+        let capture_sync_response_list = item
+            .response
+            .iter()
+            .filter(|action| {
+                action.action_type == ActionType::Capture && capture_ids.contains(&action.action_id)
+            })
+            .map(|action| (action.action_id.clone(), action.into()))
+            .collect();
+        Ok(Self {
+            response: Ok(types::PaymentsResponseData::MultipleCaptureResponse {
+                capture_sync_response_list,
+            }),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Debug, Clone, serde::Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum CheckoutRedirectResponseStatus {
@@ -0,0 +1,340 @@
+mod transformers;
+
+use std::fmt::Debug;
+
+use error_stack::ResultExt;
+use transformers as dummyfrm;
+
+use crate::{
+    configs::settings,
+    core::errors::{self, CustomResult},
+    headers,
+    services::{
+        self,
+        request::{self, Mask},
+        ConnectorIntegration,
+    },
+    types::{
+        self,
+        api::{fraud_check, ConnectorCommon, ConnectorCommonExt},
+        ErrorResponse, Response,
+    },
+    utils::{self, BytesExt},
+};
+
+/// Mock fraud and risk management connector backed by the dummy connector server, used to test
+/// the fraud check flows without an external FRM service
+#[derive(Debug, Clone)]
+pub struct DummyFrm;
+
+impl fraud_check::FraudCheck for DummyFrm {}
+impl fraud_check::FraudCheckCheckout for DummyFrm {}
+impl fraud_check::FraudCheckTransaction for DummyFrm {}
+impl fraud_check::FraudCheckFulfillment for DummyFrm {}
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for DummyFrm
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &types::RouterData<Flow, Request, Response>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            self.common_get_content_type().to_string().into(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+}
+
+impl ConnectorCommon for DummyFrm {
+    fn id(&self) -> &'static str {
+        "dummyfrm"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
+        connectors.dummyconnector.base_url.as_ref()
+    }
+
+    fn get_auth_header(
+        &self,
+        auth_type: &types::ConnectorAuthType,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let auth = dummyfrm::DummyFrmAuthType::try_from(auth_type)
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+        Ok(vec![(
+            headers::AUTHORIZATION.to_string(),
+            auth.api_key.into_masked(),
+        )])
+    }
+
+    fn build_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: dummyfrm::DummyFrmErrorResponse = res
+            .response
+            .parse_struct("DummyFrmErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(ErrorResponse {
+            status_code: res.status_code,
+            code: response.error.code,
+            message: response.error.message,
+            reason: response.error.reason,
+        })
+    }
+}
+
+impl
+    ConnectorIntegration<
+        fraud_check::Checkout,
+        types::FraudCheckCheckoutData,
+        types::FraudCheckResponseData,
+    > for DummyFrm
+{
+    fn get_headers(
+        &self,
+        req: &types::FrmCheckoutRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::FrmCheckoutRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}/frm/checkout", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::FrmCheckoutRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let req_obj = dummyfrm::DummyFrmCheckoutRequest::try_from(req)?;
+        let dummyfrm_req =
+            utils::Encode::<dummyfrm::DummyFrmCheckoutRequest>::encode_to_string_of_json(&req_obj)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(dummyfrm_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::FrmCheckoutRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::FrmCheckoutType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::FrmCheckoutType::get_headers(self, req, connectors)?)
+                .body(types::FrmCheckoutType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::FrmCheckoutRouterData,
+        res: Response,
+    ) -> CustomResult<types::FrmCheckoutRouterData, errors::ConnectorError> {
+        let response: dummyfrm::DummyFrmResponse = res
+            .response
+            .parse_struct("DummyFrm CheckoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl
+    ConnectorIntegration<
+        fraud_check::Transaction,
+        types::FraudCheckTransactionData,
+        types::FraudCheckResponseData,
+    > for DummyFrm
+{
+    fn get_headers(
+        &self,
+        req: &types::FrmTransactionRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::FrmTransactionRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}/frm/transaction", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::FrmTransactionRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let req_obj = dummyfrm::DummyFrmTransactionRequest::try_from(req)?;
+        let dummyfrm_req =
+            utils::Encode::<dummyfrm::DummyFrmTransactionRequest>::encode_to_string_of_json(
+                &req_obj,
+            )
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(dummyfrm_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::FrmTransactionRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::FrmTransactionType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::FrmTransactionType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::FrmTransactionType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::FrmTransactionRouterData,
+        res: Response,
+    ) -> CustomResult<types::FrmTransactionRouterData, errors::ConnectorError> {
+        let response: dummyfrm::DummyFrmResponse = res
+            .response
+            .parse_struct("DummyFrm TransactionResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl
+    ConnectorIntegration<
+        fraud_check::Fulfillment,
+        types::FraudCheckFulfillmentData,
+        types::FraudCheckResponseData,
+    > for DummyFrm
+{
+    fn get_headers(
+        &self,
+        req: &types::FrmFulfillmentRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::FrmFulfillmentRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}/frm/fulfillment", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::FrmFulfillmentRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let req_obj = dummyfrm::DummyFrmFulfillmentRequest::try_from(req)?;
+        let dummyfrm_req =
+            utils::Encode::<dummyfrm::DummyFrmFulfillmentRequest>::encode_to_string_of_json(
+                &req_obj,
+            )
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(dummyfrm_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::FrmFulfillmentRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::FrmFulfillmentType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::FrmFulfillmentType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::FrmFulfillmentType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::FrmFulfillmentRouterData,
+        res: Response,
+    ) -> CustomResult<types::FrmFulfillmentRouterData, errors::ConnectorError> {
+        let response: dummyfrm::DummyFrmResponse = res
+            .response
+            .parse_struct("DummyFrm FulfillmentResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
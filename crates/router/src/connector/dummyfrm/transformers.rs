@@ -0,0 +1,154 @@
+use common_utils::pii::Email;
+use serde::{Deserialize, Serialize};
+use storage_models::enums::Currency;
+
+use crate::{
+    core::errors,
+    types::{self, api::enums as api_enums, storage::enums},
+};
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct DummyFrmCheckoutRequest {
+    payment_id: String,
+    amount: i64,
+    currency: Currency,
+    email: Option<Email>,
+}
+
+impl TryFrom<&types::FrmCheckoutRouterData> for DummyFrmCheckoutRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::FrmCheckoutRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            payment_id: item.payment_id.clone(),
+            amount: item.request.amount,
+            currency: item.request.currency,
+            email: item.request.email.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DummyFrmTransactionStatus {
+    Success,
+    Failed,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct DummyFrmTransactionRequest {
+    payment_id: String,
+    amount: i64,
+    currency: Currency,
+    email: Option<Email>,
+    transaction_status: DummyFrmTransactionStatus,
+}
+
+impl TryFrom<&types::FrmTransactionRouterData> for DummyFrmTransactionRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::FrmTransactionRouterData) -> Result<Self, Self::Error> {
+        let transaction_status = match item.request.attempt_status {
+            enums::AttemptStatus::Authorized
+            | enums::AttemptStatus::Charged
+            | enums::AttemptStatus::PartialCharged
+            | enums::AttemptStatus::CaptureInitiated => DummyFrmTransactionStatus::Success,
+            _ => DummyFrmTransactionStatus::Failed,
+        };
+        Ok(Self {
+            payment_id: item.payment_id.clone(),
+            amount: item.request.amount,
+            currency: item.request.currency,
+            email: item.request.email.clone(),
+            transaction_status,
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct DummyFrmFulfillmentRequest {
+    payment_id: String,
+    fulfillment_id: String,
+}
+
+impl TryFrom<&types::FrmFulfillmentRouterData> for DummyFrmFulfillmentRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::FrmFulfillmentRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            payment_id: item.payment_id.clone(),
+            fulfillment_id: item.request.fulfillment_id.clone(),
+        })
+    }
+}
+
+// Auth Struct
+pub struct DummyFrmAuthType {
+    pub(super) api_key: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for DummyFrmAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::HeaderKey { api_key } => Ok(Self {
+                api_key: api_key.to_string(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DummyFrmDecision {
+    Accept,
+    Reject,
+    Review,
+}
+
+impl From<DummyFrmDecision> for api_enums::FraudCheckStatus {
+    fn from(item: DummyFrmDecision) -> Self {
+        match item {
+            DummyFrmDecision::Accept => Self::Legit,
+            DummyFrmDecision::Reject => Self::Fraud,
+            DummyFrmDecision::Review => Self::ManualReview,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct DummyFrmResponse {
+    id: String,
+    decision: DummyFrmDecision,
+    score: i32,
+    reason: Option<String>,
+}
+
+impl<F, T> TryFrom<types::FrmResponseRouterData<F, DummyFrmResponse, T>>
+    for types::RouterData<F, T, types::FraudCheckResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::FrmResponseRouterData<F, DummyFrmResponse, T>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::FraudCheckResponseData {
+                frm_reference_id: item.response.id,
+                status: item.response.decision.into(),
+                score: Some(item.response.score),
+                reason: item.response.reason,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
+pub struct DummyFrmErrorResponse {
+    pub error: ErrorData,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ErrorData {
+    pub code: String,
+    pub message: String,
+    pub reason: Option<String>,
+}
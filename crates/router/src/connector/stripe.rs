@@ -929,6 +929,115 @@ impl
     }
 }
 
+impl api::mandates::ConnectorMandateRevoke for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::MandateRevokeRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::MandateRevokeType::get_content_type(self)
+                .to_string()
+                .into(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::MandateRevokeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        // Mandates are set up on stripe by attaching the payment method to the customer
+        let payment_method_id = req
+            .request
+            .connector_mandate_id
+            .as_ref()
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "connector_mandate_id",
+            })
+            .into_report()?;
+        Ok(format!(
+            "{}v1/payment_methods/{}/detach",
+            self.base_url(connectors),
+            payment_method_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::MandateRevokeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::MandateRevokeType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::MandateRevokeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::MandateRevokeRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::MandateRevokeRouterData, errors::ConnectorError> {
+        let response: stripe::StripeDetachPaymentMethodResponse = res
+            .response
+            .parse_struct("StripeDetachPaymentMethodResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(connector_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        router_env::logger::info!(error_response=?response);
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
 type Verify = dyn services::ConnectorIntegration<
     api::Verify,
     types::VerifyRequestData,
@@ -1695,6 +1804,22 @@ impl api::IncomingWebhook for Stripe {
         &self,
         request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
+        let event_type: stripe::WebhookEventTypeBody = request
+            .body
+            .parse_struct("WebhookEventTypeBody")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        if let stripe::WebhookEventType::MandateUpdated = event_type.event_type {
+            let details: stripe::WebhookMandateEvent = request
+                .body
+                .parse_struct("WebhookMandateEvent")
+                .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+            return Ok(api_models::webhooks::ObjectReferenceId::MandateId(
+                api_models::webhooks::MandateIdType::ConnectorMandateId(
+                    details.event_data.event_object.payment_method,
+                ),
+            ));
+        }
+
         let details: stripe::WebhookEvent = request
             .body
             .parse_struct("WebhookEvent")
@@ -1773,6 +1898,17 @@ impl api::IncomingWebhook for Stripe {
             stripe::WebhookEventType::PaymentIntentRequiresAction => {
                 api::IncomingWebhookEvent::PaymentActionRequired
             }
+            stripe::WebhookEventType::MandateUpdated => {
+                match details.event_data.event_object.status {
+                    Some(stripe::WebhookEventStatus::Active) => {
+                        api::IncomingWebhookEvent::MandateActive
+                    }
+                    Some(stripe::WebhookEventStatus::Inactive) => {
+                        api::IncomingWebhookEvent::MandateRevoked
+                    }
+                    _ => api::IncomingWebhookEvent::EventNotSupported,
+                }
+            }
             stripe::WebhookEventType::Unknown
             | stripe::WebhookEventType::ChargeCaptured
             | stripe::WebhookEventType::ChargeDisputeCaptured
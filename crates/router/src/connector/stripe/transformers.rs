@@ -588,6 +588,8 @@ impl From<WebhookEventStatus> for api_models::webhooks::IncomingWebhookEvent {
             | WebhookEventStatus::RequiresCapture
             | WebhookEventStatus::Canceled
             | WebhookEventStatus::Chargeable
+            | WebhookEventStatus::Active
+            | WebhookEventStatus::Inactive
             | WebhookEventStatus::Unknown => Self::EventNotSupported,
         }
     }
@@ -2250,6 +2252,43 @@ impl<F, T>
     }
 }
 
+#[derive(Debug, Eq, PartialEq, Deserialize)]
+pub struct StripeDetachPaymentMethodResponse {
+    pub id: String,
+    pub customer: Option<String>,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            StripeDetachPaymentMethodResponse,
+            T,
+            types::MandateRevokeResponseData,
+        >,
+    > for types::RouterData<F, T, types::MandateRevokeResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            StripeDetachPaymentMethodResponse,
+            T,
+            types::MandateRevokeResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        // A payment method which is no longer attached to a customer cannot be charged off session
+        let mandate_status = match item.response.customer {
+            Some(_) => enums::MandateStatus::Active,
+            None => enums::MandateStatus::Revoked,
+        };
+        Ok(Self {
+            response: Ok(types::MandateRevokeResponseData { mandate_status }),
+            ..item.data
+        })
+    }
+}
+
 // #[cfg(test)]
 // mod test_stripe_transformers {
 //     use super::*;
@@ -2359,6 +2398,27 @@ pub struct WebhookEventObjectData {
     pub status: Option<WebhookEventStatus>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct WebhookMandateEvent {
+    #[serde(rename = "data")]
+    pub event_data: WebhookMandateEventData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WebhookMandateEventData {
+    #[serde(rename = "object")]
+    pub event_object: WebhookMandateObjectData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WebhookMandateObjectData {
+    pub id: String,
+    /// The payment method attached to the customer, which is what we store as the connector
+    /// mandate id
+    pub payment_method: String,
+    pub status: WebhookEventStatus,
+}
+
 #[derive(Debug, Deserialize, strum::Display)]
 #[serde(rename_all = "snake_case")]
 pub enum WebhookEventObjectType {
@@ -2416,6 +2476,8 @@ pub enum WebhookEventType {
     SourceTransactionCreated,
     #[serde(rename = "payment_intent.partially_funded")]
     PaymentIntentPartiallyFunded,
+    #[serde(rename = "mandate.updated")]
+    MandateUpdated,
     #[serde(other)]
     Unknown,
 }
@@ -2439,6 +2501,8 @@ pub enum WebhookEventStatus {
     RequiresCapture,
     Canceled,
     Chargeable,
+    Active,
+    Inactive,
     #[serde(other)]
     Unknown,
 }
@@ -26,8 +26,31 @@ pub(crate) const BASE64_ENGINE_URL_SAFE: base64::engine::GeneralPurpose =
     base64::engine::general_purpose::URL_SAFE;
 
 pub(crate) const API_KEY_LENGTH: usize = 64;
+
+// Idempotency keys
+pub(crate) const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+/// Time for which a response is replayed for retries with the same idempotency key (in seconds)
+pub(crate) const IDEMPOTENCY_KEY_TTL: i64 = 24 * 60 * 60;
+/// Time after which the idempotency key of a request that never completed is released (in seconds)
+pub(crate) const IDEMPOTENCY_KEY_IN_PROGRESS_TTL: i64 = 5 * 60;
+// Fraud and risk management
+pub(crate) const FRM_DECLINED_ERROR_CODE: &str = "FRM_DECLINED";
+pub(crate) const FRM_DECLINED_ERROR_MESSAGE: &str =
+    "Payment was declined by the fraud and risk management connector";
+
+// Automatic retries
+/// Error code recorded on an attempt whose connector call timed out
+pub(crate) const CONNECTOR_TIMEOUT_ERROR_CODE: &str = "CONNECTOR_TIMEOUT";
+/// Error code recorded on an attempt whose connector could not be reached or failed with a server
+/// error
+pub(crate) const CONNECTOR_UNAVAILABLE_ERROR_CODE: &str = "CONNECTOR_UNAVAILABLE";
+
 pub(crate) const PUB_SUB_CHANNEL: &str = "hyperswitch_invalidate";
 
 // Apple Pay validation url
 pub(crate) const APPLEPAY_VALIDATION_URL: &str =
     "https://apple-pay-gateway-cert.apple.com/paymentservices/startSession";
+
+// Cards info
+/// Maximum size of a BIN data file imported through the API (in bytes)
+pub(crate) const CARDS_INFO_IMPORT_BODY_LIMIT: usize = 10 * 1024 * 1024;
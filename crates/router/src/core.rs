@@ -6,10 +6,14 @@ pub mod customers;
 pub mod disputes;
 pub mod errors;
 pub mod files;
+pub mod fraud_check;
+pub mod health_check;
 pub mod mandate;
 pub mod metrics;
+pub mod notifications;
 pub mod payment_methods;
 pub mod payments;
+pub mod payouts;
 pub mod refunds;
 pub mod utils;
 pub mod webhooks;
@@ -1,19 +1,24 @@
+pub mod key_rotation;
+
 use api_models::admin::PrimaryBusinessDetails;
 use common_utils::{
-    crypto::{generate_cryptographically_secure_random_string, OptionalSecretValue},
+    crypto::{
+        generate_cryptographically_secure_random_string, Encryptable, GcmAes256,
+        OptionalSecretValue,
+    },
     date_time,
     ext_traits::ValueExt,
 };
 use error_stack::{report, FutureExt, ResultExt};
 use masking::Secret; //PeekInterface
-use storage_models::enums;
+use storage_models::{encryption::INITIAL_KEY_VERSION, enums};
 use uuid::Uuid;
 
 use crate::{
     consts,
     core::{
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
-        payments::helpers,
+        payments::{helpers, routing},
     },
     db::StorageInterface,
     routes::metrics,
@@ -22,7 +27,7 @@ use crate::{
         self, api,
         domain::{
             self, merchant_key_store,
-            types::{self as domain_types, AsyncLift},
+            types::{self as domain_types, AsyncLift, TypeEncryption},
         },
         storage,
         transformers::ForeignInto,
@@ -107,23 +112,26 @@ pub async fn create_merchant_account(
             .transpose()?;
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
+        let routing_algorithm: api::RoutingAlgorithm = routing_algorithm
             .clone()
             .parse_value("RoutingAlgorithm")
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "routing_algorithm",
             })
             .attach_printable("Invalid routing algorithm given")?;
+        routing::validate_routing_algorithm(&routing_algorithm)?;
     }
 
     let key_store = merchant_key_store::MerchantKeyStore {
         merchant_id: req.merchant_id.clone(),
-        key: domain_types::encrypt(key.to_vec().into(), master_key)
+        key: Encryptable::encrypt(key.to_vec().into(), master_key, GcmAes256)
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to decrypt data from key store")?,
         created_at: date_time::now(),
+        key_version: INITIAL_KEY_VERSION,
     };
+    let key = domain_types::MerchantEncryptionKeys::new(INITIAL_KEY_VERSION, key.to_vec());
 
     let enable_payment_response_hash = req.enable_payment_response_hash.unwrap_or(true);
 
@@ -177,6 +185,15 @@ pub async fn create_merchant_account(
         .insert_merchant(merchant_account)
         .await
         .to_duplicate_response(errors::ApiErrorResponse::DuplicateMerchantAccount)?;
+
+    #[cfg(feature = "email")]
+    crate::core::notifications::notify_merchant(
+        db,
+        &merchant_account.merchant_id,
+        storage::EmailNotification::MerchantAccountCreated,
+    )
+    .await;
+
     Ok(service_api::ApplicationResponse::Json(
         merchant_account
             .try_into()
@@ -224,13 +241,14 @@ pub async fn merchant_account_update(
     }
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
+        let routing_algorithm: api::RoutingAlgorithm = routing_algorithm
             .clone()
             .parse_value("RoutingAlgorithm")
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "routing_algorithm",
             })
             .attach_printable("Invalid routing algorithm given")?;
+        routing::validate_routing_algorithm(&routing_algorithm)?;
     }
 
     let primary_business_details = req
@@ -0,0 +1,527 @@
+use common_utils::{
+    crypto::{Encryptable, GcmAes256},
+    date_time,
+};
+use error_stack::{report, ResultExt};
+
+use crate::{
+    core::errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    scheduler::utils as pt_utils,
+    services,
+    types::{
+        api,
+        domain::{
+            self, merchant_key_store,
+            types::{self as domain_types, MerchantEncryptionKeys, TypeEncryption},
+        },
+        storage::{self, enums as storage_enums},
+    },
+    utils::ValueExt,
+};
+
+const KEY_ROTATION_RUNNER: &str = "MERCHANT_KEY_ROTATION_WORKFLOW";
+const KEY_ROTATION_TASK: &str = "MERCHANT_KEY_ROTATION";
+
+/// Number of customers or addresses re-encrypted between two updates of the progress
+const REENCRYPTION_BATCH_SIZE: i64 = 100;
+
+/// Number of times a customer or address is read again when it is modified while being
+/// re-encrypted, the batch is retried with the task once exhausted
+const REENCRYPTION_MAX_ATTEMPTS: usize = 3;
+
+/// Creates a new version of the key of a merchant, with which new data is encrypted right away,
+/// and schedules the re-encryption of the existing data with it
+pub async fn rotate_merchant_key(
+    db: &dyn StorageInterface,
+    merchant_id: String,
+) -> RouterResponse<api::MerchantKeyRotationResponse> {
+    db.find_merchant_account_by_merchant_id(&merchant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let key = domain_types::get_merchant_enc_key(db, &merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to get key from merchant key store")?;
+
+    let latest_rotation =
+        find_key_rotation_task(db, &merchant_id, key.get_latest_version()).await?;
+    if latest_rotation
+        .map(|process| process.status != storage_enums::ProcessTrackerStatus::Finish)
+        .unwrap_or(false)
+    {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The data of the merchant is still being re-encrypted with the latest key"
+                .to_string(),
+        })?
+    }
+
+    let new_key = services::generate_aes256_key()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to generate aes 256 key")?;
+    let key_store = merchant_key_store::MerchantKeyStore {
+        merchant_id: merchant_id.clone(),
+        key: Encryptable::encrypt(new_key.to_vec().into(), db.get_master_key(), GcmAes256)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt the new merchant key")?,
+        created_at: date_time::now(),
+        key_version: key.get_latest_version() + 1,
+    };
+    let key_store = db
+        .insert_merchant_key_store(key_store)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The key of the merchant is already being rotated".to_string(),
+        })?;
+
+    let tracking_data = storage::MerchantKeyRotationTrackingData {
+        merchant_id,
+        key_version: key_store.key_version,
+        progress: storage::ReencryptionProgress::default(),
+    };
+    add_key_rotation_task(db, &tracking_data)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while adding key rotation task to process tracker")?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_key_rotation_response(tracking_data, api::KeyRotationStatus::InProgress),
+    ))
+}
+
+pub async fn retrieve_merchant_key_rotation(
+    db: &dyn StorageInterface,
+    merchant_id: String,
+) -> RouterResponse<api::MerchantKeyRotationResponse> {
+    db.find_merchant_account_by_merchant_id(&merchant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let key = domain_types::get_merchant_enc_key(db, &merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to get key from merchant key store")?;
+
+    let response = match find_key_rotation_task(db, &merchant_id, key.get_latest_version()).await? {
+        Some(process) => {
+            let status = match process.status {
+                storage_enums::ProcessTrackerStatus::Finish
+                    if process.business_status.starts_with("COMPLETED_BY_PT") =>
+                {
+                    api::KeyRotationStatus::Completed
+                }
+                storage_enums::ProcessTrackerStatus::Finish => api::KeyRotationStatus::Failed,
+                _ => api::KeyRotationStatus::InProgress,
+            };
+            let tracking_data: storage::MerchantKeyRotationTrackingData = process
+                .tracking_data
+                .parse_value("MerchantKeyRotationTrackingData")
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            get_key_rotation_response(tracking_data, status)
+        }
+        // The key has never been rotated
+        None => get_key_rotation_response(
+            storage::MerchantKeyRotationTrackingData {
+                merchant_id,
+                key_version: key.get_latest_version(),
+                progress: storage::ReencryptionProgress::default(),
+            },
+            api::KeyRotationStatus::Completed,
+        ),
+    };
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+fn get_key_rotation_response(
+    tracking_data: storage::MerchantKeyRotationTrackingData,
+    status: api::KeyRotationStatus,
+) -> api::MerchantKeyRotationResponse {
+    api::MerchantKeyRotationResponse {
+        merchant_id: tracking_data.merchant_id,
+        key_version: tracking_data.key_version,
+        status,
+        reencrypted_records: api::ReencryptedRecords {
+            merchant_connector_accounts: tracking_data.progress.merchant_connector_accounts,
+            customers: tracking_data.progress.customers,
+            addresses: tracking_data.progress.addresses,
+        },
+    }
+}
+
+fn get_key_rotation_process_tracker_id(merchant_id: &str, key_version: i32) -> String {
+    pt_utils::get_process_tracker_id(
+        KEY_ROTATION_RUNNER,
+        KEY_ROTATION_TASK,
+        &key_version.to_string(),
+        merchant_id,
+    )
+}
+
+async fn find_key_rotation_task(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    key_version: i32,
+) -> RouterResult<Option<storage::ProcessTracker>> {
+    db.find_process_by_id(&get_key_rotation_process_tracker_id(
+        merchant_id,
+        key_version,
+    ))
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while fetching key rotation task")
+}
+
+pub async fn add_key_rotation_task(
+    db: &dyn StorageInterface,
+    tracking_data: &storage::MerchantKeyRotationTrackingData,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            get_key_rotation_process_tracker_id(
+                &tracking_data.merchant_id,
+                tracking_data.key_version,
+            ),
+            KEY_ROTATION_TASK,
+            KEY_ROTATION_RUNNER,
+            tracking_data,
+            date_time::now(),
+        )?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+    Ok(())
+}
+
+/// Re-encrypts the next batch of the data of a merchant which is not encrypted with the latest
+/// version of its key: the merchant account and its connector accounts first, then its customers
+/// and then its addresses. Returns whether all the data has been re-encrypted.
+pub async fn reencrypt_next_batch(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    key: &MerchantEncryptionKeys,
+    progress: &mut storage::ReencryptionProgress,
+) -> RouterResult<bool> {
+    let merchant_id = &merchant_account.merchant_id;
+
+    if !progress.merchant_accounts_completed {
+        reencrypt_merchant_account(db, merchant_account, key).await?;
+        progress.merchant_connector_accounts +=
+            reencrypt_merchant_connector_accounts(db, merchant_id, key).await?;
+        progress.merchant_accounts_completed = true;
+    } else if !progress.customers_completed {
+        let customers = db
+            .list_customers_by_merchant_id_after_row_id(
+                merchant_id,
+                progress.last_customer_row_id,
+                REENCRYPTION_BATCH_SIZE,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while listing customers")?;
+        progress.customers_completed = customers.is_empty();
+        for customer in customers {
+            progress.last_customer_row_id = customer.id;
+            if reencrypt_customer(db, key, customer).await? {
+                progress.customers += 1;
+            }
+        }
+    } else if !progress.addresses_completed {
+        let addresses = db
+            .list_addresses_by_merchant_id_after_row_id(
+                merchant_id,
+                progress.last_address_row_id,
+                REENCRYPTION_BATCH_SIZE,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while listing addresses")?;
+        progress.addresses_completed = addresses.is_empty();
+        for address in addresses {
+            progress.last_address_row_id = address.id;
+            if reencrypt_address(db, merchant_account.storage_scheme, key, address).await? {
+                progress.addresses += 1;
+            }
+        }
+    }
+
+    Ok(progress.addresses_completed)
+}
+
+async fn reencrypt_merchant_account(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    key: &MerchantEncryptionKeys,
+) -> RouterResult<()> {
+    let is_reencrypted = merchant_account
+        .merchant_name
+        .as_ref()
+        .map_or(true, |merchant_name| key.is_latest_version(merchant_name))
+        && merchant_account
+            .merchant_details
+            .as_ref()
+            .map_or(true, |merchant_details| {
+                key.is_latest_version(merchant_details)
+            });
+    if is_reencrypted {
+        return Ok(());
+    }
+
+    let merchant_account_update = async {
+        Ok(storage::MerchantAccountUpdate::Update {
+            merchant_name: domain_types::reencrypt_optional(
+                merchant_account.merchant_name.clone(),
+                key,
+            )
+            .await?,
+            merchant_details: domain_types::reencrypt_optional(
+                merchant_account.merchant_details.clone(),
+                key,
+            )
+            .await?,
+            return_url: None,
+            webhook_details: None,
+            sub_merchants_enabled: None,
+            parent_merchant_id: None,
+            enable_payment_response_hash: None,
+            payment_response_hash_key: None,
+            redirect_to_merchant_with_http_post: None,
+            publishable_key: None,
+            locker_id: None,
+            metadata: None,
+            routing_algorithm: None,
+            primary_business_details: None,
+            intent_fulfillment_time: None,
+            frm_routing_algorithm: None,
+        })
+    }
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while re-encrypting merchant account")?;
+
+    db.update_specific_fields_in_merchant(&merchant_account.merchant_id, merchant_account_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while updating merchant account")?;
+    Ok(())
+}
+
+async fn reencrypt_merchant_connector_accounts(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    key: &MerchantEncryptionKeys,
+) -> RouterResult<usize> {
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(merchant_id, true)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing merchant connector accounts")?;
+
+    let mut reencrypted = 0;
+    for merchant_connector_account in merchant_connector_accounts {
+        if key.is_latest_version(&merchant_connector_account.connector_account_details) {
+            continue;
+        }
+        let connector_account_details = domain_types::encrypt(
+            merchant_connector_account
+                .connector_account_details
+                .clone()
+                .into_inner(),
+            key,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while re-encrypting connector account details")?;
+
+        let merchant_connector_account_update = storage::MerchantConnectorAccountUpdate::Update {
+            merchant_id: None,
+            connector_type: None,
+            connector_name: None,
+            connector_account_details: Some(connector_account_details),
+            test_mode: None,
+            disabled: None,
+            merchant_connector_id: None,
+            payment_methods_enabled: None,
+            metadata: None,
+            frm_configs: None,
+        };
+        db.update_merchant_connector_account(
+            merchant_connector_account,
+            merchant_connector_account_update.into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while updating merchant connector account")?;
+        reencrypted += 1;
+    }
+
+    Ok(reencrypted)
+}
+
+/// Re-encrypts a customer, the write only goes through if the customer has not been modified since
+/// it was read. Otherwise the customer is read again, so that the concurrent update is not
+/// overwritten with the re-encrypted values read before it.
+async fn reencrypt_customer(
+    db: &dyn StorageInterface,
+    key: &MerchantEncryptionKeys,
+    mut customer: domain::Customer,
+) -> RouterResult<bool> {
+    for _ in 0..REENCRYPTION_MAX_ATTEMPTS {
+        let is_reencrypted = customer
+            .name
+            .as_ref()
+            .map_or(true, |name| key.is_latest_version(name))
+            && customer
+                .email
+                .as_ref()
+                .map_or(true, |email| key.is_latest_version(email))
+            && customer
+                .phone
+                .as_ref()
+                .map_or(true, |phone| key.is_latest_version(phone));
+        if is_reencrypted {
+            return Ok(false);
+        }
+
+        let domain::Customer {
+            customer_id,
+            merchant_id,
+            name,
+            email,
+            phone,
+            modified_at,
+            ..
+        } = customer;
+        let customer_update = async {
+            Ok(storage::CustomerUpdate::Update {
+                name: domain_types::reencrypt_optional(name, key).await?,
+                email: domain_types::reencrypt_optional(email, key).await?,
+                phone: domain_types::reencrypt_optional(phone, key).await?,
+                description: None,
+                phone_country_code: None,
+                metadata: None,
+                connector_customer: None,
+            })
+        }
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while re-encrypting customer")?;
+
+        match db
+            .update_customer_by_customer_id_merchant_id_modified_at(
+                customer_id.clone(),
+                merchant_id.clone(),
+                modified_at,
+                customer_update,
+            )
+            .await
+        {
+            Ok(_) => return Ok(true),
+            Err(error) if error.current_context().is_db_not_found() => {}
+            Err(error) => Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while updating customer"))?,
+        }
+
+        customer = match db
+            .find_customer_by_customer_id_merchant_id(&customer_id, &merchant_id)
+            .await
+        {
+            Ok(customer) => customer,
+            Err(error) if error.current_context().is_db_not_found() => return Ok(false),
+            Err(error) => Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while fetching customer"))?,
+        };
+    }
+
+    Err(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("Customer kept being modified while being re-encrypted")
+}
+
+/// Re-encrypts an address, retrying on concurrent updates the same way as customers
+async fn reencrypt_address(
+    db: &dyn StorageInterface,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+    key: &MerchantEncryptionKeys,
+    mut address: domain::Address,
+) -> RouterResult<bool> {
+    for _ in 0..REENCRYPTION_MAX_ATTEMPTS {
+        let is_reencrypted = [
+            &address.line1,
+            &address.line2,
+            &address.line3,
+            &address.state,
+            &address.zip,
+            &address.first_name,
+            &address.last_name,
+            &address.phone_number,
+        ]
+        .into_iter()
+        .flatten()
+        .all(|value| key.is_latest_version(value));
+        if is_reencrypted {
+            return Ok(false);
+        }
+
+        let domain::Address {
+            address_id,
+            line1,
+            line2,
+            line3,
+            state,
+            zip,
+            first_name,
+            last_name,
+            phone_number,
+            modified_at,
+            ..
+        } = address;
+        let address_update = async {
+            Ok(storage::AddressUpdate::Update {
+                city: None,
+                country: None,
+                line1: domain_types::reencrypt_optional(line1, key).await?,
+                line2: domain_types::reencrypt_optional(line2, key).await?,
+                line3: domain_types::reencrypt_optional(line3, key).await?,
+                state: domain_types::reencrypt_optional(state, key).await?,
+                zip: domain_types::reencrypt_optional(zip, key).await?,
+                first_name: domain_types::reencrypt_optional(first_name, key).await?,
+                last_name: domain_types::reencrypt_optional(last_name, key).await?,
+                phone_number: domain_types::reencrypt_optional(phone_number, key).await?,
+                country_code: None,
+            })
+        }
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while re-encrypting address")?;
+
+        match db
+            .update_address_by_address_id_modified_at(
+                address_id.clone(),
+                modified_at,
+                address_update,
+                storage_scheme,
+            )
+            .await
+        {
+            Ok(_) => return Ok(true),
+            Err(error) if error.current_context().is_db_not_found() => {}
+            Err(error) => Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while updating address"))?,
+        }
+
+        address = match db.find_address(&address_id, storage_scheme).await {
+            Ok(address) => address,
+            Err(error) if error.current_context().is_db_not_found() => return Ok(false),
+            Err(error) => Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while fetching address"))?,
+        };
+    }
+
+    Err(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("Address kept being modified while being re-encrypted")
+}
@@ -1,14 +1,16 @@
+use api_models::enums as api_enums;
 use common_utils::date_time;
 use error_stack::{report, IntoReport, ResultExt};
-#[cfg(feature = "kms")]
-use external_services::kms;
+#[cfg(feature = "secrets_management")]
+use external_services::secrets_management;
 use masking::{PeekInterface, StrongSecret};
-use router_env::{instrument, tracing};
+use redis_interface::SetnxReply;
+use router_env::{instrument, logger, tracing};
 
 use crate::{
     configs::settings,
     consts,
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::errors::{self, CustomResult, RouterResponse, StorageErrorExt},
     db::StorageInterface,
     routes::metrics,
     services::ApplicationResponse,
@@ -16,24 +18,28 @@ use crate::{
     utils,
 };
 
+const API_KEY_LAST_USED_KEY_PREFIX: &str = "api_keys_last_used";
+
 static HASH_KEY: tokio::sync::OnceCell<StrongSecret<[u8; PlaintextApiKey::HASH_KEY_LEN]>> =
     tokio::sync::OnceCell::const_new();
 
 pub async fn get_hash_key(
     api_key_config: &settings::ApiKeys,
-    #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
+    #[cfg(feature = "secrets_management")] secrets_management_config: &secrets_management::SecretsManagementConfig,
 ) -> errors::RouterResult<&'static StrongSecret<[u8; PlaintextApiKey::HASH_KEY_LEN]>> {
     HASH_KEY
         .get_or_try_init(|| async {
-            #[cfg(feature = "kms")]
-            let hash_key = kms::get_kms_client(kms_config)
+            #[cfg(feature = "secrets_management")]
+            let hash_key = secrets_management::get_secret_manager(secrets_management_config)
                 .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to initialize secret manager")?
                 .decrypt(&api_key_config.kms_encrypted_hash_key)
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Failed to KMS decrypt API key hashing key")?;
+                .attach_printable("Failed to decrypt API key hashing key")?;
 
-            #[cfg(not(feature = "kms"))]
+            #[cfg(not(feature = "secrets_management"))]
             let hash_key = &api_key_config.hash_key;
 
             <[u8; PlaintextApiKey::HASH_KEY_LEN]>::try_from(
@@ -115,18 +121,30 @@ impl PlaintextApiKey {
     }
 }
 
+fn validate_api_key_scopes(scopes: Option<&[api_enums::ApiKeyScope]>) -> errors::RouterResult<()> {
+    match scopes {
+        Some([]) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`scopes` cannot be empty, omit it for an API key with full access"
+                .to_string(),
+        })),
+        _ => Ok(()),
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn create_api_key(
     store: &dyn StorageInterface,
     api_key_config: &settings::ApiKeys,
-    #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
+    #[cfg(feature = "secrets_management")] secrets_management_config: &secrets_management::SecretsManagementConfig,
     api_key: api::CreateApiKeyRequest,
     merchant_id: String,
 ) -> RouterResponse<api::CreateApiKeyResponse> {
+    validate_api_key_scopes(api_key.scopes.as_deref())?;
+
     let hash_key = get_hash_key(
         api_key_config,
-        #[cfg(feature = "kms")]
-        kms_config,
+        #[cfg(feature = "secrets_management")]
+        secrets_management_config,
     )
     .await?;
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
@@ -140,6 +158,9 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        scopes: api_key
+            .scopes
+            .map(|scopes| scopes.into_iter().map(ForeignInto::foreign_into).collect()),
     };
 
     let api_key = store
@@ -148,6 +169,9 @@ pub async fn create_api_key(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to insert new API key")?;
 
+    #[cfg(feature = "email")]
+    crate::core::notifications::notify_api_key_expiry(store, &api_key).await;
+
     metrics::API_KEY_CREATED.add(
         &metrics::CONTEXT,
         1,
@@ -182,6 +206,8 @@ pub async fn update_api_key(
     key_id: &str,
     api_key: api::UpdateApiKeyRequest,
 ) -> RouterResponse<api::RetrieveApiKeyResponse> {
+    validate_api_key_scopes(api_key.scopes.as_deref())?;
+
     let api_key = store
         .update_api_key(
             merchant_id.to_owned(),
@@ -191,6 +217,10 @@ pub async fn update_api_key(
         .await
         .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
 
+    // A reminder is scheduled for a changed expiry, the one of the previous expiry is not sent
+    #[cfg(feature = "email")]
+    crate::core::notifications::notify_api_key_expiry(store, &api_key).await;
+
     Ok(ApplicationResponse::Json(api_key.foreign_into()))
 }
 
@@ -234,6 +264,169 @@ pub async fn list_api_keys(
     Ok(ApplicationResponse::Json(api_keys))
 }
 
+#[instrument(skip_all)]
+pub async fn rotate_api_key(
+    store: &dyn StorageInterface,
+    api_key_config: &settings::ApiKeys,
+    #[cfg(feature = "secrets_management")] secrets_management_config: &secrets_management::SecretsManagementConfig,
+    merchant_id: &str,
+    key_id: &str,
+) -> RouterResponse<api::CreateApiKeyResponse> {
+    let api_key = store
+        .find_api_key_by_merchant_id_key_id_optional(merchant_id, key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound))?;
+
+    let hash_key = get_hash_key(
+        api_key_config,
+        #[cfg(feature = "secrets_management")]
+        secrets_management_config,
+    )
+    .await?;
+    let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
+
+    // Only the secret the key had until now is kept valid during the grace period, so that
+    // rotating a key again ends the grace period of the secret it had before
+    let api_key_update = storage::ApiKeyUpdate::RotateUpdate {
+        hashed_api_key: plaintext_api_key.keyed_hash(hash_key.peek()).into(),
+        prefix: plaintext_api_key.prefix(),
+        previous_hashed_api_key: api_key.hashed_api_key,
+        previous_key_expires_at: date_time::now().saturating_add(time::Duration::seconds(
+            api_key_config.rotation_grace_period,
+        )),
+    };
+
+    let api_key = store
+        .update_api_key(merchant_id.to_owned(), key_id.to_owned(), api_key_update)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
+
+    metrics::API_KEY_ROTATED.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "merchant",
+            merchant_id.to_owned(),
+        )],
+    );
+
+    Ok(ApplicationResponse::Json(
+        (api_key, plaintext_api_key).foreign_into(),
+    ))
+}
+
+/// Records the use of an API key in a Redis hash, from which the last used times are written to
+/// the database once the current flush interval ends. The use is not recorded if the stored last
+/// used time is more recent than the flush interval, so that a frequently used key only causes a
+/// write every few intervals.
+pub async fn record_api_key_usage(
+    store: &dyn StorageInterface,
+    api_key_config: &settings::ApiKeys,
+    api_key: &storage::ApiKey,
+) -> errors::RouterResult<()> {
+    let now = date_time::now();
+    let flush_interval = time::Duration::seconds(api_key_config.last_used_flush_interval);
+    if api_key
+        .last_used
+        .map_or(false, |last_used| now - last_used < flush_interval)
+    {
+        return Ok(());
+    }
+
+    let redis_conn = store.get_redis_conn();
+    let bucket = now.assume_utc().unix_timestamp() / api_key_config.last_used_flush_interval;
+    let redis_key = format!("{API_KEY_LAST_USED_KEY_PREFIX}_{bucket}");
+    let last_used = utils::Encode::<storage::ApiKeyLastUsed>::encode_to_string_of_json(
+        &storage::ApiKeyLastUsed {
+            merchant_id: api_key.merchant_id.clone(),
+            key_id: api_key.key_id.clone(),
+            last_used: now,
+        },
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to serialize API key last used time")?;
+
+    redis_conn
+        .set_hash_fields(&redis_key, (api_key.key_id.as_str(), last_used))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record API key last used time in redis")?;
+
+    // The first use recorded in a flush interval schedules the flush of the interval
+    let reply = redis_conn
+        .set_key_if_not_exist(&format!("{redis_key}_flush_scheduled"), "true")
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to check whether the API key last used flush is scheduled")?;
+    if let SetnxReply::KeySet = reply {
+        add_api_key_last_used_flush_task(store, redis_key, now.saturating_add(flush_interval))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while adding API key last used flush task")?;
+    }
+
+    Ok(())
+}
+
+async fn add_api_key_last_used_flush_task(
+    db: &dyn StorageInterface,
+    redis_key: String,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let runner = "API_KEY_LAST_USED_FLUSH_WORKFLOW";
+    let task = "API_KEY_LAST_USED_FLUSH";
+    let process_tracker_id = format!("{runner}_{task}_{redis_key}");
+    let tracking_data = storage::ApiKeyLastUsedFlushTrackingData { redis_key };
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            task,
+            runner,
+            tracking_data,
+            schedule_time,
+        )?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        // The flush of the interval has already been scheduled
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error.change_context(errors::ProcessTrackerError::ProcessUpdateFailed)),
+    }
+}
+
+/// Writes the last used times of API keys collected in the given Redis hash to the database
+pub async fn flush_api_keys_last_used(
+    db: &dyn StorageInterface,
+    redis_key: &str,
+) -> Result<(), errors::ProcessTrackerError> {
+    let redis_conn = db.get_redis_conn();
+    let api_keys_last_used = redis_conn
+        .hscan_and_deserialize::<storage::ApiKeyLastUsed>(redis_key, "*", None)
+        .await?;
+
+    for api_key_last_used in api_keys_last_used {
+        let result = db
+            .update_api_key(
+                api_key_last_used.merchant_id,
+                api_key_last_used.key_id,
+                storage::ApiKeyUpdate::LastUsedUpdate {
+                    last_used: api_key_last_used.last_used,
+                },
+            )
+            .await;
+
+        // The key could have been revoked since it was used
+        if let Err(error) = result {
+            logger::warn!(?error, "Failed to update the last used time of an API key");
+        }
+    }
+
+    redis_conn.delete_key(redis_key).await?;
+    Ok(())
+}
+
 impl From<&str> for PlaintextApiKey {
     fn from(s: &str) -> Self {
         Self(s.to_owned().into())
@@ -270,8 +463,8 @@ mod tests {
         let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
         let hash_key = get_hash_key(
             &settings.api_keys,
-            #[cfg(feature = "kms")]
-            &settings.kms,
+            #[cfg(feature = "secrets_management")]
+            &settings.secrets_management,
         )
         .await
         .unwrap();
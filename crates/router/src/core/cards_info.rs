@@ -1,19 +1,34 @@
+use std::collections::{HashMap, HashSet};
+
+use api_models::cards_info as cards_info_api;
 use common_utils::fp_utils::when;
-use error_stack::{report, ResultExt};
-use router_env::{instrument, tracing};
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
 
 use crate::{
     core::{
-        errors::{self, RouterResponse},
+        errors::{self, CustomResult, RouterResponse, RouterResult},
         payments::helpers,
     },
+    db::StorageInterface,
     routes,
     services::ApplicationResponse,
-    types::{domain, transformers::ForeignFrom},
+    types::{domain, storage, transformers::ForeignFrom},
 };
 
+/// Lengths of the BIN prefixes stored in the cards info table
+const MIN_CARD_IIN_LENGTH: usize = 6;
+const MAX_CARD_IIN_LENGTH: usize = 8;
+
+/// Maximum length of the bank and country codes of a BIN entry
+const MAX_CODE_LENGTH: usize = 32;
+
+/// Number of BIN entries fetched per query while comparing an import with the stored data
+const IMPORT_LOOKUP_BATCH_SIZE: usize = 1000;
+
 fn verify_iin_length(card_iin: &str) -> Result<(), errors::ApiErrorResponse> {
-    let is_bin_length_in_range = card_iin.len() == 6 || card_iin.len() == 8;
+    let is_bin_length_in_range =
+        (MIN_CARD_IIN_LENGTH..=MAX_CARD_IIN_LENGTH).contains(&card_iin.len());
     when(!is_bin_length_in_range, || {
         Err(errors::ApiErrorResponse::InvalidCardIinLength)
     })
@@ -23,8 +38,8 @@ fn verify_iin_length(card_iin: &str) -> Result<(), errors::ApiErrorResponse> {
 pub async fn retrieve_card_info(
     state: &routes::AppState,
     merchant_account: domain::MerchantAccount,
-    request: api_models::cards_info::CardsInfoRequest,
-) -> RouterResponse<api_models::cards_info::CardInfoResponse> {
+    request: cards_info_api::CardsInfoRequest,
+) -> RouterResponse<cards_info_api::CardInfoResponse> {
     let db = &*state.store;
 
     verify_iin_length(&request.card_iin)?;
@@ -35,14 +50,399 @@ pub async fn retrieve_card_info(
     )
     .await?;
 
-    let card_info = db
-        .get_card_info(&request.card_iin)
+    let card_info = find_card_info_by_longest_prefix(db, &request.card_iin)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to retrieve card information")?
         .ok_or(report!(errors::ApiErrorResponse::InvalidCardIin))?;
 
     Ok(ApplicationResponse::Json(
-        api_models::cards_info::CardInfoResponse::foreign_from(card_info),
+        cards_info_api::CardInfoResponse::foreign_from(card_info),
     ))
 }
+
+/// BIN entries are stored as 6 to 8 digit prefixes, the most specific entry matching the card
+/// IIN is returned
+pub async fn find_card_info_by_longest_prefix(
+    db: &dyn StorageInterface,
+    card_iin: &str,
+) -> CustomResult<Option<storage::CardInfo>, errors::StorageError> {
+    let prefixes = (MIN_CARD_IIN_LENGTH..=card_iin.len().min(MAX_CARD_IIN_LENGTH))
+        .filter_map(|length| card_iin.get(..length))
+        .map(ToOwned::to_owned)
+        .collect::<Vec<_>>();
+
+    if prefixes.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(db
+        .find_card_info_by_iins(prefixes)
+        .await?
+        .into_iter()
+        .max_by_key(|card_info| card_info.card_iin.len()))
+}
+
+/// Imports a BIN data file into the cards info table, creating the missing BIN entries and
+/// updating the ones which differ from the file. The file is written only if all of its records
+/// are valid.
+#[instrument(skip_all)]
+pub async fn import_cards_info(
+    db: &dyn StorageInterface,
+    request: cards_info_api::CardsInfoImportRequest,
+) -> RouterResponse<cards_info_api::CardsInfoImportResponse> {
+    import_cards_info_records(db, request)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+pub async fn import_cards_info_records(
+    db: &dyn StorageInterface,
+    request: cards_info_api::CardsInfoImportRequest,
+) -> RouterResult<cards_info_api::CardsInfoImportResponse> {
+    let parsed_records = parse_import_records(request.format, &request.data)?;
+    let total_records = parsed_records.len();
+
+    let mut import_errors = Vec::new();
+    let mut records = Vec::new();
+    let mut seen_iins = HashSet::new();
+    for (index, parsed_record) in parsed_records.into_iter().enumerate() {
+        let record_number = index + 1;
+        match parsed_record.and_then(normalize_import_record) {
+            Ok(record) if !seen_iins.insert(record.card_iin.clone()) => {
+                import_errors.push(cards_info_api::CardsInfoImportError {
+                    record_number,
+                    card_iin: Some(record.card_iin),
+                    message: "card_iin is present more than once in the file".to_string(),
+                })
+            }
+            Ok(record) => records.push(record),
+            Err((card_iin, message)) => import_errors.push(cards_info_api::CardsInfoImportError {
+                record_number,
+                card_iin,
+                message,
+            }),
+        }
+    }
+
+    let mut existing = HashMap::new();
+    for chunk in records.chunks(IMPORT_LOOKUP_BATCH_SIZE) {
+        let card_iins = chunk.iter().map(|record| record.card_iin.clone()).collect();
+        existing.extend(
+            db.find_card_info_by_iins(card_iins)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the stored card information")?
+                .into_iter()
+                .map(|card_info| (card_info.card_iin.clone(), card_info)),
+        );
+    }
+
+    let mut new_records = Vec::new();
+    let mut changed_records = Vec::new();
+    let mut unchanged = 0;
+    for record in records {
+        match existing.get(&record.card_iin) {
+            None => new_records.push(record),
+            Some(card_info) if is_card_info_changed(card_info, &record) => {
+                changed_records.push(record)
+            }
+            Some(_) => unchanged += 1,
+        }
+    }
+
+    let response = cards_info_api::CardsInfoImportResponse {
+        dry_run: request.dry_run,
+        total_records,
+        created: new_records.len(),
+        updated: changed_records.len(),
+        unchanged,
+        errors: import_errors,
+    };
+
+    if request.dry_run || !response.errors.is_empty() {
+        return Ok(response);
+    }
+
+    let now = common_utils::date_time::now();
+    let new_card_info = new_records
+        .into_iter()
+        .map(|record| storage::CardInfoNew {
+            card_iin: record.card_iin,
+            card_issuer: record.card_issuer,
+            card_network: record.card_network,
+            card_type: record.card_type,
+            card_subtype: record.card_sub_type,
+            card_issuing_country: record.card_issuing_country,
+            bank_code_id: record.bank_code_id,
+            bank_code: record.bank_code,
+            country_code: record.country_code,
+            date_created: now,
+            last_updated: Some(now),
+            last_updated_provider: request.provider.clone(),
+        })
+        .collect();
+    let card_info_updates = changed_records
+        .into_iter()
+        .map(|record| {
+            (
+                record.card_iin,
+                storage::CardInfoUpdate::Update {
+                    card_issuer: record.card_issuer,
+                    card_network: record.card_network,
+                    card_type: record.card_type,
+                    card_subtype: record.card_sub_type,
+                    card_issuing_country: record.card_issuing_country,
+                    bank_code_id: record.bank_code_id,
+                    bank_code: record.bank_code,
+                    country_code: record.country_code,
+                    last_updated_provider: request.provider.clone(),
+                },
+            )
+        })
+        .collect();
+
+    db.import_card_info(new_card_info, card_info_updates)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to import card information")?;
+
+    logger::info!(
+        created = response.created,
+        updated = response.updated,
+        unchanged = response.unchanged,
+        "Imported card information"
+    );
+
+    Ok(response)
+}
+
+/// Error of a single record of an import file, along with its `card_iin` when known
+type ImportRecordError = (Option<String>, String);
+
+fn parse_import_records(
+    format: cards_info_api::CardsInfoImportFormat,
+    data: &str,
+) -> RouterResult<Vec<Result<cards_info_api::CardInfoImportRecord, ImportRecordError>>> {
+    let values = match format {
+        cards_info_api::CardsInfoImportFormat::Json => {
+            serde_json::from_str::<Vec<serde_json::Value>>(data)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "BIN file is not a JSON array of records".to_string(),
+                })?
+        }
+        cards_info_api::CardsInfoImportFormat::Csv => parse_csv(data)?,
+    };
+
+    Ok(values
+        .into_iter()
+        .map(|value| {
+            let card_iin = value
+                .get("card_iin")
+                .and_then(serde_json::Value::as_str)
+                .map(ToOwned::to_owned);
+            serde_json::from_value(value).map_err(|error| (card_iin, error.to_string()))
+        })
+        .collect())
+}
+
+/// Converts the rows of a CSV file into JSON objects keyed by the names of its header row, empty
+/// cells being left out
+fn parse_csv(data: &str) -> RouterResult<Vec<serde_json::Value>> {
+    let mut rows = split_csv_rows(data)
+        .map_err(|message| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("BIN file is not a valid CSV file: {message}"),
+            })
+        })?
+        .into_iter();
+
+    let header = rows
+        .next()
+        .map(|header| {
+            header
+                .into_iter()
+                .map(|name| name.trim().to_lowercase())
+                .collect::<Vec<_>>()
+        })
+        .filter(|header| header.iter().any(|name| name == "card_iin"))
+        .ok_or(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "BIN file must start with a header row containing a `card_iin` column"
+                .to_string(),
+        }))?;
+
+    Ok(rows
+        .map(|row| {
+            serde_json::Value::Object(
+                header
+                    .iter()
+                    .zip(row)
+                    .filter(|(_, cell)| !cell.is_empty())
+                    .map(|(name, cell)| (name.clone(), serde_json::Value::String(cell)))
+                    .collect(),
+            )
+        })
+        .collect())
+}
+
+/// Splits a CSV document into rows of cells, handling quoted cells with escaped quotes, commas and
+/// line breaks. Blank lines are skipped.
+fn split_csv_rows(data: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut in_quotes = false;
+    let mut chars = data.chars().peekable();
+
+    while let Some(character) = chars.next() {
+        match (character, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                cell.push('"');
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if cell.trim().is_empty() => {
+                cell.clear();
+                in_quotes = true;
+            }
+            (',', false) => row.push(std::mem::take(&mut cell)),
+            ('\r', false) if chars.peek() == Some(&'\n') => {}
+            ('\n', false) => {
+                row.push(std::mem::take(&mut cell));
+                let is_blank = row.len() == 1 && row.iter().all(|cell| cell.trim().is_empty());
+                let row = std::mem::take(&mut row);
+                if !is_blank {
+                    rows.push(row);
+                }
+            }
+            (character, _) => cell.push(character),
+        }
+    }
+
+    if in_quotes {
+        return Err(format!(
+            "unterminated quoted value in row {}",
+            rows.len() + 1
+        ));
+    }
+
+    row.push(cell);
+    if !(row.len() == 1 && row.iter().all(|cell| cell.trim().is_empty())) {
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+/// Trims the values of an imported record, dropping the empty ones, and validates them
+fn normalize_import_record(
+    record: cards_info_api::CardInfoImportRecord,
+) -> Result<cards_info_api::CardInfoImportRecord, ImportRecordError> {
+    let normalize = |value: Option<String>| {
+        value
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    let record = cards_info_api::CardInfoImportRecord {
+        card_iin: record.card_iin.trim().to_string(),
+        card_issuer: normalize(record.card_issuer),
+        card_network: normalize(record.card_network),
+        card_type: normalize(record.card_type),
+        card_sub_type: normalize(record.card_sub_type),
+        card_issuing_country: normalize(record.card_issuing_country),
+        bank_code_id: normalize(record.bank_code_id),
+        bank_code: normalize(record.bank_code),
+        country_code: normalize(record.country_code),
+    };
+
+    let error = |message: String| Err((Some(record.card_iin.clone()), message));
+
+    if verify_iin_length(&record.card_iin).is_err()
+        || !record.card_iin.chars().all(|c| c.is_ascii_digit())
+    {
+        return error(format!(
+            "card_iin must be a {MIN_CARD_IIN_LENGTH} to {MAX_CARD_IIN_LENGTH} digit prefix"
+        ));
+    }
+
+    for (field_name, value) in [
+        ("bank_code_id", &record.bank_code_id),
+        ("bank_code", &record.bank_code),
+        ("country_code", &record.country_code),
+    ] {
+        if value
+            .as_ref()
+            .map_or(false, |value| value.len() > MAX_CODE_LENGTH)
+        {
+            return error(format!(
+                "{field_name} must not be longer than {MAX_CODE_LENGTH} characters"
+            ));
+        }
+    }
+
+    if record.card_issuer.is_none()
+        && record.card_network.is_none()
+        && record.card_type.is_none()
+        && record.card_issuing_country.is_none()
+    {
+        return error("record has no issuer, network, type or country".to_string());
+    }
+
+    Ok(record)
+}
+
+fn is_card_info_changed(
+    card_info: &storage::CardInfo,
+    record: &cards_info_api::CardInfoImportRecord,
+) -> bool {
+    card_info.card_issuer != record.card_issuer
+        || card_info.card_network != record.card_network
+        || card_info.card_type != record.card_type
+        || card_info.card_subtype != record.card_sub_type
+        || card_info.card_issuing_country != record.card_issuing_country
+        || card_info.bank_code_id != record.bank_code_id
+        || card_info.bank_code != record.bank_code
+        || card_info.country_code != record.country_code
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_split_csv_rows_handles_quoted_cells() {
+        let rows =
+            split_csv_rows("card_iin,card_issuer\r\n424242,\"BANK, \"\"NORTH\"\"\"\n\n42424243,\n")
+                .unwrap();
+
+        assert_eq!(
+            rows,
+            vec![
+                vec!["card_iin", "card_issuer"],
+                vec!["424242", "BANK, \"NORTH\""],
+                vec!["42424243", ""],
+            ]
+        );
+        assert!(split_csv_rows("card_iin\n\"424242").is_err());
+    }
+
+    #[test]
+    fn test_parse_csv_import_records() {
+        let records = parse_import_records(
+            cards_info_api::CardsInfoImportFormat::Csv,
+            "CARD_IIN,card_network,card_subtype\n42424242,VISA,CLASSIC\n4242,,\n",
+        )
+        .unwrap()
+        .into_iter()
+        .map(|record| record.and_then(normalize_import_record))
+        .collect::<Vec<_>>();
+
+        let record = records[0].as_ref().unwrap();
+        assert_eq!(record.card_iin, "42424242");
+        assert_eq!(record.card_network.as_deref(), Some("VISA"));
+        assert_eq!(record.card_sub_type.as_deref(), Some("CLASSIC"));
+        assert!(records[1].is_err());
+    }
+}
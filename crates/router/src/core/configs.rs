@@ -1,7 +1,10 @@
 use error_stack::ResultExt;
 
 use crate::{
-    core::errors::{self, utils::StorageErrorExt, RouterResponse},
+    core::{
+        errors::{self, utils::StorageErrorExt, RouterResponse, RouterResult},
+        payments::auto_void,
+    },
     db::StorageInterface,
     services::ApplicationResponse,
     types::{api, transformers::ForeignInto},
@@ -19,6 +22,7 @@ pub async fn set_config(
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unknown error, while setting config key")?;
+    schedule_auto_void_sweep_if_required(store, &config.key).await?;
 
     Ok(ApplicationResponse::Json(config.foreign_into()))
 }
@@ -39,5 +43,24 @@ pub async fn update_config(
         .update_config_cached(&config_update.key, config_update.foreign_into())
         .await
         .to_not_found_response(errors::ApiErrorResponse::ConfigNotFound)?;
+    schedule_auto_void_sweep_if_required(store, &config.key).await?;
+
     Ok(ApplicationResponse::Json(config.foreign_into()))
 }
+
+/// Schedules the void of the authorizations already pending capture when the auto void of a
+/// merchant is configured, as only the payments authorized from now on are scheduled otherwise
+async fn schedule_auto_void_sweep_if_required(
+    store: &dyn StorageInterface,
+    key: &str,
+) -> RouterResult<()> {
+    match auto_void::get_merchant_id_from_config_key(key) {
+        Some(merchant_id) => {
+            auto_void::add_auto_void_sweep_task(store, merchant_id, common_utils::date_time::now())
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while adding auto void sweep task to process tracker")
+        }
+        None => Ok(()),
+    }
+}
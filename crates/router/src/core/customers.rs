@@ -1,7 +1,6 @@
-use common_utils::{
-    crypto::{Encryptable, GcmAes256},
-    ext_traits::ValueExt,
-};
+pub mod redaction;
+
+use common_utils::ext_traits::ValueExt;
 use error_stack::ResultExt;
 use masking::ExposeInterface;
 use router_env::{instrument, tracing};
@@ -21,7 +20,7 @@ use crate::{
         api::customers,
         domain::{
             self,
-            types::{self, AsyncLift, TypeEncryption},
+            types::{self, AsyncLift},
         },
         storage::{self, enums},
     },
@@ -102,7 +101,7 @@ pub async fn create_customer(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed while encrypting address")?;
 
-        db.insert_address(address)
+        db.insert_address(address, merchant_account.storage_scheme)
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed while inserting new address")?;
@@ -238,30 +237,15 @@ pub async fn delete_customer(
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed while getting key for encryption")?;
-    let redacted_encrypted_value: Encryptable<masking::Secret<_>> =
-        Encryptable::encrypt(REDACTED.to_string().into(), &key, GcmAes256)
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)?;
-
-    let update_address = storage::AddressUpdate::Update {
-        city: Some(REDACTED.to_string()),
-        country: None,
-        line1: Some(redacted_encrypted_value.clone()),
-        line2: Some(redacted_encrypted_value.clone()),
-        line3: Some(redacted_encrypted_value.clone()),
-        state: Some(redacted_encrypted_value.clone()),
-        zip: Some(redacted_encrypted_value.clone()),
-        first_name: Some(redacted_encrypted_value.clone()),
-        last_name: Some(redacted_encrypted_value.clone()),
-        phone_number: Some(redacted_encrypted_value.clone()),
-        country_code: Some(REDACTED.to_string()),
-    };
+    let redacted_encrypted_value = redaction::encrypt_redacted_value(&key).await?;
+    let update_address = redaction::get_redacted_address_update(&redacted_encrypted_value);
 
     match db
         .update_address_by_merchant_id_customer_id(
             &req.customer_id,
             &merchant_account.merchant_id,
             update_address,
+            merchant_account.storage_scheme,
         )
         .await
     {
@@ -278,7 +262,7 @@ pub async fn delete_customer(
     let updated_customer = storage::CustomerUpdate::Update {
         name: Some(redacted_encrypted_value.clone()),
         email: Some(
-            Encryptable::encrypt(REDACTED.to_string().into(), &key, GcmAes256)
+            types::encrypt(REDACTED.to_string().into(), &key)
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)?,
         ),
@@ -290,17 +274,26 @@ pub async fn delete_customer(
     };
     db.update_customer_by_customer_id_merchant_id(
         req.customer_id.clone(),
-        merchant_account.merchant_id,
+        merchant_account.merchant_id.clone(),
         updated_customer,
     )
     .await
     .change_context(errors::ApiErrorResponse::CustomerNotFound)?;
 
+    // The payments, mandates and disputes of the customer are redacted in the background
+    let customer_redaction = redaction::schedule_customer_redaction(
+        &**db,
+        &merchant_account.merchant_id,
+        &req.customer_id,
+    )
+    .await?;
+
     let response = customers::CustomerDeleteResponse {
         customer_id: req.customer_id,
         customer_deleted: true,
         address_deleted: true,
         payment_methods_deleted: true,
+        redaction_id: customer_redaction.redaction_id,
     };
     metrics::CUSTOMER_REDACTED.add(&metrics::CONTEXT, 1, &[]);
     Ok(services::ApplicationResponse::Json(response))
@@ -378,6 +371,7 @@ pub async fn update_customer(
             &update_customer.customer_id,
             &merchant_account.merchant_id,
             update_address,
+            merchant_account.storage_scheme,
         )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
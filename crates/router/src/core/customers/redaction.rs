@@ -0,0 +1,448 @@
+use std::collections::HashSet;
+
+use common_utils::crypto::Encryptable;
+use error_stack::ResultExt;
+use masking::Secret;
+use router_env::{instrument, logger, tracing};
+
+use super::REDACTED;
+use crate::{
+    consts,
+    core::{
+        errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        files::helpers as files_helpers,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    scheduler::utils as pt_utils,
+    types::{
+        api,
+        domain::{self, types},
+        storage::{self, enums as storage_enums},
+    },
+    utils::{generate_id, ValueExt},
+};
+
+/// Keys of the browser information of a payment attempt which identify the customer
+const BROWSER_INFO_PII_KEYS: [&str; 4] = ["ip_address", "user_agent", "accept_header", "language"];
+
+pub async fn encrypt_redacted_value(
+    key: &types::MerchantEncryptionKeys,
+) -> RouterResult<Encryptable<Secret<String>>> {
+    types::encrypt(REDACTED.to_string().into(), key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while encrypting the redacted value")
+}
+
+pub fn get_redacted_address_update(
+    redacted_encrypted_value: &Encryptable<Secret<String>>,
+) -> storage::AddressUpdate {
+    storage::AddressUpdate::Update {
+        city: Some(REDACTED.to_string()),
+        country: None,
+        line1: Some(redacted_encrypted_value.clone()),
+        line2: Some(redacted_encrypted_value.clone()),
+        line3: Some(redacted_encrypted_value.clone()),
+        state: Some(redacted_encrypted_value.clone()),
+        zip: Some(redacted_encrypted_value.clone()),
+        first_name: Some(redacted_encrypted_value.clone()),
+        last_name: Some(redacted_encrypted_value.clone()),
+        phone_number: Some(redacted_encrypted_value.clone()),
+        country_code: Some(REDACTED.to_string()),
+    }
+}
+
+/// Creates the audit record of the redaction of a customer and schedules the redaction of the
+/// records linked to the customer, which can be too many to be redacted within the request
+pub async fn schedule_customer_redaction(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    customer_id: &str,
+) -> RouterResult<storage::CustomerRedaction> {
+    let customer_redaction = db
+        .insert_customer_redaction(storage::CustomerRedactionNew {
+            redaction_id: generate_id(consts::ID_LENGTH, "redact"),
+            merchant_id: merchant_id.to_string(),
+            customer_id: customer_id.to_string(),
+            status: storage_enums::CustomerRedactionStatus::Scheduled,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting customer redaction record")?;
+
+    add_customer_redaction_task(db, &customer_redaction)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while adding customer redaction task to process tracker")?;
+
+    Ok(customer_redaction)
+}
+
+pub async fn add_customer_redaction_task(
+    db: &dyn StorageInterface,
+    customer_redaction: &storage::CustomerRedaction,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let tracking_data = storage::CustomerRedactionTrackingData {
+        merchant_id: customer_redaction.merchant_id.clone(),
+        customer_id: customer_redaction.customer_id.clone(),
+        redaction_id: customer_redaction.redaction_id.clone(),
+    };
+    let runner = "CUSTOMER_REDACTION_WORKFLOW";
+    let task = "CUSTOMER_REDACTION";
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &customer_redaction.redaction_id,
+        &customer_redaction.merchant_id,
+    );
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            task,
+            runner,
+            tracking_data,
+            common_utils::date_time::now(),
+        )?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+    Ok(())
+}
+
+/// Redacts the personal data held in the records linked to a customer: its addresses, the
+/// descriptions and payment method data of its payments, the acceptance details of its mandates,
+/// the evidence files of the disputes raised against its payments and the webhooks sent about its
+/// payments and mandates. Amounts, statuses and
+/// identifiers are left untouched, so that the records can still be used for accounting.
+///
+/// The redaction can be run again on the same customer, which is how failed tasks are retried.
+#[instrument(skip_all)]
+pub async fn redact_customer_records(
+    state: &AppState,
+    merchant_id: &str,
+    customer_id: &str,
+) -> RouterResult<storage::RedactedRecords> {
+    let db = &*state.store;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let key = types::get_merchant_enc_key(db, merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while getting key for encryption")?;
+    let redacted_encrypted_value = encrypt_redacted_value(&key).await?;
+
+    let mut redacted_records = storage::RedactedRecords::default();
+
+    let mut redacted_address_ids = match db
+        .update_address_by_merchant_id_customer_id(
+            customer_id,
+            merchant_id,
+            get_redacted_address_update(&redacted_encrypted_value),
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(addresses) => addresses
+            .into_iter()
+            .map(|address| address.address_id)
+            .collect::<HashSet<_>>(),
+        Err(error) if error.current_context().is_db_not_found() => HashSet::new(),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while redacting customer addresses"))?,
+    };
+
+    let payment_intents = db
+        .find_payment_intents_by_merchant_id_customer_id(merchant_id, customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching the payments of the customer")?;
+
+    let mut primary_object_ids = Vec::new();
+    for payment_intent in payment_intents {
+        primary_object_ids.push(payment_intent.payment_id.clone());
+        // Addresses of payments made before the customer was attached to them are not linked
+        // to the customer, they are redacted through the payment
+        for address_id in [
+            &payment_intent.shipping_address_id,
+            &payment_intent.billing_address_id,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if redacted_address_ids.contains(address_id) {
+                continue;
+            }
+            match db
+                .update_address(
+                    address_id.clone(),
+                    get_redacted_address_update(&redacted_encrypted_value),
+                    merchant_account.storage_scheme,
+                )
+                .await
+            {
+                Ok(_) => {
+                    redacted_address_ids.insert(address_id.clone());
+                }
+                Err(error) if error.current_context().is_db_not_found() => {}
+                Err(error) => Err(error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed while redacting payment address"))?,
+            }
+        }
+
+        redacted_records.payment_attempts +=
+            redact_payment_attempts(db, &merchant_account, &payment_intent).await?;
+
+        let disputes = db
+            .find_disputes_by_merchant_id_payment_id(
+                merchant_id,
+                &payment_intent.payment_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching the disputes of the payment")?;
+        for dispute in disputes {
+            let redacted_files = redact_dispute_evidence(state, &merchant_account, dispute).await?;
+            if redacted_files > 0 {
+                redacted_records.disputes += 1;
+                redacted_records.files += redacted_files;
+            }
+        }
+
+        if payment_intent.description.is_some() {
+            db.update_payment_intent(
+                payment_intent,
+                storage::PaymentIntentUpdate::RedactionUpdate {
+                    description: Some(REDACTED.to_string()),
+                },
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while redacting payment description")?;
+        }
+        redacted_records.payment_intents += 1;
+    }
+    redacted_records.addresses = redacted_address_ids.len();
+
+    let mandates = db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching the mandates of the customer")?;
+    for mandate in mandates {
+        primary_object_ids.push(mandate.mandate_id.clone());
+        if mandate.customer_ip_address.is_none() && mandate.customer_user_agent.is_none() {
+            continue;
+        }
+        db.update_mandate_by_merchant_id_mandate_id(
+            merchant_id,
+            &mandate.mandate_id,
+            storage::MandateUpdate::RedactionUpdate {
+                customer_ip_address: mandate
+                    .customer_ip_address
+                    .map(|_| Secret::new(REDACTED.to_string())),
+                customer_user_agent: mandate.customer_user_agent.map(|_| REDACTED.to_string()),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while redacting mandate")?;
+        redacted_records.mandates += 1;
+    }
+
+    // The webhooks carry the payment and mandate responses, with the customer details and
+    // addresses, the request bodies logged for their delivery are replaced as a whole
+    if !primary_object_ids.is_empty() {
+        redacted_records.event_delivery_attempts = db
+            .update_event_delivery_attempt_request_body_by_primary_object_ids(
+                merchant_id,
+                primary_object_ids,
+                REDACTED.to_string(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while redacting webhook delivery attempts")?;
+    }
+
+    Ok(redacted_records)
+}
+
+/// The payment method data of an attempt is only kept for reference, it is replaced as a whole.
+/// The browser information is still needed for authorizations, only the keys identifying the
+/// customer are removed from it.
+async fn redact_payment_attempts(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<usize> {
+    let payment_attempts = db
+        .find_payment_attempts_by_payment_id_merchant_id(
+            &payment_intent.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching the attempts of the payment")?;
+
+    let mut redacted_attempts = 0;
+    for payment_attempt in payment_attempts {
+        if payment_attempt.payment_method_data.is_none() && payment_attempt.browser_info.is_none() {
+            continue;
+        }
+        let payment_attempt_update = storage::PaymentAttemptUpdate::RedactionUpdate {
+            payment_method_data: payment_attempt
+                .payment_method_data
+                .as_ref()
+                .map(|_| serde_json::Value::String(REDACTED.to_string())),
+            browser_info: payment_attempt
+                .browser_info
+                .clone()
+                .map(redact_browser_info),
+        };
+        db.update_payment_attempt_with_attempt_id(
+            payment_attempt,
+            payment_attempt_update,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while redacting payment attempt")?;
+        redacted_attempts += 1;
+    }
+
+    Ok(redacted_attempts)
+}
+
+fn redact_browser_info(mut browser_info: serde_json::Value) -> serde_json::Value {
+    if let Some(browser_info) = browser_info.as_object_mut() {
+        for key in BROWSER_INFO_PII_KEYS {
+            browser_info.remove(key);
+        }
+    }
+    browser_info
+}
+
+/// The evidence of a dispute only references uploaded files, the files themselves are deleted
+/// and their metadata is redacted. Files which have been uploaded to a connector can only be
+/// deleted at the connector.
+async fn redact_dispute_evidence(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    dispute: storage::Dispute,
+) -> RouterResult<usize> {
+    let db = &*state.store;
+    let dispute_evidence: api::DisputeEvidence = dispute
+        .evidence
+        .clone()
+        .parse_value("DisputeEvidence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing dispute evidence record")?;
+
+    let mut redacted_files = 0;
+    for file_id in get_evidence_file_ids(dispute_evidence) {
+        let file_metadata = match db
+            .find_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, &file_id)
+            .await
+        {
+            Ok(file_metadata) => file_metadata,
+            Err(error) if error.current_context().is_db_not_found() => continue,
+            Err(error) => Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while fetching evidence file metadata"))?,
+        };
+
+        let is_redacted = !file_metadata.available
+            && file_metadata
+                .file_name
+                .as_deref()
+                .map_or(true, |file_name| file_name == REDACTED);
+        if is_redacted {
+            continue;
+        }
+
+        match (
+            file_metadata.available,
+            file_metadata.file_upload_provider,
+            file_metadata.provider_file_id.clone(),
+        ) {
+            (true, Some(storage_enums::FileUploadProvider::Router), Some(provider_file_id)) => {
+                files_helpers::delete_file(
+                    #[cfg(feature = "s3")]
+                    state,
+                    provider_file_id,
+                )
+                .await
+                .attach_printable("Failed while deleting evidence file")?
+            }
+            (true, Some(provider), _) => logger::warn!(
+                file_id = %file_metadata.file_id,
+                %provider,
+                "Evidence file uploaded to a connector has to be deleted at the connector"
+            ),
+            _ => {}
+        }
+
+        let file_name = file_metadata
+            .file_name
+            .as_ref()
+            .map(|_| REDACTED.to_string());
+        db.update_file_metadata(
+            file_metadata,
+            storage::FileMetadataUpdate::RedactionUpdate { file_name },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while redacting evidence file metadata")?;
+        redacted_files += 1;
+    }
+
+    Ok(redacted_files)
+}
+
+fn get_evidence_file_ids(dispute_evidence: api::DisputeEvidence) -> Vec<String> {
+    [
+        dispute_evidence.cancellation_policy,
+        dispute_evidence.customer_communication,
+        dispute_evidence.customer_signature,
+        dispute_evidence.receipt,
+        dispute_evidence.refund_policy,
+        dispute_evidence.service_documentation,
+        dispute_evidence.shipping_documentation,
+        dispute_evidence.invoice_showing_distinct_transactions,
+        dispute_evidence.recurring_transaction_agreement,
+        dispute_evidence.uncategorized_file,
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_browser_info_keeps_device_details() {
+        let browser_info = serde_json::json!({
+            "ip_address": "127.0.0.1",
+            "user_agent": "Mozilla/5.0",
+            "accept_header": "text/html",
+            "language": "en-US",
+            "screen_height": 1080,
+            "java_enabled": true
+        });
+
+        assert_eq!(
+            redact_browser_info(browser_info),
+            serde_json::json!({ "screen_height": 1080, "java_enabled": true })
+        );
+    }
+}
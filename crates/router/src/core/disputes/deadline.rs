@@ -0,0 +1,477 @@
+use common_utils::{date_time, ext_traits::ValueExt};
+use error_stack::ResultExt;
+use time::{Duration, PrimitiveDateTime};
+
+use crate::{
+    core::{
+        errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        webhooks,
+    },
+    db::StorageInterface,
+    logger,
+    routes::AppState,
+    scheduler::{metrics as scheduler_metrics, utils as pt_utils},
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+        transformers::{ForeignFrom, ForeignTryInto},
+    },
+};
+
+const DISPUTE_DEADLINE_RUNNER: &str = "DISPUTE_DEADLINE_WORKFLOW";
+const DISPUTE_DEADLINE_TASK: &str = "DISPUTE_DEADLINE";
+
+/// Per-merchant configuration of the tracking of dispute evidence deadlines, stored in the
+/// configs table under `dispute_deadline_{merchant_id}`
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(default)]
+pub struct DisputeDeadlineConfig {
+    /// Offsets before the deadline, in seconds, at which the merchant is reminded of it
+    pub reminder_offsets: Vec<i64>,
+    /// Whether the evidence attached to a dispute is submitted automatically before the deadline
+    pub auto_submit_evidence: bool,
+    /// Offset before the deadline, in seconds, at which the attached evidence is submitted
+    pub auto_submit_offset: i64,
+}
+
+impl Default for DisputeDeadlineConfig {
+    fn default() -> Self {
+        Self {
+            reminder_offsets: vec![
+                Duration::days(3).whole_seconds(),
+                Duration::days(1).whole_seconds(),
+            ],
+            auto_submit_evidence: false,
+            auto_submit_offset: Duration::hours(6).whole_seconds(),
+        }
+    }
+}
+
+impl DisputeDeadlineConfig {
+    /// The most imminent reminder which is due and has not been sent yet, if any
+    pub fn get_due_reminder_offset(
+        &self,
+        deadline: PrimitiveDateTime,
+        now: PrimitiveDateTime,
+        tracking_data: &storage::DisputeDeadlineTrackingData,
+    ) -> Option<i64> {
+        self.get_pending_reminder_offsets(tracking_data)
+            .filter(|offset| get_time_before(deadline, *offset) <= now)
+            .min()
+    }
+
+    /// Whether the attached evidence is due to be submitted automatically
+    pub fn is_evidence_submission_due(
+        &self,
+        deadline: PrimitiveDateTime,
+        now: PrimitiveDateTime,
+        tracking_data: &storage::DisputeDeadlineTrackingData,
+    ) -> bool {
+        self.get_pending_evidence_submission_offset(tracking_data)
+            .map_or(false, |offset| get_time_before(deadline, offset) <= now)
+    }
+
+    /// The time at which the task has to run next: right away if anything is already due,
+    /// otherwise at the next reminder, automatic submission of the evidence or at the deadline
+    pub fn get_schedule_time(
+        &self,
+        deadline: PrimitiveDateTime,
+        now: PrimitiveDateTime,
+        tracking_data: &storage::DisputeDeadlineTrackingData,
+    ) -> PrimitiveDateTime {
+        self.get_pending_reminder_offsets(tracking_data)
+            .chain(self.get_pending_evidence_submission_offset(tracking_data))
+            .map(|offset| get_time_before(deadline, offset))
+            .chain(std::iter::once(deadline))
+            .min()
+            .map_or(now, |schedule_time| schedule_time.max(now))
+    }
+
+    fn get_pending_reminder_offsets<'a>(
+        &'a self,
+        tracking_data: &'a storage::DisputeDeadlineTrackingData,
+    ) -> impl Iterator<Item = i64> + 'a {
+        self.reminder_offsets.iter().copied().filter(|offset| {
+            *offset > 0
+                && tracking_data
+                    .last_reminder_offset
+                    .map_or(true, |last_reminder_offset| *offset < last_reminder_offset)
+        })
+    }
+
+    fn get_pending_evidence_submission_offset(
+        &self,
+        tracking_data: &storage::DisputeDeadlineTrackingData,
+    ) -> Option<i64> {
+        (self.auto_submit_evidence && !tracking_data.evidence_submission_attempted)
+            .then_some(self.auto_submit_offset)
+    }
+}
+
+fn get_time_before(deadline: PrimitiveDateTime, offset: i64) -> PrimitiveDateTime {
+    deadline.saturating_sub(Duration::seconds(offset))
+}
+
+pub async fn get_dispute_deadline_config(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> DisputeDeadlineConfig {
+    let key = format!("dispute_deadline_{merchant_id}");
+    db.find_config_by_key_cached(&key)
+        .await
+        .map_err(|error| logger::debug!(?error, "dispute deadline tracking is not configured"))
+        .ok()
+        .and_then(|config| {
+            serde_json::from_str::<DisputeDeadlineConfig>(&config.config)
+                .map_err(|error| logger::error!(?error, "invalid dispute deadline config"))
+                .ok()
+        })
+        .unwrap_or_default()
+}
+
+/// Starts tracking the evidence deadline of an open dispute, if it has one.
+///
+/// Failures are only logged, as they must not fail the processing of the dispute webhook.
+pub async fn track_dispute_deadline(db: &dyn StorageInterface, dispute: &storage::Dispute) {
+    let deadline = match dispute.challenge_required_by {
+        Some(deadline) if dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened => {
+            deadline
+        }
+        _ => return,
+    };
+    let tracking_data = storage::DisputeDeadlineTrackingData {
+        merchant_id: dispute.merchant_id.clone(),
+        dispute_id: dispute.dispute_id.clone(),
+        challenge_required_by: Some(deadline),
+        last_reminder_offset: None,
+        evidence_submission_attempted: false,
+    };
+    let schedule_time = get_dispute_deadline_config(db, &dispute.merchant_id)
+        .await
+        .get_schedule_time(deadline, date_time::now(), &tracking_data);
+
+    if let Err(error) = add_dispute_deadline_task(db, tracking_data, schedule_time).await {
+        logger::error!(?error, "Failed to schedule dispute deadline tracking");
+    }
+}
+
+pub async fn add_dispute_deadline_task(
+    db: &dyn StorageInterface,
+    tracking_data: storage::DisputeDeadlineTrackingData,
+    schedule_time: PrimitiveDateTime,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        DISPUTE_DEADLINE_RUNNER,
+        DISPUTE_DEADLINE_TASK,
+        &tracking_data.dispute_id,
+        &tracking_data.merchant_id,
+    );
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id.clone(),
+            DISPUTE_DEADLINE_TASK,
+            DISPUTE_DEADLINE_RUNNER,
+            tracking_data,
+            schedule_time,
+        )?;
+    let tracking_data = process_tracker_entry.tracking_data.clone();
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            scheduler_metrics::TASKS_ADDED_COUNT.add(&scheduler_metrics::CONTEXT, 1, &[]);
+            Ok(())
+        }
+        // The deadline of the dispute is already being tracked, changes to it are picked up by
+        // the existing task. The task of a dispute which has been reopened has finished though,
+        // it is scheduled again with the new deadline.
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            match db
+                .find_process_by_id(&process_tracker_id)
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessFetchingFailed)?
+            {
+                Some(process) if process.status == storage_enums::ProcessTrackerStatus::Finish => {
+                    db.update_process(
+                        process,
+                        storage::ProcessTrackerUpdate::Update {
+                            name: None,
+                            retry_count: Some(0),
+                            schedule_time: Some(schedule_time),
+                            tracking_data: Some(tracking_data),
+                            business_status: Some(String::from("Pending")),
+                            status: Some(storage_enums::ProcessTrackerStatus::Pending),
+                            updated_at: None,
+                        },
+                    )
+                    .await
+                    .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+                    Ok(())
+                }
+                _ => Ok(()),
+            }
+        }
+        Err(error) => Err(error.change_context(errors::ProcessTrackerError::ProcessUpdateFailed)),
+    }
+}
+
+/// Reminds the merchant of the evidence deadline of a dispute, through an outgoing webhook and an
+/// email
+#[cfg_attr(not(feature = "email"), allow(unused_variables))]
+pub async fn remind_merchant(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    dispute: storage::Dispute,
+    deadline: PrimitiveDateTime,
+    reminder_offset: i64,
+) {
+    #[cfg(feature = "email")]
+    crate::core::notifications::notify_merchant(
+        &*state.store,
+        &merchant_account.merchant_id,
+        storage::EmailNotification::DisputeEvidenceDue {
+            dispute_id: dispute.dispute_id.clone(),
+            payment_id: dispute.payment_id.clone(),
+            challenge_required_by: deadline,
+            reminder_offset,
+        },
+    )
+    .await;
+
+    trigger_dispute_outgoing_webhook(
+        state,
+        merchant_account,
+        api_models::disputes::DisputeResponse::foreign_from(dispute),
+        storage_enums::EventType::DisputeEvidenceDue,
+    )
+    .await;
+}
+
+/// Marks a dispute whose evidence deadline has passed as expired
+pub async fn expire_dispute(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    dispute: storage::Dispute,
+) -> RouterResult<()> {
+    let dispute_id = dispute.dispute_id.clone();
+    let dispute = state
+        .store
+        .update_dispute(
+            dispute,
+            storage::DisputeUpdate::StatusUpdate {
+                dispute_status: storage_enums::DisputeStatus::DisputeExpired,
+                connector_status: None,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute_id.clone(),
+        })
+        .attach_printable_lazy(|| {
+            format!("Unable to update dispute with dispute_id: {dispute_id}")
+        })?;
+
+    trigger_dispute_outgoing_webhook(
+        state,
+        merchant_account,
+        api_models::disputes::DisputeResponse::foreign_from(dispute),
+        storage_enums::EventType::DisputeExpired,
+    )
+    .await;
+    Ok(())
+}
+
+/// Submits the evidence attached to a dispute to the connector, returns whether any evidence was
+/// attached
+pub async fn submit_attached_evidence(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    dispute: &storage::Dispute,
+) -> RouterResult<bool> {
+    let dispute_evidence: api::DisputeEvidence = dispute
+        .evidence
+        .clone()
+        .parse_value("DisputeEvidence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing dispute evidence record")?;
+    let submit_evidence_request =
+        match get_submit_evidence_request(dispute.dispute_id.clone(), dispute_evidence) {
+            Some(submit_evidence_request) => submit_evidence_request,
+            None => return Ok(false),
+        };
+
+    let response =
+        super::submit_evidence(state, merchant_account.clone(), submit_evidence_request).await?;
+    if let services::ApplicationResponse::Json(dispute_response) = response {
+        let event_type: Result<storage_enums::EventType, _> =
+            storage_enums::DisputeStatus::foreign_from(dispute_response.dispute_status.clone())
+                .foreign_try_into();
+        if let Ok(event_type) = event_type {
+            trigger_dispute_outgoing_webhook(state, merchant_account, dispute_response, event_type)
+                .await;
+        }
+    }
+    Ok(true)
+}
+
+/// The request submitting the attached evidence files, if any evidence is attached
+fn get_submit_evidence_request(
+    dispute_id: String,
+    dispute_evidence: api::DisputeEvidence,
+) -> Option<api_models::disputes::SubmitEvidenceRequest> {
+    let api::DisputeEvidence {
+        cancellation_policy,
+        customer_communication,
+        customer_signature,
+        receipt,
+        refund_policy,
+        service_documentation,
+        shipping_documentation,
+        invoice_showing_distinct_transactions,
+        recurring_transaction_agreement,
+        uncategorized_file,
+    } = dispute_evidence;
+    let submit_evidence_request = api_models::disputes::SubmitEvidenceRequest {
+        dispute_id,
+        cancellation_policy,
+        customer_communication,
+        customer_signature,
+        receipt,
+        refund_policy,
+        service_documentation,
+        shipping_documentation,
+        invoice_showing_distinct_transactions,
+        recurring_transaction_agreement,
+        uncategorized_file,
+        ..Default::default()
+    };
+
+    [
+        &submit_evidence_request.cancellation_policy,
+        &submit_evidence_request.customer_communication,
+        &submit_evidence_request.customer_signature,
+        &submit_evidence_request.receipt,
+        &submit_evidence_request.refund_policy,
+        &submit_evidence_request.service_documentation,
+        &submit_evidence_request.shipping_documentation,
+        &submit_evidence_request.invoice_showing_distinct_transactions,
+        &submit_evidence_request.recurring_transaction_agreement,
+        &submit_evidence_request.uncategorized_file,
+    ]
+    .iter()
+    .any(|file_id| file_id.is_some())
+    .then_some(submit_evidence_request)
+}
+
+/// Failures are only logged, as the dispute has already been updated by then
+async fn trigger_dispute_outgoing_webhook(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    dispute_response: api_models::disputes::DisputeResponse,
+    event_type: storage_enums::EventType,
+) {
+    let result = webhooks::create_event_and_trigger_outgoing_webhook::<api::OutgoingWebhook>(
+        state.clone(),
+        merchant_account,
+        event_type,
+        storage_enums::EventClass::Disputes,
+        None,
+        dispute_response.dispute_id.clone(),
+        storage_enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(Box::new(dispute_response)),
+    )
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(dispute_outgoing_webhook_error=?error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tracking_data(last_reminder_offset: Option<i64>) -> storage::DisputeDeadlineTrackingData {
+        storage::DisputeDeadlineTrackingData {
+            merchant_id: "merchant".to_string(),
+            dispute_id: "dispute".to_string(),
+            challenge_required_by: None,
+            last_reminder_offset,
+            evidence_submission_attempted: false,
+        }
+    }
+
+    #[test]
+    fn test_dispute_deadline_schedule() {
+        let config = DisputeDeadlineConfig::default();
+        let now = date_time::now();
+        let three_days = Duration::days(3).whole_seconds();
+        let one_day = Duration::days(1).whole_seconds();
+
+        // Five days before the deadline, nothing is due until the first reminder
+        let deadline = now + Duration::days(5);
+        assert_eq!(
+            config.get_due_reminder_offset(deadline, now, &tracking_data(None)),
+            None
+        );
+        assert_eq!(
+            config.get_schedule_time(deadline, now, &tracking_data(None)),
+            deadline - Duration::days(3)
+        );
+
+        // Two days before the deadline, only the first reminder is due
+        let deadline = now + Duration::days(2);
+        assert_eq!(
+            config.get_due_reminder_offset(deadline, now, &tracking_data(None)),
+            Some(three_days)
+        );
+        assert_eq!(
+            config.get_schedule_time(deadline, now, &tracking_data(Some(three_days))),
+            deadline - Duration::days(1)
+        );
+
+        // Hours before the deadline, a single reminder is sent for both offsets
+        let deadline = now + Duration::hours(12);
+        assert_eq!(
+            config.get_due_reminder_offset(deadline, now, &tracking_data(None)),
+            Some(one_day)
+        );
+        assert_eq!(
+            config.get_due_reminder_offset(deadline, now, &tracking_data(Some(one_day))),
+            None
+        );
+        assert_eq!(
+            config.get_schedule_time(deadline, now, &tracking_data(Some(one_day))),
+            deadline
+        );
+    }
+
+    #[test]
+    fn test_dispute_deadline_evidence_submission_schedule() {
+        let config = DisputeDeadlineConfig {
+            reminder_offsets: Vec::new(),
+            auto_submit_evidence: true,
+            auto_submit_offset: Duration::hours(6).whole_seconds(),
+        };
+        let now = date_time::now();
+        let deadline = now + Duration::days(1);
+        let mut tracking_data = tracking_data(None);
+
+        assert!(!config.is_evidence_submission_due(deadline, now, &tracking_data));
+        assert_eq!(
+            config.get_schedule_time(deadline, now, &tracking_data),
+            deadline - Duration::hours(6)
+        );
+
+        let later = deadline - Duration::hours(1);
+        assert!(config.is_evidence_submission_due(deadline, later, &tracking_data));
+
+        tracking_data.evidence_submission_attempted = true;
+        assert!(!config.is_evidence_submission_due(deadline, later, &tracking_data));
+        assert_eq!(
+            config.get_schedule_time(deadline, later, &tracking_data),
+            deadline
+        );
+    }
+}
@@ -217,6 +217,18 @@ pub enum ApiClientError {
     UnexpectedServerResponse,
 }
 
+impl ApiClientError {
+    pub fn from_server_error_status(status_code: u16) -> Self {
+        match status_code {
+            500 => Self::InternalServerErrorReceived,
+            502 => Self::BadGatewayReceived,
+            503 => Self::ServiceUnavailableReceived,
+            504 => Self::GatewayTimeoutReceived,
+            _ => Self::UnexpectedServerResponse,
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error, PartialEq)]
 pub enum ConnectorError {
     #[error("Error while obtaining URL for the integration")]
@@ -89,6 +89,12 @@ pub enum ApiErrorResponse {
     FlowNotSupported { flow: String, connector: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_21", message = "Missing required params")]
     MissingRequiredFields { field_names: Vec<&'static str> },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_22", message = "There is currently another in-progress request using this Idempotency-Key. Please retry after the original request completes")]
+    IdempotencyKeyInUse,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_23", message = "Keys for idempotent requests can only be used with the same parameters they were first used with")]
+    IdempotencyKeyMismatch,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_24", message = "{message}")]
+    InsufficientApiKeyScope { message: String },
     #[error(error_type = ErrorType::ConnectorError, code = "CE_00", message = "{code}: {message}", ignore = "status_code")]
     ExternalConnectorError {
         code: String,
@@ -113,6 +119,8 @@ pub enum ApiErrorResponse {
     VerificationFailed { data: Option<serde_json::Value> },
     #[error(error_type = ErrorType::ProcessingError, code = "CE_08", message = "Dispute operation failed while processing with connector. Retry operation")]
     DisputeFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::ProcessingError, code = "CE_09", message = "Payout failed while processing with connector. Retry payout")]
+    PayoutFailed { data: Option<serde_json::Value> },
 
     #[error(error_type = ErrorType::ServerNotAvailable, code = "HE_00", message = "Something went wrong")]
     InternalServerError,
@@ -128,6 +136,8 @@ pub enum ApiErrorResponse {
     DuplicatePaymentMethod,
     #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The payment with the specified payment_id '{payment_id}' already exists in our records")]
     DuplicatePayment { payment_id: String },
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The payout with the specified payout_id '{payout_id}' already exists in our records")]
+    DuplicatePayout { payout_id: String },
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Refund does not exist in our records")]
     RefundNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Customer does not exist in our records")]
@@ -150,6 +160,12 @@ pub enum ApiErrorResponse {
     MandateUpdateFailed,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "API Key does not exist in our records")]
     ApiKeyNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Payout does not exist in our records")]
+    PayoutNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Event does not exist in our records")]
+    EventNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Capture does not exist in our records")]
+    CaptureNotFound,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Return URL is not configured and not passed in payments request")]
     ReturnUrlUnavailable,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "This refund is not possible through Hyperswitch. Please raise the refund through {connector} dashboard")]
@@ -176,7 +192,7 @@ pub enum ApiErrorResponse {
     DisputeStatusValidationFailed { reason: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "HE_04", message = "Card with the provided iin does not exist")]
     InvalidCardIin,
-    #[error(error_type = ErrorType::InvalidRequestError, code = "HE_04", message = "The provided card IIN length is invalid, please provide an iin with 6 or 8 digits")]
+    #[error(error_type = ErrorType::InvalidRequestError, code = "HE_04", message = "The provided card IIN length is invalid, please provide an iin with 6 to 8 digits")]
     InvalidCardIinLength,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "File validation failed")]
     FileValidationFailed { reason: String },
@@ -245,8 +261,11 @@ impl actix_web::ResponseError for ApiErrorResponse {
             }
             Self::InvalidRequestUrl | Self::WebhookResourceNotFound => StatusCode::NOT_FOUND, // 404
             Self::InvalidHttpMethod => StatusCode::METHOD_NOT_ALLOWED,                        // 405
+            Self::IdempotencyKeyInUse => StatusCode::CONFLICT,                                // 409
+            Self::InsufficientApiKeyScope { .. } => StatusCode::FORBIDDEN,                    // 403
             Self::MissingRequiredField { .. }
             | Self::MissingRequiredFields { .. }
+            | Self::IdempotencyKeyMismatch
             | Self::InvalidDataValue { .. }
             | Self::InvalidCardIin
             | Self::InvalidCardIinLength => StatusCode::BAD_REQUEST, // 400
@@ -265,6 +284,7 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::PaymentUnexpectedState { .. }
             | Self::MandateValidationFailed { .. }
             | Self::DisputeFailed { .. }
+            | Self::PayoutFailed { .. }
             | Self::RefundAmountExceedsPaymentAmount
             | Self::MaximumRefundCount
             | Self::IncorrectPaymentMethodConfiguration
@@ -273,7 +293,9 @@ impl actix_web::ResponseError for ApiErrorResponse {
             Self::MandateUpdateFailed
             | Self::InternalServerError
             | Self::WebhookProcessingFailure => StatusCode::INTERNAL_SERVER_ERROR, // 500
-            Self::DuplicateRefundRequest | Self::DuplicatePayment { .. } => StatusCode::BAD_REQUEST, // 400
+            Self::DuplicateRefundRequest
+            | Self::DuplicatePayment { .. }
+            | Self::DuplicatePayout { .. } => StatusCode::BAD_REQUEST, // 400
             Self::RefundNotFound
             | Self::CustomerNotFound
             | Self::MandateActive
@@ -284,6 +306,9 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::MerchantConnectorAccountNotFound { .. }
             | Self::MerchantConnectorAccountDisabled
             | Self::MandateNotFound
+            | Self::PayoutNotFound
+            | Self::EventNotFound
+            | Self::CaptureNotFound
             | Self::ClientSecretNotGiven
             | Self::ClientSecretExpired
             | Self::ClientSecretInvalid
@@ -415,6 +440,11 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::MissingRequiredFields { field_names } => AER::BadRequest(
                 ApiError::new("IR", 21, "Missing required params".to_string(), Some(Extra {data: Some(serde_json::json!(field_names)), ..Default::default() })),
             ),
+            Self::IdempotencyKeyInUse => AER::Conflict(ApiError::new("IR", 22, "There is currently another in-progress request using this Idempotency-Key. Please retry after the original request completes", None)),
+            Self::IdempotencyKeyMismatch => AER::BadRequest(ApiError::new("IR", 23, "Keys for idempotent requests can only be used with the same parameters they were first used with", None)),
+            Self::InsufficientApiKeyScope { message } => {
+                AER::ForbiddenCommonResource(ApiError::new("IR", 24, message.to_string(), None))
+            }
             Self::ExternalConnectorError {
                 code,
                 message,
@@ -434,6 +464,9 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::DisputeFailed { data } => {
                 AER::BadRequest(ApiError::new("CE", 1, "Dispute operation failed while processing with connector. Retry operation", Some(Extra { data: data.clone(), ..Default::default()})))
             }
+            Self::PayoutFailed { data } => {
+                AER::BadRequest(ApiError::new("CE", 9, "Payout failed while processing with connector. Retry payout", Some(Extra { data: data.clone(), ..Default::default()})))
+            }
             Self::InvalidCardData { data } => AER::BadRequest(ApiError::new("CE", 4, "The card data is invalid", Some(Extra { data: data.clone(), ..Default::default()}))),
             Self::CardExpired { data } => AER::BadRequest(ApiError::new("CE", 5, "The card has expired", Some(Extra { data: data.clone(), ..Default::default()}))),
             Self::RefundFailed { data } => AER::BadRequest(ApiError::new("CE", 6, "Refund failed while processing with connector. Retry refund", Some(Extra { data: data.clone(), ..Default::default()}))),
@@ -453,6 +486,9 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::DuplicatePayment { payment_id } => {
                 AER::BadRequest(ApiError::new("HE", 1, format!("The payment with the specified payment_id '{payment_id}' already exists in our records"), None))
             }
+            Self::DuplicatePayout { payout_id } => {
+                AER::BadRequest(ApiError::new("HE", 1, format!("The payout with the specified payout_id '{payout_id}' already exists in our records"), None))
+            }
             Self::RefundNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "Refund does not exist in our records.", None))
             }
@@ -483,6 +519,15 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::MandateNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "Mandate does not exist in our records", None))
             }
+            Self::PayoutNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Payout does not exist in our records", None))
+            }
+            Self::EventNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Event does not exist in our records", None))
+            }
+            Self::CaptureNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Capture does not exist in our records", None))
+            }
             Self::ReturnUrlUnavailable => AER::NotFound(ApiError::new("HE", 3, "Return URL is not configured and not passed in payments request", None)),
             Self::RefundNotPossible { connector } => {
                 AER::BadRequest(ApiError::new("HE", 3, "This refund is not possible through Hyperswitch. Please raise the refund through {connector} dashboard", None))
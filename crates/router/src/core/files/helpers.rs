@@ -80,7 +80,11 @@ pub async fn validate_file_upload(
                 .ok_or(errors::ApiErrorResponse::MissingDisputeId)?;
             let dispute = state
                 .store
-                .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, dispute_id)
+                .find_dispute_by_merchant_id_dispute_id(
+                    &merchant_account.merchant_id,
+                    dispute_id,
+                    merchant_account.storage_scheme,
+                )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
                     dispute_id: dispute_id.to_string(),
@@ -282,7 +286,11 @@ pub async fn upload_and_get_provider_provider_file_id_connector_label(
                 .ok_or(errors::ApiErrorResponse::MissingDisputeId)?;
             let dispute = state
                 .store
-                .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &dispute_id)
+                .find_dispute_by_merchant_id_dispute_id(
+                    &merchant_account.merchant_id,
+                    &dispute_id,
+                    merchant_account.storage_scheme,
+                )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound { dispute_id })?;
             let connector_data = api::ConnectorData::get_connector_by_name(
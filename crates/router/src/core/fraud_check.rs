@@ -0,0 +1,910 @@
+//! Fraud and risk management (FRM) checks of payments.
+//!
+//! A merchant enables FRM by creating a merchant connector account of type `payment_vas` for an
+//! FRM connector, with `frm_configs` describing the payments to be checked. Depending on the
+//! preferred flow type, the payment is checked before it is authorized by the payment processor
+//! (checkout flow) or after it (transaction flow). If the FRM connector considers the payment
+//! fraudulent, the configured action is taken.
+//!
+//! A payment left for manual review awaits the decision of the merchant, who either approves it or
+//! rejects it to have it reversed. Merchants can also report the fulfillment of an order to the FRM
+//! connector that checked its payment.
+
+use std::marker::PhantomData;
+
+use api_models::{
+    admin::FrmConfigs,
+    enums::{FraudCheckStatus, FrmAction, FrmPreferredFlowTypes},
+    refunds::RefundRequest,
+};
+use error_stack::{report, ResultExt};
+use futures::future::BoxFuture;
+use masking::{ExposeInterface, PeekInterface};
+use router_env::{instrument, tracing};
+
+use super::{
+    errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    payments::{self, CallConnectorAction, PaymentAddress, PaymentData},
+    refunds,
+};
+pub use crate::types::storage::enums::FrmDecision;
+use crate::{
+    consts, logger,
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, fraud_check},
+        domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
+    utils::{self, OptionExt, ValueExt},
+};
+
+type FrmResponse = errors::CustomResult<
+    Result<types::FraudCheckResponseData, types::ErrorResponse>,
+    errors::ConnectorError,
+>;
+
+/// FRM connector that checks a payment, along with the configs of its merchant connector account
+#[derive(Clone)]
+pub struct FrmInfo {
+    pub frm_connector: fraud_check::FraudCheckConnectorData,
+    pub frm_configs: FrmConfigs,
+    pub connector_auth_type: types::ConnectorAuthType,
+    pub connector_meta_data: Option<masking::Secret<serde_json::Value>>,
+}
+
+fn get_decision_from_status(status: FraudCheckStatus, frm_action: &FrmAction) -> FrmDecision {
+    match (status, frm_action) {
+        (FraudCheckStatus::Fraud, FrmAction::CancelTxn | FrmAction::AutoRefund) => {
+            FrmDecision::Decline
+        }
+        (FraudCheckStatus::Fraud, FrmAction::ManualReview)
+        | (FraudCheckStatus::ManualReview, _) => FrmDecision::ManualReview,
+        (
+            FraudCheckStatus::Pending
+            | FraudCheckStatus::Legit
+            | FraudCheckStatus::TransactionFailure,
+            _,
+        ) => FrmDecision::Proceed,
+    }
+}
+
+/// Returns the FRM connector that should check the payment, if the merchant has one configured
+/// for the payment connector and the payment method of the payment
+#[instrument(skip_all)]
+pub async fn get_frm_info<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &PaymentData<F>,
+    connector_call_type: &api::ConnectorCallType,
+) -> RouterResult<Option<FrmInfo>> {
+    let payment_connector = match connector_call_type {
+        api::ConnectorCallType::Single(connector_data) => connector_data.connector_name.to_string(),
+        api::ConnectorCallType::Multiple(_) => return Ok(None),
+    };
+
+    find_frm_info(
+        state,
+        merchant_account,
+        &payment_connector,
+        payment_data.payment_attempt.payment_method,
+        payment_data.payment_attempt.payment_method_type.clone(),
+    )
+    .await
+}
+
+async fn find_frm_info(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_connector: &str,
+    payment_method: Option<storage_enums::PaymentMethod>,
+    payment_method_type: Option<storage_enums::PaymentMethodType>,
+) -> RouterResult<Option<FrmInfo>> {
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &merchant_account.merchant_id,
+            false,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant connector accounts")?;
+
+    for merchant_connector_account in merchant_connector_accounts {
+        if merchant_connector_account.connector_type != storage_enums::ConnectorType::PaymentVas {
+            continue;
+        }
+
+        let frm_configs = match merchant_connector_account.frm_configs {
+            Some(frm_configs) => frm_configs,
+            None => continue,
+        };
+
+        // Value added services other than FRM connectors are not of interest here
+        let frm_connector = match fraud_check::FraudCheckConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            &merchant_connector_account.connector_name,
+        ) {
+            Ok(frm_connector) => frm_connector,
+            Err(_) => continue,
+        };
+
+        let frm_configs: FrmConfigs = frm_configs
+            .expose()
+            .parse_value("FrmConfigs")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed to parse the FRM configs of the merchant connector account",
+            )?;
+
+        if !is_frm_enabled(
+            &frm_configs,
+            payment_connector,
+            payment_method,
+            payment_method_type.clone(),
+        ) {
+            continue;
+        }
+
+        let connector_auth_type: types::ConnectorAuthType = merchant_connector_account
+            .connector_account_details
+            .peek()
+            .clone()
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        return Ok(Some(FrmInfo {
+            frm_connector,
+            frm_configs,
+            connector_auth_type,
+            connector_meta_data: merchant_connector_account.metadata,
+        }));
+    }
+
+    Ok(None)
+}
+
+fn is_frm_enabled(
+    frm_configs: &FrmConfigs,
+    payment_connector: &str,
+    payment_method: Option<storage_enums::PaymentMethod>,
+    payment_method_type: Option<storage_enums::PaymentMethodType>,
+) -> bool {
+    // A config that is not present enables FRM for all the values
+    let is_enabled_for = |enabled_values: &Option<Vec<String>>, value: Option<String>| {
+        enabled_values.as_ref().map_or(true, |enabled_values| {
+            value.map_or(false, |value| {
+                enabled_values
+                    .iter()
+                    .any(|enabled_value| enabled_value.eq_ignore_ascii_case(&value))
+            })
+        })
+    };
+
+    is_enabled_for(
+        &frm_configs.frm_enabled_gateways,
+        Some(payment_connector.to_string()),
+    ) && is_enabled_for(
+        &frm_configs.frm_enabled_pms,
+        payment_method.map(|payment_method| payment_method.to_string()),
+    ) && is_enabled_for(
+        &frm_configs.frm_enabled_pm_types,
+        payment_method_type.map(|payment_method_type| payment_method_type.to_string()),
+    )
+}
+
+/// Checks the payment with the FRM connector before it is authorized, if the merchant prefers it.
+/// A declined payment is marked as failed, and must not be sent to the payment processor.
+#[instrument(skip_all)]
+pub async fn call_frm_before_connector_call<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    frm_info: Option<&FrmInfo>,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<FrmDecision> {
+    let frm_info = match frm_info {
+        Some(frm_info)
+            if matches!(
+                frm_info.frm_configs.frm_preferred_flow_type,
+                FrmPreferredFlowTypes::Pre
+            ) =>
+        {
+            frm_info
+        }
+        _ => return Ok(FrmDecision::Proceed),
+    };
+
+    let request = types::FraudCheckCheckoutData {
+        amount: payment_data.payment_attempt.amount,
+        currency: payment_data.currency,
+        email: payment_data.email.clone(),
+        browser_info: payment_data
+            .payment_attempt
+            .browser_info
+            .clone()
+            .map(|browser_info| browser_info.parse_value("BrowserInformation"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "browser_info",
+            })?,
+    };
+    let router_data: types::FrmCheckoutRouterData = construct_frm_router_data(
+        merchant_account,
+        frm_info,
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+        &payment_data.address,
+        request,
+    )?;
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        fraud_check::Checkout,
+        types::FraudCheckCheckoutData,
+        types::FraudCheckResponseData,
+    > = frm_info.frm_connector.connector.get_connector_integration();
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        CallConnectorAction::Trigger,
+    )
+    .await
+    .map(|router_data| router_data.response);
+
+    let decision = get_frm_decision(frm_info, &response);
+    record_fraud_check(
+        state,
+        frm_info,
+        &payment_data.payment_attempt,
+        &response,
+        decision,
+    )
+    .await?;
+    if decision == FrmDecision::Decline {
+        decline_payment(state, merchant_account, payment_data).await?;
+    }
+
+    Ok(decision)
+}
+
+/// Checks the payment with the FRM connector after it was sent to the payment processor, if the
+/// merchant prefers it, and takes the action decided on either by this check or by the one made
+/// before authorizing the payment.
+///
+/// Both cancelling and auto refunding a declined payment reverse it: a payment that was only
+/// authorized is voided, while a captured payment is refunded.
+#[instrument(skip_all)]
+pub async fn call_frm_after_connector_call<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    frm_info: &FrmInfo,
+    pre_authorization_decision: FrmDecision,
+    payment_data: PaymentData<F>,
+) -> RouterResult<PaymentData<F>> {
+    let decision = match (
+        pre_authorization_decision,
+        &frm_info.frm_configs.frm_preferred_flow_type,
+    ) {
+        // The payment was never sent to the payment processor
+        (FrmDecision::Decline, _) => return Ok(payment_data),
+        (_, FrmPreferredFlowTypes::Pre) => pre_authorization_decision,
+        (_, FrmPreferredFlowTypes::Post) => {
+            let payment_attempt = &payment_data.payment_attempt;
+            let request = types::FraudCheckTransactionData {
+                amount: payment_attempt.amount,
+                currency: payment_data.currency,
+                email: payment_data.email.clone(),
+                attempt_status: payment_attempt.status,
+                connector: payment_attempt.connector.clone(),
+                connector_transaction_id: payment_attempt.connector_transaction_id.clone(),
+                error_code: payment_attempt.error_code.clone(),
+                error_message: payment_attempt.error_message.clone(),
+            };
+            let router_data: types::FrmTransactionRouterData = construct_frm_router_data(
+                merchant_account,
+                frm_info,
+                &payment_data.payment_intent,
+                &payment_data.payment_attempt,
+                &payment_data.address,
+                request,
+            )?;
+
+            let connector_integration: services::BoxedConnectorIntegration<
+                '_,
+                fraud_check::Transaction,
+                types::FraudCheckTransactionData,
+                types::FraudCheckResponseData,
+            > = frm_info.frm_connector.connector.get_connector_integration();
+            let response = services::execute_connector_processing_step(
+                state,
+                connector_integration,
+                &router_data,
+                CallConnectorAction::Trigger,
+            )
+            .await
+            .map(|router_data| router_data.response);
+
+            let decision = get_frm_decision(frm_info, &response);
+            record_fraud_check(
+                state,
+                frm_info,
+                &payment_data.payment_attempt,
+                &response,
+                decision,
+            )
+            .await?;
+            decision
+        }
+    };
+
+    match decision {
+        FrmDecision::Proceed => Ok(payment_data),
+        FrmDecision::ManualReview => {
+            mark_for_manual_review(state, merchant_account, payment_data).await
+        }
+        FrmDecision::Decline => reverse_payment(state, merchant_account, payment_data).await,
+    }
+}
+
+/// The payment is let through if the FRM connector could not be reached or returned an error, so
+/// that an FRM outage does not stop the merchant from accepting payments
+fn get_frm_decision(frm_info: &FrmInfo, response: &FrmResponse) -> FrmDecision {
+    match response {
+        Ok(Ok(response)) => {
+            logger::info!(
+                frm_connector = %frm_info.frm_connector.connector_name,
+                frm_reference_id = %response.frm_reference_id,
+                frm_status = %response.status,
+                "Received fraud check response"
+            );
+            get_decision_from_status(response.status, &frm_info.frm_configs.frm_action)
+        }
+        Ok(Err(error_response)) => {
+            logger::error!(
+                frm_connector = %frm_info.frm_connector.connector_name,
+                ?error_response,
+                "FRM connector returned an error response"
+            );
+            FrmDecision::Proceed
+        }
+        Err(error) => {
+            logger::error!(
+                frm_connector = %frm_info.frm_connector.connector_name,
+                ?error,
+                "Failed to call the FRM connector"
+            );
+            FrmDecision::Proceed
+        }
+    }
+}
+
+/// Records the outcome of the fraud check of a payment attempt, which tells a payment left for
+/// manual review apart from other payments awaiting an action of the merchant
+async fn record_fraud_check(
+    state: &AppState,
+    frm_info: &FrmInfo,
+    payment_attempt: &storage::PaymentAttempt,
+    response: &FrmResponse,
+    decision: FrmDecision,
+) -> RouterResult<()> {
+    let response = match response {
+        Ok(Ok(response)) => Some(response),
+        Ok(Err(_)) | Err(_) => None,
+    };
+    let fraud_check = storage::FraudCheckNew {
+        frm_id: utils::generate_id(consts::ID_LENGTH, "frm"),
+        merchant_id: payment_attempt.merchant_id.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        frm_name: frm_info.frm_connector.connector_name.to_string(),
+        frm_reference_id: response.map(|response| response.frm_reference_id.clone()),
+        frm_status: response.map(|response| response.status.foreign_into()),
+        frm_decision: decision,
+    };
+
+    match state.store.insert_fraud_check(fraud_check).await {
+        Ok(_) => Ok(()),
+        // The attempt was checked already, the outcome of its first check is kept
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            logger::warn!(
+                payment_id = %payment_attempt.payment_id,
+                attempt_id = %payment_attempt.attempt_id,
+                "Fraud check of the payment attempt has been recorded already"
+            );
+            Ok(())
+        }
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to record the fraud check of the payment")),
+    }
+}
+
+fn construct_frm_router_data<F, Req>(
+    merchant_account: &domain::MerchantAccount,
+    frm_info: &FrmInfo,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    address: &PaymentAddress,
+    request: Req,
+) -> RouterResult<types::RouterData<F, Req, types::FraudCheckResponseData>> {
+    let payment_method = payment_attempt
+        .payment_method
+        .get_required_value("payment_method")?;
+
+    Ok(types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        customer_id: payment_intent.customer_id.clone(),
+        connector_customer: None,
+        connector: frm_info.frm_connector.connector_name.to_string(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        status: payment_attempt.status,
+        payment_method,
+        connector_auth_type: frm_info.connector_auth_type.clone(),
+        description: payment_intent.description.clone(),
+        return_url: payment_intent.return_url.clone(),
+        address: address.clone(),
+        auth_type: payment_attempt.authentication_type.unwrap_or_default(),
+        connector_meta_data: frm_info.connector_meta_data.clone(),
+        amount_captured: payment_intent.amount_captured,
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        preprocessing_id: None,
+        request,
+        response: Err(types::ErrorResponse::default()),
+        payment_method_id: payment_attempt.payment_method_id.clone(),
+    })
+}
+
+async fn decline_payment<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<()> {
+    let db = &*state.store;
+
+    payment_data.payment_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::ErrorUpdate {
+                connector: None,
+                status: storage_enums::AttemptStatus::Failure,
+                error_code: Some(Some(consts::FRM_DECLINED_ERROR_CODE.to_string())),
+                error_message: Some(Some(consts::FRM_DECLINED_ERROR_MESSAGE.to_string())),
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent.clone(),
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: storage_enums::IntentStatus::Failed,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Ok(())
+}
+
+async fn mark_for_manual_review<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    mut payment_data: PaymentData<F>,
+) -> RouterResult<PaymentData<F>> {
+    // Only a payment that went through can be reviewed, a failed payment is left as it is
+    if matches!(
+        payment_data.payment_intent.status,
+        storage_enums::IntentStatus::Succeeded | storage_enums::IntentStatus::RequiresCapture
+    ) {
+        payment_data.payment_intent = state
+            .store
+            .update_payment_intent(
+                payment_data.payment_intent.clone(),
+                storage::PaymentIntentUpdate::PGStatusUpdate {
+                    status: storage_enums::IntentStatus::RequiresMerchantAction,
+                },
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    }
+
+    Ok(payment_data)
+}
+
+async fn reverse_payment<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    mut payment_data: PaymentData<F>,
+) -> RouterResult<PaymentData<F>> {
+    let payment_id = payment_data.payment_intent.payment_id.clone();
+
+    if !reverse_payment_at_connector(
+        state,
+        merchant_account,
+        &payment_id,
+        payment_data.payment_intent.status,
+    )
+    .await?
+    {
+        return Ok(payment_data);
+    }
+
+    let db = &*state.store;
+    payment_data.payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    payment_data.payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            &payment_data.payment_intent.active_attempt_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    payment_data.refunds = db
+        .find_refund_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the refunds of the payment")?;
+
+    Ok(payment_data)
+}
+
+/// Voids an authorized payment or refunds a captured one, returning whether the payment had to be
+/// reversed at all
+async fn reverse_payment_at_connector(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_id: &str,
+    status: storage_enums::IntentStatus,
+) -> RouterResult<bool> {
+    match status {
+        storage_enums::IntentStatus::RequiresCapture => {
+            cancel_payment(state, merchant_account.clone(), payment_id.to_string()).await?;
+        }
+        storage_enums::IntentStatus::Succeeded => {
+            refunds::refund_create_core(
+                state,
+                merchant_account.clone(),
+                RefundRequest {
+                    payment_id: payment_id.to_string(),
+                    reason: Some(consts::FRM_DECLINED_ERROR_MESSAGE.to_string()),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        }
+        status => {
+            logger::info!(%payment_id, ?status, "Payment declined by FRM need not be reversed");
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Cancelling a payment runs the payments core, which in turn may call into this module, and
+/// hence the future has to be boxed
+fn cancel_payment(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    payment_id: String,
+) -> BoxFuture<'_, RouterResponse<api::PaymentsResponse>> {
+    Box::pin(payments::payments_core::<
+        api::Void,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+    >(
+        state,
+        merchant_account,
+        payments::PaymentCancel,
+        api::PaymentsCancelRequest {
+            payment_id,
+            cancellation_reason: Some(consts::FRM_DECLINED_ERROR_MESSAGE.to_string()),
+            merchant_connector_details: None,
+        },
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+    ))
+}
+
+/// Reports the fulfillment of the order a payment was made for to the FRM connector that checked
+/// the payment, so that the connector can take it into account for chargeback protection
+#[instrument(skip_all)]
+pub async fn frm_fulfillment_core(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    req: fraud_check::FrmFulfillmentRequest,
+) -> RouterResponse<fraud_check::FrmFulfillmentResponse> {
+    let (payment_intent, payment_attempt) =
+        find_payment(state, &merchant_account, &req.payment_id).await?;
+
+    utils::when(
+        !matches!(
+            payment_intent.status,
+            storage_enums::IntentStatus::Succeeded | storage_enums::IntentStatus::RequiresCapture
+        ),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Fulfillment cannot be reported for payment {} as it is in {} status",
+                    req.payment_id, payment_intent.status
+                ),
+            }))
+        },
+    )?;
+
+    let frm_info = match payment_attempt.connector.as_deref() {
+        Some(payment_connector) => {
+            find_frm_info(
+                state,
+                &merchant_account,
+                payment_connector,
+                payment_attempt.payment_method,
+                payment_attempt.payment_method_type.clone(),
+            )
+            .await?
+        }
+        None => None,
+    }
+    .ok_or_else(|| {
+        report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Payment {} was not checked by an FRM connector",
+                req.payment_id
+            ),
+        })
+    })?;
+
+    let request = types::FraudCheckFulfillmentData {
+        amount: payment_attempt.amount,
+        currency: payment_attempt.currency.get_required_value("currency")?,
+        fulfillment_id: req.fulfillment_id.clone(),
+    };
+    let router_data: types::FrmFulfillmentRouterData = construct_frm_router_data(
+        &merchant_account,
+        &frm_info,
+        &payment_intent,
+        &payment_attempt,
+        &PaymentAddress::default(),
+        request,
+    )?;
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        fraud_check::Fulfillment,
+        types::FraudCheckFulfillmentData,
+        types::FraudCheckResponseData,
+    > = frm_info.frm_connector.connector.get_connector_integration();
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        CallConnectorAction::Trigger,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while calling the FRM connector fulfillment api")?
+    .response
+    .map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
+        code: err.code,
+        message: err.message,
+        connector: frm_info.frm_connector.connector_name.to_string(),
+        status_code: err.status_code,
+        reason: err.reason,
+    })?;
+
+    Ok(services::ApplicationResponse::Json(
+        fraud_check::FrmFulfillmentResponse {
+            payment_id: req.payment_id,
+            fulfillment_id: req.fulfillment_id,
+            frm_reference_id: response.frm_reference_id,
+            status: response.status,
+        },
+    ))
+}
+
+/// Applies the decision of the merchant on a payment that was left for manual review by its fraud
+/// check. The payment returns to the status it had before the review, and is then reversed if it
+/// was rejected. A payment can only be reviewed once.
+#[instrument(skip_all)]
+pub async fn frm_review_core(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    req: fraud_check::FrmReviewRequest,
+) -> RouterResponse<api::PaymentsResponse> {
+    let (payment_intent, payment_attempt) =
+        find_payment(state, &merchant_account, &req.payment_id).await?;
+
+    utils::when(
+        payment_intent.status != storage_enums::IntentStatus::RequiresMerchantAction,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Payment {} is not awaiting a review as it is in {} status",
+                    req.payment_id, payment_intent.status
+                ),
+            }))
+        },
+    )?;
+
+    let review_decision = match req.decision {
+        fraud_check::FrmReviewDecision::Approve => FrmDecision::Proceed,
+        fraud_check::FrmReviewDecision::Reject => FrmDecision::Decline,
+    };
+    state
+        .store
+        .update_fraud_check_review_by_merchant_id_payment_id_attempt_id(
+            &merchant_account.merchant_id,
+            &req.payment_id,
+            &payment_attempt.attempt_id,
+            storage::FraudCheckUpdate::ReviewUpdate { review_decision },
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::PreconditionFailed {
+                    message: format!(
+                        "Payment {} was not left for manual review by its fraud check, or has \
+                        been reviewed already",
+                        req.payment_id
+                    ),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to record the review of the payment")
+            }
+        })?;
+
+    let payment_intent = state
+        .store
+        .update_payment_intent(
+            payment_intent,
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: payment_attempt.status.foreign_into(),
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if req.decision == fraud_check::FrmReviewDecision::Reject {
+        reverse_payment_at_connector(
+            state,
+            &merchant_account,
+            &req.payment_id,
+            payment_intent.status,
+        )
+        .await?;
+    }
+
+    payments::payments_core::<api::PSync, api::PaymentsResponse, _, _, _>(
+        state,
+        merchant_account,
+        payments::PaymentStatus,
+        api::PaymentsRetrieveRequest {
+            resource_id: api::PaymentIdType::PaymentIntentId(req.payment_id),
+            ..Default::default()
+        },
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Avoid,
+    )
+    .await
+}
+
+async fn find_payment(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_id: &str,
+) -> RouterResult<(storage::PaymentIntent, storage::PaymentAttempt)> {
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            &payment_intent.active_attempt_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Ok((payment_intent, payment_attempt))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frm_configs(frm_action: FrmAction) -> FrmConfigs {
+        FrmConfigs {
+            frm_enabled_pms: Some(vec!["card".to_string()]),
+            frm_enabled_pm_types: None,
+            frm_enabled_gateways: Some(vec!["stripe".to_string()]),
+            frm_action,
+            frm_preferred_flow_type: FrmPreferredFlowTypes::Pre,
+        }
+    }
+
+    #[test]
+    fn test_frm_is_enabled_only_for_configured_gateways_and_payment_methods() {
+        let frm_configs = frm_configs(FrmAction::CancelTxn);
+
+        assert!(is_frm_enabled(
+            &frm_configs,
+            "stripe",
+            Some(storage_enums::PaymentMethod::Card),
+            Some(storage_enums::PaymentMethodType::Credit),
+        ));
+        assert!(!is_frm_enabled(
+            &frm_configs,
+            "adyen",
+            Some(storage_enums::PaymentMethod::Card),
+            None,
+        ));
+        assert!(!is_frm_enabled(
+            &frm_configs,
+            "stripe",
+            Some(storage_enums::PaymentMethod::Wallet),
+            None,
+        ));
+        assert!(!is_frm_enabled(&frm_configs, "stripe", None, None));
+    }
+
+    #[test]
+    fn test_frm_decision_follows_configured_action() {
+        assert_eq!(
+            get_decision_from_status(FraudCheckStatus::Fraud, &FrmAction::CancelTxn),
+            FrmDecision::Decline
+        );
+        assert_eq!(
+            get_decision_from_status(FraudCheckStatus::Fraud, &FrmAction::AutoRefund),
+            FrmDecision::Decline
+        );
+        assert_eq!(
+            get_decision_from_status(FraudCheckStatus::Fraud, &FrmAction::ManualReview),
+            FrmDecision::ManualReview
+        );
+        assert_eq!(
+            get_decision_from_status(FraudCheckStatus::ManualReview, &FrmAction::CancelTxn),
+            FrmDecision::ManualReview
+        );
+        assert_eq!(
+            get_decision_from_status(FraudCheckStatus::Legit, &FrmAction::CancelTxn),
+            FrmDecision::Proceed
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use std::time::{Duration, Instant};
+
+use api_models::health_check::{ComponentHealth, HealthStatus, RouterHealthCheckResponse};
+use error_stack::{report, ResultExt};
+use router_env::logger;
+
+use crate::{
+    core::errors::{self, CustomResult},
+    routes::AppState,
+    services,
+};
+
+/// Checks all the components the application depends upon to serve traffic
+pub async fn check_readiness(state: &AppState) -> RouterHealthCheckResponse {
+    let timeout = Duration::from_secs(state.conf.health_check.timeout);
+    let scheduler_stream = async {
+        match state.conf.scheduler.as_ref() {
+            Some(scheduler) => Some(
+                check_component(
+                    get_streams_length(state, std::iter::once(scheduler.stream.clone())),
+                    timeout,
+                )
+                .await,
+            ),
+            None => None,
+        }
+    };
+    // Same as the names of the streams the drainer reads from, for example {shard_5}_drainer_stream
+    let drainer_streams = (0..state.conf.drainer.num_partitions)
+        .map(|partition| format!("{{shard_{partition}}}_{}", state.conf.drainer.stream_name));
+
+    let (master_db, replica_db, redis, locker, scheduler_stream, drainer_streams) = futures::join!(
+        check_component(
+            async { state.store.health_check_master_db().await.map(|()| None) },
+            timeout
+        ),
+        check_component(
+            async { state.store.health_check_replica_db().await.map(|()| None) },
+            timeout
+        ),
+        check_component(
+            async { state.store.get_redis_conn().ping().await.map(|()| None) },
+            timeout
+        ),
+        check_locker(state, timeout),
+        scheduler_stream,
+        check_component(get_streams_length(state, drainer_streams), timeout),
+    );
+    let drainer_streams = check_drainer_backlog(
+        drainer_streams,
+        state.conf.health_check.max_drainer_pending_entries,
+    );
+
+    let status = get_overall_status(
+        [&master_db, &replica_db, &redis, &locker, &drainer_streams]
+            .into_iter()
+            .chain(scheduler_stream.as_ref()),
+    );
+
+    RouterHealthCheckResponse {
+        status,
+        database_master: master_db,
+        database_replica: replica_db,
+        redis,
+        locker,
+        scheduler_stream,
+        drainer_streams,
+    }
+}
+
+/// Healthy only if all the components are healthy
+fn get_overall_status<'a>(
+    components: impl IntoIterator<Item = &'a ComponentHealth>,
+) -> HealthStatus {
+    if components
+        .into_iter()
+        .all(|component| component.status == HealthStatus::Healthy)
+    {
+        HealthStatus::Healthy
+    } else {
+        HealthStatus::Unhealthy
+    }
+}
+
+/// A reachable drainer which lags too far behind is unhealthy, since the database does not
+/// reflect the writes made through the KV store until the backlog has been drained
+fn check_drainer_backlog(
+    drainer_streams: ComponentHealth,
+    max_pending_entries: usize,
+) -> ComponentHealth {
+    match drainer_streams.pending_entries {
+        Some(pending_entries) if pending_entries > max_pending_entries => ComponentHealth {
+            status: HealthStatus::Unhealthy,
+            error: Some(format!(
+                "{pending_entries} entries pending in the drainer streams, more than the \
+                maximum of {max_pending_entries}"
+            )),
+            ..drainer_streams
+        },
+        _ => drainer_streams,
+    }
+}
+
+async fn check_component<F, E>(check: F, timeout: Duration) -> ComponentHealth
+where
+    F: futures::Future<Output = CustomResult<Option<usize>, E>>,
+    E: error_stack::Context,
+{
+    let start = Instant::now();
+    let result = tokio::time::timeout(timeout, check).await;
+    let latency_ms = start.elapsed().as_millis();
+
+    match result {
+        Ok(Ok(pending_entries)) => ComponentHealth {
+            status: HealthStatus::Healthy,
+            latency_ms,
+            pending_entries,
+            error: None,
+        },
+        Ok(Err(error)) => {
+            logger::error!(health_check_error=?error);
+            ComponentHealth {
+                status: HealthStatus::Unhealthy,
+                latency_ms,
+                pending_entries: None,
+                error: Some(error.current_context().to_string()),
+            }
+        }
+        Err(_) => {
+            logger::error!(health_check_error = "Health check timed out");
+            ComponentHealth {
+                status: HealthStatus::Unhealthy,
+                latency_ms,
+                pending_entries: None,
+                error: Some(format!("No response within {} seconds", timeout.as_secs())),
+            }
+        }
+    }
+}
+
+async fn check_locker(state: &AppState, timeout: Duration) -> ComponentHealth {
+    if state.conf.locker.mock_locker {
+        // The mock locker is stored in the database, a missing card means that it is reachable
+        check_component(
+            async {
+                match state.store.find_locker_by_card_id("health_check").await {
+                    Ok(_) => Ok(None),
+                    Err(error) if error.current_context().is_db_not_found() => Ok(None),
+                    Err(error) => Err(error),
+                }
+            },
+            timeout,
+        )
+        .await
+    } else {
+        check_component(check_locker_health_endpoint(state), timeout).await
+    }
+}
+
+async fn check_locker_health_endpoint(
+    state: &AppState,
+) -> CustomResult<Option<usize>, errors::ApiClientError> {
+    let url = format!("{}/health", state.conf.locker.host);
+    let request = services::Request::new(services::Method::Get, &url);
+    let response =
+        services::api::send_request(state, request, Some(state.conf.health_check.timeout)).await?;
+
+    if response.status().is_success() {
+        Ok(None)
+    } else {
+        Err(report!(errors::ApiClientError::UnexpectedServerResponse))
+            .attach_printable_lazy(|| format!("Locker responded with status {}", response.status()))
+    }
+}
+
+async fn get_streams_length(
+    state: &AppState,
+    streams: impl Iterator<Item = String>,
+) -> CustomResult<Option<usize>, redis_interface::errors::RedisError> {
+    let redis_conn = state.store.get_redis_conn();
+    let mut pending_entries = 0;
+    for stream in streams {
+        pending_entries += redis_conn.stream_get_length(stream.as_str()).await?;
+    }
+    Ok(Some(pending_entries))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component(status: HealthStatus, pending_entries: Option<usize>) -> ComponentHealth {
+        ComponentHealth {
+            status,
+            latency_ms: 1,
+            pending_entries,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_overall_status_is_healthy_only_if_all_components_are() {
+        let healthy = component(HealthStatus::Healthy, None);
+        let unhealthy = component(HealthStatus::Unhealthy, None);
+
+        assert_eq!(
+            get_overall_status([&healthy, &healthy]),
+            HealthStatus::Healthy
+        );
+        assert_eq!(
+            get_overall_status([&healthy, &unhealthy, &healthy]),
+            HealthStatus::Unhealthy
+        );
+    }
+
+    #[test]
+    fn test_drainer_backlog_above_threshold_is_unhealthy() {
+        let drainer_streams = |pending_entries| {
+            check_drainer_backlog(component(HealthStatus::Healthy, pending_entries), 100)
+        };
+
+        assert_eq!(drainer_streams(Some(100)).status, HealthStatus::Healthy);
+        assert_eq!(drainer_streams(Some(101)).status, HealthStatus::Unhealthy);
+        assert!(drainer_streams(Some(101)).error.is_some());
+        assert_eq!(
+            check_drainer_backlog(component(HealthStatus::Unhealthy, None), 100).status,
+            HealthStatus::Unhealthy
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_component_check_times_out() {
+        let slow_check = async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Ok::<_, error_stack::Report<errors::ApiClientError>>(None)
+        };
+
+        let health = check_component(slow_check, Duration::from_millis(10)).await;
+
+        assert_eq!(health.status, HealthStatus::Unhealthy);
+        assert!(health.error.is_some());
+    }
+}
@@ -6,20 +6,24 @@ use storage_models::enums as storage_enums;
 
 use super::payments::helpers;
 use crate::{
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::{
+        errors::{self, CustomResult, RouterResponse, StorageErrorExt},
+        payments, utils as core_utils, webhooks,
+    },
     db::StorageInterface,
     routes::{metrics, AppState},
+    scheduler::utils as pt_utils,
     services,
     types::{
         self,
         api::{
-            customers,
+            self, customers,
             mandates::{self, MandateResponseExt},
         },
         domain, storage,
-        transformers::{ForeignInto, ForeignTryFrom},
+        transformers::{ForeignInto, ForeignTryFrom, ForeignTryInto},
     },
-    utils::OptionExt,
+    utils::{OptionExt, ValueExt},
 };
 
 #[instrument(skip(state))]
@@ -38,22 +42,78 @@ pub async fn get_mandate(
     ))
 }
 
-#[instrument(skip(db))]
+#[instrument(skip(state))]
 pub async fn revoke_mandate(
-    db: &dyn StorageInterface,
+    state: &AppState,
     merchant_account: domain::MerchantAccount,
     req: mandates::MandateId,
 ) -> RouterResponse<mandates::MandateRevokedResponse> {
+    let db = &*state.store;
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, &req.mandate_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+
+    // The agreement at the connector has already been cancelled
+    if mandate.mandate_status == storage_enums::MandateStatus::Revoked {
+        return Ok(services::ApplicationResponse::Json(
+            mandates::MandateRevokedResponse {
+                mandate_id: mandate.mandate_id,
+                status: mandate.mandate_status.foreign_into(),
+            },
+        ));
+    }
+
+    // Without a connector account or payment method to revoke it with, the mandate is only
+    // revoked on our end
+    let mandate_status =
+        match core_utils::construct_mandate_revoke_router_data(state, &merchant_account, &mandate)
+            .await?
+        {
+            Some(router_data) => {
+                let connector_data = api::ConnectorData::get_connector_by_name(
+                    &state.conf.connectors,
+                    &mandate.connector,
+                    api::GetToken::Connector,
+                )?;
+                let connector_integration: services::BoxedConnectorIntegration<
+                    '_,
+                    mandates::MandateRevoke,
+                    types::MandateRevokeRequestData,
+                    types::MandateRevokeResponseData,
+                > = connector_data.connector.get_connector_integration();
+                let response = services::execute_connector_processing_step(
+                    state,
+                    connector_integration,
+                    &router_data,
+                    payments::CallConnectorAction::Trigger,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while calling revoke mandate connector api")?;
+                let revoke_response = response.response.map_err(|err| {
+                    errors::ApiErrorResponse::ExternalConnectorError {
+                        code: err.code,
+                        message: err.message,
+                        connector: mandate.connector.clone(),
+                        status_code: err.status_code,
+                        reason: err.reason,
+                    }
+                })?;
+                revoke_response.mandate_status
+            }
+            None => storage_enums::MandateStatus::Revoked,
+        };
+
     let mandate = db
         .update_mandate_by_merchant_id_mandate_id(
             &merchant_account.merchant_id,
             &req.mandate_id,
-            storage::MandateUpdate::StatusUpdate {
-                mandate_status: storage::enums::MandateStatus::Revoked,
-            },
+            storage::MandateUpdate::StatusUpdate { mandate_status },
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+    trigger_mandate_outgoing_webhook(state, mandate.clone()).await;
 
     Ok(services::ApplicationResponse::Json(
         mandates::MandateRevokedResponse {
@@ -80,10 +140,15 @@ pub async fn update_connector_mandate_id(
             .change_context(errors::ApiErrorResponse::MandateNotFound)?;
         // only update the connector_mandate_id if existing is none
         if mandate.connector_mandate_id.is_none() {
+            let mandate_reference: types::MandateReference = connector_id
+                .clone()
+                .parse_value("MandateReference")
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
             db.update_mandate_by_merchant_id_mandate_id(
                 &merchant_account,
                 mandate_id,
                 storage::MandateUpdate::ConnectorReferenceUpdate {
+                    connector_mandate_id: mandate_reference.connector_mandate_id,
                     connector_mandate_ids: Some(connector_id),
                 },
             )
@@ -126,52 +191,102 @@ pub async fn get_customer_mandates(
     }
 }
 
+/// Reserves a usage of the mandate a payment is made with, along with the amount of the payment,
+/// before the connector is called. The reservation is kept by [`mandate_procedure`] once the
+/// payment succeeds, and released if it fails.
+pub async fn reserve_mandate_usage<F, FData>(
+    state: &AppState,
+    router_data: &types::RouterData<F, FData, types::PaymentsResponseData>,
+) -> errors::RouterResult<Option<storage::Mandate>>
+where
+    FData: MandateBehaviour,
+{
+    let mandate_id = match router_data.request.get_mandate_id() {
+        Some(mandate_ids) => &mandate_ids.mandate_id,
+        None => return Ok(None),
+    };
+
+    state
+        .store
+        .reserve_mandate_usage_by_merchant_id_mandate_id(
+            &router_data.merchant_id,
+            mandate_id,
+            router_data.request.get_amount(),
+        )
+        .await
+        .map(Some)
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::MandateValidationFailed {
+                    reason: "mandate usage limit or amount has been reached".to_string(),
+                })
+            } else {
+                error.change_context(errors::ApiErrorResponse::MandateUpdateFailed)
+            }
+        })
+}
+
+/// Releases the usage of a mandate reserved for a payment which failed. A failure to do so is only
+/// logged, so that it does not hide the failure of the payment.
+pub async fn release_mandate_usage(state: &AppState, mandate: &storage::Mandate, amount: i64) {
+    if let Err(error) = state
+        .store
+        .release_mandate_usage_by_merchant_id_mandate_id(
+            &mandate.merchant_id,
+            &mandate.mandate_id,
+            amount,
+        )
+        .await
+    {
+        logger::error!(
+            ?error,
+            mandate_id = %mandate.mandate_id,
+            "Failed to release the reserved usage of the mandate"
+        );
+    }
+}
+
 pub async fn mandate_procedure<F, FData>(
     state: &AppState,
     mut resp: types::RouterData<F, FData, types::PaymentsResponseData>,
     maybe_customer: &Option<domain::Customer>,
     pm_id: Option<String>,
+    reserved_mandate: Option<storage::Mandate>,
 ) -> errors::RouterResult<types::RouterData<F, FData, types::PaymentsResponseData>>
 where
     FData: MandateBehaviour,
 {
     match resp.response {
-        Err(_) => {}
-        Ok(_) => match resp.request.get_mandate_id() {
-            Some(mandate_id) => {
-                let mandate_id = &mandate_id.mandate_id;
-                let mandate = state
-                    .store
-                    .find_mandate_by_merchant_id_mandate_id(resp.merchant_id.as_ref(), mandate_id)
-                    .await
-                    .change_context(errors::ApiErrorResponse::MandateNotFound)?;
-                let mandate = match mandate.mandate_type {
-                    storage_enums::MandateType::SingleUse => state
-                        .store
-                        .update_mandate_by_merchant_id_mandate_id(
-                            &resp.merchant_id,
-                            mandate_id,
-                            storage::MandateUpdate::StatusUpdate {
-                                mandate_status: storage_enums::MandateStatus::Revoked,
-                            },
-                        )
-                        .await
-                        .change_context(errors::ApiErrorResponse::MandateUpdateFailed),
-                    storage_enums::MandateType::MultiUse => state
+        Err(_) => {
+            if let Some(mandate) = reserved_mandate {
+                release_mandate_usage(state, &mandate, resp.request.get_amount()).await;
+            }
+        }
+        Ok(_) => match reserved_mandate {
+            Some(mandate) if payments::retry::FAILED_ATTEMPT_STATUSES.contains(&resp.status) => {
+                release_mandate_usage(state, &mandate, resp.request.get_amount()).await;
+            }
+            Some(mandate) => {
+                let mandate_status = get_mandate_status_after_usage(
+                    &mandate,
+                    mandate.amount_captured.unwrap_or(0),
+                    mandate.usage_count,
+                );
+                let mandate = if mandate_status != storage_enums::MandateStatus::Active {
+                    let mandate = state
                         .store
                         .update_mandate_by_merchant_id_mandate_id(
-                            &resp.merchant_id,
-                            mandate_id,
-                            storage::MandateUpdate::CaptureAmountUpdate {
-                                amount_captured: Some(
-                                    mandate.amount_captured.unwrap_or(0)
-                                        + resp.request.get_amount(),
-                                ),
-                            },
+                            &mandate.merchant_id,
+                            &mandate.mandate_id,
+                            storage::MandateUpdate::StatusUpdate { mandate_status },
                         )
                         .await
-                        .change_context(errors::ApiErrorResponse::MandateUpdateFailed),
-                }?;
+                        .change_context(errors::ApiErrorResponse::MandateUpdateFailed)?;
+                    trigger_mandate_outgoing_webhook(state, mandate.clone()).await;
+                    mandate
+                } else {
+                    mandate
+                };
                 metrics::SUBSEQUENT_MANDATE_PAYMENT.add(
                     &metrics::CONTEXT,
                     1,
@@ -194,6 +309,9 @@ where
                         _ => (None, None),
                     };
 
+                    let connector_mandate_id = mandate_reference
+                        .as_ref()
+                        .and_then(|md| md.connector_mandate_id.clone());
                     let mandate_ids = mandate_reference
                         .map(|md| {
                             Encode::<types::MandateReference>::encode_to_value(&md)
@@ -202,7 +320,7 @@ where
                         })
                         .transpose()?;
 
-                    if let Some(new_mandate_data) = helpers::generate_mandate(
+                    if let Some(mut new_mandate_data) = helpers::generate_mandate(
                         resp.merchant_id.clone(),
                         resp.connector.clone(),
                         resp.request.get_setup_mandate_details().map(Clone::clone),
@@ -211,6 +329,9 @@ where
                         mandate_ids,
                         network_txn_id,
                     )? {
+                        // Stored separately as well, to look the mandate up from the
+                        // connector's webhooks
+                        new_mandate_data.set_connector_mandate_id(connector_mandate_id);
                         let connector = new_mandate_data.connector.clone();
                         logger::debug!("{:?}", new_mandate_data);
                         resp.request
@@ -240,11 +361,19 @@ where
                                     }
                                 )))
                         }));
-                        state
+                        let mandate = state
                             .store
                             .insert_mandate(new_mandate_data)
                             .await
                             .to_duplicate_response(errors::ApiErrorResponse::DuplicateMandate)?;
+                        if let Some(end_date) = mandate.end_date {
+                            add_mandate_expiry_task(&*state.store, &mandate, end_date)
+                                .await
+                                .change_context(errors::ApiErrorResponse::InternalServerError)
+                                .attach_printable(
+                                    "Failed while adding mandate expiry task to process tracker",
+                                )?;
+                        }
                         metrics::MANDATE_COUNT.add(
                             &metrics::CONTEXT,
                             1,
@@ -258,6 +387,126 @@ where
     Ok(resp)
 }
 
+/// A single use mandate is used up by its first payment, a multi use mandate once it reaches
+/// its usage limit or its cumulative amount
+pub fn get_mandate_status_after_usage(
+    mandate: &storage::Mandate,
+    amount_captured: i64,
+    usage_count: i32,
+) -> storage_enums::MandateStatus {
+    let is_used = match mandate.mandate_type {
+        storage_enums::MandateType::SingleUse => true,
+        storage_enums::MandateType::MultiUse => {
+            mandate
+                .usage_limit
+                .map_or(false, |usage_limit| usage_count >= usage_limit)
+                || mandate
+                    .mandate_amount
+                    .map_or(false, |mandate_amount| amount_captured >= mandate_amount)
+        }
+    };
+
+    if is_used {
+        storage_enums::MandateStatus::Used
+    } else {
+        storage_enums::MandateStatus::Active
+    }
+}
+
+pub fn is_mandate_expired(mandate: &storage::Mandate) -> bool {
+    mandate
+        .end_date
+        .map_or(false, |end_date| end_date <= common_utils::date_time::now())
+}
+
+/// Moves a mandate past its end date to the expired status and notifies the merchant
+pub async fn expire_mandate(
+    state: &AppState,
+    mandate: storage::Mandate,
+) -> errors::RouterResult<storage::Mandate> {
+    let mandate = state
+        .store
+        .update_mandate_by_merchant_id_mandate_id(
+            &mandate.merchant_id,
+            &mandate.mandate_id,
+            storage::MandateUpdate::StatusUpdate {
+                mandate_status: storage_enums::MandateStatus::Expired,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MandateUpdateFailed)?;
+
+    trigger_mandate_outgoing_webhook(state, mandate.clone()).await;
+    Ok(mandate)
+}
+
+pub async fn add_mandate_expiry_task(
+    db: &dyn StorageInterface,
+    mandate: &storage::Mandate,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let tracking_data = storage::MandateExpiryTrackingData {
+        merchant_id: mandate.merchant_id.clone(),
+        mandate_id: mandate.mandate_id.clone(),
+    };
+    let runner = "MANDATE_EXPIRY_WORKFLOW";
+    let task = "MANDATE_EXPIRY";
+    let process_tracker_id =
+        pt_utils::get_process_tracker_id(runner, task, &mandate.mandate_id, &mandate.merchant_id);
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            task,
+            runner,
+            tracking_data,
+            schedule_time,
+        )?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+    Ok(())
+}
+
+/// Failures are only logged, as the status of the mandate has already been updated by then
+pub async fn trigger_mandate_outgoing_webhook(state: &AppState, mandate: storage::Mandate) {
+    let event_type: storage_enums::EventType = match mandate.mandate_status.foreign_try_into() {
+        Ok(event_type) => event_type,
+        Err(_) => return,
+    };
+
+    if let Err(error) = create_mandate_outgoing_webhook(state, mandate, event_type).await {
+        logger::error!(mandate_outgoing_webhook_error=?error);
+    }
+}
+
+async fn create_mandate_outgoing_webhook(
+    state: &AppState,
+    mandate: storage::Mandate,
+    event_type: storage_enums::EventType,
+) -> errors::RouterResult<()> {
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(&mandate.merchant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let mandate_id = mandate.mandate_id.clone();
+    let mandate_response =
+        mandates::MandateResponse::from_db_mandate(state, mandate, &merchant_account).await?;
+
+    webhooks::create_event_and_trigger_outgoing_webhook::<api::OutgoingWebhook>(
+        state.clone(),
+        merchant_account,
+        event_type,
+        storage_enums::EventClass::Mandates,
+        None,
+        mandate_id,
+        storage_enums::EventObjectType::MandateDetails,
+        api::OutgoingWebhookContent::MandateDetails(Box::new(mandate_response)),
+    )
+    .await
+}
+
 #[instrument(skip(state))]
 pub async fn retrieve_mandates_list(
     state: &AppState,
@@ -0,0 +1,311 @@
+use common_utils::{date_time, pii};
+use masking::ExposeInterface;
+use router_env::logger;
+use time::{format_description::well_known::Rfc3339, Duration, PrimitiveDateTime};
+
+use crate::{
+    core::errors::{self, CustomResult},
+    db::StorageInterface,
+    scheduler::{metrics as scheduler_metrics, utils as pt_utils},
+    types::{api, domain, storage},
+    utils::ValueExt,
+};
+
+const EMAIL_NOTIFICATION_RUNNER: &str = "EMAIL_NOTIFICATION_WORKFLOW";
+const EMAIL_NOTIFICATION_TASK: &str = "EMAIL_NOTIFICATION";
+
+/// Number of days before the expiry of an API key at which the merchant is reminded of it
+const API_KEY_EXPIRY_REMINDER_DAYS: i64 = 7;
+
+/// Schedules an email notification to be sent to the merchant as soon as possible.
+///
+/// The email is sent by the scheduler, so a failure is only logged and never fails the caller.
+pub async fn notify_merchant(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    notification: storage::EmailNotification,
+) {
+    if let Err(error) =
+        add_email_notification_task(db, merchant_id, notification, date_time::now()).await
+    {
+        logger::error!(?error, "Failed to schedule email notification");
+    }
+}
+
+/// Schedules the reminder of the expiry of an API key, if it expires
+pub async fn notify_api_key_expiry(db: &dyn StorageInterface, api_key: &storage::ApiKey) {
+    let expires_at = match api_key.expires_at {
+        Some(expires_at) if expires_at > date_time::now() => expires_at,
+        _ => return,
+    };
+    let schedule_time = std::cmp::max(
+        expires_at.saturating_sub(Duration::days(API_KEY_EXPIRY_REMINDER_DAYS)),
+        date_time::now(),
+    );
+    let notification = storage::EmailNotification::ApiKeyExpiringSoon {
+        key_id: api_key.key_id.clone(),
+        key_name: api_key.name.clone(),
+        expires_at,
+    };
+
+    if let Err(error) =
+        add_email_notification_task(db, &api_key.merchant_id, notification, schedule_time).await
+    {
+        logger::error!(
+            ?error,
+            "Failed to schedule API key expiry email notification"
+        );
+    }
+}
+
+pub async fn add_email_notification_task(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    notification: storage::EmailNotification,
+    schedule_time: PrimitiveDateTime,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        EMAIL_NOTIFICATION_RUNNER,
+        EMAIL_NOTIFICATION_TASK,
+        &get_notification_id(&notification),
+        merchant_id,
+    );
+    let tracking_data = storage::EmailNotificationTrackingData {
+        merchant_id: merchant_id.to_owned(),
+        notification,
+    };
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            EMAIL_NOTIFICATION_TASK,
+            EMAIL_NOTIFICATION_RUNNER,
+            tracking_data,
+            schedule_time,
+        )?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            scheduler_metrics::TASKS_ADDED_COUNT.add(&scheduler_metrics::CONTEXT, 1, &[]);
+            Ok(())
+        }
+        // The same notification is already scheduled
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error.change_context(errors::ProcessTrackerError::ProcessUpdateFailed)),
+    }
+}
+
+/// Identifies a notification, so that the same notification is not sent twice
+fn get_notification_id(notification: &storage::EmailNotification) -> String {
+    match notification {
+        storage::EmailNotification::MerchantAccountCreated => "merchant_account_created".into(),
+        storage::EmailNotification::ApiKeyExpiringSoon {
+            key_id, expires_at, ..
+        } => format!(
+            "api_key_expiring_{key_id}_{}",
+            expires_at.assume_utc().unix_timestamp()
+        ),
+        storage::EmailNotification::DisputeOpened { dispute_id, .. } => {
+            format!("dispute_opened_{dispute_id}")
+        }
+        storage::EmailNotification::DisputeEvidenceDue {
+            dispute_id,
+            challenge_required_by,
+            reminder_offset,
+            ..
+        } => format!(
+            "dispute_evidence_due_{dispute_id}_{}_{reminder_offset}",
+            challenge_required_by.assume_utc().unix_timestamp()
+        ),
+        // Every exhausted event of a failing endpoint would otherwise send its own email, so
+        // the merchant is notified once per day
+        storage::EmailNotification::WebhookEndpointFailing { failed_at, .. } => {
+            format!("webhook_endpoint_failing_{}", failed_at.date())
+        }
+    }
+}
+
+/// Whether a scheduled notification still applies when it is about to be sent
+pub async fn is_notification_relevant(
+    db: &dyn StorageInterface,
+    tracking_data: &storage::EmailNotificationTrackingData,
+) -> CustomResult<bool, errors::StorageError> {
+    match &tracking_data.notification {
+        // The key may have been revoked, or its expiry changed, since the reminder was scheduled
+        storage::EmailNotification::ApiKeyExpiringSoon {
+            key_id, expires_at, ..
+        } => Ok(db
+            .find_api_key_by_merchant_id_key_id_optional(&tracking_data.merchant_id, key_id)
+            .await?
+            .map_or(false, |api_key| api_key.expires_at == Some(*expires_at))),
+        // The dispute may have been resolved, or its deadline changed, since the reminder was
+        // scheduled
+        storage::EmailNotification::DisputeEvidenceDue {
+            dispute_id,
+            challenge_required_by,
+            ..
+        } => {
+            let merchant_account = db
+                .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+                .await?;
+            let dispute = db
+                .find_dispute_by_merchant_id_dispute_id(
+                    &tracking_data.merchant_id,
+                    dispute_id,
+                    merchant_account.storage_scheme,
+                )
+                .await?;
+            Ok(
+                dispute.dispute_status == storage::enums::DisputeStatus::DisputeOpened
+                    && dispute.challenge_required_by == Some(*challenge_required_by)
+                    && *challenge_required_by > date_time::now(),
+            )
+        }
+        storage::EmailNotification::MerchantAccountCreated
+        | storage::EmailNotification::DisputeOpened { .. }
+        | storage::EmailNotification::WebhookEndpointFailing { .. } => Ok(true),
+    }
+}
+
+/// The primary email in the merchant details of the merchant account, if any
+pub fn get_merchant_email(merchant_account: &domain::MerchantAccount) -> Option<pii::Email> {
+    let merchant_details = merchant_account.merchant_details.clone()?;
+    merchant_details
+        .into_inner()
+        .expose()
+        .parse_value::<api::MerchantDetails>("MerchantDetails")
+        .map_err(|error| logger::error!(?error, "Failed to parse merchant details"))
+        .ok()?
+        .primary_email
+}
+
+/// Subject and body of the email of a notification
+pub fn get_email_contents(
+    merchant_id: &str,
+    notification: &storage::EmailNotification,
+) -> (String, String) {
+    match notification {
+        storage::EmailNotification::MerchantAccountCreated => (
+            "Your merchant account has been created".to_string(),
+            format!(
+                "Hello,\n\n\
+                Your merchant account {merchant_id} has been created. You can now create API \
+                keys and configure payment connectors for it.\n"
+            ),
+        ),
+        storage::EmailNotification::ApiKeyExpiringSoon {
+            key_id,
+            key_name,
+            expires_at,
+        } => (
+            format!("Your API key {key_name} is expiring soon"),
+            format!(
+                "Hello,\n\n\
+                The API key {key_name} ({key_id}) of the merchant account {merchant_id} expires \
+                on {}. Requests authenticated with it will be rejected after that, create a new \
+                API key or rotate this one before it expires.\n",
+                format_time(*expires_at)
+            ),
+        ),
+        storage::EmailNotification::DisputeOpened {
+            dispute_id,
+            payment_id,
+            amount,
+            currency,
+            challenge_required_by,
+        } => (
+            format!("A dispute has been opened for the payment {payment_id}"),
+            format!(
+                "Hello,\n\n\
+                A dispute {dispute_id} of {amount} {currency} has been opened for the payment \
+                {payment_id} of the merchant account {merchant_id}.\n{}",
+                challenge_required_by
+                    .map(|challenge_required_by| format!(
+                        "Evidence to challenge it has to be submitted by {}.\n",
+                        format_time(challenge_required_by)
+                    ))
+                    .unwrap_or_default()
+            ),
+        ),
+        storage::EmailNotification::DisputeEvidenceDue {
+            dispute_id,
+            payment_id,
+            challenge_required_by,
+            ..
+        } => (
+            format!("Evidence for the dispute {dispute_id} is due soon"),
+            format!(
+                "Hello,\n\n\
+                Evidence to challenge the dispute {dispute_id} for the payment {payment_id} of \
+                the merchant account {merchant_id} has to be submitted by {}. The dispute is lost \
+                if no evidence is submitted by then.\n",
+                format_time(*challenge_required_by)
+            ),
+        ),
+        storage::EmailNotification::WebhookEndpointFailing {
+            event_id,
+            delivery_attempts,
+            ..
+        } => (
+            "Your webhook endpoint is failing".to_string(),
+            format!(
+                "Hello,\n\n\
+                The webhook for the event {event_id} of the merchant account {merchant_id} could \
+                not be delivered to your webhook endpoint after {delivery_attempts} attempts and \
+                will not be retried anymore. Webhooks of other events may be failing as well, \
+                this email is sent at most once per day. Check that the endpoint is reachable \
+                and responds with a successful status code, the events can be redelivered \
+                manually.\n"
+            ),
+        ),
+    }
+}
+
+fn format_time(time: PrimitiveDateTime) -> String {
+    time.assume_utc()
+        .format(&Rfc3339)
+        .unwrap_or_else(|_| time.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_key_expiry_notification_id_changes_with_expiry() {
+        let notification = |expires_at| storage::EmailNotification::ApiKeyExpiringSoon {
+            key_id: "dev_key".to_string(),
+            key_name: "Key".to_string(),
+            expires_at,
+        };
+        let now = date_time::now();
+
+        assert_eq!(
+            get_notification_id(&notification(now)),
+            get_notification_id(&notification(now))
+        );
+        assert_ne!(
+            get_notification_id(&notification(now)),
+            get_notification_id(&notification(now + Duration::days(1)))
+        );
+    }
+
+    #[test]
+    fn test_webhook_endpoint_failing_notification_is_sent_once_per_day() {
+        let notification =
+            |event_id: &str, failed_at| storage::EmailNotification::WebhookEndpointFailing {
+                event_id: event_id.to_string(),
+                delivery_attempts: 12,
+                failed_at,
+            };
+        let midnight = time::macros::datetime!(2023-06-01 00:00);
+
+        assert_eq!(
+            get_notification_id(&notification("evt_1", midnight)),
+            get_notification_id(&notification("evt_2", midnight + Duration::hours(23)))
+        );
+        assert_ne!(
+            get_notification_id(&notification("evt_1", midnight)),
+            get_notification_id(&notification("evt_1", midnight + Duration::days(1)))
+        );
+    }
+}
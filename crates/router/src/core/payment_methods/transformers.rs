@@ -149,18 +149,18 @@ pub fn get_dotted_jws(jws: encryption::JwsBody) -> String {
 }
 
 pub async fn get_decrypted_response_payload(
-    #[cfg(not(feature = "kms"))] jwekey: &settings::Jwekey,
-    #[cfg(feature = "kms")] jwekey: &settings::ActiveKmsSecrets,
+    #[cfg(not(feature = "secrets_management"))] jwekey: &settings::Jwekey,
+    #[cfg(feature = "secrets_management")] jwekey: &settings::ActiveKmsSecrets,
     jwe_body: encryption::JweBody,
 ) -> CustomResult<String, errors::VaultError> {
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "secrets_management")]
     let public_key = jwekey.jwekey.peek().vault_encryption_key.clone();
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "secrets_management")]
     let private_key = jwekey.jwekey.peek().vault_private_key.clone();
 
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "secrets_management"))]
     let public_key = jwekey.vault_encryption_key.to_owned();
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "secrets_management"))]
     let private_key = jwekey.vault_private_key.to_owned();
 
     let jwt = get_dotted_jwe(jwe_body);
@@ -187,8 +187,8 @@ pub async fn get_decrypted_response_payload(
 }
 
 pub async fn mk_basilisk_req(
-    #[cfg(feature = "kms")] jwekey: &settings::ActiveKmsSecrets,
-    #[cfg(not(feature = "kms"))] jwekey: &settings::Jwekey,
+    #[cfg(feature = "secrets_management")] jwekey: &settings::ActiveKmsSecrets,
+    #[cfg(not(feature = "secrets_management"))] jwekey: &settings::Jwekey,
     jws: &str,
 ) -> CustomResult<encryption::JweBody, errors::VaultError> {
     let jws_payload: Vec<&str> = jws.split('.').collect();
@@ -206,10 +206,10 @@ pub async fn mk_basilisk_req(
     let payload = utils::Encode::<encryption::JwsBody>::encode_to_vec(&jws_body)
         .change_context(errors::VaultError::SaveCardFailed)?;
 
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "secrets_management")]
     let public_key = jwekey.jwekey.peek().vault_encryption_key.clone();
 
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "secrets_management"))]
     let public_key = jwekey.vault_encryption_key.to_owned();
 
     let jwe_encrypted = encryption::encrypt_jwe(&payload, public_key)
@@ -234,8 +234,8 @@ pub async fn mk_basilisk_req(
 }
 
 pub async fn mk_add_card_request_hs(
-    #[cfg(not(feature = "kms"))] jwekey: &settings::Jwekey,
-    #[cfg(feature = "kms")] jwekey: &settings::ActiveKmsSecrets,
+    #[cfg(not(feature = "secrets_management"))] jwekey: &settings::Jwekey,
+    #[cfg(feature = "secrets_management")] jwekey: &settings::ActiveKmsSecrets,
     locker: &settings::Locker,
     card: &api::CardDetail,
     customer_id: &str,
@@ -259,10 +259,10 @@ pub async fn mk_add_card_request_hs(
     let payload = utils::Encode::<StoreCardReq<'_>>::encode_to_vec(&store_card_req)
         .change_context(errors::VaultError::RequestEncodingFailed)?;
 
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "secrets_management")]
     let private_key = jwekey.jwekey.peek().vault_private_key.clone();
 
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "secrets_management"))]
     let private_key = jwekey.vault_private_key.to_owned();
 
     let jws = encryption::jws_sign_payload(&payload, &locker.locker_signing_key_id, private_key)
@@ -387,8 +387,8 @@ pub fn mk_add_card_request(
 }
 
 pub async fn mk_get_card_request_hs(
-    #[cfg(not(feature = "kms"))] jwekey: &settings::Jwekey,
-    #[cfg(feature = "kms")] jwekey: &settings::ActiveKmsSecrets,
+    #[cfg(not(feature = "secrets_management"))] jwekey: &settings::Jwekey,
+    #[cfg(feature = "secrets_management")] jwekey: &settings::ActiveKmsSecrets,
     locker: &settings::Locker,
     customer_id: &str,
     merchant_id: &str,
@@ -403,10 +403,10 @@ pub async fn mk_get_card_request_hs(
     let payload = utils::Encode::<CardReqBody<'_>>::encode_to_vec(&card_req_body)
         .change_context(errors::VaultError::RequestEncodingFailed)?;
 
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "secrets_management")]
     let private_key = jwekey.jwekey.peek().vault_private_key.clone();
 
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "secrets_management"))]
     let private_key = jwekey.vault_private_key.to_owned();
 
     let jws = encryption::jws_sign_payload(&payload, &locker.locker_signing_key_id, private_key)
@@ -467,8 +467,8 @@ pub fn mk_get_card_response(card: GetCardResponse) -> errors::RouterResult<Card>
 }
 
 pub async fn mk_delete_card_request_hs(
-    #[cfg(feature = "kms")] jwekey: &settings::ActiveKmsSecrets,
-    #[cfg(not(feature = "kms"))] jwekey: &settings::Jwekey,
+    #[cfg(feature = "secrets_management")] jwekey: &settings::ActiveKmsSecrets,
+    #[cfg(not(feature = "secrets_management"))] jwekey: &settings::Jwekey,
     locker: &settings::Locker,
     customer_id: &str,
     merchant_id: &str,
@@ -483,10 +483,10 @@ pub async fn mk_delete_card_request_hs(
     let payload = utils::Encode::<CardReqBody<'_>>::encode_to_vec(&card_req_body)
         .change_context(errors::VaultError::RequestEncodingFailed)?;
 
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "secrets_management")]
     let private_key = jwekey.jwekey.peek().vault_private_key.clone();
 
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "secrets_management"))]
     let private_key = jwekey.vault_private_key.to_owned();
 
     let jws = encryption::jws_sign_payload(&payload, &locker.locker_signing_key_id, private_key)
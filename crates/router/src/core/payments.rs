@@ -1,8 +1,12 @@
 pub mod access_token;
+pub mod auto_void;
 pub mod customers;
 pub mod flows;
 pub mod helpers;
+pub mod multiple_capture;
 pub mod operations;
+pub mod retry;
+pub mod routing;
 pub mod tokenization;
 pub mod transformers;
 
@@ -30,6 +34,7 @@ use crate::{
     configs::settings::PaymentMethodTypeTokenFilter,
     core::{
         errors::{self, CustomResult, RouterResponse, RouterResult},
+        fraud_check,
         payment_methods::vault,
     },
     db::StorageInterface,
@@ -140,7 +145,23 @@ where
         )
         .await?;
 
+    let frm_info = match connector.as_ref() {
+        Some(connector_details) if is_payment_confirmed(&operation, &payment_data) => {
+            fraud_check::get_frm_info(state, &merchant_account, &payment_data, connector_details)
+                .await?
+        }
+        _ => None,
+    };
+
     if let Some(connector_details) = connector {
+        let frm_decision = fraud_check::call_frm_before_connector_call(
+            state,
+            &merchant_account,
+            frm_info.as_ref(),
+            &mut payment_data,
+        )
+        .await?;
+
         if should_add_task_to_process_tracker(&payment_data) {
             operation
                 .to_domain()?
@@ -149,6 +170,12 @@ where
         }
 
         payment_data = match connector_details {
+            // The payment was declined by the FRM connector and must not be authorized
+            api::ConnectorCallType::Single(_)
+                if frm_decision == fraud_check::FrmDecision::Decline =>
+            {
+                payment_data
+            }
             api::ConnectorCallType::Single(connector) => {
                 let router_data = call_connector_service(
                     state,
@@ -157,23 +184,70 @@ where
                     &operation,
                     &payment_data,
                     &customer,
-                    call_connector_action,
+                    call_connector_action.clone(),
                     tokenization_action,
                 )
-                .await?;
+                .await;
 
-                let operation = Box::new(PaymentResponse);
                 let db = &*state.store;
-                operation
-                    .to_post_update_tracker()?
-                    .update_tracker(
-                        db,
-                        &validate_result.payment_id,
-                        payment_data,
-                        router_data,
-                        merchant_account.storage_scheme,
-                    )
-                    .await?
+                match router_data {
+                    Ok(router_data) => {
+                        let response_operation = Box::new(PaymentResponse);
+                        let payment_data = response_operation
+                            .to_post_update_tracker()?
+                            .update_tracker(
+                                db,
+                                &validate_result.payment_id,
+                                payment_data,
+                                router_data,
+                                merchant_account.storage_scheme,
+                            )
+                            .await?;
+
+                        let retry_config = if is_payment_confirmed(&operation, &payment_data) {
+                            retry::get_auto_retry_config(db, &merchant_account.merchant_id).await
+                        } else {
+                            None
+                        };
+
+                        match retry_config {
+                            Some(retry_config) => {
+                                retry::retry_on_fallback_connectors(
+                                    state,
+                                    &merchant_account,
+                                    &operation,
+                                    payment_data,
+                                    &customer,
+                                    call_connector_action,
+                                    &validate_result,
+                                    retry_config,
+                                )
+                                .await?
+                            }
+                            None => payment_data,
+                        }
+                    }
+                    Err(error) => {
+                        fail_current_capture(db, &payment_data, &error).await;
+                        if !is_payment_confirmed(&operation, &payment_data) {
+                            return Err(error);
+                        }
+
+                        // Timeouts and server errors of the connector are retried like declines,
+                        // if the merchant retries on them
+                        retry::retry_on_connector_call_error(
+                            state,
+                            &merchant_account,
+                            &operation,
+                            payment_data,
+                            &customer,
+                            call_connector_action,
+                            &validate_result,
+                            error,
+                        )
+                        .await?
+                    }
+                }
             }
 
             api::ConnectorCallType::Multiple(connectors) => {
@@ -188,6 +262,29 @@ where
                 .await?
             }
         };
+
+        if let Some(frm_info) = frm_info.as_ref() {
+            payment_data = fraud_check::call_frm_after_connector_call(
+                state,
+                &merchant_account,
+                frm_info,
+                frm_decision,
+                payment_data,
+            )
+            .await?;
+        }
+
+        if let Some(capture_on) = get_scheduled_capture_time(&payment_data) {
+            add_scheduled_capture_task(&*state.store, &payment_data.payment_attempt, capture_on)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed while adding scheduled capture task to process tracker",
+                )?;
+        }
+
+        auto_void::schedule_auto_void_if_required(&*state.store, &payment_data).await?;
+
         if should_delete_pm_from_locker(payment_data.payment_intent.status) {
             vault::Vault::delete_locker_payment_method_by_lookup_key(state, &payment_data.token)
                 .await
@@ -452,6 +549,7 @@ impl PaymentRedirectFlow for PaymentRedirectSync {
                     encoded_data: None,
                 }
             }),
+            capture_id: None,
         };
         payments_core::<api::PSync, api::PaymentsResponse, _, _, _>(
             state,
@@ -972,6 +1070,7 @@ where
     pub payment_method_data: Option<api::PaymentMethodData>,
     pub refunds: Vec<storage::Refund>,
     pub disputes: Vec<storage::Dispute>,
+    pub attempts: Option<Vec<storage::PaymentAttempt>>,
     pub sessions_token: Vec<api::SessionToken>,
     pub card_cvc: Option<Secret<String>>,
     pub email: Option<pii::Email>,
@@ -980,6 +1079,7 @@ where
     pub connector_customer_id: Option<String>,
     pub ephemeral_key: Option<ephemeral_key::EphemeralKey>,
     pub redirect_response: Option<api_models::payments::RedirectResponse>,
+    pub multiple_capture_data: Option<multiple_capture::MultipleCaptureData>,
 }
 
 #[derive(Debug, Default)]
@@ -1046,14 +1146,18 @@ pub fn should_call_connector<Op: Debug, F: Clone>(
                 .is_none()
         }
         "PaymentStatus" => {
-            matches!(
+            (matches!(
                 payment_data.payment_intent.status,
                 storage_enums::IntentStatus::Failed
                     | storage_enums::IntentStatus::Processing
                     | storage_enums::IntentStatus::Succeeded
                     | storage_enums::IntentStatus::RequiresCustomerAction
                     | storage_enums::IntentStatus::RequiresMerchantAction
-            ) && payment_data.force_sync.unwrap_or(false)
+            ) || payment_data
+                .multiple_capture_data
+                .as_ref()
+                .map_or(false, |data| data.has_pending_captures()))
+                && payment_data.force_sync.unwrap_or(false)
         }
         "PaymentCancel" => matches!(
             payment_data.payment_intent.status,
@@ -1082,6 +1186,47 @@ pub fn is_operation_confirm<Op: Debug>(operation: &Op) -> bool {
     matches!(format!("{operation:?}").as_str(), "PaymentConfirm")
 }
 
+/// The capture made by the request is recorded before the connector is called. It is marked as
+/// failed when the connector could not be called, so that its amount can be captured again.
+async fn fail_current_capture<F: Clone>(
+    db: &dyn StorageInterface,
+    payment_data: &PaymentData<F>,
+    error: &error_stack::Report<errors::ApiErrorResponse>,
+) {
+    let capture = payment_data
+        .multiple_capture_data
+        .as_ref()
+        .and_then(|data| data.get_current_capture())
+        .filter(|capture| capture.status == storage_enums::CaptureStatus::Started);
+
+    if let Some(capture) = capture {
+        let capture_update = storage::CaptureUpdate::ErrorUpdate {
+            status: storage_enums::CaptureStatus::Failed,
+            error_code: None,
+            error_message: Some(error.current_context().to_string()),
+        };
+        if let Err(update_error) = db
+            .update_capture_with_capture_id(capture.clone(), capture_update)
+            .await
+        {
+            logger::error!(?update_error, "Failed to mark the capture as failed");
+        }
+    }
+}
+
+/// Whether the payment is being authorized in this request, either through the confirm operation
+/// or through a create or update request with `confirm` set
+pub fn is_payment_confirmed<F: Clone, Op: Debug>(
+    operation: &Op,
+    payment_data: &PaymentData<F>,
+) -> bool {
+    is_operation_confirm(operation)
+        || (matches!(
+            format!("{operation:?}").as_str(),
+            "PaymentCreate" | "PaymentUpdate"
+        ) && payment_data.confirm.unwrap_or(false))
+}
+
 #[cfg(feature = "olap")]
 pub async fn list_payments(
     db: &dyn StorageInterface,
@@ -1158,6 +1303,56 @@ pub async fn add_process_sync_task(
     Ok(())
 }
 
+/// Returns the time at which an authorized payment with scheduled capture is to be captured
+pub fn get_scheduled_capture_time<F: Clone>(
+    payment_data: &PaymentData<F>,
+) -> Option<time::PrimitiveDateTime> {
+    let payment_attempt = &payment_data.payment_attempt;
+    match (payment_attempt.capture_method, payment_attempt.status) {
+        (
+            Some(storage_enums::CaptureMethod::Scheduled),
+            storage_enums::AttemptStatus::Authorized,
+        ) => payment_attempt.capture_on,
+        _ => None,
+    }
+}
+
+pub async fn add_scheduled_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    capture_on: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let tracking_data = api::PaymentsCaptureRequest {
+        payment_id: Some(payment_attempt.payment_id.clone()),
+        merchant_id: Some(payment_attempt.merchant_id.clone()),
+        amount_to_capture: payment_attempt.amount_to_capture,
+        ..Default::default()
+    };
+    let runner = "SCHEDULED_CAPTURE_WORKFLOW";
+    let task = "SCHEDULED_CAPTURE";
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &payment_attempt.attempt_id,
+        &payment_attempt.merchant_id,
+    );
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            task,
+            runner,
+            tracking_data,
+            capture_on,
+        )?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        // The capture has already been scheduled for this attempt
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error.change_context(errors::ProcessTrackerError::ProcessUpdateFailed)),
+    }
+}
+
 pub fn update_straight_through_routing<F>(
     payment_data: &mut PaymentData<F>,
     request_straight_through: serde_json::Value,
@@ -1258,11 +1453,18 @@ where
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Invalid straight through routing rules format")?;
 
+    if let Some(ref straight_through) = request_straight_through {
+        routing::validate_routing_algorithm(&straight_through.clone().into())?;
+    }
+
+    let routing_context = routing::RoutingContext::from(&*payment_data);
+
     let decided_connector = decide_connector(
         state,
         merchant_account,
         request_straight_through,
         &mut routing_data,
+        &routing_context,
     )?;
 
     let encoded_algorithm = routing_data
@@ -1283,6 +1485,7 @@ pub fn decide_connector(
     merchant_account: &domain::MerchantAccount,
     request_straight_through: Option<api::StraightThroughAlgorithm>,
     routing_data: &mut storage::RoutingData,
+    routing_context: &routing::RoutingContext,
 ) -> RouterResult<api::ConnectorCallType> {
     if let Some(ref connector_name) = routing_data.routed_through {
         let connector_data = api::ConnectorData::get_connector_by_name(
@@ -1297,9 +1500,8 @@ pub fn decide_connector(
     }
 
     if let Some(routing_algorithm) = request_straight_through {
-        let connector_name = match &routing_algorithm {
-            api::StraightThroughAlgorithm::Single(conn) => conn.to_string(),
-        };
+        let connector_name =
+            get_connector_name_from_algorithm(&routing_algorithm.clone().into(), routing_context)?;
 
         let connector_data = api::ConnectorData::get_connector_by_name(
             &state.conf.connectors,
@@ -1315,9 +1517,8 @@ pub fn decide_connector(
     }
 
     if let Some(ref routing_algorithm) = routing_data.algorithm {
-        let connector_name = match routing_algorithm {
-            api::StraightThroughAlgorithm::Single(conn) => conn.to_string(),
-        };
+        let connector_name =
+            get_connector_name_from_algorithm(&routing_algorithm.clone().into(), routing_context)?;
 
         let connector_data = api::ConnectorData::get_connector_by_name(
             &state.conf.connectors,
@@ -1342,9 +1543,7 @@ pub fn decide_connector(
         .change_context(errors::ApiErrorResponse::InternalServerError) // Deserialization failed
         .attach_printable("Unable to deserialize merchant routing algorithm")?;
 
-    let connector_name = match routing_algorithm {
-        api::RoutingAlgorithm::Single(conn) => conn.to_string(),
-    };
+    let connector_name = get_connector_name_from_algorithm(&routing_algorithm, routing_context)?;
 
     let connector_data = api::ConnectorData::get_connector_by_name(
         &state.conf.connectors,
@@ -1354,11 +1553,28 @@ pub fn decide_connector(
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("Routing algorithm gave invalid connector")?;
 
+    // The volume split used for this payment is recorded, so that a change in the merchant's
+    // splits does not move the subsequent attempts of this payment to another connector
+    if let api::RoutingAlgorithm::VolumeSplit(_) = routing_algorithm {
+        routing_data.algorithm = Some(routing_algorithm.into());
+    }
     routing_data.routed_through = Some(connector_name);
 
     Ok(api::ConnectorCallType::Single(connector_data))
 }
 
+fn get_connector_name_from_algorithm(
+    routing_algorithm: &api::RoutingAlgorithm,
+    routing_context: &routing::RoutingContext,
+) -> RouterResult<String> {
+    routing::get_connectors_from_routing_algorithm(routing_algorithm, routing_context)
+        .first()
+        .map(ToString::to_string)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Routing algorithm did not yield any connector")
+}
+
 pub fn should_add_task_to_process_tracker<F: Clone>(payment_data: &PaymentData<F>) -> bool {
     let connector = payment_data.payment_attempt.connector.as_deref();
 
@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use error_stack::ResultExt;
+
+use super::PaymentData;
+use crate::{
+    core::errors::{self, CustomResult, RouterResult},
+    db::StorageInterface,
+    logger,
+    scheduler::utils as pt_utils,
+    types::{
+        api,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+/// Cancellation reason of the payments voided by the [`AutoVoidWorkflow`]
+///
+/// [`AutoVoidWorkflow`]: crate::scheduler::workflows::AutoVoidWorkflow
+pub const AUTO_VOID_CANCELLATION_REASON: &str = "authorization_expired";
+
+/// Per-merchant configuration of the automatic void of stale authorizations, stored in the
+/// configs table under `auto_void_{merchant_id}`
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct AutoVoidConfig {
+    /// Age in seconds after which an uncaptured authorization is voided
+    pub max_authorization_age: Option<i64>,
+    /// Connector specific overrides of `max_authorization_age`, keyed by connector name
+    pub connector_max_authorization_age: HashMap<String, i64>,
+}
+
+impl AutoVoidConfig {
+    pub fn get_max_authorization_age(&self, connector: &str) -> Option<time::Duration> {
+        self.connector_max_authorization_age
+            .get(connector)
+            .copied()
+            .or(self.max_authorization_age)
+            .map(time::Duration::seconds)
+    }
+}
+
+/// Tracking data of the process tracker task scheduling the void of the authorizations which were
+/// already pending capture when the auto void of a merchant was configured
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct AutoVoidSweepTrackingData {
+    pub merchant_id: String,
+    /// Only the payments created before this time are swept
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_before: time::PrimitiveDateTime,
+}
+
+const AUTO_VOID_CONFIG_KEY_PREFIX: &str = "auto_void_";
+
+/// Returns the merchant whose auto void is configured by the config with the given key, if any
+pub fn get_merchant_id_from_config_key(key: &str) -> Option<&str> {
+    key.strip_prefix(AUTO_VOID_CONFIG_KEY_PREFIX)
+}
+
+pub async fn get_auto_void_config(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> Option<AutoVoidConfig> {
+    let key = format!("{AUTO_VOID_CONFIG_KEY_PREFIX}{merchant_id}");
+    db.find_config_by_key_cached(&key)
+        .await
+        .map_err(|error| logger::debug!(?error, "auto void is not configured"))
+        .ok()
+        .and_then(|config| {
+            serde_json::from_str::<AutoVoidConfig>(&config.config)
+                .map_err(|error| logger::error!(?error, "invalid auto void config"))
+                .ok()
+        })
+}
+
+/// Schedules the void of a payment which has just been authorized, if the merchant has
+/// configured a maximum authorization age for its connector
+pub async fn schedule_auto_void_if_required<F: Clone>(
+    db: &dyn StorageInterface,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    let payment_attempt = &payment_data.payment_attempt;
+    if payment_attempt.status != storage_enums::AttemptStatus::Authorized
+        || payment_data.payment_intent.status != storage_enums::IntentStatus::RequiresCapture
+    {
+        return Ok(());
+    }
+
+    let max_authorization_age = match (
+        payment_attempt.connector.as_deref(),
+        get_auto_void_config(db, &payment_attempt.merchant_id).await,
+    ) {
+        (Some(connector), Some(config)) => config.get_max_authorization_age(connector),
+        _ => None,
+    };
+
+    match max_authorization_age {
+        Some(max_authorization_age) => add_auto_void_task(
+            db,
+            payment_attempt,
+            common_utils::date_time::now().saturating_add(max_authorization_age),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while adding auto void task to process tracker"),
+        None => Ok(()),
+    }
+}
+
+pub async fn add_auto_void_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let tracking_data = api::PaymentsRetrieveRequest {
+        merchant_id: Some(payment_attempt.merchant_id.clone()),
+        resource_id: api::PaymentIdType::PaymentIntentId(payment_attempt.payment_id.clone()),
+        ..Default::default()
+    };
+    let runner = "AUTO_VOID_WORKFLOW";
+    let task = "AUTO_VOID";
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &payment_attempt.attempt_id,
+        &payment_attempt.merchant_id,
+    );
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            task,
+            runner,
+            tracking_data,
+            schedule_time,
+        )?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        // The void has already been scheduled for this attempt
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error.change_context(errors::ProcessTrackerError::ProcessUpdateFailed)),
+    }
+}
+
+/// Schedules the void of the payments of a merchant created before the given time which are still
+/// pending capture, such as the ones authorized before the auto void was configured. The voids are
+/// due once the payments reach the maximum authorization age of their connector, payments which are
+/// already older are voided right away.
+pub async fn schedule_auto_void_of_pending_authorizations(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    created_before: time::PrimitiveDateTime,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> Result<(), errors::ProcessTrackerError> {
+    let config = match get_auto_void_config(db, merchant_id).await {
+        Some(config) => config,
+        None => return Ok(()),
+    };
+
+    let payment_intents = db
+        .find_payment_intents_by_merchant_id_status_created_before(
+            merchant_id,
+            storage_enums::IntentStatus::RequiresCapture,
+            created_before,
+        )
+        .await?;
+
+    for payment_intent in payment_intents {
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                &payment_intent.payment_id,
+                merchant_id,
+                &payment_intent.active_attempt_id,
+                storage_scheme,
+            )
+            .await?;
+        let max_authorization_age = match payment_attempt.connector.as_deref() {
+            Some(connector) => config.get_max_authorization_age(connector),
+            None => None,
+        };
+
+        if let Some(max_authorization_age) = max_authorization_age {
+            add_auto_void_task(
+                db,
+                &payment_attempt,
+                payment_intent
+                    .created_at
+                    .saturating_add(max_authorization_age),
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    payment_id = %payment_intent.payment_id,
+                    "Failed while adding auto void task to process tracker"
+                );
+                errors::ProcessTrackerError::ProcessInsertionFailed
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn add_auto_void_sweep_task(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    created_before: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let tracking_data = AutoVoidSweepTrackingData {
+        merchant_id: merchant_id.to_string(),
+        created_before,
+    };
+    let runner = "AUTO_VOID_SWEEP_WORKFLOW";
+    let task = "AUTO_VOID_SWEEP";
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &created_before.assume_utc().unix_timestamp().to_string(),
+        merchant_id,
+    );
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            task,
+            runner,
+            tracking_data,
+            common_utils::date_time::now(),
+        )?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        // The sweep has already been scheduled for this change of the config
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error.change_context(errors::ProcessTrackerError::ProcessUpdateFailed)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connector_max_authorization_age_overrides_default() {
+        let config: AutoVoidConfig = serde_json::from_str(
+            r#"{"max_authorization_age":604800,"connector_max_authorization_age":{"adyen":2419200}}"#,
+        )
+        .unwrap_or_default();
+
+        assert_eq!(
+            config.get_max_authorization_age("adyen"),
+            Some(time::Duration::days(28))
+        );
+        assert_eq!(
+            config.get_max_authorization_age("stripe"),
+            Some(time::Duration::days(7))
+        );
+        assert_eq!(
+            AutoVoidConfig::default().get_max_authorization_age("stripe"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_merchant_id_is_read_from_auto_void_config_key() {
+        assert_eq!(
+            get_merchant_id_from_config_key("auto_void_merchant_1"),
+            Some("merchant_1")
+        );
+        assert_eq!(get_merchant_id_from_config_key("merchant_1"), None);
+    }
+}
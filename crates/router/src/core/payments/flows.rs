@@ -652,3 +652,193 @@ default_imp_for_pre_processing_steps!(
     connector::Worldpay,
     connector::Zen
 );
+
+macro_rules! default_imp_for_payouts{
+    ($($path:ident::$connector:ident),*)=> {
+        $(
+            impl api::Payouts for $path::$connector {}
+            impl api::PayoutCreate for $path::$connector {}
+            impl
+                services::ConnectorIntegration<
+                api::PoCreate,
+                types::PayoutsData,
+                types::PayoutsResponseData,
+            > for $path::$connector
+            {}
+            impl api::PayoutFulfill for $path::$connector {}
+            impl
+                services::ConnectorIntegration<
+                api::PoFulfill,
+                types::PayoutsData,
+                types::PayoutsResponseData,
+            > for $path::$connector
+            {}
+            impl api::PayoutCancel for $path::$connector {}
+            impl
+                services::ConnectorIntegration<
+                api::PoCancel,
+                types::PayoutsData,
+                types::PayoutsResponseData,
+            > for $path::$connector
+            {}
+            impl api::PayoutSync for $path::$connector {}
+            impl
+                services::ConnectorIntegration<
+                api::PoSync,
+                types::PayoutsData,
+                types::PayoutsResponseData,
+            > for $path::$connector
+            {}
+            impl api::PayoutReverse for $path::$connector {}
+            impl
+                services::ConnectorIntegration<
+                api::PoReverse,
+                types::PayoutsData,
+                types::PayoutsResponseData,
+            > for $path::$connector
+            {}
+    )*
+    };
+}
+
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::Payouts for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::PayoutCreate for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for connector::DummyConnector<T>
+{
+}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::PayoutFulfill for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for connector::DummyConnector<T>
+{
+}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::PayoutCancel for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for connector::DummyConnector<T>
+{
+}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::PayoutSync for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for connector::DummyConnector<T>
+{
+}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::PayoutReverse for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for connector::DummyConnector<T>
+{
+}
+
+default_imp_for_payouts!(
+    connector::Aci,
+    connector::Airwallex,
+    connector::Authorizedotnet,
+    connector::Bambora,
+    connector::Bitpay,
+    connector::Bluesnap,
+    connector::Braintree,
+    connector::Checkout,
+    connector::Coinbase,
+    connector::Cybersource,
+    connector::Dlocal,
+    connector::Fiserv,
+    connector::Forte,
+    connector::Globalpay,
+    connector::Iatapay,
+    connector::Klarna,
+    connector::Mollie,
+    connector::Multisafepay,
+    connector::Nexinets,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opennode,
+    connector::Payeezy,
+    connector::Paypal,
+    connector::Payu,
+    connector::Rapyd,
+    connector::Shift4,
+    connector::Stripe,
+    connector::Trustpay,
+    connector::Worldline,
+    connector::Worldpay,
+    connector::Zen
+);
+
+macro_rules! default_imp_for_revoking_mandates{
+    ($($path:ident::$connector:ident),*)=> {
+        $(
+            impl api::mandates::ConnectorMandateRevoke for $path::$connector {}
+            impl
+                services::ConnectorIntegration<
+                api::mandates::MandateRevoke,
+                types::MandateRevokeRequestData,
+                types::MandateRevokeResponseData,
+            > for $path::$connector
+            {}
+    )*
+    };
+}
+
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::mandates::ConnectorMandateRevoke for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for connector::DummyConnector<T>
+{
+}
+
+default_imp_for_revoking_mandates!(
+    connector::Aci,
+    connector::Adyen,
+    connector::Airwallex,
+    connector::Authorizedotnet,
+    connector::Bambora,
+    connector::Bitpay,
+    connector::Bluesnap,
+    connector::Braintree,
+    connector::Checkout,
+    connector::Coinbase,
+    connector::Cybersource,
+    connector::Dlocal,
+    connector::Fiserv,
+    connector::Forte,
+    connector::Globalpay,
+    connector::Iatapay,
+    connector::Klarna,
+    connector::Mollie,
+    connector::Multisafepay,
+    connector::Nexinets,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opennode,
+    connector::Payeezy,
+    connector::Paypal,
+    connector::Payu,
+    connector::Rapyd,
+    connector::Shift4,
+    connector::Trustpay,
+    connector::Worldline,
+    connector::Worldpay,
+    connector::Zen
+);
@@ -158,14 +158,24 @@ impl types::PaymentsAuthorizeRouterData {
                 if self.should_proceed_with_authorize() {
                     self.decide_authentication_type();
                     logger::debug!(auth_type=?self.auth_type);
-                    let resp = services::execute_connector_processing_step(
+                    let reserved_mandate = mandate::reserve_mandate_usage(state, self).await?;
+                    let resp = match services::execute_connector_processing_step(
                         state,
                         connector_integration,
                         self,
                         call_connector_action,
                     )
                     .await
-                    .map_err(|error| error.to_payment_failed_response())?;
+                    {
+                        Ok(resp) => resp,
+                        Err(error) => {
+                            if let Some(mandate) = &reserved_mandate {
+                                mandate::release_mandate_usage(state, mandate, self.request.amount)
+                                    .await;
+                            }
+                            Err(error.to_payment_failed_response())?
+                        }
+                    };
 
                     let pm_id = tokenization::save_payment_method(
                         state,
@@ -176,7 +186,14 @@ impl types::PaymentsAuthorizeRouterData {
                     )
                     .await?;
 
-                    Ok(mandate::mandate_procedure(state, resp, maybe_customer, pm_id).await?)
+                    Ok(mandate::mandate_procedure(
+                        state,
+                        resp,
+                        maybe_customer,
+                        pm_id,
+                        reserved_mandate,
+                    )
+                    .await?)
                 } else {
                     Ok(self.clone())
                 }
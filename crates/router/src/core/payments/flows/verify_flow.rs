@@ -126,14 +126,23 @@ impl types::VerifyRouterData {
                     types::VerifyRequestData,
                     types::PaymentsResponseData,
                 > = connector.connector.get_connector_integration();
-                let resp = services::execute_connector_processing_step(
+                let reserved_mandate = mandate::reserve_mandate_usage(state, self).await?;
+                let resp = match services::execute_connector_processing_step(
                     state,
                     connector_integration,
                     self,
                     call_connector_action,
                 )
                 .await
-                .map_err(|err| err.to_verify_failed_response())?;
+                {
+                    Ok(resp) => resp,
+                    Err(err) => {
+                        if let Some(mandate) = &reserved_mandate {
+                            mandate::release_mandate_usage(state, mandate, 0).await;
+                        }
+                        Err(err.to_verify_failed_response())?
+                    }
+                };
 
                 let pm_id = tokenization::save_payment_method(
                     state,
@@ -144,7 +153,16 @@ impl types::VerifyRouterData {
                 )
                 .await?;
 
-                Ok(mandate::mandate_procedure(state, resp, maybe_customer, pm_id).await?)
+                Ok(
+                    mandate::mandate_procedure(
+                        state,
+                        resp,
+                        maybe_customer,
+                        pm_id,
+                        reserved_mandate,
+                    )
+                    .await?,
+                )
             }
             _ => Ok(self.clone()),
         }
@@ -23,6 +23,7 @@ use crate::{
     consts,
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        mandate,
         payment_methods::{cards, vault},
         payments,
     },
@@ -96,6 +97,7 @@ pub async fn get_address_for_payment_request(
     req_address: Option<&api::Address>,
     address_id: Option<&str>,
     merchant_id: &str,
+    storage_scheme: storage_enums::MerchantStorageScheme,
     customer_id: &Option<String>,
 ) -> CustomResult<Option<domain::Address>, errors::ApiErrorResponse> {
     let key = types::get_merchant_enc_key(db, merchant_id.to_string())
@@ -172,7 +174,7 @@ pub async fn get_address_for_payment_request(
                     .change_context(errors::ApiErrorResponse::InternalServerError)
                     .attach_printable("Failed while encrypting address")?;
                     Some(
-                        db.update_address(id.to_owned(), address_update)
+                        db.update_address(id.to_owned(), address_update, storage_scheme)
                             .await
                             .to_not_found_response(errors::ApiErrorResponse::AddressNotFound)?,
                     )
@@ -237,6 +239,7 @@ pub async fn get_address_for_payment_request(
                             .await
                             .change_context(errors::ApiErrorResponse::InternalServerError)
                             .attach_printable("Failed while encrypting address while insert")?,
+                            storage_scheme,
                         )
                         .await
                         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -246,7 +249,7 @@ pub async fn get_address_for_payment_request(
             }
         }
         None => match address_id {
-            Some(id) => Some(db.find_address(id).await)
+            Some(id) => Some(db.find_address(id, storage_scheme).await)
                 .transpose()
                 .to_not_found_response(errors::ApiErrorResponse::AddressNotFound)?,
             None => None,
@@ -257,10 +260,11 @@ pub async fn get_address_for_payment_request(
 pub async fn get_address_by_id(
     db: &dyn StorageInterface,
     address_id: Option<String>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> CustomResult<Option<domain::Address>, errors::ApiErrorResponse> {
     match address_id {
         None => Ok(None),
-        Some(address_id) => Ok(db.find_address(&address_id).await.ok()),
+        Some(address_id) => Ok(db.find_address(&address_id, storage_scheme).await.ok()),
     }
 }
 
@@ -334,6 +338,12 @@ pub async fn get_token_for_recurring_mandate(
         };
         mandate.payment_method_id.clone()
     };
+    if mandate::is_mandate_expired(&mandate) {
+        mandate::expire_mandate(state, mandate).await?;
+        return Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+            reason: "mandate has expired".to_string()
+        }));
+    }
     verify_mandate_details(
         req.amount.get_required_value("amount")?.into(),
         req.currency.get_required_value("currency")?,
@@ -433,6 +443,28 @@ pub fn validate_request_amount_and_amount_to_capture(
     }
 }
 
+#[instrument(skip_all)]
+pub fn validate_capture_on(
+    capture_method: Option<api_enums::CaptureMethod>,
+    capture_on: Option<time::PrimitiveDateTime>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    match (capture_method, capture_on) {
+        (Some(api_enums::CaptureMethod::Scheduled), None) => {
+            Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "capture_on"
+            }))
+        }
+        (Some(api_enums::CaptureMethod::Scheduled), Some(capture_on)) => {
+            utils::when(capture_on <= common_utils::date_time::now(), || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "capture_on should be a time in the future".to_string()
+                }))
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
 pub fn validate_mandate(
     req: impl Into<api::MandateValidationFields>,
     is_confirm_operation: bool,
@@ -638,6 +670,36 @@ pub fn verify_mandate_details(
             },
         ),
     }?;
+    utils::when(
+        mandate
+            .max_amount_per_payment
+            .map_or(false, |max_amount| request_amount > max_amount),
+        || {
+            Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "request amount is greater than the maximum amount per payment".to_string()
+            }))
+        },
+    )?;
+    utils::when(
+        mandate
+            .usage_limit
+            .map_or(false, |usage_limit| mandate.usage_count >= usage_limit),
+        || {
+            Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "mandate usage limit has been reached".to_string()
+            }))
+        },
+    )?;
+    utils::when(
+        mandate.start_date.map_or(false, |start_date| {
+            start_date > common_utils::date_time::now()
+        }),
+        || {
+            Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "mandate is not valid before its start date".to_string()
+            }))
+        },
+    )?;
     utils::when(
         mandate
             .mandate_currency
@@ -1148,6 +1210,23 @@ pub(crate) fn validate_amount_to_capture(
     )
 }
 
+#[instrument(skip_all)]
+pub(crate) fn validate_amount_to_capture_with_capturable_amount(
+    capturable_amount: i64,
+    amount_to_capture: i64,
+) -> RouterResult<()> {
+    utils::when(
+        amount_to_capture <= 0 || amount_to_capture > capturable_amount,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "amount_to_capture must be greater than 0 and at most the capturable amount of {capturable_amount}"
+                )
+            }))
+        },
+    )
+}
+
 #[instrument(skip_all)]
 pub(crate) fn validate_payment_method_fields_present(
     req: &api::PaymentsRequest,
@@ -1451,6 +1530,9 @@ pub fn generate_mandate(
                     api::MandateType::SingleUse(data) => new_mandate
                         .set_mandate_amount(Some(data.amount))
                         .set_mandate_currency(Some(data.currency.foreign_into()))
+                        .set_start_date(data.start_date)
+                        .set_end_date(data.end_date)
+                        .set_usage_limit(Some(1))
                         .set_mandate_type(storage_enums::MandateType::SingleUse)
                         .to_owned(),
 
@@ -1460,6 +1542,8 @@ pub fn generate_mandate(
                             .set_mandate_currency(Some(data.currency.foreign_into()))
                             .set_start_date(data.start_date)
                             .set_end_date(data.end_date)
+                            .set_max_amount_per_payment(data.max_amount_per_payment)
+                            .set_usage_limit(data.usage_limit)
                             .set_metadata(data.metadata),
                         None => &mut new_mandate,
                     }
@@ -1859,10 +1943,10 @@ pub async fn get_merchant_connector_account(
                     },
                 )?;
 
-            #[cfg(feature = "kms")]
+            #[cfg(feature = "secrets_management")]
             let private_key = state.kms_secrets.jwekey.peek().tunnel_private_key.clone();
 
-            #[cfg(not(feature = "kms"))]
+            #[cfg(not(feature = "secrets_management"))]
             let private_key = state.conf.jwekey.tunnel_private_key.to_owned();
 
             let decrypted_mca = services::decrypt_jwe(mca_config.config.as_str(), services::KeyIdCheck::SkipKeyIdCheck, private_key, jwe::RSA_OAEP_256)
@@ -0,0 +1,234 @@
+use crate::types::storage::{self, enums as storage_enums};
+
+/// The captures made against an authorization with
+/// [`storage_enums::CaptureMethod::ManualMultiple`], ordered by their capture sequence.
+#[derive(Clone, Debug)]
+pub struct MultipleCaptureData {
+    captures: Vec<storage::Capture>,
+    /// The capture being made by the current request, if any
+    current_capture_id: Option<String>,
+    /// The only capture to be synced with the connector, all pending captures are synced otherwise
+    capture_id_to_sync: Option<String>,
+}
+
+impl MultipleCaptureData {
+    pub fn new(captures: Vec<storage::Capture>) -> Self {
+        Self {
+            captures,
+            current_capture_id: None,
+            capture_id_to_sync: None,
+        }
+    }
+
+    pub fn get_all_captures(&self) -> &[storage::Capture] {
+        &self.captures
+    }
+
+    pub fn get_capture(&self, capture_id: &str) -> Option<&storage::Capture> {
+        self.captures
+            .iter()
+            .find(|capture| capture.capture_id == capture_id)
+    }
+
+    pub fn set_capture_to_sync(&mut self, capture_id: String) {
+        self.capture_id_to_sync = Some(capture_id);
+    }
+
+    pub fn get_current_capture(&self) -> Option<&storage::Capture> {
+        self.current_capture_id.as_ref().and_then(|capture_id| {
+            self.captures
+                .iter()
+                .find(|capture| &capture.capture_id == capture_id)
+        })
+    }
+
+    pub fn set_current_capture(&mut self, capture: storage::Capture) {
+        self.current_capture_id = Some(capture.capture_id.clone());
+        self.update_capture(capture);
+    }
+
+    /// Replaces the stored capture having the same capture id, or appends it if it is new.
+    pub fn update_capture(&mut self, capture: storage::Capture) {
+        match self
+            .captures
+            .iter_mut()
+            .find(|existing| existing.capture_id == capture.capture_id)
+        {
+            Some(existing) => *existing = capture,
+            None => self.captures.push(capture),
+        }
+    }
+
+    pub fn next_capture_sequence(&self) -> i16 {
+        self.captures
+            .iter()
+            .map(|capture| capture.capture_sequence)
+            .max()
+            .unwrap_or(0)
+            + 1
+    }
+
+    fn sum_amount_with_status(&self, statuses: &[storage_enums::CaptureStatus]) -> i64 {
+        self.captures
+            .iter()
+            .filter(|capture| statuses.contains(&capture.status))
+            .map(|capture| capture.amount)
+            .sum()
+    }
+
+    pub fn get_total_charged_amount(&self) -> i64 {
+        self.sum_amount_with_status(&[storage_enums::CaptureStatus::Charged])
+    }
+
+    pub fn get_total_pending_amount(&self) -> i64 {
+        self.sum_amount_with_status(&[
+            storage_enums::CaptureStatus::Started,
+            storage_enums::CaptureStatus::Pending,
+        ])
+    }
+
+    /// The part of the authorized amount that has neither been captured nor is being captured.
+    pub fn get_capturable_amount(&self, authorized_amount: i64) -> i64 {
+        authorized_amount - self.get_total_charged_amount() - self.get_total_pending_amount()
+    }
+
+    /// The pending captures which are to be synced with the connector
+    fn pending_captures_to_sync(&self) -> impl Iterator<Item = &storage::Capture> {
+        self.captures.iter().filter(move |capture| {
+            capture.status == storage_enums::CaptureStatus::Pending
+                && self
+                    .capture_id_to_sync
+                    .as_ref()
+                    .map_or(true, |capture_id| &capture.capture_id == capture_id)
+        })
+    }
+
+    pub fn has_pending_captures(&self) -> bool {
+        self.pending_captures_to_sync()
+            .any(|capture| capture.connector_capture_id.is_some())
+    }
+
+    pub fn get_pending_connector_capture_ids(&self) -> Vec<String> {
+        self.pending_captures_to_sync()
+            .filter_map(|capture| capture.connector_capture_id.clone())
+            .collect()
+    }
+
+    pub fn get_attempt_status(&self, authorized_amount: i64) -> storage_enums::AttemptStatus {
+        let total_charged_amount = self.get_total_charged_amount();
+        if total_charged_amount >= authorized_amount {
+            storage_enums::AttemptStatus::Charged
+        } else if total_charged_amount > 0 && self.get_capturable_amount(authorized_amount) > 0 {
+            storage_enums::AttemptStatus::PartialCharged
+        } else if self.get_total_pending_amount() > 0 {
+            storage_enums::AttemptStatus::CaptureInitiated
+        } else {
+            storage_enums::AttemptStatus::Authorized
+        }
+    }
+
+    /// The payment remains capturable as long as some part of the authorized amount is left
+    /// uncaptured, and succeeds once the whole of it has been charged.
+    pub fn get_intent_status(&self, authorized_amount: i64) -> storage_enums::IntentStatus {
+        if self.get_total_charged_amount() >= authorized_amount {
+            storage_enums::IntentStatus::Succeeded
+        } else if self.get_capturable_amount(authorized_amount) > 0 {
+            storage_enums::IntentStatus::RequiresCapture
+        } else {
+            storage_enums::IntentStatus::Processing
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn capture(
+        sequence: i16,
+        amount: i64,
+        status: storage_enums::CaptureStatus,
+    ) -> storage::Capture {
+        let now = common_utils::date_time::now();
+        storage::Capture {
+            id: sequence.into(),
+            capture_id: format!("capture_{sequence}"),
+            payment_id: "payment_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            authorized_attempt_id: "payment_1_1".to_string(),
+            capture_sequence: sequence,
+            status,
+            amount,
+            currency: Some(storage_enums::Currency::USD),
+            connector: "checkout".to_string(),
+            connector_capture_id: Some(format!("act_{sequence}")),
+            error_code: None,
+            error_message: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_status_of_partially_captured_payment() {
+        let data = MultipleCaptureData::new(vec![
+            capture(1, 300, storage_enums::CaptureStatus::Charged),
+            capture(2, 200, storage_enums::CaptureStatus::Pending),
+            capture(3, 100, storage_enums::CaptureStatus::Failed),
+        ]);
+
+        assert_eq!(data.get_total_charged_amount(), 300);
+        assert_eq!(data.get_capturable_amount(1000), 500);
+        assert_eq!(data.next_capture_sequence(), 4);
+        assert_eq!(data.get_pending_connector_capture_ids(), vec!["act_2"]);
+        assert_eq!(
+            data.get_attempt_status(1000),
+            storage_enums::AttemptStatus::PartialCharged
+        );
+        assert_eq!(
+            data.get_intent_status(1000),
+            storage_enums::IntentStatus::RequiresCapture
+        );
+    }
+
+    #[test]
+    fn test_sync_of_single_capture() {
+        let mut data = MultipleCaptureData::new(vec![
+            capture(1, 300, storage_enums::CaptureStatus::Pending),
+            capture(2, 200, storage_enums::CaptureStatus::Pending),
+            capture(3, 100, storage_enums::CaptureStatus::Charged),
+        ]);
+
+        data.set_capture_to_sync("capture_2".to_string());
+        assert!(data.has_pending_captures());
+        assert_eq!(data.get_pending_connector_capture_ids(), vec!["act_2"]);
+
+        data.set_capture_to_sync("capture_3".to_string());
+        assert!(!data.has_pending_captures());
+    }
+
+    #[test]
+    fn test_status_of_fully_captured_payment() {
+        let mut data = MultipleCaptureData::new(vec![
+            capture(1, 600, storage_enums::CaptureStatus::Charged),
+            capture(2, 400, storage_enums::CaptureStatus::Pending),
+        ]);
+
+        assert_eq!(
+            data.get_intent_status(1000),
+            storage_enums::IntentStatus::Processing
+        );
+
+        data.update_capture(capture(2, 400, storage_enums::CaptureStatus::Charged));
+
+        assert!(!data.has_pending_captures());
+        assert_eq!(
+            data.get_attempt_status(1000),
+            storage_enums::AttemptStatus::Charged
+        );
+        assert_eq!(
+            data.get_intent_status(1000),
+            storage_enums::IntentStatus::Succeeded
+        );
+    }
+}
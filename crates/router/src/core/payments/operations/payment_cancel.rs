@@ -80,6 +80,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
             None,
             payment_intent.shipping_address_id.as_deref(),
             merchant_id,
+            storage_scheme,
             &payment_intent.customer_id,
         )
         .await?;
@@ -88,6 +89,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
             None,
             payment_intent.billing_address_id.as_deref(),
             merchant_id,
+            storage_scheme,
             &payment_intent.customer_id,
         )
         .await?;
@@ -147,6 +149,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
                 refunds: vec![],
                 disputes: vec![],
                 connector_response,
+                attempts: None,
                 sessions_token: vec![],
                 card_cvc: None,
                 creds_identifier,
@@ -154,6 +157,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
                 connector_customer_id: None,
                 ephemeral_key: None,
                 redirect_response: None,
+                multiple_capture_data: None,
             },
             None,
         ))
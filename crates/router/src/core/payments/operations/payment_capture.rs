@@ -2,14 +2,14 @@ use std::marker::PhantomData;
 
 use async_trait::async_trait;
 use common_utils::ext_traits::AsyncExt;
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
 
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payments::{self, helpers, operations},
+        payments::{self, helpers, multiple_capture::MultipleCaptureData, operations},
     },
     db::StorageInterface,
     routes::AppState,
@@ -18,7 +18,7 @@ use crate::{
         domain,
         storage::{self, enums},
     },
-    utils::OptionExt,
+    utils::{self, OptionExt},
 };
 
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
@@ -80,6 +80,50 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
 
         helpers::validate_capture_method(capture_method)?;
 
+        let multiple_capture_data = if capture_method == enums::CaptureMethod::ManualMultiple {
+            let connector_name = payment_attempt
+                .connector
+                .clone()
+                .get_required_value("connector")?;
+            let connector = api::ConnectorData::get_connector_by_name(
+                &state.conf.connectors,
+                &connector_name,
+                api::GetToken::Connector,
+            )?;
+            utils::when(!connector.connector.supports_multiple_captures(), || {
+                Err(report!(errors::ApiErrorResponse::NotSupported {
+                    message: format!(
+                        "Multiple partial captures are not supported by {connector_name}"
+                    ),
+                }))
+            })?;
+
+            let captures = db
+                .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+                    merchant_id,
+                    &payment_attempt.payment_id,
+                    &payment_attempt.attempt_id,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while fetching the captures of the payment attempt")?;
+            let multiple_capture_data = MultipleCaptureData::new(captures);
+
+            // Without an explicit amount, whatever is left of the authorization is captured
+            let capturable_amount =
+                multiple_capture_data.get_capturable_amount(payment_attempt.amount);
+            let amount_to_capture = request.amount_to_capture.unwrap_or(capturable_amount);
+            helpers::validate_amount_to_capture_with_capturable_amount(
+                capturable_amount,
+                amount_to_capture,
+            )?;
+            payment_attempt.amount_to_capture = Some(amount_to_capture);
+
+            Some(multiple_capture_data)
+        } else {
+            None
+        };
+
         currency = payment_attempt.currency.get_required_value("currency")?;
 
         amount = payment_attempt.amount.into();
@@ -99,6 +143,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             None,
             payment_intent.shipping_address_id.as_deref(),
             merchant_id,
+            storage_scheme,
             &payment_intent.customer_id,
         )
         .await?;
@@ -108,6 +153,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             None,
             payment_intent.billing_address_id.as_deref(),
             merchant_id,
+            storage_scheme,
             &payment_intent.customer_id,
         )
         .await?;
@@ -153,6 +199,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 refunds: vec![],
                 disputes: vec![],
                 connector_response,
+                attempts: None,
                 sessions_token: vec![],
                 card_cvc: None,
                 creds_identifier,
@@ -160,6 +207,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 connector_customer_id: None,
                 ephemeral_key: None,
                 redirect_response: None,
+                multiple_capture_data,
             },
             None,
         ))
@@ -173,9 +221,9 @@ impl<F: Clone> UpdateTracker<F, payments::PaymentData<F>, api::PaymentsCaptureRe
     #[instrument(skip_all)]
     async fn update_trackers<'b>(
         &'b self,
-        _db: &dyn StorageInterface,
+        db: &dyn StorageInterface,
         _payment_id: &api::PaymentIdType,
-        payment_data: payments::PaymentData<F>,
+        mut payment_data: payments::PaymentData<F>,
         _customer: Option<domain::Customer>,
         _storage_scheme: enums::MerchantStorageScheme,
         _updated_customer: Option<storage::CustomerUpdate>,
@@ -186,6 +234,39 @@ impl<F: Clone> UpdateTracker<F, payments::PaymentData<F>, api::PaymentsCaptureRe
     where
         F: 'b + Send,
     {
+        if let Some(multiple_capture_data) = payment_data.multiple_capture_data.as_mut() {
+            let payment_attempt = &payment_data.payment_attempt;
+            let capture_sequence = multiple_capture_data.next_capture_sequence();
+            let capture_id = format!("{}_capture_{capture_sequence}", payment_attempt.attempt_id);
+
+            let capture = db
+                .insert_capture(storage::CaptureNew {
+                    capture_id,
+                    payment_id: payment_attempt.payment_id.clone(),
+                    merchant_id: payment_attempt.merchant_id.clone(),
+                    authorized_attempt_id: payment_attempt.attempt_id.clone(),
+                    capture_sequence,
+                    status: enums::CaptureStatus::Started,
+                    amount: payment_attempt
+                        .amount_to_capture
+                        .unwrap_or(payment_attempt.amount),
+                    currency: payment_attempt.currency,
+                    connector: payment_attempt
+                        .connector
+                        .clone()
+                        .get_required_value("connector")?,
+                    connector_capture_id: None,
+                    error_code: None,
+                    error_message: None,
+                })
+                .await
+                .to_duplicate_response(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Another capture is in progress for this payment".to_string(),
+                })?;
+
+            multiple_capture_data.set_current_capture(capture);
+        }
+
         Ok((Box::new(self), payment_data))
     }
 }
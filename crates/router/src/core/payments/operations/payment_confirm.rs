@@ -148,6 +148,12 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .capture_method
             .or(payment_attempt.capture_method.map(|cm| cm.foreign_into()))
             .map(|cm| cm.foreign_into());
+        payment_attempt.capture_on = request.capture_on.or(payment_attempt.capture_on);
+
+        helpers::validate_capture_on(
+            payment_attempt.capture_method.map(|cm| cm.foreign_into()),
+            payment_attempt.capture_on,
+        )?;
 
         currency = payment_attempt.currency.get_required_value("currency")?;
         amount = payment_attempt.amount.into();
@@ -167,6 +173,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             request.shipping.as_ref(),
             payment_intent.shipping_address_id.as_deref(),
             merchant_id,
+            storage_scheme,
             &payment_intent.customer_id,
         )
         .await?;
@@ -175,6 +182,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             request.billing.as_ref(),
             payment_intent.billing_address_id.as_deref(),
             merchant_id,
+            storage_scheme,
             &payment_intent.customer_id,
         )
         .await?;
@@ -247,6 +255,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 force_sync: None,
                 refunds: vec![],
                 disputes: vec![],
+                attempts: None,
                 sessions_token: vec![],
                 card_cvc: request.card_cvc.clone(),
                 creds_identifier,
@@ -254,6 +263,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 connector_customer_id: None,
                 ephemeral_key: None,
                 redirect_response: None,
+                multiple_capture_data: None,
             },
             Some(CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -376,6 +386,8 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
             .attach_printable("Failed to encode additional pm data")?;
 
         let business_sub_label = payment_data.payment_attempt.business_sub_label.clone();
+        let capture_method = payment_data.payment_attempt.capture_method;
+        let capture_on = payment_data.payment_attempt.capture_on;
 
         payment_data.payment_attempt = db
             .update_payment_attempt_with_attempt_id(
@@ -394,6 +406,8 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     payment_experience,
                     business_sub_label,
                     straight_through_algorithm,
+                    capture_method,
+                    capture_on,
                 },
                 storage_scheme,
             )
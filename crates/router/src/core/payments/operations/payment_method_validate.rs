@@ -176,6 +176,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::VerifyRequest> for Paym
                 force_sync: None,
                 refunds: vec![],
                 disputes: vec![],
+                attempts: None,
                 sessions_token: vec![],
                 card_cvc: None,
                 creds_identifier,
@@ -183,6 +184,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::VerifyRequest> for Paym
                 connector_customer_id: None,
                 ephemeral_key: None,
                 redirect_response: None,
+                multiple_capture_data: None,
             },
             Some(payments::CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -8,7 +8,7 @@ use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
         mandate,
-        payments::PaymentData,
+        payments::{multiple_capture::MultipleCaptureData, PaymentData},
     },
     db::StorageInterface,
     routes::metrics,
@@ -291,6 +291,17 @@ async fn payment_response_update_tracker<F: Clone, T>(
     router_data: types::RouterData<F, T, types::PaymentsResponseData>,
     storage_scheme: enums::MerchantStorageScheme,
 ) -> RouterResult<PaymentData<F>> {
+    if let Some(multiple_capture_data) = payment_data.multiple_capture_data.take() {
+        return multiple_capture_response_update_tracker(
+            db,
+            payment_data,
+            multiple_capture_data,
+            router_data,
+            storage_scheme,
+        )
+        .await;
+    }
+
     let (payment_attempt_update, connector_response_update) = match router_data.response.clone() {
         Err(err) => (
             Some(storage::PaymentAttemptUpdate::ErrorUpdate {
@@ -402,6 +413,7 @@ async fn payment_response_update_tracker<F: Clone, T>(
             types::PaymentsResponseData::TokenizationResponse { .. } => (None, None),
             types::PaymentsResponseData::ConnectorCustomerResponse { .. } => (None, None),
             types::PaymentsResponseData::ThreeDSEnrollmentResponse { .. } => (None, None),
+            types::PaymentsResponseData::MultipleCaptureResponse { .. } => (None, None),
         },
     };
 
@@ -467,3 +479,117 @@ async fn payment_response_update_tracker<F: Clone, T>(
 
     Ok(payment_data)
 }
+
+/// Updates the captures affected by the connector response, and derives the status of the
+/// payment from the total amount that has been captured so far.
+async fn multiple_capture_response_update_tracker<F: Clone, T>(
+    db: &dyn StorageInterface,
+    mut payment_data: PaymentData<F>,
+    mut multiple_capture_data: MultipleCaptureData,
+    router_data: types::RouterData<F, T, types::PaymentsResponseData>,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<PaymentData<F>> {
+    let capture_updates = match router_data.response {
+        Err(err) => multiple_capture_data
+            .get_current_capture()
+            .map(|capture| {
+                (
+                    capture.clone(),
+                    storage::CaptureUpdate::ErrorUpdate {
+                        status: enums::CaptureStatus::Failed,
+                        error_code: Some(err.code),
+                        error_message: Some(err.message),
+                    },
+                )
+            })
+            .into_iter()
+            .collect::<Vec<_>>(),
+        Ok(types::PaymentsResponseData::TransactionResponse { resource_id, .. }) => {
+            let connector_capture_id = match resource_id {
+                types::ResponseId::NoResponseId => None,
+                types::ResponseId::ConnectorTransactionId(id)
+                | types::ResponseId::EncodedData(id) => Some(id),
+            };
+            multiple_capture_data
+                .get_current_capture()
+                .map(|capture| {
+                    (
+                        capture.clone(),
+                        storage::CaptureUpdate::ResponseUpdate {
+                            status: router_data.status.foreign_into(),
+                            connector_capture_id,
+                        },
+                    )
+                })
+                .into_iter()
+                .collect()
+        }
+        Ok(types::PaymentsResponseData::MultipleCaptureResponse {
+            capture_sync_response_list,
+        }) => multiple_capture_data
+            .get_all_captures()
+            .iter()
+            .filter_map(|capture| {
+                let capture_sync_response = capture
+                    .connector_capture_id
+                    .as_ref()
+                    .and_then(|id| capture_sync_response_list.get(id))
+                    .filter(|response| response.status != capture.status)?;
+                let capture_update = match capture_sync_response.status {
+                    enums::CaptureStatus::Failed => storage::CaptureUpdate::ErrorUpdate {
+                        status: enums::CaptureStatus::Failed,
+                        error_code: capture_sync_response.error_code.clone(),
+                        error_message: capture_sync_response.error_message.clone(),
+                    },
+                    status => storage::CaptureUpdate::ResponseUpdate {
+                        status,
+                        connector_capture_id: None,
+                    },
+                };
+                Some((capture.clone(), capture_update))
+            })
+            .collect(),
+        Ok(_) => Vec::new(),
+    };
+
+    for (capture, capture_update) in capture_updates {
+        let updated_capture = db
+            .update_capture_with_capture_id(capture, capture_update)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while updating the capture")?;
+        multiple_capture_data.update_capture(updated_capture);
+    }
+
+    let authorized_amount = payment_data.payment_attempt.amount;
+    let attempt_status = multiple_capture_data.get_attempt_status(authorized_amount);
+    if attempt_status != payment_data.payment_attempt.status {
+        payment_data.payment_attempt = db
+            .update_payment_attempt_with_attempt_id(
+                payment_data.payment_attempt,
+                storage::PaymentAttemptUpdate::StatusUpdate {
+                    status: attempt_status,
+                },
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    }
+
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent,
+            storage::PaymentIntentUpdate::ResponseUpdate {
+                status: multiple_capture_data.get_intent_status(authorized_amount),
+                amount_captured: Some(multiple_capture_data.get_total_charged_amount()),
+                return_url: None,
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    payment_data.multiple_capture_data = Some(multiple_capture_data);
+
+    Ok(payment_data)
+}
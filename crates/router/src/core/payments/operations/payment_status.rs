@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 
 use async_trait::async_trait;
 use common_utils::ext_traits::AsyncExt;
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use router_derive::PaymentOperation;
 use router_env::{instrument, tracing};
 
@@ -10,7 +10,10 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
-        payments::{helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+        payments::{
+            helpers, multiple_capture::MultipleCaptureData, operations, CustomerDetails,
+            PaymentAddress, PaymentData,
+        },
     },
     db::StorageInterface,
     routes::AppState,
@@ -213,10 +216,18 @@ async fn get_tracker_for_sync<
     currency = payment_attempt.currency.get_required_value("currency")?;
     amount = payment_attempt.amount.into();
 
-    let shipping_address =
-        helpers::get_address_by_id(db, payment_intent.shipping_address_id.clone()).await?;
-    let billing_address =
-        helpers::get_address_by_id(db, payment_intent.billing_address_id.clone()).await?;
+    let shipping_address = helpers::get_address_by_id(
+        db,
+        payment_intent.shipping_address_id.clone(),
+        storage_scheme,
+    )
+    .await?;
+    let billing_address = helpers::get_address_by_id(
+        db,
+        payment_intent.billing_address_id.clone(),
+        storage_scheme,
+    )
+    .await?;
 
     let refunds = db
         .find_refund_by_payment_id_merchant_id(&payment_id_str, merchant_id, storage_scheme)
@@ -230,13 +241,57 @@ async fn get_tracker_for_sync<
         })?;
 
     let disputes = db
-        .find_disputes_by_merchant_id_payment_id(merchant_id, &payment_id_str)
+        .find_disputes_by_merchant_id_payment_id(merchant_id, &payment_id_str, storage_scheme)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable_lazy(|| {
             format!("Error while retrieving dispute list for, merchant_id: {merchant_id}, payment_id: {payment_id_str}")
         })?;
 
+    let attempts = db
+        .find_payment_attempts_by_payment_id_merchant_id(
+            &payment_id_str,
+            merchant_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Error while retrieving attempt list for, merchant_id: {merchant_id}, payment_id: {payment_id_str}")
+        })?;
+
+    let multiple_capture_data = if payment_attempt.capture_method
+        == Some(enums::CaptureMethod::ManualMultiple)
+    {
+        let captures = db
+            .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+                merchant_id,
+                &payment_id_str,
+                &payment_attempt.attempt_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Error while retrieving capture list for, merchant_id: {merchant_id}, payment_id: {payment_id_str}")
+            })?;
+        Some(MultipleCaptureData::new(captures))
+    } else {
+        None
+    };
+
+    // A single capture is retrieved, which is the only one synced with the connector
+    let multiple_capture_data = match request.capture_id.clone() {
+        Some(capture_id) => {
+            let mut multiple_capture_data = multiple_capture_data
+                .filter(|data| data.get_capture(&capture_id).is_some())
+                .ok_or(errors::ApiErrorResponse::CaptureNotFound)
+                .into_report()?;
+            multiple_capture_data.set_capture_to_sync(capture_id);
+            Some(multiple_capture_data)
+        }
+        None => multiple_capture_data,
+    };
+
     let contains_encoded_data = connector_response.encoded_data.is_some();
 
     let creds_identifier = request
@@ -285,6 +340,7 @@ async fn get_tracker_for_sync<
             payment_attempt,
             refunds,
             disputes,
+            attempts: Some(attempts),
             sessions_token: vec![],
             card_cvc: None,
             creds_identifier,
@@ -292,6 +348,7 @@ async fn get_tracker_for_sync<
             connector_customer_id: None,
             ephemeral_key: None,
             redirect_response: None,
+            multiple_capture_data,
         },
         None,
     ))
@@ -129,6 +129,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             request.shipping.as_ref(),
             payment_intent.shipping_address_id.as_deref(),
             merchant_id,
+            storage_scheme,
             &payment_intent.customer_id,
         )
         .await?;
@@ -137,6 +138,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             request.billing.as_ref(),
             payment_intent.billing_address_id.as_deref(),
             merchant_id,
+            storage_scheme,
             &payment_intent.customer_id,
         )
         .await?;
@@ -158,6 +160,19 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .clone()
             .or(payment_attempt.business_sub_label);
 
+        payment_attempt.capture_method = request
+            .capture_method
+            .map(ForeignInto::foreign_into)
+            .or(payment_attempt.capture_method);
+        payment_attempt.capture_on = request.capture_on.or(payment_attempt.capture_on);
+
+        helpers::validate_capture_on(
+            payment_attempt
+                .capture_method
+                .map(ForeignInto::foreign_into),
+            payment_attempt.capture_on,
+        )?;
+
         let token = token.or_else(|| payment_attempt.payment_token.clone());
 
         if request.confirm.unwrap_or(false) {
@@ -304,6 +319,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 refunds: vec![],
                 disputes: vec![],
                 connector_response,
+                attempts: None,
                 sessions_token: vec![],
                 card_cvc: request.card_cvc.clone(),
                 creds_identifier,
@@ -311,6 +327,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 connector_customer_id: None,
                 ephemeral_key: None,
                 redirect_response: None,
+                multiple_capture_data: None,
             },
             Some(CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -426,6 +443,8 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
 
         let payment_method_type = payment_data.payment_attempt.payment_method_type.clone();
         let payment_experience = payment_data.payment_attempt.payment_experience.clone();
+        let capture_method = payment_data.payment_attempt.capture_method;
+        let capture_on = payment_data.payment_attempt.capture_on;
         payment_data.payment_attempt = db
             .update_payment_attempt_with_attempt_id(
                 payment_data.payment_attempt,
@@ -440,6 +459,8 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     payment_experience,
                     payment_method_type,
                     business_sub_label,
+                    capture_method,
+                    capture_on,
                 },
                 storage_scheme,
             )
@@ -0,0 +1,656 @@
+use error_stack::{Report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::{
+    call_connector_service,
+    flows::{ConstructFlowSpecificData, Feature},
+    get_connector_tokenization_action,
+    operations::{self, BoxedOperation, Operation, PaymentResponse},
+    routing, CallConnectorAction, PaymentData,
+};
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments,
+    },
+    db::StorageInterface,
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        self, api, domain,
+        storage::{self, enums as storage_enums},
+    },
+    utils::ValueExt,
+};
+
+/// Statuses of an attempt whose authorization failed, only such attempts are ever retried
+pub(crate) const FAILED_ATTEMPT_STATUSES: [storage_enums::AttemptStatus; 3] = [
+    storage_enums::AttemptStatus::AuthenticationFailed,
+    storage_enums::AttemptStatus::AuthorizationFailed,
+    storage_enums::AttemptStatus::Failure,
+];
+
+/// Per-merchant configuration of automatic retries, stored in the configs table under
+/// `auto_retry_{merchant_id}`
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct AutoRetryConfig {
+    pub enabled: bool,
+    /// Maximum number of attempts a payment can have, including the first one
+    pub max_attempts: u16,
+    /// Error codes returned by the connector on which the payment is retried
+    pub retryable_error_codes: Vec<String>,
+    /// Attempt statuses on which the payment is retried, limited to the statuses of a failed
+    /// authorization
+    pub retryable_attempt_statuses: Vec<storage_enums::AttemptStatus>,
+}
+
+impl AutoRetryConfig {
+    /// Rejects statuses which are not failures, which would have a successful payment retried
+    pub fn validate(&self) -> Result<(), String> {
+        match self
+            .retryable_attempt_statuses
+            .iter()
+            .find(|status| !FAILED_ATTEMPT_STATUSES.contains(status))
+        {
+            Some(status) => Err(format!(
+                "attempt status {status} is not a failure and cannot be retried"
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the error code to record on the attempt, if its connector call failed with an
+    /// error the merchant retries on. Such attempts are then retried like declined ones.
+    pub fn get_retryable_connector_call_error_code(
+        &self,
+        error: &Report<errors::ApiErrorResponse>,
+    ) -> Option<&'static str> {
+        get_connector_call_error_code(error).filter(|error_code| {
+            self.enabled
+                && self
+                    .retryable_error_codes
+                    .iter()
+                    .any(|code| code == error_code)
+        })
+    }
+
+    pub fn is_retryable(&self, payment_attempt: &storage::PaymentAttempt) -> bool {
+        self.enabled
+            && FAILED_ATTEMPT_STATUSES.contains(&payment_attempt.status)
+            && (self
+                .retryable_attempt_statuses
+                .contains(&payment_attempt.status)
+                || payment_attempt
+                    .error_code
+                    .as_ref()
+                    .map_or(false, |code| self.retryable_error_codes.contains(code)))
+    }
+}
+
+/// Error code of a connector call which failed without a response from the connector, that is
+/// timeouts, network errors and server errors the connector could not describe
+fn get_connector_call_error_code(error: &Report<errors::ApiErrorResponse>) -> Option<&'static str> {
+    match error.downcast_ref::<errors::ApiClientError>()? {
+        errors::ApiClientError::RequestTimeoutReceived
+        | errors::ApiClientError::GatewayTimeoutReceived => {
+            Some(consts::CONNECTOR_TIMEOUT_ERROR_CODE)
+        }
+        errors::ApiClientError::RequestNotSent(_)
+        | errors::ApiClientError::InternalServerErrorReceived
+        | errors::ApiClientError::BadGatewayReceived
+        | errors::ApiClientError::ServiceUnavailableReceived
+        | errors::ApiClientError::UnexpectedServerResponse => {
+            Some(consts::CONNECTOR_UNAVAILABLE_ERROR_CODE)
+        }
+        _ => None,
+    }
+}
+
+pub async fn get_auto_retry_config(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> Option<AutoRetryConfig> {
+    let key = format!("auto_retry_{merchant_id}");
+    db.find_config_by_key_cached(&key)
+        .await
+        .map_err(|error| logger::debug!(?error, "auto retry is not configured"))
+        .ok()
+        .and_then(|config| {
+            serde_json::from_str::<AutoRetryConfig>(&config.config)
+                .map_err(|error| logger::error!(?error, "invalid auto retry config"))
+                .ok()
+        })
+        .filter(|config| {
+            config
+                .validate()
+                .map_err(|error| logger::error!(%error, "invalid auto retry config"))
+                .is_ok()
+        })
+}
+
+/// Retries a payment whose connector call failed, if the merchant retries on the error. The failed
+/// call is recorded on the attempt and the payment is retried on the fallback connectors, the
+/// error is returned as is otherwise.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub async fn retry_on_connector_call_error<F, Req, FData>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    operation: &BoxedOperation<'_, F, Req>,
+    payment_data: PaymentData<F>,
+    customer: &Option<domain::Customer>,
+    call_connector_action: CallConnectorAction,
+    validate_result: &operations::ValidateResult<'_>,
+    error: Report<errors::ApiErrorResponse>,
+) -> RouterResult<PaymentData<F>>
+where
+    F: Send + Clone + Sync,
+
+    // To create connector flow specific interface data
+    PaymentData<F>: ConstructFlowSpecificData<F, FData, types::PaymentsResponseData>,
+    types::RouterData<F, FData, types::PaymentsResponseData>: Feature<F, FData> + Send,
+
+    // To construct connector flow specific api
+    dyn api::Connector: services::api::ConnectorIntegration<F, FData, types::PaymentsResponseData>,
+
+    // To perform router related operation for PaymentResponse
+    PaymentResponse: Operation<F, FData>,
+    FData: Send + Sync,
+{
+    let db = &*state.store;
+    let retry_config = match get_auto_retry_config(db, &merchant_account.merchant_id).await {
+        Some(retry_config) => retry_config,
+        None => return Err(error),
+    };
+    let error_code = match retry_config.get_retryable_connector_call_error_code(&error) {
+        Some(error_code) => error_code,
+        None => return Err(error),
+    };
+
+    let payment_data = fail_attempt_on_connector_call_error(
+        db,
+        payment_data,
+        error_code,
+        &error,
+        merchant_account.storage_scheme,
+    )
+    .await?;
+
+    retry_on_fallback_connectors(
+        state,
+        merchant_account,
+        operation,
+        payment_data,
+        customer,
+        call_connector_action,
+        validate_result,
+        retry_config,
+    )
+    .await
+}
+
+/// Retries a failed authorization on the next connectors of the routing output, until an attempt
+/// is no longer retryable, the routing output is exhausted or `max_attempts` is reached.
+/// Every attempt of the payment is collected in `payment_data.attempts`.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub async fn retry_on_fallback_connectors<F, Req, FData>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    operation: &BoxedOperation<'_, F, Req>,
+    mut payment_data: PaymentData<F>,
+    customer: &Option<domain::Customer>,
+    call_connector_action: CallConnectorAction,
+    validate_result: &operations::ValidateResult<'_>,
+    retry_config: AutoRetryConfig,
+) -> RouterResult<PaymentData<F>>
+where
+    F: Send + Clone + Sync,
+
+    // To create connector flow specific interface data
+    PaymentData<F>: ConstructFlowSpecificData<F, FData, types::PaymentsResponseData>,
+    types::RouterData<F, FData, types::PaymentsResponseData>: Feature<F, FData> + Send,
+
+    // To construct connector flow specific api
+    dyn api::Connector: services::api::ConnectorIntegration<F, FData, types::PaymentsResponseData>,
+
+    // To perform router related operation for PaymentResponse
+    PaymentResponse: Operation<F, FData>,
+    FData: Send + Sync,
+{
+    let db = &*state.store;
+
+    let mut attempts = db
+        .find_payment_attempts_by_payment_id_merchant_id(
+            &payment_data.payment_intent.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    while retry_config.is_retryable(&payment_data.payment_attempt)
+        && attempts.len() < usize::from(retry_config.max_attempts)
+    {
+        let tried_connectors = attempts
+            .iter()
+            .filter_map(|attempt| attempt.connector.clone())
+            .collect::<Vec<_>>();
+
+        let next_connector =
+            match get_fallback_connector(merchant_account, &payment_data, &tried_connectors)? {
+                Some(connector) => connector,
+                None => {
+                    logger::info!("no fallback connector left to retry the payment on");
+                    break;
+                }
+            };
+
+        logger::info!(
+            failed_attempt_id = %payment_data.payment_attempt.attempt_id,
+            connector = %next_connector,
+            "retrying payment on fallback connector"
+        );
+
+        let connector = api::ConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            &next_connector,
+            api::GetToken::Connector,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Routing algorithm gave invalid connector")?;
+
+        payment_data = make_retry_attempt(
+            db,
+            payment_data,
+            next_connector,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+        let (retry_payment_data, tokenization_action) =
+            get_connector_tokenization_action(state, operation, payment_data, validate_result)
+                .await?;
+        payment_data = retry_payment_data;
+
+        let router_data = match call_connector_service(
+            state,
+            merchant_account,
+            connector,
+            operation,
+            &payment_data,
+            customer,
+            call_connector_action.clone(),
+            tokenization_action,
+        )
+        .await
+        {
+            Ok(router_data) => router_data,
+            Err(error) => match retry_config.get_retryable_connector_call_error_code(&error) {
+                Some(error_code) => {
+                    payment_data = fail_attempt_on_connector_call_error(
+                        db,
+                        payment_data,
+                        error_code,
+                        &error,
+                        merchant_account.storage_scheme,
+                    )
+                    .await?;
+                    attempts.push(payment_data.payment_attempt.clone());
+                    continue;
+                }
+                None => return Err(error),
+            },
+        };
+
+        let operation = Box::new(PaymentResponse);
+        payment_data = operation
+            .to_post_update_tracker()?
+            .update_tracker(
+                db,
+                &validate_result.payment_id,
+                payment_data,
+                router_data,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        attempts.push(payment_data.payment_attempt.clone());
+    }
+
+    payment_data.attempts = Some(attempts);
+
+    Ok(payment_data)
+}
+
+/// Returns the first connector of the routing output of the payment which has not been tried yet
+fn get_fallback_connector<F: Clone>(
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &PaymentData<F>,
+    tried_connectors: &[String],
+) -> RouterResult<Option<String>> {
+    let straight_through_algorithm = payment_data
+        .payment_attempt
+        .straight_through_algorithm
+        .clone()
+        .map(|val| val.parse_value::<api::StraightThroughAlgorithm>("RoutingAlgorithm"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid straight through algorithm format in payment attempt")?;
+
+    let routing_algorithm = match straight_through_algorithm {
+        Some(algorithm) => Some(api::RoutingAlgorithm::from(algorithm)),
+        None => merchant_account
+            .routing_algorithm
+            .clone()
+            .map(|val| val.parse_value::<api::RoutingAlgorithm>("RoutingAlgorithm"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to deserialize merchant routing algorithm")?,
+    };
+
+    let routing_context = routing::RoutingContext::from(payment_data);
+
+    Ok(routing_algorithm.and_then(|algorithm| {
+        routing::get_connectors_from_routing_algorithm(&algorithm, &routing_context)
+            .into_iter()
+            .map(|connector| connector.to_string())
+            .find(|connector| !tried_connectors.contains(connector))
+    }))
+}
+
+/// Creates a new attempt routed through `connector` from the failed attempt, and makes it the
+/// active attempt of the payment
+async fn make_retry_attempt<F: Clone>(
+    db: &dyn StorageInterface,
+    mut payment_data: PaymentData<F>,
+    connector: String,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<PaymentData<F>> {
+    let payment_attempt = db
+        .insert_payment_attempt(
+            make_new_payment_attempt(&payment_data.payment_attempt, connector),
+            storage_scheme,
+        )
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+            payment_id: payment_data.payment_intent.payment_id.clone(),
+        })?;
+
+    let connector_response = db
+        .insert_connector_response(
+            payments::PaymentCreate::make_connector_response(&payment_attempt),
+            storage_scheme,
+        )
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+            payment_id: payment_attempt.payment_id.clone(),
+        })?;
+
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent,
+            storage::PaymentIntentUpdate::StatusAndAttemptUpdate {
+                status: storage_enums::IntentStatus::Processing,
+                active_attempt_id: payment_attempt.attempt_id.clone(),
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    payment_data.payment_attempt = payment_attempt;
+    payment_data.connector_response = connector_response;
+    // The token of the connector which declined the payment cannot be used with the next one
+    payment_data.pm_token = None;
+
+    Ok(payment_data)
+}
+
+/// Marks the attempt and the payment as failed with the error of the connector call, the payment
+/// stays failed unless it is retried successfully
+async fn fail_attempt_on_connector_call_error<F: Clone>(
+    db: &dyn StorageInterface,
+    mut payment_data: PaymentData<F>,
+    error_code: &str,
+    error: &Report<errors::ApiErrorResponse>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<PaymentData<F>> {
+    logger::info!(
+        ?error,
+        error_code,
+        "connector call failed with a retryable error"
+    );
+
+    payment_data.payment_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt,
+            storage::PaymentAttemptUpdate::ErrorUpdate {
+                connector: None,
+                status: storage_enums::AttemptStatus::Failure,
+                error_code: Some(Some(error_code.to_string())),
+                error_message: Some(Some(error.current_context().to_string())),
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent,
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: storage_enums::IntentStatus::Failed,
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Ok(payment_data)
+}
+
+fn make_new_payment_attempt(
+    failed_attempt: &storage::PaymentAttempt,
+    connector: String,
+) -> storage::PaymentAttemptNew {
+    let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
+
+    storage::PaymentAttemptNew {
+        payment_id: failed_attempt.payment_id.clone(),
+        merchant_id: failed_attempt.merchant_id.clone(),
+        attempt_id: uuid::Uuid::new_v4().simple().to_string(),
+        status: storage_enums::AttemptStatus::Pending,
+        amount: failed_attempt.amount,
+        currency: failed_attempt.currency,
+        save_to_locker: failed_attempt.save_to_locker,
+        connector: Some(connector),
+        offer_amount: failed_attempt.offer_amount,
+        surcharge_amount: failed_attempt.surcharge_amount,
+        tax_amount: failed_attempt.tax_amount,
+        payment_method_id: failed_attempt.payment_method_id.clone(),
+        payment_method: failed_attempt.payment_method,
+        capture_method: failed_attempt.capture_method,
+        capture_on: failed_attempt.capture_on,
+        confirm: failed_attempt.confirm,
+        authentication_type: failed_attempt.authentication_type,
+        created_at,
+        modified_at,
+        last_synced,
+        amount_to_capture: failed_attempt.amount_to_capture,
+        mandate_id: failed_attempt.mandate_id.clone(),
+        browser_info: failed_attempt.browser_info.clone(),
+        payment_token: failed_attempt.payment_token.clone(),
+        payment_experience: failed_attempt.payment_experience.clone(),
+        payment_method_type: failed_attempt.payment_method_type.clone(),
+        payment_method_data: failed_attempt.payment_method_data.clone(),
+        business_sub_label: failed_attempt.business_sub_label.clone(),
+        straight_through_algorithm: failed_attempt.straight_through_algorithm.clone(),
+        mandate_details: failed_attempt.mandate_details.clone(),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use error_stack::report;
+
+    use super::*;
+
+    fn failed_attempt(error_code: Option<&str>) -> storage::PaymentAttempt {
+        storage::PaymentAttempt {
+            id: 1,
+            payment_id: "pay_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            attempt_id: "attempt_123".to_string(),
+            status: storage_enums::AttemptStatus::Failure,
+            amount: 1000,
+            currency: Some(storage_enums::Currency::USD),
+            save_to_locker: None,
+            connector: Some("stripe".to_string()),
+            error_message: Some("declined".to_string()),
+            offer_amount: None,
+            surcharge_amount: None,
+            tax_amount: None,
+            payment_method_id: None,
+            payment_method: Some(storage_enums::PaymentMethod::Card),
+            connector_transaction_id: None,
+            capture_method: None,
+            capture_on: None,
+            confirm: true,
+            authentication_type: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            last_synced: None,
+            cancellation_reason: None,
+            amount_to_capture: None,
+            mandate_id: None,
+            browser_info: None,
+            error_code: error_code.map(ToString::to_string),
+            payment_token: None,
+            connector_metadata: None,
+            payment_experience: None,
+            payment_method_type: None,
+            payment_method_data: None,
+            business_sub_label: None,
+            straight_through_algorithm: None,
+            preprocessing_step_id: None,
+            mandate_details: None,
+        }
+    }
+
+    #[test]
+    fn test_auto_retry_config_deserialization() {
+        let config: AutoRetryConfig = serde_json::from_str(
+            r#"{"enabled":true,"max_attempts":3,"retryable_attempt_statuses":["authorization_failed"]}"#,
+        )
+        .unwrap();
+
+        assert!(config.enabled);
+        assert_eq!(config.max_attempts, 3);
+        assert!(config.retryable_error_codes.is_empty());
+        assert_eq!(
+            config.retryable_attempt_statuses,
+            vec![storage_enums::AttemptStatus::AuthorizationFailed]
+        );
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        let config = AutoRetryConfig {
+            enabled: true,
+            max_attempts: 2,
+            retryable_error_codes: vec!["card_declined".to_string()],
+            retryable_attempt_statuses: vec![],
+        };
+
+        assert!(config.is_retryable(&failed_attempt(Some("card_declined"))));
+        assert!(!config.is_retryable(&failed_attempt(Some("expired_card"))));
+        assert!(!config.is_retryable(&failed_attempt(None)));
+
+        let disabled = AutoRetryConfig {
+            enabled: false,
+            ..config
+        };
+        assert!(!disabled.is_retryable(&failed_attempt(Some("card_declined"))));
+    }
+
+    #[test]
+    fn test_successful_attempt_is_not_retryable() {
+        let config = AutoRetryConfig {
+            enabled: true,
+            max_attempts: 2,
+            retryable_error_codes: vec!["card_declined".to_string()],
+            retryable_attempt_statuses: vec![storage_enums::AttemptStatus::Charged],
+        };
+
+        let charged_attempt = storage::PaymentAttempt {
+            status: storage_enums::AttemptStatus::Charged,
+            ..failed_attempt(Some("card_declined"))
+        };
+        assert!(!config.is_retryable(&charged_attempt));
+    }
+
+    #[test]
+    fn test_auto_retry_config_validation() {
+        let config: AutoRetryConfig = serde_json::from_str(
+            r#"{"enabled":true,"max_attempts":3,"retryable_attempt_statuses":["authorization_failed","failure"]}"#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+
+        let config: AutoRetryConfig = serde_json::from_str(
+            r#"{"enabled":true,"max_attempts":3,"retryable_attempt_statuses":["failure","charged"]}"#,
+        )
+        .unwrap();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_connector_call_errors_are_retried_only_if_configured() {
+        let config = AutoRetryConfig {
+            enabled: true,
+            max_attempts: 2,
+            retryable_error_codes: vec![consts::CONNECTOR_UNAVAILABLE_ERROR_CODE.to_string()],
+            retryable_attempt_statuses: vec![],
+        };
+
+        let server_error = report!(errors::ConnectorError::ResponseDeserializationFailed)
+            .attach(errors::ApiClientError::from_server_error_status(502))
+            .change_context(errors::ApiErrorResponse::PaymentAuthorizationFailed { data: None });
+        let timeout = report!(errors::ApiClientError::RequestTimeoutReceived)
+            .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+            .change_context(errors::ApiErrorResponse::PaymentAuthorizationFailed { data: None });
+
+        assert_eq!(
+            config.get_retryable_connector_call_error_code(&server_error),
+            Some(consts::CONNECTOR_UNAVAILABLE_ERROR_CODE)
+        );
+        assert_eq!(
+            config.get_retryable_connector_call_error_code(&timeout),
+            None
+        );
+        assert_eq!(
+            config.get_retryable_connector_call_error_code(&report!(
+                errors::ApiErrorResponse::InternalServerError
+            )),
+            None
+        );
+    }
+
+    #[test]
+    fn test_new_payment_attempt_is_routed_through_fallback_connector() {
+        let failed_attempt = failed_attempt(Some("card_declined"));
+        let new_attempt = make_new_payment_attempt(&failed_attempt, "adyen".to_string());
+
+        assert_ne!(new_attempt.attempt_id, failed_attempt.attempt_id);
+        assert_eq!(new_attempt.connector.as_deref(), Some("adyen"));
+        assert_eq!(new_attempt.status, storage_enums::AttemptStatus::Pending);
+        assert_eq!(new_attempt.payment_method, failed_attempt.payment_method);
+        assert!(new_attempt.error_code.is_none());
+    }
+}
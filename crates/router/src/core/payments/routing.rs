@@ -0,0 +1,419 @@
+use api_models::admin::{ConnectorVolumeSplit, RoutingCondition, RoutingRules};
+use error_stack::report;
+use masking::PeekInterface;
+
+use super::PaymentData;
+use crate::{
+    core::errors::{self, RouterResult},
+    types::{
+        api::{self, enums as api_enums},
+        transformers::ForeignInto,
+    },
+    utils,
+};
+
+/// The attributes of a payment that the routing rules are evaluated against
+#[derive(Clone, Debug, Default)]
+pub struct RoutingContext {
+    /// Identifier used to pick the connector in volume split routing, so that all the attempts
+    /// of the same payment are routed through the same connector
+    pub routing_key: Option<String>,
+    pub amount: Option<i64>,
+    pub currency: Option<api_enums::Currency>,
+    pub card_network: Option<api_enums::CardNetwork>,
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+    pub billing_country: Option<api_enums::CountryAlpha2>,
+    pub metadata: Option<serde_json::Value>,
+}
+
+impl<F: Clone> From<&PaymentData<F>> for RoutingContext {
+    fn from(payment_data: &PaymentData<F>) -> Self {
+        let card_network = match payment_data.payment_method_data {
+            Some(api::PaymentMethodData::Card(ref card)) => card.card_network.clone(),
+            _ => None,
+        };
+
+        Self {
+            routing_key: Some(payment_data.payment_intent.payment_id.clone()),
+            amount: Some(payment_data.payment_attempt.amount),
+            currency: Some(payment_data.currency.foreign_into()),
+            card_network,
+            payment_method_type: payment_data
+                .payment_attempt
+                .payment_method_type
+                .clone()
+                .map(ForeignInto::foreign_into),
+            billing_country: payment_data
+                .address
+                .billing
+                .as_ref()
+                .and_then(|billing| billing.address.as_ref())
+                .and_then(|address| address.country),
+            metadata: payment_data
+                .payment_intent
+                .metadata
+                .as_ref()
+                .map(|metadata| metadata.peek().clone()),
+        }
+    }
+}
+
+/// Returns the connectors chosen by the routing algorithm, in the order of preference
+pub fn get_connectors_from_routing_algorithm(
+    routing_algorithm: &api::RoutingAlgorithm,
+    routing_context: &RoutingContext,
+) -> Vec<api_enums::RoutableConnectors> {
+    match routing_algorithm {
+        api::RoutingAlgorithm::Single(conn) => vec![*conn],
+        api::RoutingAlgorithm::Priority(conns) => conns.clone(),
+        api::RoutingAlgorithm::Rules(rules) => evaluate_routing_rules(rules, routing_context),
+        api::RoutingAlgorithm::VolumeSplit(splits) => {
+            evaluate_volume_split(splits, routing_context.routing_key.as_deref())
+        }
+    }
+}
+
+/// Picks a connector based on the bucket that the routing key falls into, the remaining
+/// connectors follow in the descending order of their split
+fn evaluate_volume_split(
+    splits: &[ConnectorVolumeSplit],
+    routing_key: Option<&str>,
+) -> Vec<api_enums::RoutableConnectors> {
+    let bucket = crc32fast::hash(routing_key.unwrap_or_default().as_bytes()) % 100;
+
+    let mut cumulative_split = 0;
+    let chosen = splits
+        .iter()
+        .find(|volume_split| {
+            cumulative_split += u32::from(volume_split.split);
+            bucket < cumulative_split
+        })
+        .or_else(|| splits.last())
+        .map(|volume_split| volume_split.connector);
+
+    let mut remaining = splits
+        .iter()
+        .filter(|volume_split| Some(volume_split.connector) != chosen)
+        .collect::<Vec<_>>();
+    remaining.sort_by(|a, b| b.split.cmp(&a.split));
+
+    chosen
+        .into_iter()
+        .chain(
+            remaining
+                .into_iter()
+                .map(|volume_split| volume_split.connector),
+        )
+        .collect()
+}
+
+fn evaluate_routing_rules(
+    routing_rules: &RoutingRules,
+    routing_context: &RoutingContext,
+) -> Vec<api_enums::RoutableConnectors> {
+    routing_rules
+        .rules
+        .iter()
+        .find(|rule| {
+            rule.conditions
+                .iter()
+                .all(|condition| is_condition_satisfied(condition, routing_context))
+        })
+        .map(|rule| rule.connectors.clone())
+        .unwrap_or_else(|| routing_rules.default_connectors.clone())
+}
+
+fn is_condition_satisfied(condition: &RoutingCondition, routing_context: &RoutingContext) -> bool {
+    match condition {
+        RoutingCondition::Amount { min, max } => routing_context.amount.map_or(false, |amount| {
+            min.map_or(true, |min| amount >= min) && max.map_or(true, |max| amount <= max)
+        }),
+        RoutingCondition::Currency { values } => routing_context
+            .currency
+            .map_or(false, |currency| values.contains(&currency)),
+        RoutingCondition::CardNetwork { values } => routing_context
+            .card_network
+            .as_ref()
+            .map_or(false, |card_network| values.contains(card_network)),
+        RoutingCondition::PaymentMethodType { values } => routing_context
+            .payment_method_type
+            .map_or(false, |payment_method_type| {
+                values.contains(&payment_method_type)
+            }),
+        RoutingCondition::BillingCountry { values } => routing_context
+            .billing_country
+            .map_or(false, |country| values.contains(&country)),
+        RoutingCondition::Metadata { key, value } => {
+            routing_context.metadata.as_ref().map_or(false, |metadata| {
+                // Values passed in `routing_parameters` take precedence over the other metadata
+                metadata
+                    .get("routing_parameters")
+                    .and_then(|routing_parameters| routing_parameters.get(key))
+                    .or_else(|| metadata.get(key))
+                    .map_or(false, |metadata_value| metadata_value == value)
+            })
+        }
+    }
+}
+
+pub fn validate_routing_algorithm(routing_algorithm: &api::RoutingAlgorithm) -> RouterResult<()> {
+    match routing_algorithm {
+        api::RoutingAlgorithm::Single(_) => Ok(()),
+        api::RoutingAlgorithm::Priority(conns) => {
+            validate_connector_list(conns, "priority routing algorithm")
+        }
+        api::RoutingAlgorithm::VolumeSplit(splits) => {
+            utils::when(splits.is_empty(), || {
+                Err(invalid_routing_algorithm(
+                    "volume split routing algorithm should have at least one connector".to_string(),
+                ))
+            })?;
+
+            let total_split: u32 = splits
+                .iter()
+                .map(|volume_split| u32::from(volume_split.split))
+                .sum();
+            utils::when(total_split != 100, || {
+                Err(invalid_routing_algorithm(format!(
+                    "volume splits should add up to 100, but they add up to {total_split}"
+                )))
+            })?;
+
+            let has_duplicates = splits.iter().enumerate().any(|(index, volume_split)| {
+                splits[..index]
+                    .iter()
+                    .any(|previous| previous.connector == volume_split.connector)
+            });
+            utils::when(has_duplicates, || {
+                Err(invalid_routing_algorithm(
+                    "a connector can be present only once in the volume splits".to_string(),
+                ))
+            })
+        }
+        api::RoutingAlgorithm::Rules(routing_rules) => {
+            validate_connector_list(
+                &routing_rules.default_connectors,
+                "default connectors of the routing rules",
+            )?;
+
+            for rule in &routing_rules.rules {
+                let rule_name = format!("routing rule '{}'", rule.name);
+
+                utils::when(rule.conditions.is_empty(), || {
+                    Err(invalid_routing_algorithm(format!(
+                        "{rule_name} should have at least one condition"
+                    )))
+                })?;
+                validate_connector_list(&rule.connectors, &rule_name)?;
+
+                rule.conditions
+                    .iter()
+                    .try_for_each(|condition| validate_routing_condition(condition, &rule_name))?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+fn validate_connector_list(
+    connectors: &[api_enums::RoutableConnectors],
+    context: &str,
+) -> RouterResult<()> {
+    utils::when(connectors.is_empty(), || {
+        Err(invalid_routing_algorithm(format!(
+            "{context} should have at least one connector"
+        )))
+    })
+}
+
+fn validate_routing_condition(condition: &RoutingCondition, rule_name: &str) -> RouterResult<()> {
+    let is_empty = match condition {
+        RoutingCondition::Amount { min, max } => {
+            utils::when(min.is_none() && max.is_none(), || {
+                Err(invalid_routing_algorithm(format!(
+                    "amount condition of {rule_name} should have either min or max"
+                )))
+            })?;
+            utils::when(min.zip(*max).map_or(false, |(min, max)| min > max), || {
+                Err(invalid_routing_algorithm(format!(
+                    "amount condition of {rule_name} has min greater than max"
+                )))
+            })?;
+            false
+        }
+        RoutingCondition::Currency { values } => values.is_empty(),
+        RoutingCondition::CardNetwork { values } => values.is_empty(),
+        RoutingCondition::PaymentMethodType { values } => values.is_empty(),
+        RoutingCondition::BillingCountry { values } => values.is_empty(),
+        RoutingCondition::Metadata { key, .. } => key.is_empty(),
+    };
+
+    utils::when(is_empty, || {
+        Err(invalid_routing_algorithm(format!(
+            "{rule_name} has a condition without any values"
+        )))
+    })
+}
+
+fn invalid_routing_algorithm(message: String) -> error_stack::Report<errors::ApiErrorResponse> {
+    report!(errors::ApiErrorResponse::InvalidRequestData {
+        message: format!("Invalid routing algorithm: {message}"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use api_models::admin::RoutingRule;
+
+    use super::*;
+
+    fn routing_rules() -> api::RoutingAlgorithm {
+        api::RoutingAlgorithm::Rules(RoutingRules {
+            rules: vec![
+                RoutingRule {
+                    name: "high_value_eur".to_string(),
+                    conditions: vec![
+                        RoutingCondition::Amount {
+                            min: Some(10000),
+                            max: None,
+                        },
+                        RoutingCondition::Currency {
+                            values: vec![api_enums::Currency::EUR],
+                        },
+                    ],
+                    connectors: vec![
+                        api_enums::RoutableConnectors::Adyen,
+                        api_enums::RoutableConnectors::Checkout,
+                    ],
+                },
+                RoutingRule {
+                    name: "vip_customers".to_string(),
+                    conditions: vec![RoutingCondition::Metadata {
+                        key: "tier".to_string(),
+                        value: serde_json::json!("vip"),
+                    }],
+                    connectors: vec![api_enums::RoutableConnectors::Checkout],
+                },
+            ],
+            default_connectors: vec![api_enums::RoutableConnectors::Stripe],
+        })
+    }
+
+    #[test]
+    fn test_first_matching_rule_is_used() {
+        let context = RoutingContext {
+            amount: Some(20000),
+            currency: Some(api_enums::Currency::EUR),
+            metadata: Some(serde_json::json!({ "tier": "vip" })),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_connectors_from_routing_algorithm(&routing_rules(), &context),
+            vec![
+                api_enums::RoutableConnectors::Adyen,
+                api_enums::RoutableConnectors::Checkout
+            ]
+        );
+    }
+
+    #[test]
+    fn test_metadata_rule_with_routing_parameters() {
+        let context = RoutingContext {
+            amount: Some(500),
+            currency: Some(api_enums::Currency::EUR),
+            metadata: Some(serde_json::json!({ "routing_parameters": { "tier": "vip" } })),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_connectors_from_routing_algorithm(&routing_rules(), &context),
+            vec![api_enums::RoutableConnectors::Checkout]
+        );
+    }
+
+    #[test]
+    fn test_default_connectors_when_no_rule_matches() {
+        let context = RoutingContext {
+            amount: Some(20000),
+            currency: Some(api_enums::Currency::USD),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_connectors_from_routing_algorithm(&routing_rules(), &context),
+            vec![api_enums::RoutableConnectors::Stripe]
+        );
+    }
+
+    fn volume_split() -> api::RoutingAlgorithm {
+        api::RoutingAlgorithm::VolumeSplit(vec![
+            ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Stripe,
+                split: 70,
+            },
+            ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Adyen,
+                split: 30,
+            },
+        ])
+    }
+
+    #[test]
+    fn test_volume_split_is_deterministic() {
+        let context = RoutingContext {
+            routing_key: Some("pay_mbabizu24mvu3mela5njyhpit4".to_string()),
+            ..Default::default()
+        };
+
+        let connectors = get_connectors_from_routing_algorithm(&volume_split(), &context);
+        assert_eq!(connectors.len(), 2);
+        assert_eq!(
+            connectors,
+            get_connectors_from_routing_algorithm(&volume_split(), &context)
+        );
+    }
+
+    #[test]
+    fn test_volume_split_distribution() {
+        let stripe_count = (0..1000)
+            .map(|index| RoutingContext {
+                routing_key: Some(format!("pay_{index}")),
+                ..Default::default()
+            })
+            .filter(|context| {
+                get_connectors_from_routing_algorithm(&volume_split(), context).first()
+                    == Some(&api_enums::RoutableConnectors::Stripe)
+            })
+            .count();
+
+        assert!((600..800).contains(&stripe_count));
+    }
+
+    #[test]
+    fn test_validate_routing_algorithm() {
+        assert!(validate_routing_algorithm(&routing_rules()).is_ok());
+        assert!(validate_routing_algorithm(&api::RoutingAlgorithm::Priority(vec![])).is_err());
+
+        let invalid_amount_range = api::RoutingAlgorithm::Rules(RoutingRules {
+            rules: vec![RoutingRule {
+                name: "invalid_amount".to_string(),
+                conditions: vec![RoutingCondition::Amount {
+                    min: Some(100),
+                    max: Some(10),
+                }],
+                connectors: vec![api_enums::RoutableConnectors::Adyen],
+            }],
+            default_connectors: vec![api_enums::RoutableConnectors::Stripe],
+        });
+        assert!(validate_routing_algorithm(&invalid_amount_range).is_err());
+
+        assert!(validate_routing_algorithm(&volume_split()).is_ok());
+        let invalid_volume_split = api::RoutingAlgorithm::VolumeSplit(vec![ConnectorVolumeSplit {
+            connector: api_enums::RoutableConnectors::Stripe,
+            split: 70,
+        }]);
+        assert!(validate_routing_algorithm(&invalid_volume_split).is_err());
+    }
+}
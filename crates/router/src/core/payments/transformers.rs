@@ -161,6 +161,10 @@ where
             payment_data.payment_intent,
             payment_data.refunds,
             payment_data.disputes,
+            payment_data.attempts,
+            payment_data
+                .multiple_capture_data
+                .map(|data| data.get_all_captures().to_vec()),
             payment_data.payment_method_data,
             customer,
             auth_flow,
@@ -173,6 +177,33 @@ where
     }
 }
 
+impl<F, Op> ToResponse<api::PaymentsRetrieveRequest, PaymentData<F>, Op> for api::CaptureResponse
+where
+    F: Clone,
+    Op: Debug,
+{
+    fn generate_response(
+        req: Option<api::PaymentsRetrieveRequest>,
+        payment_data: PaymentData<F>,
+        _customer: Option<domain::Customer>,
+        _auth_flow: services::AuthFlow,
+        _server: &Server,
+        _operation: Op,
+    ) -> RouterResponse<Self> {
+        let capture_id = req
+            .and_then(|req| req.capture_id)
+            .get_required_value("capture_id")?;
+        let capture = payment_data
+            .multiple_capture_data
+            .as_ref()
+            .and_then(|data| data.get_capture(&capture_id))
+            .cloned()
+            .ok_or(errors::ApiErrorResponse::CaptureNotFound)?;
+
+        Ok(services::ApplicationResponse::Json(capture.foreign_into()))
+    }
+}
+
 impl<F, Req, Op> ToResponse<Req, PaymentData<F>, Op> for api::PaymentsSessionResponse
 where
     Self: From<Req>,
@@ -252,6 +283,8 @@ pub fn payments_to_payments_response<R, Op>(
     payment_intent: storage::PaymentIntent,
     refunds: Vec<storage::Refund>,
     disputes: Vec<storage::Dispute>,
+    attempts: Option<Vec<storage::PaymentAttempt>>,
+    captures: Option<Vec<storage::Capture>>,
     payment_method_data: Option<api::PaymentMethodData>,
     customer: Option<domain::Customer>,
     auth_flow: services::AuthFlow,
@@ -289,6 +322,18 @@ where
                 .collect(),
         )
     };
+    let attempts_response = attempts.map(|attempts| {
+        attempts
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect()
+    });
+    let captures_response = captures.map(|captures| {
+        captures
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect()
+    });
     let merchant_id = payment_attempt.merchant_id.to_owned();
     let payment_method_type = payment_attempt
         .payment_method_type
@@ -393,6 +438,8 @@ where
                         .set_description(payment_intent.description)
                         .set_refunds(refunds_response) // refunds.iter().map(refund_to_refund_response),
                         .set_disputes(disputes_response)
+                        .set_attempts(attempts_response)
+                        .set_captures(captures_response)
                         .set_payment_method(
                             payment_attempt
                                 .payment_method
@@ -467,6 +514,8 @@ where
             description: payment_intent.description,
             refunds: refunds_response,
             disputes: disputes_response,
+            attempts: attempts_response,
+            captures: captures_response,
             payment_method: payment_attempt
                 .payment_method
                 .map(ForeignInto::foreign_into),
@@ -697,6 +746,14 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsSyncData
             encoded_data: payment_data.connector_response.encoded_data,
             capture_method: payment_data.payment_attempt.capture_method,
             connector_meta: payment_data.payment_attempt.connector_metadata,
+            sync_type: match payment_data.multiple_capture_data {
+                Some(multiple_capture_data) if multiple_capture_data.has_pending_captures() => {
+                    types::SyncRequestType::MultipleCaptureSync(
+                        multiple_capture_data.get_pending_connector_capture_ids(),
+                    )
+                }
+                _ => types::SyncRequestType::SinglePaymentSync,
+            },
         })
     }
 }
@@ -752,6 +809,14 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCaptureD
                 .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)?,
             payment_amount: payment_data.amount.into(),
             connector_meta: payment_data.payment_attempt.connector_metadata,
+            multiple_capture_data: payment_data
+                .multiple_capture_data
+                .as_ref()
+                .and_then(|data| data.get_current_capture())
+                .map(|capture| types::MultipleCaptureRequestData {
+                    capture_sequence: capture.capture_sequence,
+                    capture_reference: capture.capture_id.clone(),
+                }),
         })
     }
 }
@@ -0,0 +1,793 @@
+use std::str::FromStr;
+
+use error_stack::{report, IntoReport, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, access_token, helpers, routing, PaymentAddress},
+        utils as core_utils, webhooks,
+    },
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, enums as api_enums, payouts},
+        domain,
+        storage::{self, enums},
+        transformers::{ForeignInto, ForeignTryInto},
+    },
+    utils::{self, OptionExt, ValueExt},
+};
+
+// ********************************************** PAYOUT CREATE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_create_core(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutCreateRequest,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    utils::when(req.amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "amount".to_string(),
+            expected_format: "positive integer".to_string()
+        })
+        .attach_printable("amount less than or equal to zero"))
+    })?;
+
+    let payout_type: enums::PayoutType = req.payout_type.foreign_into();
+    let payout_method_matches_type = matches!(
+        (&req.payout_method_data, payout_type),
+        (payouts::PayoutMethodData::Card(_), enums::PayoutType::Card)
+            | (payouts::PayoutMethodData::Bank(_), enums::PayoutType::Bank)
+    );
+    utils::when(!payout_method_matches_type, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payout_method_data does not match the payout_type".to_string()
+        }))
+    })?;
+
+    let payout_id = core_utils::get_or_generate_id("payout_id", &req.payout_id, "payout")?;
+
+    let existing_payout = db
+        .find_optional_payout_by_merchant_id_payout_id(merchant_id, &payout_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while finding payout")?;
+    utils::when(existing_payout.is_some(), || {
+        Err(report!(errors::ApiErrorResponse::DuplicatePayout {
+            payout_id: payout_id.clone()
+        }))
+    })?;
+
+    let connector_name = get_connector_name(&merchant_account, &payout_id, &req)?;
+    validate_payout_connector(connector_name)?;
+
+    let (business_country, business_label) = helpers::get_business_details(
+        req.business_country,
+        req.business_label.as_ref(),
+        &merchant_account,
+    )?;
+
+    let payout_new = storage::PayoutNew {
+        payout_id: payout_id.clone(),
+        merchant_id: merchant_id.to_owned(),
+        customer_id: req.customer_id.clone(),
+        connector: Some(connector_name.to_string()),
+        connector_payout_id: None,
+        amount: req.amount,
+        currency: req.currency.foreign_into(),
+        payout_type,
+        status: enums::PayoutStatus::RequiresCreation,
+        auto_fulfill: req.auto_fulfill.unwrap_or(false),
+        description: req.description.clone(),
+        business_country: Some(business_country),
+        business_label: Some(business_label),
+        return_url: req.return_url.clone(),
+        metadata: req.metadata.clone(),
+        error_code: None,
+        error_message: None,
+    };
+
+    let payout = db.insert_payout(payout_new).await.to_duplicate_response(
+        errors::ApiErrorResponse::DuplicatePayout {
+            payout_id: payout_id.clone(),
+        },
+    )?;
+
+    let address = PaymentAddress {
+        shipping: None,
+        billing: req.billing.clone(),
+    };
+
+    let payout = trigger_payout_to_gateway::<api::PoCreate>(
+        state,
+        &merchant_account,
+        &payout,
+        Some(req.payout_method_data.clone()),
+        address,
+        (req.email.clone(), req.name.clone()),
+    )
+    .await?;
+
+    let payout = if payout.auto_fulfill && payout.status == enums::PayoutStatus::RequiresFulfillment
+    {
+        trigger_payout_to_gateway::<api::PoFulfill>(
+            state,
+            &merchant_account,
+            &payout,
+            None,
+            PaymentAddress::default(),
+            (None, None),
+        )
+        .await?
+    } else {
+        payout
+    };
+
+    let response: payouts::PayoutResponse = payout.foreign_into();
+    trigger_payout_outgoing_webhook(state, merchant_account, response.clone()).await;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+// ********************************************** PAYOUT RETRIEVE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_retrieve_core(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutRetrieveRequest,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let payout = find_payout(state, &merchant_account, &req.payout_id).await?;
+
+    if !should_sync_payout(
+        payout.status,
+        payout.connector_payout_id.as_deref(),
+        req.force_sync.unwrap_or(false),
+    ) {
+        return Ok(services::ApplicationResponse::Json(payout.foreign_into()));
+    }
+
+    validate_payout_flow_support(&payout, "PayoutSync")?;
+
+    let synced_payout = trigger_payout_to_gateway::<api::PoSync>(
+        state,
+        &merchant_account,
+        &payout,
+        None,
+        PaymentAddress::default(),
+        (None, None),
+    )
+    .await?;
+
+    let status_changed = synced_payout.status != payout.status;
+    let response: payouts::PayoutResponse = synced_payout.foreign_into();
+    if status_changed {
+        trigger_payout_outgoing_webhook(state, merchant_account, response.clone()).await;
+    }
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+// ********************************************** PAYOUT UPDATE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_update_core(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    payout_id: String,
+    req: payouts::PayoutUpdateRequest,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let db = &*state.store;
+
+    let payout = db
+        .find_payout_by_merchant_id_payout_id(&merchant_account.merchant_id, &payout_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+
+    utils::when(is_payout_terminal_state(payout.status), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Payout {} cannot be updated as it is in {} status",
+                payout_id, payout.status
+            ),
+        }))
+    })?;
+
+    let payout_update = storage::PayoutUpdate::Update {
+        description: req.description,
+        return_url: req.return_url,
+        metadata: req.metadata,
+    };
+
+    let response = db
+        .update_payout(payout, payout_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Unable to update payout with payout_id: {payout_id}"))?;
+
+    Ok(services::ApplicationResponse::Json(response.foreign_into()))
+}
+
+// ********************************************** PAYOUT CANCEL **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_cancel_core(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutActionRequest,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let payout = find_payout(state, &merchant_account, &req.payout_id).await?;
+    payout_action_core::<api::PoCancel>(
+        state,
+        merchant_account,
+        payout,
+        enums::PayoutStatus::RequiresFulfillment,
+        "cancelled",
+    )
+    .await
+}
+
+// ********************************************** PAYOUT FULFILL **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_fulfill_core(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutActionRequest,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let payout = find_payout(state, &merchant_account, &req.payout_id).await?;
+    payout_action_core::<api::PoFulfill>(
+        state,
+        merchant_account,
+        payout,
+        enums::PayoutStatus::RequiresFulfillment,
+        "fulfilled",
+    )
+    .await
+}
+
+// ********************************************** PAYOUT REVERSE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_reverse_core(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutActionRequest,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let payout = find_payout(state, &merchant_account, &req.payout_id).await?;
+    validate_payout_flow_support(&payout, "PayoutReverse")?;
+    payout_action_core::<api::PoReverse>(
+        state,
+        merchant_account,
+        payout,
+        enums::PayoutStatus::Success,
+        "reversed",
+    )
+    .await
+}
+
+async fn payout_action_core<F>(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    payout: storage::Payout,
+    required_status: enums::PayoutStatus,
+    action: &str,
+) -> RouterResponse<payouts::PayoutResponse>
+where
+    F: Clone + std::fmt::Debug + Send + Sync + 'static,
+    dyn api::Connector + Sync:
+        services::ConnectorIntegration<F, types::PayoutsData, types::PayoutsResponseData>,
+{
+    utils::when(payout.status != required_status, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Payout {} cannot be {} as it is in {} status",
+                payout.payout_id, action, payout.status
+            ),
+        }))
+    })?;
+
+    let payout = trigger_payout_to_gateway::<F>(
+        state,
+        &merchant_account,
+        &payout,
+        None,
+        PaymentAddress::default(),
+        (None, None),
+    )
+    .await?;
+
+    let response: payouts::PayoutResponse = payout.foreign_into();
+    trigger_payout_outgoing_webhook(state, merchant_account, response.clone()).await;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+// ********************************************** HELPERS **********************************************
+
+async fn find_payout(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payout_id: &str,
+) -> RouterResult<storage::Payout> {
+    state
+        .store
+        .find_payout_by_merchant_id_payout_id(&merchant_account.merchant_id, payout_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)
+}
+
+#[instrument(skip_all)]
+pub async fn trigger_payout_to_gateway<F>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payout: &storage::Payout,
+    payout_method_data: Option<api::PayoutMethodData>,
+    address: PaymentAddress,
+    recipient: (
+        Option<common_utils::pii::Email>,
+        Option<masking::Secret<String>>,
+    ),
+) -> RouterResult<storage::Payout>
+where
+    F: Clone + std::fmt::Debug + Send + Sync + 'static,
+    dyn api::Connector + Sync:
+        services::ConnectorIntegration<F, types::PayoutsData, types::PayoutsResponseData>,
+{
+    let routed_through = payout
+        .connector
+        .clone()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Failed to retrieve connector from payout")?;
+
+    let connector: api::ConnectorData = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &routed_through,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector")?;
+
+    let mut router_data = core_utils::construct_payout_router_data::<F>(
+        state,
+        &routed_through,
+        merchant_account,
+        payout,
+        payout_method_data,
+        address,
+        recipient,
+    )
+    .await?;
+
+    let add_access_token_result =
+        access_token::add_access_token(state, &connector, merchant_account, &router_data).await?;
+
+    access_token::update_router_data_with_access_token_result(
+        &add_access_token_result,
+        &mut router_data,
+        &payments::CallConnectorAction::Trigger,
+    );
+
+    let router_data_res = if !(add_access_token_result.connector_supports_access_token
+        && router_data.access_token.is_none())
+    {
+        let connector_integration: services::BoxedConnectorIntegration<
+            '_,
+            F,
+            types::PayoutsData,
+            types::PayoutsResponseData,
+        > = connector.connector.get_connector_integration();
+        let result = services::execute_connector_processing_step(
+            state,
+            connector_integration,
+            &router_data,
+            payments::CallConnectorAction::Trigger,
+        )
+        .await;
+
+        match result {
+            Ok(router_data_res) => router_data_res,
+            Err(error) => {
+                if let Some(payout_update) = get_payout_update_on_connector_failure(payout.status) {
+                    if let Err(update_error) = update_payout(state, payout, payout_update).await {
+                        logger::error!(?update_error, "Failed to mark the payout as failed");
+                    }
+                }
+                return Err(error.to_payout_failed_response());
+            }
+        }
+    } else {
+        router_data
+    };
+
+    let payout_update = get_payout_update(payout.status, router_data_res.response);
+    update_payout(state, payout, payout_update).await
+}
+
+async fn update_payout(
+    state: &AppState,
+    payout: &storage::Payout,
+    payout_update: storage::PayoutUpdate,
+) -> RouterResult<storage::Payout> {
+    state
+        .store
+        .update_payout(payout.to_owned(), payout_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while updating payout: payout_id: {}",
+                payout.payout_id
+            )
+        })
+}
+
+/// The update to the payout from the response of the connector. An error response only fails a
+/// payout which was not yet created at the connector, a payout the connector refused to fulfill,
+/// cancel or reverse remains in its current status with the error recorded.
+fn get_payout_update(
+    status: enums::PayoutStatus,
+    response: Result<types::PayoutsResponseData, types::ErrorResponse>,
+) -> storage::PayoutUpdate {
+    match response {
+        Err(err) => storage::PayoutUpdate::ErrorUpdate {
+            status: match status {
+                enums::PayoutStatus::RequiresCreation => enums::PayoutStatus::Failed,
+                status => status,
+            },
+            error_code: Some(err.code),
+            error_message: Some(err.message),
+        },
+        Ok(response) => storage::PayoutUpdate::StatusUpdate {
+            status: response.status,
+            connector_payout_id: Some(response.connector_payout_id),
+        },
+    }
+}
+
+/// The update to the payout when the connector could not be called or its response could not be
+/// processed. A payout which is still being created is failed, as it would otherwise remain in
+/// `RequiresCreation` forever. Adyen only pays out a submitted payout once it is confirmed, so a
+/// payout whose creation failed this way is never paid out. Any other flow can be retried.
+fn get_payout_update_on_connector_failure(
+    status: enums::PayoutStatus,
+) -> Option<storage::PayoutUpdate> {
+    (status == enums::PayoutStatus::RequiresCreation).then(|| storage::PayoutUpdate::ErrorUpdate {
+        status: enums::PayoutStatus::Failed,
+        error_code: None,
+        error_message: Some("Payout creation failed while processing with connector".to_string()),
+    })
+}
+
+/// A payout is synced with the connector only when requested, and once the connector knows of it
+fn should_sync_payout(
+    status: enums::PayoutStatus,
+    connector_payout_id: Option<&str>,
+    force_sync: bool,
+) -> bool {
+    force_sync && connector_payout_id.is_some() && !is_payout_terminal_state(status)
+}
+
+/// Use the connector passed in the request, else fall back to the merchant's routing algorithm
+fn get_connector_name(
+    merchant_account: &domain::MerchantAccount,
+    payout_id: &str,
+    req: &payouts::PayoutCreateRequest,
+) -> RouterResult<api_enums::Connector> {
+    if let Some(connector) = req.connector {
+        return Ok(connector);
+    }
+
+    let routing_algorithm = merchant_account
+        .routing_algorithm
+        .clone()
+        .get_required_value("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::PreconditionFailed {
+            message: "no routing algorithm has been configured".to_string(),
+        })?
+        .parse_value::<api::RoutingAlgorithm>("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to deserialize merchant routing algorithm")?;
+
+    let routing_context = routing::RoutingContext {
+        routing_key: Some(payout_id.to_string()),
+        amount: Some(req.amount),
+        currency: Some(req.currency),
+        billing_country: req
+            .billing
+            .as_ref()
+            .and_then(|billing| billing.address.as_ref())
+            .and_then(|address| address.country),
+        metadata: req
+            .metadata
+            .as_ref()
+            .map(|metadata| metadata.peek().clone()),
+        ..Default::default()
+    };
+
+    let connector_name =
+        routing::get_connectors_from_routing_algorithm(&routing_algorithm, &routing_context)
+            .first()
+            .map(ToString::to_string)
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Routing algorithm did not yield any connector")?;
+
+    api_enums::Connector::from_str(&connector_name)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Routing algorithm gave invalid connector")
+}
+
+/// Connectors which do not implement the payout flows would silently skip the connector call,
+/// hence the connector is validated before the payout is created
+fn validate_payout_connector(connector: api_enums::Connector) -> RouterResult<()> {
+    match connector {
+        api_enums::Connector::Adyen => Ok(()),
+        _ => Err(report!(errors::ApiErrorResponse::FlowNotSupported {
+            flow: "Payouts".to_string(),
+            connector: connector.to_string(),
+        })),
+    }
+}
+
+/// Adyen offers no API to retrieve or to reverse a payout, the status of its payouts is only
+/// updated through incoming webhooks. Syncing or reversing such a payout is rejected up front, as the
+/// connector call would otherwise fail or leave the payout unchanged.
+fn validate_payout_flow_support(payout: &storage::Payout, flow: &str) -> RouterResult<()> {
+    let connector = payout.connector.as_deref().unwrap_or_default();
+    match api_enums::Connector::from_str(connector) {
+        Ok(api_enums::Connector::Adyen) => {
+            Err(report!(errors::ApiErrorResponse::FlowNotSupported {
+                flow: flow.to_string(),
+                connector: connector.to_string(),
+            }))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn is_payout_terminal_state(status: enums::PayoutStatus) -> bool {
+    matches!(
+        status,
+        enums::PayoutStatus::Success
+            | enums::PayoutStatus::Failed
+            | enums::PayoutStatus::Cancelled
+            | enums::PayoutStatus::Reversed
+    )
+}
+
+/// Only a successful payout can be reversed, every other final status is reached from a
+/// non-terminal one
+pub fn is_payout_status_transition_allowed(
+    current_status: enums::PayoutStatus,
+    new_status: enums::PayoutStatus,
+) -> bool {
+    match new_status {
+        enums::PayoutStatus::Reversed => current_status == enums::PayoutStatus::Success,
+        _ => !is_payout_terminal_state(current_status) && current_status != new_status,
+    }
+}
+
+async fn trigger_payout_outgoing_webhook(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    payout_response: payouts::PayoutResponse,
+) {
+    let payout_status: enums::PayoutStatus = payout_response.status.foreign_into();
+    let event_type: enums::EventType = match payout_status.foreign_try_into() {
+        Ok(event_type) => event_type,
+        Err(_) => return,
+    };
+
+    let payout_id = payout_response.payout_id.clone();
+    let result = webhooks::create_event_and_trigger_outgoing_webhook::<api::OutgoingWebhook>(
+        state.clone(),
+        merchant_account,
+        event_type,
+        enums::EventClass::Payouts,
+        None,
+        payout_id,
+        enums::EventObjectType::PayoutDetails,
+        api::OutgoingWebhookContent::PayoutDetails(payout_response),
+    )
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(payout_outgoing_webhook_error=?error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_payout_connector_support() {
+        assert!(validate_payout_connector(api_enums::Connector::Adyen).is_ok());
+        assert!(validate_payout_connector(api_enums::Connector::Stripe).is_err());
+    }
+
+    #[test]
+    fn payout_sync_and_reverse_are_rejected_for_adyen() {
+        let payout = storage::Payout {
+            id: 1,
+            payout_id: "payout_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            customer_id: None,
+            connector: Some("adyen".to_string()),
+            connector_payout_id: Some("connector_payout_1".to_string()),
+            amount: 1000,
+            currency: enums::Currency::EUR,
+            payout_type: enums::PayoutType::Card,
+            status: enums::PayoutStatus::Success,
+            auto_fulfill: true,
+            description: None,
+            business_country: None,
+            business_label: None,
+            return_url: None,
+            metadata: None,
+            error_code: None,
+            error_message: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+        };
+
+        assert!(validate_payout_flow_support(&payout, "PayoutReverse").is_err());
+        assert!(validate_payout_flow_support(&payout, "PayoutSync").is_err());
+    }
+
+    #[test]
+    fn payout_webhook_status_transitions() {
+        assert!(is_payout_status_transition_allowed(
+            enums::PayoutStatus::Pending,
+            enums::PayoutStatus::Success
+        ));
+        assert!(is_payout_status_transition_allowed(
+            enums::PayoutStatus::Success,
+            enums::PayoutStatus::Reversed
+        ));
+        assert!(!is_payout_status_transition_allowed(
+            enums::PayoutStatus::Success,
+            enums::PayoutStatus::Failed
+        ));
+        assert!(!is_payout_status_transition_allowed(
+            enums::PayoutStatus::Pending,
+            enums::PayoutStatus::Reversed
+        ));
+        assert!(!is_payout_status_transition_allowed(
+            enums::PayoutStatus::Reversed,
+            enums::PayoutStatus::Reversed
+        ));
+    }
+
+    #[test]
+    fn payout_terminal_states() {
+        assert!(is_payout_terminal_state(enums::PayoutStatus::Success));
+        assert!(is_payout_terminal_state(enums::PayoutStatus::Cancelled));
+        assert!(is_payout_terminal_state(enums::PayoutStatus::Reversed));
+        assert!(!is_payout_terminal_state(
+            enums::PayoutStatus::RequiresFulfillment
+        ));
+    }
+
+    fn connector_error_response() -> Result<types::PayoutsResponseData, types::ErrorResponse> {
+        Err(types::ErrorResponse {
+            code: "Refused".to_string(),
+            message: "Not enough balance".to_string(),
+            reason: None,
+            status_code: 422,
+        })
+    }
+
+    #[test]
+    fn payout_creation_fails_on_connector_error_response() {
+        let payout_update = get_payout_update(
+            enums::PayoutStatus::RequiresCreation,
+            connector_error_response(),
+        );
+
+        assert!(matches!(
+            payout_update,
+            storage::PayoutUpdate::ErrorUpdate {
+                status: enums::PayoutStatus::Failed,
+                error_code: Some(ref code),
+                ..
+            } if code == "Refused"
+        ));
+    }
+
+    #[test]
+    fn payout_action_keeps_status_on_connector_error_response() {
+        let payout_update = get_payout_update(
+            enums::PayoutStatus::RequiresFulfillment,
+            connector_error_response(),
+        );
+        assert!(matches!(
+            payout_update,
+            storage::PayoutUpdate::ErrorUpdate {
+                status: enums::PayoutStatus::RequiresFulfillment,
+                ..
+            }
+        ));
+
+        let payout_update =
+            get_payout_update(enums::PayoutStatus::Success, connector_error_response());
+        assert!(matches!(
+            payout_update,
+            storage::PayoutUpdate::ErrorUpdate {
+                status: enums::PayoutStatus::Success,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn payout_status_follows_connector_response() {
+        let payout_update = get_payout_update(
+            enums::PayoutStatus::RequiresCreation,
+            Ok(types::PayoutsResponseData {
+                connector_payout_id: "connector_payout_1".to_string(),
+                status: enums::PayoutStatus::RequiresFulfillment,
+            }),
+        );
+
+        assert!(matches!(
+            payout_update,
+            storage::PayoutUpdate::StatusUpdate {
+                status: enums::PayoutStatus::RequiresFulfillment,
+                connector_payout_id: Some(ref connector_payout_id),
+            } if connector_payout_id == "connector_payout_1"
+        ));
+    }
+
+    #[test]
+    fn payout_creation_fails_when_connector_call_fails() {
+        assert!(matches!(
+            get_payout_update_on_connector_failure(enums::PayoutStatus::RequiresCreation),
+            Some(storage::PayoutUpdate::ErrorUpdate {
+                status: enums::PayoutStatus::Failed,
+                ..
+            })
+        ));
+        assert!(
+            get_payout_update_on_connector_failure(enums::PayoutStatus::RequiresFulfillment)
+                .is_none()
+        );
+        assert!(get_payout_update_on_connector_failure(enums::PayoutStatus::Success).is_none());
+    }
+
+    #[test]
+    fn payout_sync_conditions() {
+        assert!(should_sync_payout(
+            enums::PayoutStatus::Pending,
+            Some("connector_payout_1"),
+            true
+        ));
+        assert!(!should_sync_payout(
+            enums::PayoutStatus::Pending,
+            Some("connector_payout_1"),
+            false
+        ));
+        assert!(!should_sync_payout(
+            enums::PayoutStatus::RequiresCreation,
+            None,
+            true
+        ));
+        assert!(!should_sync_payout(
+            enums::PayoutStatus::Cancelled,
+            Some("connector_payout_1"),
+            true
+        ));
+    }
+}
@@ -1,9 +1,10 @@
 use std::marker::PhantomData;
 
 use api_models::enums::{DisputeStage, DisputeStatus};
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, pii::Email};
 use error_stack::{IntoReport, ResultExt};
-use router_env::{instrument, tracing};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
 
 use super::payments::{helpers, PaymentAddress};
 use crate::{
@@ -11,7 +12,7 @@ use crate::{
     core::errors::{self, RouterResult},
     routes::AppState,
     types::{
-        self, domain,
+        self, api, domain,
         storage::{self, enums},
         ErrorResponse,
     },
@@ -109,6 +110,189 @@ pub async fn construct_refund_router_data<'a, F>(
     Ok(router_data)
 }
 
+#[instrument(skip_all)]
+pub async fn construct_payout_router_data<'a, F>(
+    state: &'a AppState,
+    connector_id: &str,
+    merchant_account: &domain::MerchantAccount,
+    payout: &'a storage::Payout,
+    payout_method_data: Option<api::PayoutMethodData>,
+    address: PaymentAddress,
+    recipient: (Option<Email>, Option<Secret<String>>),
+) -> RouterResult<types::PayoutsRouterData<F>> {
+    let (business_country, business_label) = helpers::get_business_details(
+        payout.business_country,
+        payout.business_label.as_ref(),
+        merchant_account,
+    )?;
+
+    let connector_label =
+        helpers::get_connector_label(business_country, &business_label, None, connector_id);
+
+    let merchant_connector_account = helpers::get_merchant_connector_account(
+        state,
+        merchant_account.merchant_id.as_str(),
+        &connector_label,
+        None,
+    )
+    .await?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .get_connector_account_details()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let payment_method = match payout.payout_type {
+        enums::PayoutType::Card => enums::PaymentMethod::Card,
+        enums::PayoutType::Bank => enums::PaymentMethod::BankTransfer,
+    };
+
+    let (email, name) = recipient;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        customer_id: payout.customer_id.to_owned(),
+        connector: connector_id.to_string(),
+        // Payouts are not tied to a payment, the payout_id is used as the reference instead
+        payment_id: payout.payout_id.clone(),
+        attempt_id: payout.payout_id.clone(),
+        status: enums::AttemptStatus::default(),
+        payment_method,
+        connector_auth_type: auth_type,
+        description: payout.description.clone(),
+        return_url: payout.return_url.clone(),
+        payment_method_id: None,
+        address,
+        auth_type: enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.get_metadata(),
+        amount_captured: None,
+        request: types::PayoutsData {
+            payout_id: payout.payout_id.clone(),
+            amount: payout.amount,
+            currency: payout.currency,
+            payout_type: payout.payout_type,
+            payout_method_data,
+            connector_payout_id: payout.connector_payout_id.clone(),
+            email,
+            name,
+        },
+        response: Ok(types::PayoutsResponseData {
+            connector_payout_id: payout.connector_payout_id.clone().unwrap_or_default(),
+            status: payout.status,
+        }),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        connector_customer: None,
+        preprocessing_id: None,
+    };
+
+    Ok(router_data)
+}
+
+/// Returns `None` when the mandate can no longer be revoked at the connector, as its connector
+/// account has been disabled or its payment method deleted
+#[instrument(skip_all)]
+pub async fn construct_mandate_revoke_router_data(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    mandate: &storage::Mandate,
+) -> RouterResult<Option<types::MandateRevokeRouterData>> {
+    let db = &*state.store;
+    // Mandates do not record the business details of the payment they were set up with, hence
+    // the enabled connector account of the mandate's connector is used
+    let merchant_connector_account = match db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &merchant_account.merchant_id,
+            false,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching merchant connector accounts")?
+        .into_iter()
+        .find(|mca| mca.connector_name == mandate.connector)
+    {
+        Some(merchant_connector_account) => merchant_connector_account,
+        None => {
+            logger::info!(
+                "No enabled connector account for {}, mandate {} can only be revoked locally",
+                mandate.connector,
+                mandate.mandate_id
+            );
+            return Ok(None);
+        }
+    };
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .peek()
+        .clone()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let payment_method = match db.find_payment_method(&mandate.payment_method_id).await {
+        Ok(payment_method) => payment_method.payment_method,
+        Err(error) if error.current_context().is_db_not_found() => {
+            logger::info!(
+                "Payment method {} has been deleted, mandate {} can only be revoked locally",
+                mandate.payment_method_id,
+                mandate.mandate_id
+            );
+            return Ok(None);
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while fetching the payment method of the mandate")
+        }
+    };
+    let connector_mandate_id = mandate
+        .connector_mandate_ids
+        .clone()
+        .map(|ids| ids.parse_value::<types::MandateReference>("MandateReference"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .and_then(|mandate_reference| mandate_reference.connector_mandate_id)
+        .or_else(|| mandate.connector_mandate_id.clone());
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        customer_id: Some(mandate.customer_id.clone()),
+        connector: mandate.connector.clone(),
+        // Mandates are not tied to a single payment, the mandate_id is used as the reference instead
+        payment_id: mandate.mandate_id.clone(),
+        attempt_id: mandate.mandate_id.clone(),
+        status: enums::AttemptStatus::default(),
+        payment_method,
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: None,
+        payment_method_id: Some(mandate.payment_method_id.clone()),
+        address: PaymentAddress::default(),
+        auth_type: enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.metadata,
+        amount_captured: None,
+        request: types::MandateRevokeRequestData {
+            mandate_id: mandate.mandate_id.clone(),
+            connector_mandate_id,
+        },
+        // Connectors which do not support revoking mandates leave the response untouched, in
+        // which case the mandate is only revoked on our end
+        response: Ok(types::MandateRevokeResponseData {
+            mandate_status: enums::MandateStatus::Revoked,
+        }),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        connector_customer: None,
+        preprocessing_id: None,
+    };
+
+    Ok(Some(router_data))
+}
+
 pub fn get_or_generate_id(
     key: &str,
     provided_id: &Option<String>,
@@ -1,6 +1,9 @@
+pub mod events;
 pub mod transformers;
 pub mod utils;
 
+use std::time::Instant;
+
 use common_utils::{crypto::SignMessage, ext_traits};
 use error_stack::{report, IntoReport, ResultExt};
 use masking::ExposeInterface;
@@ -11,14 +14,20 @@ use super::{errors::StorageErrorExt, metrics};
 use crate::{
     consts,
     core::{
+        disputes,
         errors::{self, CustomResult, RouterResponse},
-        payments, refunds,
+        payments, payouts, refunds,
     },
+    db::StorageInterface,
     headers, logger,
     routes::AppState,
+    scheduler::{
+        metrics as scheduler_metrics, utils as pt_utils, workflows::outgoing_webhook_retry,
+    },
     services,
     types::{
-        api, domain,
+        api::{self, mandates::MandateResponseExt},
+        domain,
         storage::{self, enums},
         transformers::{ForeignInto, ForeignTryInto},
     },
@@ -26,6 +35,7 @@ use crate::{
 };
 
 const OUTGOING_WEBHOOK_TIMEOUT_SECS: u64 = 5;
+const OUTGOING_WEBHOOK_RESPONSE_BODY_MAX_CHARS: usize = 1024;
 
 #[instrument(skip_all)]
 pub async fn payments_incoming_webhook_flow<W: api::OutgoingWebhookType>(
@@ -53,6 +63,7 @@ pub async fn payments_incoming_webhook_flow<W: api::OutgoingWebhookType>(
                     connector: None,
                     param: None,
                     merchant_connector_details: None,
+                    capture_id: None,
                 },
                 services::AuthFlow::Merchant,
                 consume_or_trigger_flow,
@@ -250,6 +261,7 @@ pub async fn get_or_update_dispute_object(
     option_dispute: Option<storage_models::dispute::Dispute>,
     dispute_details: api::disputes::DisputePayload,
     merchant_id: &str,
+    storage_scheme: enums::MerchantStorageScheme,
     payment_attempt: &storage_models::payment_attempt::PaymentAttempt,
     event_type: api_models::webhooks::IncomingWebhookEvent,
     connector_name: &str,
@@ -282,11 +294,29 @@ pub async fn get_or_update_dispute_object(
                 connector_updated_at: dispute_details.updated_at,
                 evidence: None,
             };
-            state
+            let dispute = state
                 .store
-                .insert_dispute(new_dispute.clone())
+                .insert_dispute(new_dispute.clone(), storage_scheme)
                 .await
-                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+
+            #[cfg(feature = "email")]
+            crate::core::notifications::notify_merchant(
+                db,
+                merchant_id,
+                storage::EmailNotification::DisputeOpened {
+                    dispute_id: dispute.dispute_id.clone(),
+                    payment_id: dispute.payment_id.clone(),
+                    amount: dispute.amount.clone(),
+                    currency: dispute.currency.clone(),
+                    challenge_required_by: dispute.challenge_required_by,
+                },
+            )
+            .await;
+
+            disputes::deadline::track_dispute_deadline(db, &dispute).await;
+
+            Ok(dispute)
         }
         Some(dispute) => {
             logger::info!("Dispute Already exists, Updating the dispute details");
@@ -313,9 +343,15 @@ pub async fn get_or_update_dispute_object(
                 challenge_required_by: dispute_details.challenge_required_by,
                 connector_updated_at: dispute_details.updated_at,
             };
-            db.update_dispute(dispute, update_dispute)
+            let dispute = db
+                .update_dispute(dispute, update_dispute, storage_scheme)
                 .await
-                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+            // The deadline may have been set only now, otherwise the existing tracking picks up
+            // changes to it
+            disputes::deadline::track_dispute_deadline(db, &dispute).await;
+
+            Ok(dispute)
         }
     }
 }
@@ -345,6 +381,7 @@ pub async fn disputes_incoming_webhook_flow<W: api::OutgoingWebhookType>(
                 &merchant_account.merchant_id,
                 &payment_attempt.payment_id,
                 &dispute_details.connector_dispute_id,
+                merchant_account.storage_scheme,
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
@@ -353,6 +390,7 @@ pub async fn disputes_incoming_webhook_flow<W: api::OutgoingWebhookType>(
             option_dispute,
             dispute_details,
             &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
             &payment_attempt,
             event_type.clone(),
             connector.id(),
@@ -384,6 +422,150 @@ pub async fn disputes_incoming_webhook_flow<W: api::OutgoingWebhookType>(
     }
 }
 
+#[instrument(skip_all)]
+pub async fn mandates_incoming_webhook_flow<W: api::OutgoingWebhookType>(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    event_type: api_models::webhooks::IncomingWebhookEvent,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    // Mandates cannot be synced with the connector, hence unverified webhooks are rejected
+    if !source_verified {
+        return Err(errors::ApiErrorResponse::WebhookAuthenticationFailed).into_report();
+    }
+
+    let db = &*state.store;
+    let mandate = match webhook_details.object_reference_id {
+        api_models::webhooks::ObjectReferenceId::MandateId(
+            api_models::webhooks::MandateIdType::MandateId(mandate_id),
+        ) => db
+            .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, &mandate_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?,
+        api_models::webhooks::ObjectReferenceId::MandateId(
+            api_models::webhooks::MandateIdType::ConnectorMandateId(connector_mandate_id),
+        ) => db
+            .find_mandate_by_merchant_id_connector_mandate_id(
+                &merchant_account.merchant_id,
+                &connector_mandate_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?,
+        _ => Err(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .into_report()
+            .attach_printable("received a non-mandate id when processing mandate webhooks")?,
+    };
+    let mandate_status: enums::MandateStatus = event_type
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+        .attach_printable("event type to mandate status mapping failed")?;
+    let updated_mandate = db
+        .update_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            &mandate.mandate_id,
+            storage::MandateUpdate::StatusUpdate { mandate_status },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+
+    let mandate_id = updated_mandate.mandate_id.clone();
+    let event_type: enums::EventType = updated_mandate
+        .mandate_status
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+        .attach_printable("mandate status to event type mapping failed")?;
+    let mandate_response =
+        api::mandates::MandateResponse::from_db_mandate(&state, updated_mandate, &merchant_account)
+            .await?;
+    create_event_and_trigger_outgoing_webhook::<W>(
+        state,
+        merchant_account,
+        event_type,
+        enums::EventClass::Mandates,
+        None,
+        mandate_id,
+        enums::EventObjectType::MandateDetails,
+        api::OutgoingWebhookContent::MandateDetails(Box::new(mandate_response)),
+    )
+    .await?;
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn payouts_incoming_webhook_flow<W: api::OutgoingWebhookType>(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    event_type: api_models::webhooks::IncomingWebhookEvent,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    // Payouts are not synced with the connector, hence unverified webhooks are rejected
+    if !source_verified {
+        return Err(errors::ApiErrorResponse::WebhookAuthenticationFailed).into_report();
+    }
+
+    let db = &*state.store;
+    let payout = match webhook_details.object_reference_id {
+        api_models::webhooks::ObjectReferenceId::PayoutId(payout_id) => db
+            .find_payout_by_merchant_id_payout_id(&merchant_account.merchant_id, &payout_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?,
+        _ => Err(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .into_report()
+            .attach_printable("received a non-payout id when processing payout webhooks")?,
+    };
+    let payout_status: enums::PayoutStatus = event_type
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+        .attach_printable("event type to payout status mapping failed")?;
+
+    // Redelivered or out of order notifications must not move a payout out of its final status
+    if !payouts::is_payout_status_transition_allowed(payout.status, payout_status) {
+        logger::info!(
+            payout_id = %payout.payout_id,
+            current_status = %payout.status,
+            webhook_status = %payout_status,
+            "Ignoring payout webhook"
+        );
+        return Ok(());
+    }
+
+    let updated_payout = db
+        .update_payout(
+            payout,
+            storage::PayoutUpdate::StatusUpdate {
+                status: payout_status,
+                connector_payout_id: None,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+
+    let payout_id = updated_payout.payout_id.clone();
+    let event_type: enums::EventType = updated_payout
+        .status
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+        .attach_printable("payout status to event type mapping failed")?;
+    create_event_and_trigger_outgoing_webhook::<W>(
+        state,
+        merchant_account,
+        event_type,
+        enums::EventClass::Payouts,
+        None,
+        payout_id,
+        enums::EventObjectType::PayoutDetails,
+        api::OutgoingWebhookContent::PayoutDetails(updated_payout.foreign_into()),
+    )
+    .await?;
+    Ok(())
+}
+
 async fn bank_transfer_webhook_flow<W: api::OutgoingWebhookType>(
     state: AppState,
     merchant_account: domain::MerchantAccount,
@@ -477,6 +659,7 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: api::OutgoingWebhookTy
         intent_reference_id,
         primary_object_id,
         primary_object_type,
+        merchant_id: Some(merchant_account.merchant_id.clone()),
     };
 
     let event = state
@@ -500,25 +683,9 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: api::OutgoingWebhookTy
             timestamp: event.created_at,
         };
 
-        let webhook_signature_payload =
-            ext_traits::Encode::<serde_json::Value>::encode_to_string_of_json(&outgoing_webhook)
-                .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-                .attach_printable("failed encoding outgoing webhook payload")?;
-
-        let outgoing_webhooks_signature = merchant_account
-            .payment_response_hash_key
-            .clone()
-            .map(|key| {
-                common_utils::crypto::HmacSha512::sign_message(
-                    &common_utils::crypto::HmacSha512,
-                    key.as_bytes(),
-                    webhook_signature_payload.as_bytes(),
-                )
-            })
-            .transpose()
-            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("Failed to sign the message")?
-            .map(hex::encode);
+        let outgoing_webhooks_signature =
+            get_outgoing_webhook_signature(&merchant_account, &outgoing_webhook)
+                .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)?;
 
         arbiter.spawn(async move {
             let result = trigger_webhook_to_merchant::<W>(
@@ -526,6 +693,7 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: api::OutgoingWebhookTy
                 outgoing_webhook,
                 outgoing_webhooks_signature,
                 &state,
+                false,
             )
             .await;
 
@@ -538,14 +706,99 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: api::OutgoingWebhookTy
     Ok(())
 }
 
+/// Signs the outgoing webhook with the payment response hash key of the merchant, if configured
+pub fn get_outgoing_webhook_signature(
+    merchant_account: &domain::MerchantAccount,
+    outgoing_webhook: &api::OutgoingWebhook,
+) -> CustomResult<Option<String>, errors::WebhooksFlowError> {
+    let webhook_signature_payload =
+        ext_traits::Encode::<serde_json::Value>::encode_to_string_of_json(outgoing_webhook)
+            .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+            .attach_printable("failed encoding outgoing webhook payload")?;
+
+    sign_outgoing_webhook_payload(merchant_account, &webhook_signature_payload)
+}
+
+/// Signs an already encoded outgoing webhook payload with the payment response hash key of the
+/// merchant, if configured
+pub fn sign_outgoing_webhook_payload(
+    merchant_account: &domain::MerchantAccount,
+    webhook_signature_payload: &str,
+) -> CustomResult<Option<String>, errors::WebhooksFlowError> {
+    merchant_account
+        .payment_response_hash_key
+        .clone()
+        .map(|key| {
+            common_utils::crypto::HmacSha512::sign_message(
+                &common_utils::crypto::HmacSha512,
+                key.as_bytes(),
+                webhook_signature_payload.as_bytes(),
+            )
+        })
+        .transpose()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign the message")
+        .map(|signature| signature.map(hex::encode))
+}
+
+/// Delivers the outgoing webhook to the merchant. Failed automatic deliveries are scheduled for
+/// retry, manual retries are not, since the merchant triggered them and sees their outcome.
 pub async fn trigger_webhook_to_merchant<W: api::OutgoingWebhookType>(
     merchant_account: domain::MerchantAccount,
     webhook: api::OutgoingWebhook,
     outgoing_webhooks_signature: Option<String>,
     state: &AppState,
+    is_manual_retry: bool,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let outgoing_webhook_event_id = webhook.event_id.clone();
+
+    let transformed_outgoing_webhook = W::from(webhook);
+
+    let transformed_outgoing_webhook_string =
+        Encode::<serde_json::Value>::encode_to_string_of_json(&transformed_outgoing_webhook)
+            .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+            .attach_printable("There was an issue when encoding the outgoing webhook body")?;
+
+    let delivery_result = send_webhook_to_merchant(
+        state,
+        &merchant_account,
+        &outgoing_webhook_event_id,
+        transformed_outgoing_webhook_string,
+        outgoing_webhooks_signature,
+        is_manual_retry,
+    )
+    .await;
+
+    if let Err(error) = &delivery_result {
+        if !is_manual_retry && is_webhook_delivery_retryable(error.current_context()) {
+            let tracking_data = storage::OutgoingWebhookTrackingData {
+                merchant_id: merchant_account.merchant_id.clone(),
+                event_id: outgoing_webhook_event_id,
+            };
+
+            if let Err(error) = add_outgoing_webhook_retry_task(&*state.store, tracking_data).await
+            {
+                logger::error!(?error, "Failed to schedule outgoing webhook for retry");
+            }
+        }
+    }
+
+    delivery_result
+}
+
+/// Sends an already encoded outgoing webhook to the webhook URL of the merchant, records the
+/// delivery attempt and marks the event as notified once the merchant has acknowledged it.
+pub async fn send_webhook_to_merchant(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    event_id: &str,
+    request_body: String,
+    outgoing_webhooks_signature: Option<String>,
+    is_manual_retry: bool,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
     let webhook_details_json = merchant_account
         .webhook_details
+        .clone()
         .get_required_value("webhook_details")
         .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
 
@@ -560,15 +813,6 @@ pub async fn trigger_webhook_to_merchant<W: api::OutgoingWebhookType>(
         .change_context(errors::WebhooksFlowError::MerchantWebhookURLNotConfigured)
         .map(ExposeInterface::expose)?;
 
-    let outgoing_webhook_event_id = webhook.event_id.clone();
-
-    let transformed_outgoing_webhook = W::from(webhook);
-
-    let transformed_outgoing_webhook_string =
-        Encode::<serde_json::Value>::encode_to_string_of_json(&transformed_outgoing_webhook)
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
-            .attach_printable("There was an issue when encoding the outgoing webhook body")?;
-
     let mut header = vec![(
         reqwest::header::CONTENT_TYPE.to_string(),
         "application/json".into(),
@@ -583,31 +827,59 @@ pub async fn trigger_webhook_to_merchant<W: api::OutgoingWebhookType>(
         .url(&webhook_url)
         .attach_default_headers()
         .headers(header)
-        .body(Some(transformed_outgoing_webhook_string))
+        .body(Some(request_body.clone()))
         .build();
 
+    let request_started_at = Instant::now();
     let response =
         services::api::send_request(state, request, Some(OUTGOING_WEBHOOK_TIMEOUT_SECS)).await;
+    let latency_ms = i64::try_from(request_started_at.elapsed().as_millis()).ok();
 
     logger::debug!(outgoing_webhook_response=?response);
 
+    let mut delivery_attempt = storage::EventDeliveryAttemptNew {
+        attempt_id: generate_id(consts::ID_LENGTH, "whatt"),
+        event_id: event_id.to_owned(),
+        merchant_id: merchant_account.merchant_id.clone(),
+        request_body,
+        response_status_code: None,
+        response_body: None,
+        latency_ms,
+        error_message: None,
+        is_manual_retry,
+    };
+
     match response {
         Err(e) => {
-            // [#217]: Schedule webhook for retry.
+            delivery_attempt.error_message = Some(e.current_context().to_string());
+            record_delivery_attempt(state, delivery_attempt).await;
             Err(e).change_context(errors::WebhooksFlowError::CallToMerchantFailed)?;
         }
         Ok(res) => {
-            if res.status().is_success() {
+            let status = res.status();
+            delivery_attempt.response_status_code = i16::try_from(status.as_u16()).ok();
+            delivery_attempt.response_body = res
+                .text()
+                .await
+                .map(|body| {
+                    body.chars()
+                        .take(OUTGOING_WEBHOOK_RESPONSE_BODY_MAX_CHARS)
+                        .collect()
+                })
+                .map_err(|error| logger::warn!(?error, "Failed to read webhook response body"))
+                .ok();
+            record_delivery_attempt(state, delivery_attempt).await;
+
+            if status.is_success() {
                 let update_event = storage::EventUpdate::UpdateWebhookNotified {
                     is_webhook_notified: Some(true),
                 };
                 state
                     .store
-                    .update_event(outgoing_webhook_event_id, update_event)
+                    .update_event(event_id.to_owned(), update_event)
                     .await
                     .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)?;
             } else {
-                // [#217]: Schedule webhook for retry.
                 Err(errors::WebhooksFlowError::NotReceivedByMerchant).into_report()?;
             }
         }
@@ -616,6 +888,79 @@ pub async fn trigger_webhook_to_merchant<W: api::OutgoingWebhookType>(
     Ok(())
 }
 
+async fn record_delivery_attempt(
+    state: &AppState,
+    delivery_attempt: storage::EventDeliveryAttemptNew,
+) {
+    // The delivery outcome matters more than its audit trail, so a failed insert is only logged
+    if let Err(error) = state
+        .store
+        .insert_event_delivery_attempt(delivery_attempt)
+        .await
+    {
+        logger::error!(?error, "Failed to record outgoing webhook delivery attempt");
+    }
+}
+
+/// Failures of the merchant endpoint are retried, a missing webhook configuration is not
+pub fn is_webhook_delivery_retryable(error: &errors::WebhooksFlowError) -> bool {
+    matches!(
+        error,
+        errors::WebhooksFlowError::CallToMerchantFailed
+            | errors::WebhooksFlowError::NotReceivedByMerchant
+    )
+}
+
+pub async fn add_outgoing_webhook_retry_task(
+    db: &dyn StorageInterface,
+    tracking_data: storage::OutgoingWebhookTrackingData,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let runner = "OUTGOING_WEBHOOK_RETRY_WORKFLOW";
+    let task = "OUTGOING_WEBHOOK_RETRY";
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &tracking_data.event_id,
+        &tracking_data.merchant_id,
+    );
+
+    let schedule_time = outgoing_webhook_retry::get_outgoing_webhook_retry_schedule_time(
+        db,
+        &tracking_data.merchant_id,
+        0,
+    )
+    .await;
+
+    let current_time = common_utils::date_time::now();
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: process_tracker_id,
+        name: Some(String::from(task)),
+        tag: vec![String::from("WEBHOOKS")],
+        runner: Some(String::from(runner)),
+        retry_count: 0,
+        schedule_time,
+        rule: String::new(),
+        tracking_data: serde_json::to_value(tracking_data)
+            .into_report()
+            .change_context(errors::ProcessTrackerError::SerializationFailed)?,
+        business_status: String::from("Pending"),
+        status: enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            scheduler_metrics::TASKS_ADDED_COUNT.add(&scheduler_metrics::CONTEXT, 1, &[]);
+            Ok(())
+        }
+        // A retry is already scheduled for the event
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error.change_context(errors::ProcessTrackerError::ProcessUpdateFailed)),
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn webhooks_core<W: api::OutgoingWebhookType>(
     state: &AppState,
@@ -734,6 +1079,26 @@ pub async fn webhooks_core<W: api::OutgoingWebhookType>(
             .await
             .attach_printable("Incoming webhook flow for disputes failed")?,
 
+            api::WebhookFlow::Mandate => mandates_incoming_webhook_flow::<W>(
+                state.clone(),
+                merchant_account,
+                webhook_details,
+                source_verified,
+                event_type,
+            )
+            .await
+            .attach_printable("Incoming webhook flow for mandates failed")?,
+
+            api::WebhookFlow::Payout => payouts_incoming_webhook_flow::<W>(
+                state.clone(),
+                merchant_account,
+                webhook_details,
+                source_verified,
+                event_type,
+            )
+            .await
+            .attach_printable("Incoming webhook flow for payouts failed")?,
+
             api::WebhookFlow::BankTransfer => bank_transfer_webhook_flow::<W>(
                 state.clone(),
                 merchant_account,
@@ -0,0 +1,307 @@
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        customers,
+        errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+        payments,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    services,
+    types::{
+        api::{self, mandates::MandateResponseExt, webhook_events},
+        domain,
+        storage::{self, enums},
+        transformers::{ForeignFrom, ForeignInto},
+    },
+};
+
+#[instrument(skip(state))]
+pub async fn list_events(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    constraints: webhook_events::EventListConstraints,
+) -> RouterResponse<Vec<webhook_events::EventResponse>> {
+    list_events_by_merchant_id(state, &merchant_account.merchant_id, constraints).await
+}
+
+#[instrument(skip(state))]
+pub async fn list_events_for_merchant(
+    state: &AppState,
+    merchant_id: String,
+    constraints: webhook_events::EventListConstraints,
+) -> RouterResponse<Vec<webhook_events::EventResponse>> {
+    state
+        .store
+        .find_merchant_account_by_merchant_id(&merchant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    list_events_by_merchant_id(state, &merchant_id, constraints).await
+}
+
+async fn list_events_by_merchant_id(
+    state: &AppState,
+    merchant_id: &str,
+    constraints: webhook_events::EventListConstraints,
+) -> RouterResponse<Vec<webhook_events::EventResponse>> {
+    let events = state
+        .store
+        .list_events_by_merchant_id(merchant_id, constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve events")?;
+    let events_list = events
+        .into_iter()
+        .map(webhook_events::EventResponse::foreign_from)
+        .collect();
+    Ok(services::ApplicationResponse::Json(events_list))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_event(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    req: webhook_events::EventId,
+) -> RouterResponse<webhook_events::EventResponse> {
+    let event = find_event(state, &merchant_account.merchant_id, &req.event_id).await?;
+    let event_response = get_event_response_with_attempts(state, event).await?;
+    Ok(services::ApplicationResponse::Json(event_response))
+}
+
+/// The payload the webhook of an event was first delivered with. Payloads are not kept anywhere
+/// else, so that the redaction of a customer covers them, and `None` is returned once the payload
+/// has been redacted.
+pub async fn find_original_request_body(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    event_id: &str,
+) -> CustomResult<Option<String>, errors::StorageError> {
+    Ok(db
+        .find_event_delivery_attempts_by_merchant_id_event_id(merchant_id, event_id)
+        .await?
+        .into_iter()
+        .next()
+        .map(|delivery_attempt| delivery_attempt.request_body)
+        .filter(|request_body| request_body != customers::REDACTED))
+}
+
+/// Resends the webhook of an event to the merchant with the payload of its original delivery
+/// attempt, so that the merchant receives the webhook exactly as it was first sent. Events raised
+/// before delivery attempts were recorded, or whose payload has been redacted, have no stored
+/// payload, and their webhook is rebuilt from the current state of the object the event was raised
+/// for. The outcome of the delivery is reported as a new delivery attempt on the event.
+#[instrument(skip(state))]
+pub async fn retry_event<W: api::OutgoingWebhookType>(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    req: webhook_events::EventId,
+) -> RouterResponse<webhook_events::EventResponse> {
+    let event = find_event(state, &merchant_account.merchant_id, &req.event_id).await?;
+
+    let original_request_body = find_original_request_body(
+        &*state.store,
+        &merchant_account.merchant_id,
+        &event.event_id,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to retrieve event delivery attempts")?;
+
+    let delivery_result = match original_request_body {
+        Some(request_body) => {
+            let outgoing_webhooks_signature =
+                super::sign_outgoing_webhook_payload(&merchant_account, &request_body)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+            super::send_webhook_to_merchant(
+                state,
+                &merchant_account,
+                &event.event_id,
+                request_body,
+                outgoing_webhooks_signature,
+                true,
+            )
+            .await
+        }
+        None => {
+            let content = get_outgoing_webhook_content(state, &merchant_account, &event).await?;
+            let outgoing_webhook = api::OutgoingWebhook {
+                merchant_id: merchant_account.merchant_id.clone(),
+                event_id: event.event_id.clone(),
+                event_type: event.event_type.foreign_into(),
+                content,
+                timestamp: event.created_at,
+            };
+
+            let outgoing_webhooks_signature =
+                super::get_outgoing_webhook_signature(&merchant_account, &outgoing_webhook)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+            super::trigger_webhook_to_merchant::<W>(
+                merchant_account.clone(),
+                outgoing_webhook,
+                outgoing_webhooks_signature,
+                state,
+                true,
+            )
+            .await
+        }
+    };
+
+    if let Err(error) = delivery_result {
+        match error.current_context() {
+            error_context if super::is_webhook_delivery_retryable(error_context) => {
+                logger::info!(?error, "Manual retry of outgoing webhook failed");
+            }
+            errors::WebhooksFlowError::MerchantWebhookDetailsNotFound
+            | errors::WebhooksFlowError::MerchantWebhookURLNotConfigured => {
+                return Err(
+                    error.change_context(errors::ApiErrorResponse::PreconditionFailed {
+                        message: "Webhook URL is not configured for the merchant".to_string(),
+                    }),
+                );
+            }
+            _ => {
+                return Err(error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to resend the webhook of the event"));
+            }
+        }
+    }
+
+    let event = find_event(state, &merchant_account.merchant_id, &event.event_id).await?;
+    let event_response = get_event_response_with_attempts(state, event).await?;
+    Ok(services::ApplicationResponse::Json(event_response))
+}
+
+/// Builds the webhook content from the current state of the primary object of the event, for events
+/// whose webhook payload was never recorded
+async fn get_outgoing_webhook_content(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    event: &storage::Event,
+) -> RouterResult<api::OutgoingWebhookContent> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let object_id = &event.primary_object_id;
+
+    match event.primary_object_type {
+        enums::EventObjectType::PaymentDetails => {
+            let payments_response =
+                payments::payments_core::<api::PSync, api::PaymentsResponse, _, _, _>(
+                    state,
+                    merchant_account.clone(),
+                    payments::operations::PaymentStatus,
+                    api::PaymentsRetrieveRequest {
+                        resource_id: api::PaymentIdType::PaymentIntentId(object_id.clone()),
+                        merchant_id: Some(merchant_id.clone()),
+                        force_sync: false,
+                        connector: None,
+                        param: None,
+                        merchant_connector_details: None,
+                        capture_id: None,
+                    },
+                    services::AuthFlow::Merchant,
+                    payments::CallConnectorAction::Avoid,
+                )
+                .await?;
+
+            match payments_response {
+                services::ApplicationResponse::Json(payments_response) => Ok(
+                    api::OutgoingWebhookContent::PaymentDetails(payments_response),
+                ),
+                _ => Err(errors::ApiErrorResponse::InternalServerError)
+                    .into_report()
+                    .attach_printable("received non-json response from payments core"),
+            }
+        }
+        enums::EventObjectType::RefundDetails => {
+            let refund = db
+                .find_refund_by_merchant_id_refund_id(
+                    merchant_id,
+                    object_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+            Ok(api::OutgoingWebhookContent::RefundDetails(
+                refund.foreign_into(),
+            ))
+        }
+        enums::EventObjectType::DisputeDetails => {
+            let dispute = db
+                .find_dispute_by_merchant_id_dispute_id(
+                    merchant_id,
+                    object_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+                    dispute_id: object_id.clone(),
+                })?;
+            Ok(api::OutgoingWebhookContent::DisputeDetails(Box::new(
+                dispute.foreign_into(),
+            )))
+        }
+        enums::EventObjectType::PayoutDetails => {
+            let payout = db
+                .find_payout_by_merchant_id_payout_id(merchant_id, object_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+            Ok(api::OutgoingWebhookContent::PayoutDetails(
+                payout.foreign_into(),
+            ))
+        }
+        enums::EventObjectType::MandateDetails => {
+            let mandate = db
+                .find_mandate_by_merchant_id_mandate_id(merchant_id, object_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+            let mandate_response =
+                api::mandates::MandateResponse::from_db_mandate(state, mandate, merchant_account)
+                    .await?;
+            Ok(api::OutgoingWebhookContent::MandateDetails(Box::new(
+                mandate_response,
+            )))
+        }
+    }
+}
+
+async fn find_event(
+    state: &AppState,
+    merchant_id: &str,
+    event_id: &str,
+) -> RouterResult<storage::Event> {
+    state
+        .store
+        .find_event_by_merchant_id_event_id(merchant_id, event_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)
+}
+
+async fn get_event_response_with_attempts(
+    state: &AppState,
+    event: storage::Event,
+) -> RouterResult<webhook_events::EventResponse> {
+    let delivery_attempts = match event.merchant_id.as_deref() {
+        Some(merchant_id) => state
+            .store
+            .find_event_delivery_attempts_by_merchant_id_event_id(merchant_id, &event.event_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to retrieve event delivery attempts")?,
+        None => Vec::new(),
+    };
+
+    let mut event_response = webhook_events::EventResponse::foreign_from(event);
+    event_response.delivery_attempts = Some(
+        delivery_attempts
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect(),
+    );
+    Ok(event_response)
+}
@@ -13,6 +13,11 @@ fn default_webhook_config() -> api::MerchantWebhookConfig {
         api::IncomingWebhookEvent::PaymentIntentProcessing,
         api::IncomingWebhookEvent::PaymentActionRequired,
         api::IncomingWebhookEvent::RefundSuccess,
+        // Payouts of connectors without a retrieve API are only updated through webhooks
+        api::IncomingWebhookEvent::PayoutSuccess,
+        api::IncomingWebhookEvent::PayoutFailure,
+        api::IncomingWebhookEvent::PayoutCancelled,
+        api::IncomingWebhookEvent::PayoutReversed,
     ])
 }
 
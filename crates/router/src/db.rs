@@ -1,14 +1,19 @@
 pub mod address;
 pub mod api_keys;
 pub mod cache;
+pub mod capture;
 pub mod cards_info;
 pub mod configs;
 pub mod connector_response;
+pub mod customer_redaction;
 pub mod customers;
 pub mod dispute;
 pub mod ephemeral_key;
+pub mod event_delivery_attempt;
 pub mod events;
 pub mod file;
+pub mod fraud_check;
+pub mod health_check;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -17,6 +22,7 @@ pub mod merchant_key_store;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payouts;
 pub mod process_tracker;
 pub mod queue;
 pub mod refund;
@@ -45,13 +51,18 @@ pub trait StorageInterface:
     + dyn_clone::DynClone
     + address::AddressInterface
     + api_keys::ApiKeyInterface
+    + capture::CaptureInterface
     + configs::ConfigInterface
     + connector_response::ConnectorResponseInterface
+    + customer_redaction::CustomerRedactionInterface
     + customers::CustomerInterface
     + dispute::DisputeInterface
     + ephemeral_key::EphemeralKeyInterface
+    + event_delivery_attempt::EventDeliveryAttemptInterface
     + events::EventInterface
     + file::FileMetadataInterface
+    + fraud_check::FraudCheckInterface
+    + health_check::HealthCheckInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
@@ -60,6 +71,7 @@ pub trait StorageInterface:
     + payment_attempt::PaymentAttemptInterface
     + payment_intent::PaymentIntentInterface
     + payment_method::PaymentMethodInterface
+    + payouts::PayoutsInterface
     + process_tracker::ProcessTrackerInterface
     + queue::QueueInterface
     + refund::RefundInterface
@@ -110,8 +122,13 @@ pub struct MockDb {
     api_keys: Arc<Mutex<Vec<storage::ApiKey>>>,
     cards_info: Arc<Mutex<Vec<storage::CardInfo>>>,
     events: Arc<Mutex<Vec<storage::Event>>>,
+    event_delivery_attempts: Arc<Mutex<Vec<storage::EventDeliveryAttempt>>>,
     disputes: Arc<Mutex<Vec<storage::Dispute>>>,
     lockers: Arc<Mutex<Vec<storage::LockerMockUp>>>,
+    payouts: Arc<Mutex<Vec<storage::Payout>>>,
+    captures: Arc<Mutex<Vec<storage::Capture>>>,
+    customer_redactions: Arc<Mutex<Vec<storage::CustomerRedaction>>>,
+    fraud_checks: Arc<Mutex<Vec<storage::FraudCheck>>>,
 }
 
 impl MockDb {
@@ -130,8 +147,13 @@ impl MockDb {
             api_keys: Default::default(),
             cards_info: Default::default(),
             events: Default::default(),
+            event_delivery_attempts: Default::default(),
             disputes: Default::default(),
             lockers: Default::default(),
+            payouts: Default::default(),
+            captures: Default::default(),
+            customer_redactions: Default::default(),
+            fraud_checks: Default::default(),
         }
     }
 }
@@ -1,57 +1,147 @@
-use common_utils::ext_traits::AsyncExt;
-use error_stack::{IntoReport, ResultExt};
+use error_stack::ResultExt;
 use storage_models::address::AddressUpdateInternal;
 
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection,
     core::errors::{self, CustomResult},
     types::{
         domain::{
             self,
             behaviour::{Conversion, ReverseConversion},
         },
-        storage,
+        storage::{self as storage_types, enums},
     },
 };
 
 #[async_trait::async_trait]
 pub trait AddressInterface
 where
-    domain::Address: Conversion<DstType = storage::Address, NewDstType = storage::AddressNew>,
+    domain::Address:
+        Conversion<DstType = storage_types::Address, NewDstType = storage_types::AddressNew>,
 {
     async fn update_address(
         &self,
         address_id: String,
-        address: storage::AddressUpdate,
+        address: storage_types::AddressUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<domain::Address, errors::StorageError>;
+
+    /// Updates the address only if it has not been modified since `modified_at`, so that
+    /// concurrent updates are not overwritten. A not found error is returned otherwise.
+    async fn update_address_by_address_id_modified_at(
+        &self,
+        address_id: String,
+        modified_at: time::PrimitiveDateTime,
+        address: storage_types::AddressUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Address, errors::StorageError>;
 
     async fn insert_address(
         &self,
         address: domain::Address,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Address, errors::StorageError>;
 
     async fn find_address(
         &self,
         address_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Address, errors::StorageError>;
 
     async fn update_address_by_merchant_id_customer_id(
         &self,
         customer_id: &str,
         merchant_id: &str,
-        address: storage::AddressUpdate,
+        address: storage_types::AddressUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<domain::Address>, errors::StorageError>;
-}
 
-#[async_trait::async_trait]
-impl AddressInterface for Store {
-    async fn find_address(
+    /// Lists the addresses of a merchant from the database only, addresses which have not been
+    /// drained from the KV store yet are not listed
+    async fn list_addresses_by_merchant_id_after_row_id(
         &self,
-        address_id: &str,
-    ) -> CustomResult<domain::Address, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Address::find_by_address_id(&conn, address_id)
+        merchant_id: &str,
+        last_row_id: Option<i32>,
+        limit: i64,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError>;
+}
+
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use common_utils::ext_traits::AsyncExt;
+    use error_stack::{IntoReport, ResultExt};
+
+    use super::AddressInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::{
+            domain::{
+                self,
+                behaviour::{Conversion, ReverseConversion},
+            },
+            storage,
+        },
+    };
+
+    #[async_trait::async_trait]
+    impl AddressInterface for Store {
+        async fn find_address(
+            &self,
+            address_id: &str,
+            _storage_scheme: storage::enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Address, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::Address::find_by_address_id(&conn, address_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+                .async_and_then(|address| async {
+                    let merchant_id = address.merchant_id.clone();
+                    address
+                        .convert(self, &merchant_id)
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                })
+                .await
+        }
+
+        async fn update_address(
+            &self,
+            address_id: String,
+            address: storage::AddressUpdate,
+            _storage_scheme: storage::enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Address, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::Address::update_by_address_id(&conn, address_id, address.into())
+                .await
+                .map_err(Into::into)
+                .into_report()
+                .async_and_then(|address| async {
+                    let merchant_id = address.merchant_id.clone();
+                    address
+                        .convert(self, &merchant_id)
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                })
+                .await
+        }
+
+        async fn update_address_by_address_id_modified_at(
+            &self,
+            address_id: String,
+            modified_at: time::PrimitiveDateTime,
+            address: storage::AddressUpdate,
+            _storage_scheme: storage::enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Address, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::Address::update_by_address_id_modified_at(
+                &conn,
+                address_id,
+                modified_at,
+                address.into(),
+            )
             .await
             .map_err(Into::into)
             .into_report()
@@ -63,68 +153,357 @@ impl AddressInterface for Store {
                     .change_context(errors::StorageError::DecryptionError)
             })
             .await
-    }
+        }
 
-    async fn update_address(
-        &self,
-        address_id: String,
-        address: storage::AddressUpdate,
-    ) -> CustomResult<domain::Address, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::Address::update_by_address_id(&conn, address_id, address.into())
+        async fn insert_address(
+            &self,
+            address: domain::Address,
+            _storage_scheme: storage::enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Address, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            address
+                .construct_new()
+                .await
+                .change_context(errors::StorageError::EncryptionError)?
+                .insert(&conn)
+                .await
+                .map_err(Into::into)
+                .into_report()
+                .async_and_then(|address| async {
+                    let merchant_id = address.merchant_id.clone();
+                    address
+                        .convert(self, &merchant_id)
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                })
+                .await
+        }
+
+        async fn update_address_by_merchant_id_customer_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            address: storage::AddressUpdate,
+            _storage_scheme: storage::enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::Address::update_by_merchant_id_customer_id(
+                &conn,
+                customer_id,
+                merchant_id,
+                address.into(),
+            )
             .await
             .map_err(Into::into)
             .into_report()
-            .async_and_then(|address| async {
-                let merchant_id = address.merchant_id.clone();
-                address
-                    .convert(self, &merchant_id)
-                    .await
-                    .change_context(errors::StorageError::DecryptionError)
+            .async_and_then(|addresses| async {
+                let mut output = Vec::with_capacity(addresses.len());
+                for address in addresses.into_iter() {
+                    let merchant_id = address.merchant_id.clone();
+                    output.push(
+                        address
+                            .convert(self, &merchant_id)
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)?,
+                    )
+                }
+                Ok(output)
             })
             .await
-    }
+        }
 
-    async fn insert_address(
-        &self,
-        address: domain::Address,
-    ) -> CustomResult<domain::Address, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        address
-            .construct_new()
-            .await
-            .change_context(errors::StorageError::EncryptionError)?
-            .insert(&conn)
+        async fn list_addresses_by_merchant_id_after_row_id(
+            &self,
+            merchant_id: &str,
+            last_row_id: Option<i32>,
+            limit: i64,
+        ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            let addresses = storage::Address::list_by_merchant_id_after_row_id(
+                &conn,
+                merchant_id,
+                last_row_id,
+                limit,
+            )
             .await
             .map_err(Into::into)
-            .into_report()
-            .async_and_then(|address| async {
-                let merchant_id = address.merchant_id.clone();
-                address
-                    .convert(self, &merchant_id)
-                    .await
-                    .change_context(errors::StorageError::DecryptionError)
-            })
-            .await
+            .into_report()?;
+
+            let mut output = Vec::with_capacity(addresses.len());
+            for address in addresses.into_iter() {
+                output.push(
+                    address
+                        .convert(self, merchant_id)
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                )
+            }
+            Ok(output)
+        }
     }
+}
+
+#[cfg(feature = "kv_store")]
+mod storage {
+    use error_stack::{report, IntoReport, ResultExt};
+    use redis_interface::SetnxReply;
+    use storage_models::errors as storage_errors;
+
+    use super::AddressInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::{
+            domain::{
+                self,
+                behaviour::{Conversion, ReverseConversion},
+            },
+            storage::{self, enums, kv},
+        },
+        utils::{db_utils, storage_partitioning::PartitionKey},
+    };
+
+    #[async_trait::async_trait]
+    impl AddressInterface for Store {
+        async fn find_address(
+            &self,
+            address_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Address, errors::StorageError> {
+            let address = find_storage_address(self, address_id, storage_scheme).await?;
+            let merchant_id = address.merchant_id.clone();
+            address
+                .convert(self, &merchant_id)
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+        }
+
+        async fn update_address(
+            &self,
+            address_id: String,
+            address: storage::AddressUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Address, errors::StorageError> {
+            let updated_address = match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    storage::Address::update_by_address_id(&conn, address_id, address.into())
+                        .await
+                        .map_err(Into::into)
+                        .into_report()?
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let this = find_storage_address(self, &address_id, storage_scheme).await?;
+                    update_kv_address(self, this, address.into()).await?
+                }
+            };
+
+            let merchant_id = updated_address.merchant_id.clone();
+            updated_address
+                .convert(self, &merchant_id)
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+        }
+
+        async fn update_address_by_address_id_modified_at(
+            &self,
+            address_id: String,
+            modified_at: time::PrimitiveDateTime,
+            address: storage::AddressUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Address, errors::StorageError> {
+            let updated_address = match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    storage::Address::update_by_address_id_modified_at(
+                        &conn,
+                        address_id,
+                        modified_at,
+                        address.into(),
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()?
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let this = find_storage_address(self, &address_id, storage_scheme).await?;
+                    if this.modified_at != modified_at {
+                        Err(errors::StorageError::DatabaseError(
+                            report!(storage_errors::DatabaseError::NotFound)
+                                .attach_printable("Address has been modified"),
+                        ))?
+                    }
+                    update_kv_address(self, this, address.into()).await?
+                }
+            };
+
+            let merchant_id = updated_address.merchant_id.clone();
+            updated_address
+                .convert(self, &merchant_id)
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+        }
+
+        async fn insert_address(
+            &self,
+            address: domain::Address,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<domain::Address, errors::StorageError> {
+            let address_new = address
+                .construct_new()
+                .await
+                .change_context(errors::StorageError::EncryptionError)?;
+
+            let created_address = match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    address_new
+                        .insert(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()?
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let created_address = storage::Address {
+                        id: Default::default(),
+                        address_id: address_new.address_id.clone(),
+                        city: address_new.city.clone(),
+                        country: address_new.country,
+                        line1: address_new.line1.clone(),
+                        line2: address_new.line2.clone(),
+                        line3: address_new.line3.clone(),
+                        state: address_new.state.clone(),
+                        zip: address_new.zip.clone(),
+                        first_name: address_new.first_name.clone(),
+                        last_name: address_new.last_name.clone(),
+                        phone_number: address_new.phone_number.clone(),
+                        country_code: address_new.country_code.clone(),
+                        created_at: address_new.created_at,
+                        modified_at: address_new.modified_at,
+                        customer_id: address_new.customer_id.clone(),
+                        merchant_id: address_new.merchant_id.clone(),
+                    };
+
+                    match self
+                        .redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .serialize_and_set_key_if_not_exist(
+                            &get_address_key(&created_address.address_id),
+                            &created_address,
+                        )
+                        .await
+                    {
+                        Ok(SetnxReply::KeyNotSet) => {
+                            return Err(errors::StorageError::DuplicateValue {
+                                entity: "address",
+                                key: Some(created_address.address_id),
+                            })
+                            .into_report()
+                        }
+                        Ok(SetnxReply::KeySet) => {
+                            self.redis_conn()
+                                .map_err(Into::<errors::StorageError>::into)?
+                                .set_hash_fields(
+                                    &get_customer_addresses_key(
+                                        &created_address.merchant_id,
+                                        &created_address.customer_id,
+                                    ),
+                                    (&created_address.address_id, &created_address.address_id),
+                                )
+                                .await
+                                .change_context(errors::StorageError::KVError)?;
+
+                            let redis_entry = kv::TypedSql {
+                                op: kv::DBOperation::Insert {
+                                    insertable: kv::Insertable::Address(address_new),
+                                },
+                            };
+                            self.push_to_drainer_stream::<storage::Address>(
+                                redis_entry,
+                                PartitionKey::AddressId {
+                                    address_id: &created_address.address_id,
+                                },
+                            )
+                            .await?;
+                            created_address
+                        }
+                        Err(error) => {
+                            return Err(error.change_context(errors::StorageError::KVError))
+                        }
+                    }
+                }
+            };
+
+            let merchant_id = created_address.merchant_id.clone();
+            created_address
+                .convert(self, &merchant_id)
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+        }
+
+        async fn update_address_by_merchant_id_customer_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            address: storage::AddressUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+            let addresses = match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    storage::Address::update_by_merchant_id_customer_id(
+                        &conn,
+                        customer_id,
+                        merchant_id,
+                        address.into(),
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()?
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    // Every address is updated through the KV store, so that the update is
+                    // drained after the pending insert or updates of the address. Addresses which
+                    // have not been drained yet are only found through the index of the customer.
+                    let mut address_ids = self
+                        .redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .hscan(
+                            &get_customer_addresses_key(merchant_id, customer_id),
+                            "*",
+                            None,
+                        )
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    let conn = connection::pg_connection_read(self).await?;
+                    address_ids.extend(
+                        storage::Address::find_by_merchant_id_customer_id(
+                            &conn,
+                            merchant_id,
+                            customer_id,
+                        )
+                        .await
+                        .map_err(Into::into)
+                        .into_report()?
+                        .into_iter()
+                        .map(|address| address.address_id),
+                    );
+                    address_ids.sort();
+                    address_ids.dedup();
+
+                    let address_update = storage::AddressUpdateInternal::from(address);
+                    let mut addresses = Vec::with_capacity(address_ids.len());
+                    for address_id in address_ids.iter() {
+                        let this = find_storage_address(self, address_id, storage_scheme).await?;
+                        addresses
+                            .push(update_kv_address(self, this, address_update.clone()).await?);
+                    }
+                    addresses
+                }
+            };
 
-    async fn update_address_by_merchant_id_customer_id(
-        &self,
-        customer_id: &str,
-        merchant_id: &str,
-        address: storage::AddressUpdate,
-    ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::Address::update_by_merchant_id_customer_id(
-            &conn,
-            customer_id,
-            merchant_id,
-            address.into(),
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
-        .async_and_then(|addresses| async {
             let mut output = Vec::with_capacity(addresses.len());
             for address in addresses.into_iter() {
                 let merchant_id = address.merchant_id.clone();
@@ -136,8 +515,108 @@ impl AddressInterface for Store {
                 )
             }
             Ok(output)
-        })
-        .await
+        }
+
+        async fn list_addresses_by_merchant_id_after_row_id(
+            &self,
+            merchant_id: &str,
+            last_row_id: Option<i32>,
+            limit: i64,
+        ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            let addresses = storage::Address::list_by_merchant_id_after_row_id(
+                &conn,
+                merchant_id,
+                last_row_id,
+                limit,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()?;
+
+            let mut output = Vec::with_capacity(addresses.len());
+            for address in addresses.into_iter() {
+                output.push(
+                    address
+                        .convert(self, merchant_id)
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                )
+            }
+            Ok(output)
+        }
+    }
+
+    #[inline]
+    fn get_address_key(address_id: &str) -> String {
+        format!("address_{address_id}")
+    }
+
+    #[inline]
+    fn get_customer_addresses_key(merchant_id: &str, customer_id: &str) -> String {
+        format!("customer_addresses_{merchant_id}_{customer_id}")
+    }
+
+    async fn update_kv_address(
+        store: &Store,
+        this: storage::Address,
+        address_update: storage::AddressUpdateInternal,
+    ) -> CustomResult<storage::Address, errors::StorageError> {
+        let updated_address = address_update.clone().create_address(this.clone());
+
+        store
+            .redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .serialize_and_set_key(&get_address_key(&this.address_id), &updated_address)
+            .await
+            .change_context(errors::StorageError::KVError)?;
+
+        let redis_entry = kv::TypedSql {
+            op: kv::DBOperation::Update {
+                updatable: kv::Updateable::AddressUpdate(kv::AddressUpdateMems {
+                    orig: this,
+                    update_data: address_update,
+                }),
+            },
+        };
+        store
+            .push_to_drainer_stream::<storage::Address>(
+                redis_entry,
+                PartitionKey::AddressId {
+                    address_id: &updated_address.address_id,
+                },
+            )
+            .await?;
+
+        Ok(updated_address)
+    }
+
+    async fn find_storage_address(
+        store: &Store,
+        address_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage::Address, errors::StorageError> {
+        let database_call = || async {
+            let conn = connection::pg_connection_read(store).await?;
+            storage::Address::find_by_address_id(&conn, address_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        };
+        match storage_scheme {
+            enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+            enums::MerchantStorageScheme::RedisKv => {
+                let key = get_address_key(address_id);
+                db_utils::try_redis_get_else_try_database_get(
+                    store
+                        .redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .get_and_deserialize_key(&key, "Address"),
+                    database_call,
+                )
+                .await
+            }
+        }
     }
 }
 
@@ -146,6 +625,7 @@ impl AddressInterface for MockDb {
     async fn find_address(
         &self,
         address_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Address, errors::StorageError> {
         match self
             .addresses
@@ -173,7 +653,8 @@ impl AddressInterface for MockDb {
     async fn update_address(
         &self,
         address_id: String,
-        address_update: storage::AddressUpdate,
+        address_update: storage_types::AddressUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Address, errors::StorageError> {
         match self
             .addresses
@@ -203,9 +684,44 @@ impl AddressInterface for MockDb {
         }
     }
 
+    async fn update_address_by_address_id_modified_at(
+        &self,
+        address_id: String,
+        modified_at: time::PrimitiveDateTime,
+        address_update: storage_types::AddressUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<domain::Address, errors::StorageError> {
+        match self
+            .addresses
+            .lock()
+            .await
+            .iter_mut()
+            .find(|address| address.address_id == address_id && address.modified_at == modified_at)
+            .map(|a| {
+                let address_updated =
+                    AddressUpdateInternal::from(address_update).create_address(a.clone());
+                *a = address_updated.clone();
+                address_updated
+            }) {
+            Some(address_updated) => {
+                let merchant_id = address_updated.merchant_id.clone();
+                address_updated
+                    .convert(self, &merchant_id)
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)
+            }
+            None => Err(errors::StorageError::DatabaseError(
+                error_stack::report!(storage_models::errors::DatabaseError::NotFound)
+                    .attach_printable("cannot find unmodified address to update"),
+            )
+            .into()),
+        }
+    }
+
     async fn insert_address(
         &self,
         address_new: domain::Address,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<domain::Address, errors::StorageError> {
         let mut addresses = self.addresses.lock().await;
 
@@ -226,7 +742,8 @@ impl AddressInterface for MockDb {
         &self,
         customer_id: &str,
         merchant_id: &str,
-        address_update: storage::AddressUpdate,
+        address_update: storage_types::AddressUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
         match self
             .addresses
@@ -256,4 +773,37 @@ impl AddressInterface for MockDb {
             }
         }
     }
+
+    async fn list_addresses_by_merchant_id_after_row_id(
+        &self,
+        merchant_id: &str,
+        last_row_id: Option<i32>,
+        limit: i64,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+        let mut addresses = self
+            .addresses
+            .lock()
+            .await
+            .iter()
+            .filter(|address| {
+                address.merchant_id == merchant_id && address.id > last_row_id.unwrap_or_default()
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        addresses.sort_by_key(|address| address.id);
+
+        let mut output = Vec::new();
+        for address in addresses
+            .into_iter()
+            .take(usize::try_from(limit).unwrap_or_default())
+        {
+            output.push(
+                address
+                    .convert(self, merchant_id)
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            )
+        }
+        Ok(output)
+    }
 }
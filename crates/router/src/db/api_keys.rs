@@ -1,6 +1,8 @@
 use error_stack::IntoReport;
 
 use super::{MockDb, Store};
+#[cfg(feature = "accounts_cache")]
+use crate::cache::{self, API_KEYS_CACHE};
 use crate::{
     connection,
     core::errors::{self, CustomResult},
@@ -66,11 +68,26 @@ impl ApiKeyInterface for Store {
         key_id: String,
         api_key: storage::ApiKeyUpdate,
     ) -> CustomResult<storage::ApiKey, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::ApiKey::update_by_merchant_id_key_id(&conn, merchant_id, key_id, api_key)
-            .await
-            .map_err(Into::into)
-            .into_report()
+        #[cfg(feature = "accounts_cache")]
+        let cache_keys = get_api_key_cache_keys(self, &merchant_id, &key_id).await?;
+
+        let update_call = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::ApiKey::update_by_merchant_id_key_id(&conn, merchant_id, key_id, api_key)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            update_call().await
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            super::cache::publish_and_redact_multiple(self, cache_keys, update_call).await
+        }
     }
 
     async fn revoke_api_key(
@@ -78,11 +95,24 @@ impl ApiKeyInterface for Store {
         merchant_id: &str,
         key_id: &str,
     ) -> CustomResult<bool, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::ApiKey::revoke_by_merchant_id_key_id(&conn, merchant_id, key_id)
-            .await
-            .map_err(Into::into)
-            .into_report()
+        let revoke_call = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::ApiKey::revoke_by_merchant_id_key_id(&conn, merchant_id, key_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            revoke_call().await
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            let cache_keys = get_api_key_cache_keys(self, merchant_id, key_id).await?;
+            super::cache::publish_and_redact_multiple(self, cache_keys, revoke_call).await
+        }
     }
 
     async fn find_api_key_by_merchant_id_key_id_optional(
@@ -101,11 +131,47 @@ impl ApiKeyInterface for Store {
         &self,
         hashed_api_key: storage::HashedApiKey,
     ) -> CustomResult<Option<storage::ApiKey>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::ApiKey::find_optional_by_hashed_api_key(&conn, hashed_api_key)
+        #[cfg(feature = "accounts_cache")]
+        let cache_key = get_api_key_cache_key(&hashed_api_key);
+
+        let find_call = || async {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::ApiKey::find_optional_by_hashed_api_key(&conn, hashed_api_key)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            find_call().await
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            // Only keys which exist are cached, so that newly created keys can be used right away
+            let find_existing_call = || async {
+                find_call()
+                    .await?
+                    .ok_or(errors::StorageError::ValueNotFound("API key".to_string()))
+                    .into_report()
+            };
+
+            match super::cache::get_or_populate_in_memory(
+                self,
+                &cache_key,
+                find_existing_call,
+                &API_KEYS_CACHE,
+            )
             .await
-            .map_err(Into::into)
-            .into_report()
+            {
+                Ok(api_key) => Ok(Some(api_key)),
+                Err(error) => match error.current_context() {
+                    errors::StorageError::ValueNotFound(_) => Ok(None),
+                    _ => Err(error),
+                },
+            }
+        }
     }
 
     async fn list_api_keys_by_merchant_id(
@@ -122,6 +188,33 @@ impl ApiKeyInterface for Store {
     }
 }
 
+#[cfg(feature = "accounts_cache")]
+fn get_api_key_cache_key(hashed_api_key: &storage::HashedApiKey) -> String {
+    format!("api_key_{}", hashed_api_key.clone().into_inner())
+}
+
+/// The keys under which the given API key may be cached, one for its current secret and one for
+/// the secret it had before it was last rotated
+#[cfg(feature = "accounts_cache")]
+async fn get_api_key_cache_keys(
+    store: &Store,
+    merchant_id: &str,
+    key_id: &str,
+) -> CustomResult<Vec<cache::CacheKind<'static>>, errors::StorageError> {
+    Ok(store
+        .find_api_key_by_merchant_id_key_id_optional(merchant_id, key_id)
+        .await?
+        .map(|api_key| {
+            std::iter::once(api_key.hashed_api_key)
+                .chain(api_key.previous_hashed_api_key)
+                .map(|hashed_api_key| {
+                    cache::CacheKind::ApiKeys(get_api_key_cache_key(&hashed_api_key).into())
+                })
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
 #[async_trait::async_trait]
 impl ApiKeyInterface for MockDb {
     async fn insert_api_key(
@@ -144,6 +237,9 @@ impl ApiKeyInterface for MockDb {
             created_at: api_key.created_at,
             expires_at: api_key.expires_at,
             last_used: api_key.last_used,
+            scopes: api_key.scopes,
+            previous_hashed_api_key: None,
+            previous_key_expires_at: None,
         };
         locked_api_keys.push(stored_key.clone());
 
@@ -169,6 +265,7 @@ impl ApiKeyInterface for MockDb {
                 description,
                 expires_at,
                 last_used,
+                scopes,
             } => {
                 if let Some(name) = name {
                     key_to_update.name = name;
@@ -183,10 +280,24 @@ impl ApiKeyInterface for MockDb {
                 if last_used.is_some() {
                     key_to_update.last_used = last_used
                 }
+                if scopes.is_some() {
+                    key_to_update.scopes = scopes;
+                }
             }
             storage::ApiKeyUpdate::LastUsedUpdate { last_used } => {
                 key_to_update.last_used = Some(last_used);
             }
+            storage::ApiKeyUpdate::RotateUpdate {
+                hashed_api_key,
+                prefix,
+                previous_hashed_api_key,
+                previous_key_expires_at,
+            } => {
+                key_to_update.hashed_api_key = hashed_api_key;
+                key_to_update.prefix = prefix;
+                key_to_update.previous_hashed_api_key = Some(previous_hashed_api_key);
+                key_to_update.previous_key_expires_at = Some(previous_key_expires_at);
+            }
         }
 
         Ok(key_to_update.clone())
@@ -235,7 +346,10 @@ impl ApiKeyInterface for MockDb {
             .lock()
             .await
             .iter()
-            .find(|k| k.hashed_api_key == hashed_api_key)
+            .find(|k| {
+                k.hashed_api_key == hashed_api_key
+                    || k.previous_hashed_api_key.as_ref() == Some(&hashed_api_key)
+            })
             .cloned())
     }
 
@@ -308,6 +422,7 @@ mod tests {
                 created_at: datetime!(2023-02-01 0:00),
                 expires_at: Some(datetime!(2023-03-01 0:00)),
                 last_used: None,
+                scopes: None,
             })
             .await
             .unwrap();
@@ -323,6 +438,7 @@ mod tests {
                 created_at: datetime!(2023-03-01 0:00),
                 expires_at: None,
                 last_used: None,
+                scopes: None,
             })
             .await
             .unwrap();
@@ -356,6 +472,28 @@ mod tests {
             .unwrap();
         assert_eq!(updated_key1.last_used, Some(datetime!(2023-02-04 1:11)));
 
+        mockdb
+            .update_api_key(
+                "merchant1".into(),
+                "key_id1".into(),
+                storage::ApiKeyUpdate::RotateUpdate {
+                    hashed_api_key: "hashed_key3".to_string().into(),
+                    prefix: "def".into(),
+                    previous_hashed_api_key: "hashed_key1".to_string().into(),
+                    previous_key_expires_at: datetime!(2023-02-05 0:00),
+                },
+            )
+            .await
+            .unwrap();
+        for hashed_api_key in ["hashed_key1", "hashed_key3"] {
+            let rotated_key1 = mockdb
+                .find_api_key_by_hash_optional(hashed_api_key.to_string().into())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(rotated_key1.key_id, key1.key_id);
+        }
+
         assert_eq!(
             mockdb
                 .list_api_keys_by_merchant_id("merchant1", None, None)
@@ -105,3 +105,26 @@ where
         .change_context(errors::StorageError::KVError)?;
     Ok(data)
 }
+
+/// Same as [`publish_and_redact`], for data cached under multiple keys
+pub async fn publish_and_redact_multiple<'a, T, F, Fut>(
+    store: &Store,
+    keys: Vec<cache::CacheKind<'a>>,
+    fun: F,
+) -> CustomResult<T, errors::StorageError>
+where
+    F: FnOnce() -> Fut + Send,
+    Fut: futures::Future<Output = CustomResult<T, errors::StorageError>> + Send,
+{
+    let data = fun().await?;
+    let redis_conn = store
+        .redis_conn()
+        .map_err(Into::<errors::StorageError>::into)?;
+    for key in keys {
+        redis_conn
+            .publish(consts::PUB_SUB_CHANNEL, key)
+            .await
+            .change_context(errors::StorageError::KVError)?;
+    }
+    Ok(data)
+}
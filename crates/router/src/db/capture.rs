@@ -0,0 +1,291 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CaptureInterface {
+    async fn insert_capture(
+        &self,
+        capture: storage::CaptureNew,
+    ) -> CustomResult<storage::Capture, errors::StorageError>;
+
+    async fn find_capture_by_merchant_id_capture_id(
+        &self,
+        merchant_id: &str,
+        capture_id: &str,
+    ) -> CustomResult<storage::Capture, errors::StorageError>;
+
+    async fn find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        authorized_attempt_id: &str,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError>;
+
+    async fn update_capture_with_capture_id(
+        &self,
+        this: storage::Capture,
+        capture: storage::CaptureUpdate,
+    ) -> CustomResult<storage::Capture, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CaptureInterface for Store {
+    async fn insert_capture(
+        &self,
+        capture: storage::CaptureNew,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        capture
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_capture_by_merchant_id_capture_id(
+        &self,
+        merchant_id: &str,
+        capture_id: &str,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Capture::find_by_merchant_id_capture_id(&conn, merchant_id, capture_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        authorized_attempt_id: &str,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Capture::find_all_by_merchant_id_payment_id_authorized_attempt_id(
+            &conn,
+            merchant_id,
+            payment_id,
+            authorized_attempt_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn update_capture_with_capture_id(
+        &self,
+        this: storage::Capture,
+        capture: storage::CaptureUpdate,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update_with_capture_id(&conn, capture)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl CaptureInterface for MockDb {
+    async fn insert_capture(
+        &self,
+        capture: storage::CaptureNew,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let mut locked_captures = self.captures.lock().await;
+
+        if locked_captures
+            .iter()
+            .any(|c| c.merchant_id == capture.merchant_id && c.capture_id == capture.capture_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "capture_id",
+                key: Some(capture.capture_id.clone()),
+            })?;
+        }
+
+        let now = common_utils::date_time::now();
+
+        let new_capture = storage::Capture {
+            #[allow(clippy::as_conversions)]
+            id: locked_captures.len() as i32,
+            capture_id: capture.capture_id,
+            payment_id: capture.payment_id,
+            merchant_id: capture.merchant_id,
+            authorized_attempt_id: capture.authorized_attempt_id,
+            capture_sequence: capture.capture_sequence,
+            status: capture.status,
+            amount: capture.amount,
+            currency: capture.currency,
+            connector: capture.connector,
+            connector_capture_id: capture.connector_capture_id,
+            error_code: capture.error_code,
+            error_message: capture.error_message,
+            created_at: now,
+            modified_at: now,
+        };
+
+        locked_captures.push(new_capture.clone());
+
+        Ok(new_capture)
+    }
+
+    async fn find_capture_by_merchant_id_capture_id(
+        &self,
+        merchant_id: &str,
+        capture_id: &str,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        self.captures
+            .lock()
+            .await
+            .iter()
+            .find(|c| c.merchant_id == merchant_id && c.capture_id == capture_id)
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No capture available for merchant_id = {merchant_id} and capture_id = {capture_id}"
+            )))
+            .into_report()
+    }
+
+    async fn find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        authorized_attempt_id: &str,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError> {
+        let mut captures = self
+            .captures
+            .lock()
+            .await
+            .iter()
+            .filter(|c| {
+                c.merchant_id == merchant_id
+                    && c.payment_id == payment_id
+                    && c.authorized_attempt_id == authorized_attempt_id
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        captures.sort_by_key(|c| c.capture_sequence);
+
+        Ok(captures)
+    }
+
+    async fn update_capture_with_capture_id(
+        &self,
+        this: storage::Capture,
+        capture: storage::CaptureUpdate,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let mut locked_captures = self.captures.lock().await;
+
+        let capture_to_update = locked_captures
+            .iter_mut()
+            .find(|c| c.merchant_id == this.merchant_id && c.capture_id == this.capture_id)
+            .ok_or(errors::StorageError::MockDbError)?;
+
+        *capture_to_update = capture.apply_changeset(capture_to_update.clone());
+
+        Ok(capture_to_update.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(clippy::unwrap_used)]
+    mod mockdb_capture_interface {
+        use storage_models::{
+            capture::{CaptureNew, CaptureUpdate},
+            enums::{CaptureStatus, Currency},
+        };
+
+        use crate::db::{capture::CaptureInterface, MockDb};
+
+        fn create_capture_new(capture_id: &str, capture_sequence: i16) -> CaptureNew {
+            CaptureNew {
+                capture_id: capture_id.into(),
+                payment_id: "payment_1".into(),
+                merchant_id: "merchant_1".into(),
+                authorized_attempt_id: "payment_1_1".into(),
+                capture_sequence,
+                status: CaptureStatus::Started,
+                amount: 500,
+                currency: Some(Currency::USD),
+                connector: "checkout".into(),
+                connector_capture_id: None,
+                error_code: None,
+                error_message: None,
+            }
+        }
+
+        #[tokio::test]
+        async fn test_find_all_captures_in_sequence() {
+            let mockdb = MockDb::new(&Default::default()).await;
+
+            mockdb
+                .insert_capture(create_capture_new("capture_2", 2))
+                .await
+                .unwrap();
+            mockdb
+                .insert_capture(create_capture_new("capture_1", 1))
+                .await
+                .unwrap();
+
+            assert!(mockdb
+                .insert_capture(create_capture_new("capture_1", 3))
+                .await
+                .is_err());
+
+            let captures = mockdb
+                .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+                    "merchant_1",
+                    "payment_1",
+                    "payment_1_1",
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(
+                captures
+                    .iter()
+                    .map(|c| c.capture_id.as_str())
+                    .collect::<Vec<_>>(),
+                vec!["capture_1", "capture_2"]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_update_capture() {
+            let mockdb = MockDb::new(&Default::default()).await;
+
+            let created_capture = mockdb
+                .insert_capture(create_capture_new("capture_1", 1))
+                .await
+                .unwrap();
+
+            let updated_capture = mockdb
+                .update_capture_with_capture_id(
+                    created_capture,
+                    CaptureUpdate::ResponseUpdate {
+                        status: CaptureStatus::Pending,
+                        connector_capture_id: Some("act_1".into()),
+                    },
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(updated_capture.status, CaptureStatus::Pending);
+
+            let found_capture = mockdb
+                .find_capture_by_merchant_id_capture_id("merchant_1", "capture_1")
+                .await
+                .unwrap();
+
+            assert_eq!(found_capture, updated_capture);
+            assert_eq!(found_capture.connector_capture_id, Some("act_1".into()));
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use async_bb8_diesel::AsyncConnection;
 use error_stack::IntoReport;
 
 use crate::{
@@ -5,7 +6,7 @@ use crate::{
     core::errors::{self, CustomResult},
     db::MockDb,
     services::Store,
-    types::storage::cards_info::CardInfo,
+    types::storage::cards_info::{CardInfo, CardInfoNew, CardInfoUpdate},
 };
 
 #[async_trait::async_trait]
@@ -14,6 +15,29 @@ pub trait CardsInfoInterface {
         &self,
         _card_iin: &str,
     ) -> CustomResult<Option<CardInfo>, errors::StorageError>;
+
+    async fn find_card_info_by_iins(
+        &self,
+        card_iins: Vec<String>,
+    ) -> CustomResult<Vec<CardInfo>, errors::StorageError>;
+
+    async fn insert_card_info(
+        &self,
+        card_info: CardInfoNew,
+    ) -> CustomResult<CardInfo, errors::StorageError>;
+
+    async fn update_card_info(
+        &self,
+        card_iin: &str,
+        card_info_update: CardInfoUpdate,
+    ) -> CustomResult<CardInfo, errors::StorageError>;
+
+    /// Inserts and updates the BIN entries of an import, either all of them or none
+    async fn import_card_info(
+        &self,
+        new_card_info: Vec<CardInfoNew>,
+        card_info_updates: Vec<(String, CardInfoUpdate)>,
+    ) -> CustomResult<(), errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -28,6 +52,67 @@ impl CardsInfoInterface for Store {
             .map_err(Into::into)
             .into_report()
     }
+
+    async fn find_card_info_by_iins(
+        &self,
+        card_iins: Vec<String>,
+    ) -> CustomResult<Vec<CardInfo>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        CardInfo::find_by_iins(&conn, card_iins)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn insert_card_info(
+        &self,
+        card_info: CardInfoNew,
+    ) -> CustomResult<CardInfo, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        card_info
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_card_info(
+        &self,
+        card_iin: &str,
+        card_info_update: CardInfoUpdate,
+    ) -> CustomResult<CardInfo, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        CardInfo::update_by_iin(&conn, card_iin, card_info_update)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn import_card_info(
+        &self,
+        new_card_info: Vec<CardInfoNew>,
+        card_info_updates: Vec<(String, CardInfoUpdate)>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        conn.transaction_async(|conn| async move {
+            for card_info in new_card_info {
+                card_info
+                    .insert(&conn)
+                    .await
+                    .map_err(|error| *error.current_context())?;
+            }
+            for (card_iin, card_info_update) in card_info_updates {
+                CardInfo::update_by_iin(&conn, &card_iin, card_info_update)
+                    .await
+                    .map_err(|error| *error.current_context())?;
+            }
+            Ok::<_, storage_models::errors::DatabaseError>(())
+        })
+        .await
+        .into_report()
+        .map_err(Into::into)
+        .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -44,4 +129,134 @@ impl CardsInfoInterface for MockDb {
             .find(|ci| ci.card_iin == card_iin)
             .cloned())
     }
+
+    async fn find_card_info_by_iins(
+        &self,
+        card_iins: Vec<String>,
+    ) -> CustomResult<Vec<CardInfo>, errors::StorageError> {
+        Ok(self
+            .cards_info
+            .lock()
+            .await
+            .iter()
+            .filter(|ci| card_iins.contains(&ci.card_iin))
+            .cloned()
+            .collect())
+    }
+
+    async fn insert_card_info(
+        &self,
+        card_info: CardInfoNew,
+    ) -> CustomResult<CardInfo, errors::StorageError> {
+        let mut cards_info = self.cards_info.lock().await;
+        if cards_info
+            .iter()
+            .any(|ci| ci.card_iin == card_info.card_iin)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "card_iin",
+                key: Some(card_info.card_iin.clone()),
+            })?;
+        }
+
+        let card_info = card_info_from_new(card_info);
+        cards_info.push(card_info.clone());
+        Ok(card_info)
+    }
+
+    async fn update_card_info(
+        &self,
+        card_iin: &str,
+        card_info_update: CardInfoUpdate,
+    ) -> CustomResult<CardInfo, errors::StorageError> {
+        let mut cards_info = self.cards_info.lock().await;
+        let card_info = cards_info
+            .iter_mut()
+            .find(|ci| ci.card_iin == card_iin)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No card info found for card_iin = {card_iin}"
+            )))?;
+
+        apply_card_info_update(card_info, card_info_update);
+
+        Ok(card_info.clone())
+    }
+
+    async fn import_card_info(
+        &self,
+        new_card_info: Vec<CardInfoNew>,
+        card_info_updates: Vec<(String, CardInfoUpdate)>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let mut cards_info = self.cards_info.lock().await;
+        if let Some(card_info) = new_card_info
+            .iter()
+            .find(|new| cards_info.iter().any(|ci| ci.card_iin == new.card_iin))
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "card_iin",
+                key: Some(card_info.card_iin.clone()),
+            })?;
+        }
+        if let Some((card_iin, _)) = card_info_updates
+            .iter()
+            .find(|(card_iin, _)| !cards_info.iter().any(|ci| &ci.card_iin == card_iin))
+        {
+            Err(errors::StorageError::ValueNotFound(format!(
+                "No card info found for card_iin = {card_iin}"
+            )))?;
+        }
+
+        cards_info.extend(new_card_info.into_iter().map(card_info_from_new));
+        for (card_iin, card_info_update) in card_info_updates {
+            if let Some(card_info) = cards_info.iter_mut().find(|ci| ci.card_iin == card_iin) {
+                apply_card_info_update(card_info, card_info_update);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn card_info_from_new(card_info: CardInfoNew) -> CardInfo {
+    CardInfo {
+        card_iin: card_info.card_iin,
+        card_issuer: card_info.card_issuer,
+        card_network: card_info.card_network,
+        card_type: card_info.card_type,
+        card_subtype: card_info.card_subtype,
+        card_issuing_country: card_info.card_issuing_country,
+        bank_code_id: card_info.bank_code_id,
+        bank_code: card_info.bank_code,
+        country_code: card_info.country_code,
+        date_created: card_info.date_created,
+        last_updated: card_info.last_updated,
+        last_updated_provider: card_info.last_updated_provider,
+    }
+}
+
+fn apply_card_info_update(card_info: &mut CardInfo, card_info_update: CardInfoUpdate) {
+    match card_info_update {
+        CardInfoUpdate::Update {
+            card_issuer,
+            card_network,
+            card_type,
+            card_subtype,
+            card_issuing_country,
+            bank_code_id,
+            bank_code,
+            country_code,
+            last_updated_provider,
+        } => {
+            card_info.card_issuer = card_issuer;
+            card_info.card_network = card_network;
+            card_info.card_type = card_type;
+            card_info.card_subtype = card_subtype;
+            card_info.card_issuing_country = card_issuing_country;
+            card_info.bank_code_id = bank_code_id;
+            card_info.bank_code = bank_code;
+            card_info.country_code = country_code;
+            card_info.last_updated = Some(common_utils::date_time::now());
+            card_info.last_updated_provider = last_updated_provider;
+        }
+    }
 }
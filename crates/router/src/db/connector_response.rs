@@ -1,19 +1,16 @@
-use error_stack::IntoReport;
-
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection,
     core::errors::{self, CustomResult},
-    types::storage::{self, enums},
+    types::storage::{self as storage_types, enums},
 };
 
 #[async_trait::async_trait]
 pub trait ConnectorResponseInterface {
     async fn insert_connector_response(
         &self,
-        connector_response: storage::ConnectorResponseNew,
+        connector_response: storage_types::ConnectorResponseNew,
         storage_scheme: enums::MerchantStorageScheme,
-    ) -> CustomResult<storage::ConnectorResponse, errors::StorageError>;
+    ) -> CustomResult<storage_types::ConnectorResponse, errors::StorageError>;
 
     async fn find_connector_response_by_payment_id_merchant_id_attempt_id(
         &self,
@@ -21,61 +18,259 @@ pub trait ConnectorResponseInterface {
         merchant_id: &str,
         attempt_id: &str,
         storage_scheme: enums::MerchantStorageScheme,
-    ) -> CustomResult<storage::ConnectorResponse, errors::StorageError>;
+    ) -> CustomResult<storage_types::ConnectorResponse, errors::StorageError>;
 
     async fn update_connector_response(
         &self,
-        this: storage::ConnectorResponse,
-        payment_attempt: storage::ConnectorResponseUpdate,
+        this: storage_types::ConnectorResponse,
+        payment_attempt: storage_types::ConnectorResponseUpdate,
         storage_scheme: enums::MerchantStorageScheme,
-    ) -> CustomResult<storage::ConnectorResponse, errors::StorageError>;
+    ) -> CustomResult<storage_types::ConnectorResponse, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl ConnectorResponseInterface for Store {
-    async fn insert_connector_response(
-        &self,
-        connector_response: storage::ConnectorResponseNew,
-        _storage_scheme: enums::MerchantStorageScheme,
-    ) -> CustomResult<storage::ConnectorResponse, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        connector_response
-            .insert(&conn)
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::IntoReport;
+
+    use super::ConnectorResponseInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums},
+    };
+
+    #[async_trait::async_trait]
+    impl ConnectorResponseInterface for Store {
+        async fn insert_connector_response(
+            &self,
+            connector_response: storage_types::ConnectorResponseNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::ConnectorResponse, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            connector_response
+                .insert(&conn)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn find_connector_response_by_payment_id_merchant_id_attempt_id(
+            &self,
+            payment_id: &str,
+            merchant_id: &str,
+            attempt_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::ConnectorResponse, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::ConnectorResponse::find_by_payment_id_merchant_id_attempt_id(
+                &conn,
+                payment_id,
+                merchant_id,
+                attempt_id,
+            )
             .await
             .map_err(Into::into)
             .into_report()
-    }
+        }
 
-    async fn find_connector_response_by_payment_id_merchant_id_attempt_id(
-        &self,
-        payment_id: &str,
-        merchant_id: &str,
-        attempt_id: &str,
-        _storage_scheme: enums::MerchantStorageScheme,
-    ) -> CustomResult<storage::ConnectorResponse, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::ConnectorResponse::find_by_payment_id_merchant_id_attempt_id(
-            &conn,
-            payment_id,
-            merchant_id,
-            attempt_id,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
+        async fn update_connector_response(
+            &self,
+            this: storage_types::ConnectorResponse,
+            connector_response_update: storage_types::ConnectorResponseUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::ConnectorResponse, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            this.update(&conn, connector_response_update)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
     }
+}
 
-    async fn update_connector_response(
-        &self,
-        this: storage::ConnectorResponse,
-        connector_response_update: storage::ConnectorResponseUpdate,
-        _storage_scheme: enums::MerchantStorageScheme,
-    ) -> CustomResult<storage::ConnectorResponse, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        this.update(&conn, connector_response_update)
-            .await
-            .map_err(Into::into)
-            .into_report()
+#[cfg(feature = "kv_store")]
+mod storage {
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::HsetnxReply;
+
+    use super::ConnectorResponseInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums, kv},
+        utils::{self, db_utils, storage_partitioning::PartitionKey},
+    };
+
+    #[async_trait::async_trait]
+    impl ConnectorResponseInterface for Store {
+        async fn insert_connector_response(
+            &self,
+            connector_response: storage_types::ConnectorResponseNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::ConnectorResponse, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    connector_response
+                        .insert(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!(
+                        "{}_{}",
+                        connector_response.merchant_id, connector_response.payment_id
+                    );
+                    let field = format!("connector_resp_{}", connector_response.attempt_id);
+
+                    let created_connector_response = storage_types::ConnectorResponse {
+                        id: Default::default(),
+                        payment_id: connector_response.payment_id.clone(),
+                        merchant_id: connector_response.merchant_id.clone(),
+                        attempt_id: connector_response.attempt_id.clone(),
+                        created_at: connector_response.created_at,
+                        modified_at: connector_response.modified_at,
+                        connector_name: connector_response.connector_name.clone(),
+                        connector_transaction_id: connector_response
+                            .connector_transaction_id
+                            .clone(),
+                        authentication_data: connector_response.authentication_data.clone(),
+                        encoded_data: connector_response.encoded_data.clone(),
+                    };
+
+                    match self
+                        .redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .serialize_and_set_hash_field_if_not_exist(
+                            &key,
+                            &field,
+                            &created_connector_response,
+                        )
+                        .await
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "connector_response",
+                            key: Some(key),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => {
+                            let redis_entry = kv::TypedSql {
+                                op: kv::DBOperation::Insert {
+                                    insertable: kv::Insertable::ConnectorResponse(
+                                        connector_response,
+                                    ),
+                                },
+                            };
+                            self.push_to_drainer_stream::<storage_types::ConnectorResponse>(
+                                redis_entry,
+                                PartitionKey::MerchantIdPaymentId {
+                                    merchant_id: &created_connector_response.merchant_id,
+                                    payment_id: &created_connector_response.payment_id,
+                                },
+                            )
+                            .await?;
+                            Ok(created_connector_response)
+                        }
+                        Err(error) => Err(error.change_context(errors::StorageError::KVError)),
+                    }
+                }
+            }
+        }
+
+        async fn find_connector_response_by_payment_id_merchant_id_attempt_id(
+            &self,
+            payment_id: &str,
+            merchant_id: &str,
+            attempt_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::ConnectorResponse, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage_types::ConnectorResponse::find_by_payment_id_merchant_id_attempt_id(
+                    &conn,
+                    payment_id,
+                    merchant_id,
+                    attempt_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{merchant_id}_{payment_id}");
+                    let field = format!("connector_resp_{attempt_id}");
+
+                    db_utils::try_redis_get_else_try_database_get(
+                        self.redis_conn()
+                            .map_err(Into::<errors::StorageError>::into)?
+                            .get_hash_field_and_deserialize(&key, &field, "ConnectorResponse"),
+                        database_call,
+                    )
+                    .await
+                }
+            }
+        }
+
+        async fn update_connector_response(
+            &self,
+            this: storage_types::ConnectorResponse,
+            connector_response_update: storage_types::ConnectorResponseUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::ConnectorResponse, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    this.update(&conn, connector_response_update)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{}_{}", this.merchant_id, this.payment_id);
+                    let field = format!("connector_resp_{}", this.attempt_id);
+
+                    let updated_connector_response = connector_response_update
+                        .clone()
+                        .apply_changeset(this.clone());
+                    let redis_value =
+                        utils::Encode::<storage_types::ConnectorResponse>::encode_to_string_of_json(
+                            &updated_connector_response,
+                        )
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    self.redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .set_hash_fields(&key, (&field, redis_value))
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::ConnectorResponseUpdate(
+                                kv::ConnectorResponseUpdateMems {
+                                    orig: this,
+                                    update_data: connector_response_update,
+                                },
+                            ),
+                        },
+                    };
+                    self.push_to_drainer_stream::<storage_types::ConnectorResponse>(
+                        redis_entry,
+                        PartitionKey::MerchantIdPaymentId {
+                            merchant_id: &updated_connector_response.merchant_id,
+                            payment_id: &updated_connector_response.payment_id,
+                        },
+                    )
+                    .await?;
+                    Ok(updated_connector_response)
+                }
+            }
+        }
     }
 }
 
@@ -83,11 +278,11 @@ impl ConnectorResponseInterface for Store {
 impl ConnectorResponseInterface for MockDb {
     async fn insert_connector_response(
         &self,
-        new: storage::ConnectorResponseNew,
+        new: storage_types::ConnectorResponseNew,
         _storage_scheme: enums::MerchantStorageScheme,
-    ) -> CustomResult<storage::ConnectorResponse, errors::StorageError> {
+    ) -> CustomResult<storage_types::ConnectorResponse, errors::StorageError> {
         let mut connector_response = self.connector_response.lock().await;
-        let response = storage::ConnectorResponse {
+        let response = storage_types::ConnectorResponse {
             #[allow(clippy::as_conversions)]
             id: connector_response.len() as i32,
             payment_id: new.payment_id,
@@ -110,7 +305,7 @@ impl ConnectorResponseInterface for MockDb {
         _merchant_id: &str,
         _attempt_id: &str,
         _storage_scheme: enums::MerchantStorageScheme,
-    ) -> CustomResult<storage::ConnectorResponse, errors::StorageError> {
+    ) -> CustomResult<storage_types::ConnectorResponse, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
@@ -119,10 +314,10 @@ impl ConnectorResponseInterface for MockDb {
     #[allow(clippy::unwrap_used)]
     async fn update_connector_response(
         &self,
-        this: storage::ConnectorResponse,
-        connector_response_update: storage::ConnectorResponseUpdate,
+        this: storage_types::ConnectorResponse,
+        connector_response_update: storage_types::ConnectorResponseUpdate,
         _storage_scheme: enums::MerchantStorageScheme,
-    ) -> CustomResult<storage::ConnectorResponse, errors::StorageError> {
+    ) -> CustomResult<storage_types::ConnectorResponse, errors::StorageError> {
         let mut connector_response = self.connector_response.lock().await;
         let response = connector_response
             .iter_mut()
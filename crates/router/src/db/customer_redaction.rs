@@ -0,0 +1,139 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CustomerRedactionInterface {
+    async fn insert_customer_redaction(
+        &self,
+        customer_redaction: storage::CustomerRedactionNew,
+    ) -> CustomResult<storage::CustomerRedaction, errors::StorageError>;
+
+    async fn find_customer_redaction_by_redaction_id(
+        &self,
+        redaction_id: &str,
+    ) -> CustomResult<storage::CustomerRedaction, errors::StorageError>;
+
+    async fn update_customer_redaction_by_redaction_id(
+        &self,
+        redaction_id: &str,
+        customer_redaction: storage::CustomerRedactionUpdate,
+    ) -> CustomResult<storage::CustomerRedaction, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CustomerRedactionInterface for Store {
+    async fn insert_customer_redaction(
+        &self,
+        customer_redaction: storage::CustomerRedactionNew,
+    ) -> CustomResult<storage::CustomerRedaction, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        customer_redaction
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_customer_redaction_by_redaction_id(
+        &self,
+        redaction_id: &str,
+    ) -> CustomResult<storage::CustomerRedaction, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomerRedaction::find_by_redaction_id(&conn, redaction_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_customer_redaction_by_redaction_id(
+        &self,
+        redaction_id: &str,
+        customer_redaction: storage::CustomerRedactionUpdate,
+    ) -> CustomResult<storage::CustomerRedaction, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::CustomerRedaction::update_by_redaction_id(&conn, redaction_id, customer_redaction)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerRedactionInterface for MockDb {
+    async fn insert_customer_redaction(
+        &self,
+        customer_redaction: storage::CustomerRedactionNew,
+    ) -> CustomResult<storage::CustomerRedaction, errors::StorageError> {
+        let mut locked_customer_redactions = self.customer_redactions.lock().await;
+
+        if locked_customer_redactions
+            .iter()
+            .any(|r| r.redaction_id == customer_redaction.redaction_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "redaction_id",
+                key: Some(customer_redaction.redaction_id.clone()),
+            })?;
+        }
+
+        let now = common_utils::date_time::now();
+
+        let new_customer_redaction = storage::CustomerRedaction {
+            #[allow(clippy::as_conversions)]
+            id: locked_customer_redactions.len() as i32,
+            redaction_id: customer_redaction.redaction_id,
+            merchant_id: customer_redaction.merchant_id,
+            customer_id: customer_redaction.customer_id,
+            status: customer_redaction.status,
+            redacted_records: None,
+            error_message: None,
+            created_at: now,
+            modified_at: now,
+            completed_at: None,
+        };
+
+        locked_customer_redactions.push(new_customer_redaction.clone());
+
+        Ok(new_customer_redaction)
+    }
+
+    async fn find_customer_redaction_by_redaction_id(
+        &self,
+        redaction_id: &str,
+    ) -> CustomResult<storage::CustomerRedaction, errors::StorageError> {
+        self.customer_redactions
+            .lock()
+            .await
+            .iter()
+            .find(|r| r.redaction_id == redaction_id)
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No customer redaction available for redaction_id = {redaction_id}"
+            )))
+            .into_report()
+    }
+
+    async fn update_customer_redaction_by_redaction_id(
+        &self,
+        redaction_id: &str,
+        customer_redaction: storage::CustomerRedactionUpdate,
+    ) -> CustomResult<storage::CustomerRedaction, errors::StorageError> {
+        let mut locked_customer_redactions = self.customer_redactions.lock().await;
+
+        let customer_redaction_to_update = locked_customer_redactions
+            .iter_mut()
+            .find(|r| r.redaction_id == redaction_id)
+            .ok_or(errors::StorageError::MockDbError)?;
+
+        *customer_redaction_to_update =
+            customer_redaction.apply_changeset(customer_redaction_to_update.clone());
+
+        Ok(customer_redaction_to_update.clone())
+    }
+}
@@ -42,6 +42,16 @@ where
         customer: storage::CustomerUpdate,
     ) -> CustomResult<domain::Customer, errors::StorageError>;
 
+    /// Updates the customer only if it has not been modified since `modified_at`, so that
+    /// concurrent updates are not overwritten. A not found error is returned otherwise.
+    async fn update_customer_by_customer_id_merchant_id_modified_at(
+        &self,
+        customer_id: String,
+        merchant_id: String,
+        modified_at: time::PrimitiveDateTime,
+        customer: storage::CustomerUpdate,
+    ) -> CustomResult<domain::Customer, errors::StorageError>;
+
     async fn find_customer_by_customer_id_merchant_id(
         &self,
         customer_id: &str,
@@ -52,6 +62,13 @@ where
         &self,
         customer_data: domain::Customer,
     ) -> CustomResult<domain::Customer, errors::StorageError>;
+
+    async fn list_customers_by_merchant_id_after_row_id(
+        &self,
+        merchant_id: &str,
+        last_row_id: Option<i32>,
+        limit: i64,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -115,6 +132,33 @@ impl CustomerInterface for Store {
         .await
     }
 
+    async fn update_customer_by_customer_id_merchant_id_modified_at(
+        &self,
+        customer_id: String,
+        merchant_id: String,
+        modified_at: time::PrimitiveDateTime,
+        customer: storage::CustomerUpdate,
+    ) -> CustomResult<domain::Customer, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Customer::update_by_customer_id_merchant_id_modified_at(
+            &conn,
+            customer_id,
+            merchant_id,
+            modified_at,
+            customer.into(),
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+        .async_and_then(|c| async {
+            let merchant_id = c.merchant_id.clone();
+            c.convert(self, &merchant_id)
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+        })
+        .await
+    }
+
     async fn find_customer_by_customer_id_merchant_id(
         &self,
         customer_id: &str,
@@ -174,6 +218,35 @@ impl CustomerInterface for Store {
             .map_err(Into::into)
             .into_report()
     }
+
+    async fn list_customers_by_merchant_id_after_row_id(
+        &self,
+        merchant_id: &str,
+        last_row_id: Option<i32>,
+        limit: i64,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        let customers = storage::Customer::list_by_merchant_id_after_row_id(
+            &conn,
+            merchant_id,
+            last_row_id,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()?;
+
+        let mut domain_customers = Vec::with_capacity(customers.len());
+        for customer in customers {
+            domain_customers.push(
+                customer
+                    .convert(self, merchant_id)
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            );
+        }
+        Ok(domain_customers)
+    }
 }
 
 #[async_trait::async_trait]
@@ -212,6 +285,17 @@ impl CustomerInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn update_customer_by_customer_id_merchant_id_modified_at(
+        &self,
+        _customer_id: String,
+        _merchant_id: String,
+        _modified_at: time::PrimitiveDateTime,
+        _customer: storage::CustomerUpdate,
+    ) -> CustomResult<domain::Customer, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn find_customer_by_customer_id_merchant_id(
         &self,
         _customer_id: &str,
@@ -249,4 +333,35 @@ impl CustomerInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn list_customers_by_merchant_id_after_row_id(
+        &self,
+        merchant_id: &str,
+        last_row_id: Option<i32>,
+        limit: i64,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+        let customers = self.customers.lock().await;
+        let mut customers = customers
+            .iter()
+            .filter(|customer| {
+                customer.merchant_id == merchant_id && customer.id > last_row_id.unwrap_or_default()
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        customers.sort_by_key(|customer| customer.id);
+
+        let mut domain_customers = Vec::new();
+        for customer in customers
+            .into_iter()
+            .take(usize::try_from(limit).unwrap_or_default())
+        {
+            domain_customers.push(
+                customer
+                    .convert(self, merchant_id)
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            );
+        }
+        Ok(domain_customers)
+    }
 }
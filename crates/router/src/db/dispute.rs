@@ -1,12 +1,10 @@
 use api_models::enums::{DisputeStage, DisputeStatus};
-use error_stack::IntoReport;
 
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection,
     core::errors::{self, CustomResult},
     types::{
-        storage::{self, DisputeDbExt},
+        storage::{self as storage_types, enums},
         transformers::ForeignFrom,
     },
 };
@@ -15,119 +13,441 @@ use crate::{
 pub trait DisputeInterface {
     async fn insert_dispute(
         &self,
-        dispute: storage::DisputeNew,
-    ) -> CustomResult<storage::Dispute, errors::StorageError>;
+        dispute: storage_types::DisputeNew,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Dispute, errors::StorageError>;
 
     async fn find_by_merchant_id_payment_id_connector_dispute_id(
         &self,
         merchant_id: &str,
         payment_id: &str,
         connector_dispute_id: &str,
-    ) -> CustomResult<Option<storage::Dispute>, errors::StorageError>;
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Option<storage_types::Dispute>, errors::StorageError>;
 
     async fn find_dispute_by_merchant_id_dispute_id(
         &self,
         merchant_id: &str,
         dispute_id: &str,
-    ) -> CustomResult<storage::Dispute, errors::StorageError>;
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Dispute, errors::StorageError>;
 
     async fn find_disputes_by_merchant_id(
         &self,
         merchant_id: &str,
         dispute_constraints: api_models::disputes::DisputeListConstraints,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
+    ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError>;
 
     async fn find_disputes_by_merchant_id_payment_id(
         &self,
         merchant_id: &str,
         payment_id: &str,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError>;
 
     async fn update_dispute(
         &self,
-        this: storage::Dispute,
-        dispute: storage::DisputeUpdate,
-    ) -> CustomResult<storage::Dispute, errors::StorageError>;
+        this: storage_types::Dispute,
+        dispute: storage_types::DisputeUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Dispute, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl DisputeInterface for Store {
-    async fn insert_dispute(
-        &self,
-        dispute: storage::DisputeNew,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        dispute
-            .insert(&conn)
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::IntoReport;
+
+    use super::DisputeInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums, DisputeDbExt},
+    };
+
+    #[async_trait::async_trait]
+    impl DisputeInterface for Store {
+        async fn insert_dispute(
+            &self,
+            dispute: storage_types::DisputeNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            dispute
+                .insert(&conn)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn find_by_merchant_id_payment_id_connector_dispute_id(
+            &self,
+            merchant_id: &str,
+            payment_id: &str,
+            connector_dispute_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Option<storage_types::Dispute>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Dispute::find_by_merchant_id_payment_id_connector_dispute_id(
+                &conn,
+                merchant_id,
+                payment_id,
+                connector_dispute_id,
+            )
             .await
             .map_err(Into::into)
             .into_report()
-    }
+        }
 
-    async fn find_by_merchant_id_payment_id_connector_dispute_id(
-        &self,
-        merchant_id: &str,
-        payment_id: &str,
-        connector_dispute_id: &str,
-    ) -> CustomResult<Option<storage::Dispute>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Dispute::find_by_merchant_id_payment_id_connector_dispute_id(
-            &conn,
-            merchant_id,
-            payment_id,
-            connector_dispute_id,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
-    }
+        async fn find_dispute_by_merchant_id_dispute_id(
+            &self,
+            merchant_id: &str,
+            dispute_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Dispute::find_by_merchant_id_dispute_id(&conn, merchant_id, dispute_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
 
-    async fn find_dispute_by_merchant_id_dispute_id(
-        &self,
-        merchant_id: &str,
-        dispute_id: &str,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Dispute::find_by_merchant_id_dispute_id(&conn, merchant_id, dispute_id)
-            .await
-            .map_err(Into::into)
-            .into_report()
+        async fn find_disputes_by_merchant_id(
+            &self,
+            merchant_id: &str,
+            dispute_constraints: api_models::disputes::DisputeListConstraints,
+        ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Dispute::filter_by_constraints(&conn, merchant_id, dispute_constraints)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn find_disputes_by_merchant_id_payment_id(
+            &self,
+            merchant_id: &str,
+            payment_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Dispute::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn update_dispute(
+            &self,
+            this: storage_types::Dispute,
+            dispute: storage_types::DisputeUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            this.update(&conn, dispute)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
     }
+}
 
-    async fn find_disputes_by_merchant_id(
-        &self,
-        merchant_id: &str,
-        dispute_constraints: api_models::disputes::DisputeListConstraints,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Dispute::filter_by_constraints(&conn, merchant_id, dispute_constraints)
-            .await
-            .map_err(Into::into)
-            .into_report()
+#[cfg(feature = "kv_store")]
+mod storage {
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::HsetnxReply;
+
+    use super::DisputeInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        db::reverse_lookup::ReverseLookupInterface,
+        services::Store,
+        types::storage::{self as storage_types, enums, kv, DisputeDbExt},
+        utils::{self, db_utils, storage_partitioning::PartitionKey},
+    };
+
+    #[async_trait::async_trait]
+    impl DisputeInterface for Store {
+        async fn insert_dispute(
+            &self,
+            dispute: storage_types::DisputeNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    dispute
+                        .insert(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{}_{}", dispute.merchant_id, dispute.payment_id);
+                    let now = common_utils::date_time::now();
+                    let created_dispute = storage_types::Dispute {
+                        id: Default::default(),
+                        dispute_id: dispute.dispute_id.clone(),
+                        amount: dispute.amount.clone(),
+                        currency: dispute.currency.clone(),
+                        dispute_stage: dispute.dispute_stage,
+                        dispute_status: dispute.dispute_status,
+                        payment_id: dispute.payment_id.clone(),
+                        attempt_id: dispute.attempt_id.clone(),
+                        merchant_id: dispute.merchant_id.clone(),
+                        connector_status: dispute.connector_status.clone(),
+                        connector_dispute_id: dispute.connector_dispute_id.clone(),
+                        connector_reason: dispute.connector_reason.clone(),
+                        connector_reason_code: dispute.connector_reason_code.clone(),
+                        challenge_required_by: dispute.challenge_required_by,
+                        connector_created_at: dispute.connector_created_at,
+                        connector_updated_at: dispute.connector_updated_at,
+                        created_at: now,
+                        modified_at: now,
+                        connector: dispute.connector.clone(),
+                        evidence: dispute.evidence.clone().unwrap_or_else(|| {
+                            masking::Secret::new(serde_json::Value::Object(Default::default()))
+                        }),
+                    };
+
+                    let field = format!("dispute_{}", created_dispute.dispute_id);
+                    match self
+                        .redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .serialize_and_set_hash_field_if_not_exist(&key, &field, &created_dispute)
+                        .await
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "dispute",
+                            key: Some(created_dispute.dispute_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => {
+                            self.insert_reverse_lookup(
+                                storage_types::ReverseLookupNew {
+                                    lookup_id: format!(
+                                        "{}_{}",
+                                        created_dispute.merchant_id, created_dispute.dispute_id
+                                    ),
+                                    pk_id: key,
+                                    sk_id: field,
+                                    source: "dispute".to_string(),
+                                },
+                                storage_scheme,
+                            )
+                            .await?;
+
+                            let redis_entry = kv::TypedSql {
+                                op: kv::DBOperation::Insert {
+                                    insertable: kv::Insertable::Dispute(dispute),
+                                },
+                            };
+                            self.push_to_drainer_stream::<storage_types::Dispute>(
+                                redis_entry,
+                                PartitionKey::MerchantIdPaymentId {
+                                    merchant_id: &created_dispute.merchant_id,
+                                    payment_id: &created_dispute.payment_id,
+                                },
+                            )
+                            .await?;
+                            Ok(created_dispute)
+                        }
+                        Err(error) => Err(error.change_context(errors::StorageError::KVError)),
+                    }
+                }
+            }
+        }
+
+        async fn find_by_merchant_id_payment_id_connector_dispute_id(
+            &self,
+            merchant_id: &str,
+            payment_id: &str,
+            connector_dispute_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Option<storage_types::Dispute>, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage_types::Dispute::find_by_merchant_id_payment_id_connector_dispute_id(
+                    &conn,
+                    merchant_id,
+                    payment_id,
+                    connector_dispute_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let dispute = find_disputes_in_redis(self, merchant_id, payment_id)
+                        .await?
+                        .into_iter()
+                        .find(|dispute| dispute.connector_dispute_id == connector_dispute_id);
+                    match dispute {
+                        Some(dispute) => Ok(Some(dispute)),
+                        None => database_call().await,
+                    }
+                }
+            }
+        }
+
+        async fn find_dispute_by_merchant_id_dispute_id(
+            &self,
+            merchant_id: &str,
+            dispute_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage_types::Dispute::find_by_merchant_id_dispute_id(
+                    &conn,
+                    merchant_id,
+                    dispute_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let lookup_id = format!("{merchant_id}_{dispute_id}");
+                    let lookup = match self.get_lookup_by_lookup_id(&lookup_id).await {
+                        Ok(lookup) => lookup,
+                        // Disputes created before the merchant moved to the KV store do not
+                        // have a reverse lookup
+                        Err(error) if error.current_context().is_db_not_found() => {
+                            return database_call().await
+                        }
+                        Err(error) => return Err(error),
+                    };
+
+                    db_utils::try_redis_get_else_try_database_get(
+                        self.redis_conn()
+                            .map_err(Into::<errors::StorageError>::into)?
+                            .get_hash_field_and_deserialize(
+                                &lookup.pk_id,
+                                &lookup.sk_id,
+                                "Dispute",
+                            ),
+                        database_call,
+                    )
+                    .await
+                }
+            }
+        }
+
+        async fn find_disputes_by_merchant_id(
+            &self,
+            merchant_id: &str,
+            dispute_constraints: api_models::disputes::DisputeListConstraints,
+        ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Dispute::filter_by_constraints(&conn, merchant_id, dispute_constraints)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn find_disputes_by_merchant_id_payment_id(
+            &self,
+            merchant_id: &str,
+            payment_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage_types::Dispute::find_by_merchant_id_payment_id(
+                    &conn,
+                    merchant_id,
+                    payment_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let disputes = find_disputes_in_redis(self, merchant_id, payment_id).await?;
+                    if disputes.is_empty() {
+                        database_call().await
+                    } else {
+                        Ok(disputes)
+                    }
+                }
+            }
+        }
+
+        async fn update_dispute(
+            &self,
+            this: storage_types::Dispute,
+            dispute: storage_types::DisputeUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    this.update(&conn, dispute)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{}_{}", this.merchant_id, this.payment_id);
+                    let field = format!("dispute_{}", this.dispute_id);
+
+                    let updated_dispute =
+                        storage_types::DisputeUpdateInternal::from(dispute.clone())
+                            .create_dispute(this.clone());
+                    let redis_value =
+                        utils::Encode::<storage_types::Dispute>::encode_to_string_of_json(
+                            &updated_dispute,
+                        )
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    self.redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .set_hash_fields(&key, (&field, redis_value))
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::DisputeUpdate(kv::DisputeUpdateMems {
+                                orig: this,
+                                update_data: dispute,
+                            }),
+                        },
+                    };
+                    self.push_to_drainer_stream::<storage_types::Dispute>(
+                        redis_entry,
+                        PartitionKey::MerchantIdPaymentId {
+                            merchant_id: &updated_dispute.merchant_id,
+                            payment_id: &updated_dispute.payment_id,
+                        },
+                    )
+                    .await?;
+                    Ok(updated_dispute)
+                }
+            }
+        }
     }
 
-    async fn find_disputes_by_merchant_id_payment_id(
-        &self,
+    async fn find_disputes_in_redis(
+        store: &Store,
         merchant_id: &str,
         payment_id: &str,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Dispute::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
-            .await
-            .map_err(Into::into)
-            .into_report()
-    }
-
-    async fn update_dispute(
-        &self,
-        this: storage::Dispute,
-        dispute: storage::DisputeUpdate,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        this.update(&conn, dispute)
+    ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
+        let key = format!("{merchant_id}_{payment_id}");
+        store
+            .redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .hscan_and_deserialize(&key, "dispute_*", None)
             .await
-            .map_err(Into::into)
-            .into_report()
+            .change_context(errors::StorageError::KVError)
     }
 }
 
@@ -135,8 +455,9 @@ impl DisputeInterface for Store {
 impl DisputeInterface for MockDb {
     async fn insert_dispute(
         &self,
-        dispute: storage::DisputeNew,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        dispute: storage_types::DisputeNew,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
         let evidence = dispute.evidence.ok_or(errors::StorageError::MockDbError)?;
 
         let mut locked_disputes = self.disputes.lock().await;
@@ -150,7 +471,7 @@ impl DisputeInterface for MockDb {
 
         let now = common_utils::date_time::now();
 
-        let new_dispute = storage::Dispute {
+        let new_dispute = storage_types::Dispute {
             #[allow(clippy::as_conversions)]
             id: locked_disputes.len() as i32,
             dispute_id: dispute.dispute_id,
@@ -183,7 +504,8 @@ impl DisputeInterface for MockDb {
         merchant_id: &str,
         payment_id: &str,
         connector_dispute_id: &str,
-    ) -> CustomResult<Option<storage::Dispute>, errors::StorageError> {
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Option<storage_types::Dispute>, errors::StorageError> {
         Ok(self
             .disputes
             .lock()
@@ -201,7 +523,8 @@ impl DisputeInterface for MockDb {
         &self,
         merchant_id: &str,
         dispute_id: &str,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
         let locked_disputes = self.disputes.lock().await;
 
         locked_disputes
@@ -216,7 +539,7 @@ impl DisputeInterface for MockDb {
         &self,
         merchant_id: &str,
         dispute_constraints: api_models::disputes::DisputeListConstraints,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+    ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
         let locked_disputes = self.disputes.lock().await;
 
         Ok(locked_disputes
@@ -287,7 +610,8 @@ impl DisputeInterface for MockDb {
         &self,
         merchant_id: &str,
         payment_id: &str,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
         let locked_disputes = self.disputes.lock().await;
 
         Ok(locked_disputes
@@ -299,9 +623,10 @@ impl DisputeInterface for MockDb {
 
     async fn update_dispute(
         &self,
-        this: storage::Dispute,
-        dispute: storage::DisputeUpdate,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        this: storage_types::Dispute,
+        dispute: storage_types::DisputeUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
         let mut locked_disputes = self.disputes.lock().await;
 
         let mut dispute_to_update = locked_disputes
@@ -312,7 +637,7 @@ impl DisputeInterface for MockDb {
         let now = common_utils::date_time::now();
 
         match dispute {
-            storage::DisputeUpdate::Update {
+            storage_types::DisputeUpdate::Update {
                 dispute_stage,
                 dispute_status,
                 connector_status,
@@ -341,7 +666,7 @@ impl DisputeInterface for MockDb {
                 dispute_to_update.dispute_status = dispute_status;
                 dispute_to_update.connector_status = connector_status;
             }
-            storage::DisputeUpdate::StatusUpdate {
+            storage_types::DisputeUpdate::StatusUpdate {
                 dispute_status,
                 connector_status,
             } => {
@@ -350,7 +675,7 @@ impl DisputeInterface for MockDb {
                 }
                 dispute_to_update.dispute_status = dispute_status;
             }
-            storage::DisputeUpdate::EvidenceUpdate { evidence } => {
+            storage_types::DisputeUpdate::EvidenceUpdate { evidence } => {
                 dispute_to_update.evidence = evidence;
             }
         }
@@ -370,7 +695,7 @@ mod tests {
         use serde_json::Value;
         use storage_models::{
             dispute::DisputeNew,
-            enums::{DisputeStage, DisputeStatus},
+            enums::{DisputeStage, DisputeStatus, MerchantStorageScheme},
         };
         use time::macros::datetime;
 
@@ -411,13 +736,16 @@ mod tests {
             let mockdb = MockDb::new(&Default::default()).await;
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: "merchant_1".into(),
-                    payment_id: "payment_1".into(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_1".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -439,24 +767,30 @@ mod tests {
             let mockdb = MockDb::new(&Default::default()).await;
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: "merchant_1".into(),
-                    payment_id: "payment_1".into(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_1".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let _ = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_2".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: "merchant_1".into(),
-                    payment_id: "payment_1".into(),
-                    connector_dispute_id: "connector_dispute_2".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_2".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_1".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_2".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -465,6 +799,7 @@ mod tests {
                     "merchant_1",
                     "payment_1",
                     "connector_dispute_1",
+                    MerchantStorageScheme::PostgresOnly,
                 )
                 .await
                 .unwrap();
@@ -479,29 +814,39 @@ mod tests {
             let mockdb = MockDb::new(&Default::default()).await;
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: "merchant_1".into(),
-                    payment_id: "payment_1".into(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_1".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let _ = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_2".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: "merchant_1".into(),
-                    payment_id: "payment_1".into(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_2".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_1".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let found_dispute = mockdb
-                .find_dispute_by_merchant_id_dispute_id("merchant_1", "dispute_1")
+                .find_dispute_by_merchant_id_dispute_id(
+                    "merchant_1",
+                    "dispute_1",
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -513,24 +858,30 @@ mod tests {
             let mockdb = MockDb::new(&Default::default()).await;
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: "merchant_1".into(),
-                    payment_id: "payment_1".into(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_1".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let _ = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_2".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: "merchant_2".into(),
-                    payment_id: "payment_1".into(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_2".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_2".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -563,29 +914,39 @@ mod tests {
             let mockdb = MockDb::new(&Default::default()).await;
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: "merchant_1".into(),
-                    payment_id: "payment_1".into(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_1".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let _ = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_2".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: "merchant_2".into(),
-                    payment_id: "payment_1".into(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_2".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_2".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let found_disputes = mockdb
-                .find_disputes_by_merchant_id_payment_id("merchant_1", "payment_1")
+                .find_disputes_by_merchant_id_payment_id(
+                    "merchant_1",
+                    "payment_1",
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -599,7 +960,7 @@ mod tests {
             use serde_json::Value;
             use storage_models::{
                 dispute::DisputeUpdate,
-                enums::{DisputeStage, DisputeStatus},
+                enums::{DisputeStage, DisputeStatus, MerchantStorageScheme},
             };
             use time::macros::datetime;
 
@@ -616,13 +977,16 @@ mod tests {
                 let mockdb = MockDb::new(&Default::default()).await;
 
                 let created_dispute = mockdb
-                    .insert_dispute(create_dispute_new(DisputeNewIds {
-                        dispute_id: "dispute_1".into(),
-                        attempt_id: "attempt_1".into(),
-                        merchant_id: "merchant_1".into(),
-                        payment_id: "payment_1".into(),
-                        connector_dispute_id: "connector_dispute_1".into(),
-                    }))
+                    .insert_dispute(
+                        create_dispute_new(DisputeNewIds {
+                            dispute_id: "dispute_1".into(),
+                            attempt_id: "attempt_1".into(),
+                            merchant_id: "merchant_1".into(),
+                            payment_id: "payment_1".into(),
+                            connector_dispute_id: "connector_dispute_1".into(),
+                        }),
+                        MerchantStorageScheme::PostgresOnly,
+                    )
                     .await
                     .unwrap();
 
@@ -638,6 +1002,7 @@ mod tests {
                             challenge_required_by: Some(datetime!(2019-01-10 0:00)),
                             connector_updated_at: Some(datetime!(2019-01-11 0:00)),
                         },
+                        MerchantStorageScheme::PostgresOnly,
                     )
                     .await
                     .unwrap();
@@ -693,13 +1058,16 @@ mod tests {
                 let mockdb = MockDb::new(&Default::default()).await;
 
                 let created_dispute = mockdb
-                    .insert_dispute(create_dispute_new(DisputeNewIds {
-                        dispute_id: "dispute_1".into(),
-                        attempt_id: "attempt_1".into(),
-                        merchant_id: "merchant_1".into(),
-                        payment_id: "payment_1".into(),
-                        connector_dispute_id: "connector_dispute_1".into(),
-                    }))
+                    .insert_dispute(
+                        create_dispute_new(DisputeNewIds {
+                            dispute_id: "dispute_1".into(),
+                            attempt_id: "attempt_1".into(),
+                            merchant_id: "merchant_1".into(),
+                            payment_id: "payment_1".into(),
+                            connector_dispute_id: "connector_dispute_1".into(),
+                        }),
+                        MerchantStorageScheme::PostgresOnly,
+                    )
                     .await
                     .unwrap();
 
@@ -710,6 +1078,7 @@ mod tests {
                             dispute_status: DisputeStatus::DisputeExpired,
                             connector_status: Some("updated_connector_status".into()),
                         },
+                        MerchantStorageScheme::PostgresOnly,
                     )
                     .await
                     .unwrap();
@@ -765,13 +1134,16 @@ mod tests {
                 let mockdb = MockDb::new(&Default::default()).await;
 
                 let created_dispute = mockdb
-                    .insert_dispute(create_dispute_new(DisputeNewIds {
-                        dispute_id: "dispute_1".into(),
-                        attempt_id: "attempt_1".into(),
-                        merchant_id: "merchant_1".into(),
-                        payment_id: "payment_1".into(),
-                        connector_dispute_id: "connector_dispute_1".into(),
-                    }))
+                    .insert_dispute(
+                        create_dispute_new(DisputeNewIds {
+                            dispute_id: "dispute_1".into(),
+                            attempt_id: "attempt_1".into(),
+                            merchant_id: "merchant_1".into(),
+                            payment_id: "payment_1".into(),
+                            connector_dispute_id: "connector_dispute_1".into(),
+                        }),
+                        MerchantStorageScheme::PostgresOnly,
+                    )
                     .await
                     .unwrap();
 
@@ -781,6 +1153,7 @@ mod tests {
                         DisputeUpdate::EvidenceUpdate {
                             evidence: Secret::from(Value::String("updated_evidence".into())),
                         },
+                        MerchantStorageScheme::PostgresOnly,
                     )
                     .await
                     .unwrap();
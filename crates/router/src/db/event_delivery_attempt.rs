@@ -0,0 +1,193 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait EventDeliveryAttemptInterface {
+    async fn insert_event_delivery_attempt(
+        &self,
+        delivery_attempt: storage::EventDeliveryAttemptNew,
+    ) -> CustomResult<storage::EventDeliveryAttempt, errors::StorageError>;
+    async fn find_event_delivery_attempts_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<Vec<storage::EventDeliveryAttempt>, errors::StorageError>;
+    async fn update_event_delivery_attempt_request_body_by_primary_object_ids(
+        &self,
+        merchant_id: &str,
+        primary_object_ids: Vec<String>,
+        request_body: String,
+    ) -> CustomResult<usize, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl EventDeliveryAttemptInterface for Store {
+    async fn insert_event_delivery_attempt(
+        &self,
+        delivery_attempt: storage::EventDeliveryAttemptNew,
+    ) -> CustomResult<storage::EventDeliveryAttempt, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        delivery_attempt
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+    async fn find_event_delivery_attempts_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<Vec<storage::EventDeliveryAttempt>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::EventDeliveryAttempt::find_by_merchant_id_event_id(&conn, merchant_id, event_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+    async fn update_event_delivery_attempt_request_body_by_primary_object_ids(
+        &self,
+        merchant_id: &str,
+        primary_object_ids: Vec<String>,
+        request_body: String,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::EventDeliveryAttempt::update_request_body_by_merchant_id_primary_object_ids(
+            &conn,
+            merchant_id,
+            primary_object_ids,
+            request_body,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl EventDeliveryAttemptInterface for MockDb {
+    async fn insert_event_delivery_attempt(
+        &self,
+        delivery_attempt: storage::EventDeliveryAttemptNew,
+    ) -> CustomResult<storage::EventDeliveryAttempt, errors::StorageError> {
+        let mut locked_attempts = self.event_delivery_attempts.lock().await;
+
+        let stored_attempt = storage::EventDeliveryAttempt {
+            #[allow(clippy::as_conversions)]
+            id: locked_attempts.len() as i32,
+            attempt_id: delivery_attempt.attempt_id,
+            event_id: delivery_attempt.event_id,
+            merchant_id: delivery_attempt.merchant_id,
+            request_body: delivery_attempt.request_body,
+            response_status_code: delivery_attempt.response_status_code,
+            response_body: delivery_attempt.response_body,
+            latency_ms: delivery_attempt.latency_ms,
+            error_message: delivery_attempt.error_message,
+            is_manual_retry: delivery_attempt.is_manual_retry,
+            created_at: common_utils::date_time::now(),
+        };
+
+        locked_attempts.push(stored_attempt.clone());
+
+        Ok(stored_attempt)
+    }
+    async fn find_event_delivery_attempts_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<Vec<storage::EventDeliveryAttempt>, errors::StorageError> {
+        let locked_attempts = self.event_delivery_attempts.lock().await;
+
+        Ok(locked_attempts
+            .iter()
+            .filter(|attempt| attempt.merchant_id == merchant_id && attempt.event_id == event_id)
+            .cloned()
+            .collect())
+    }
+    async fn update_event_delivery_attempt_request_body_by_primary_object_ids(
+        &self,
+        merchant_id: &str,
+        primary_object_ids: Vec<String>,
+        request_body: String,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let event_ids = self
+            .events
+            .lock()
+            .await
+            .iter()
+            .filter(|event| {
+                event.merchant_id.as_deref() == Some(merchant_id)
+                    && primary_object_ids.contains(&event.primary_object_id)
+            })
+            .map(|event| event.event_id.clone())
+            .collect::<Vec<_>>();
+        let mut locked_attempts = self.event_delivery_attempts.lock().await;
+
+        let mut updated_attempts = 0;
+        for attempt in locked_attempts.iter_mut().filter(|attempt| {
+            attempt.merchant_id == merchant_id
+                && attempt.request_body != request_body
+                && event_ids.contains(&attempt.event_id)
+        }) {
+            attempt.request_body = request_body.clone();
+            updated_attempts += 1;
+        }
+
+        Ok(updated_attempts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        db::{event_delivery_attempt::EventDeliveryAttemptInterface, MockDb},
+        types::storage,
+    };
+
+    fn delivery_attempt(attempt_id: &str, event_id: &str) -> storage::EventDeliveryAttemptNew {
+        storage::EventDeliveryAttemptNew {
+            attempt_id: attempt_id.into(),
+            event_id: event_id.into(),
+            merchant_id: "merchant_1".into(),
+            request_body: "{}".into(),
+            response_status_code: Some(500),
+            response_body: Some("internal server error".into()),
+            latency_ms: Some(42),
+            error_message: None,
+            is_manual_retry: false,
+        }
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mockdb_event_delivery_attempt_interface() {
+        let mockdb = MockDb::new(&Default::default()).await;
+
+        mockdb
+            .insert_event_delivery_attempt(delivery_attempt("attempt_1", "event_1"))
+            .await
+            .unwrap();
+        mockdb
+            .insert_event_delivery_attempt(delivery_attempt("attempt_2", "event_1"))
+            .await
+            .unwrap();
+        mockdb
+            .insert_event_delivery_attempt(delivery_attempt("attempt_3", "event_2"))
+            .await
+            .unwrap();
+
+        let attempts = mockdb
+            .find_event_delivery_attempts_by_merchant_id_event_id("merchant_1", "event_1")
+            .await
+            .unwrap();
+
+        assert_eq!(attempts.len(), 2);
+        assert_eq!(attempts[0].attempt_id, "attempt_1");
+        assert_eq!(attempts[1].attempt_id, "attempt_2");
+    }
+}
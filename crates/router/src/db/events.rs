@@ -1,10 +1,14 @@
+use api_models::enums::{EventClass, EventType};
 use error_stack::IntoReport;
 
 use super::{MockDb, Store};
 use crate::{
     connection,
     core::errors::{self, CustomResult},
-    types::storage,
+    types::{
+        storage::{self, EventDbExt},
+        transformers::ForeignFrom,
+    },
 };
 
 #[async_trait::async_trait]
@@ -18,6 +22,16 @@ pub trait EventInterface {
         event_id: String,
         event: storage::EventUpdate,
     ) -> CustomResult<storage::Event, errors::StorageError>;
+    async fn find_event_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError>;
+    async fn list_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        event_list_constraints: api_models::webhook_events::EventListConstraints,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -40,6 +54,28 @@ impl EventInterface for Store {
             .map_err(Into::into)
             .into_report()
     }
+    async fn find_event_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::find_by_merchant_id_event_id(&conn, merchant_id, event_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+    async fn list_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        event_list_constraints: api_models::webhook_events::EventListConstraints,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::filter_by_constraints(&conn, merchant_id, event_list_constraints)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -62,6 +98,7 @@ impl EventInterface for MockDb {
             primary_object_id: event.primary_object_id,
             primary_object_type: event.primary_object_type,
             created_at: now,
+            merchant_id: event.merchant_id,
         };
 
         locked_events.push(stored_event.clone());
@@ -91,6 +128,73 @@ impl EventInterface for MockDb {
 
         Ok(event_to_update.clone())
     }
+    async fn find_event_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        locked_events
+            .iter()
+            .find(|e| e.merchant_id.as_deref() == Some(merchant_id) && e.event_id == event_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No event available for merchant_id = {merchant_id} and event_id = {event_id}"
+                ))
+                .into(),
+            )
+    }
+    async fn list_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        event_list_constraints: api_models::webhook_events::EventListConstraints,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+
+        let mut events = locked_events
+            .iter()
+            .filter(|e| {
+                e.merchant_id.as_deref() == Some(merchant_id)
+                    && event_list_constraints
+                        .event_type
+                        .map(|event_type| event_type == EventType::foreign_from(e.event_type))
+                        .unwrap_or(true)
+                    && event_list_constraints
+                        .event_class
+                        .map(|event_class| event_class == EventClass::foreign_from(e.event_class))
+                        .unwrap_or(true)
+                    && event_list_constraints
+                        .object_id
+                        .as_ref()
+                        .map(|object_id| object_id == &e.primary_object_id)
+                        .unwrap_or(true)
+                    && event_list_constraints
+                        .is_webhook_notified
+                        .map(|is_webhook_notified| is_webhook_notified == e.is_webhook_notified)
+                        .unwrap_or(true)
+                    && event_list_constraints
+                        .created_lt
+                        .map(|created_lt| created_lt > e.created_at)
+                        .unwrap_or(true)
+                    && event_list_constraints
+                        .created_gt
+                        .map(|created_gt| created_gt < e.created_at)
+                        .unwrap_or(true)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        events.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        events.truncate(
+            event_list_constraints
+                .limit
+                .and_then(|limit| usize::try_from(limit).ok())
+                .unwrap_or(usize::MAX),
+        );
+
+        Ok(events)
+    }
 }
 
 #[cfg(test)]
@@ -116,6 +220,7 @@ mod tests {
                 intent_reference_id: Some("test".into()),
                 primary_object_id: "primary_object_tet".into(),
                 primary_object_type: enums::EventObjectType::PaymentDetails,
+                merchant_id: Some("merchant_1".into()),
             })
             .await
             .unwrap();
@@ -0,0 +1,222 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums as storage_enums},
+};
+
+#[async_trait::async_trait]
+pub trait FraudCheckInterface {
+    async fn insert_fraud_check(
+        &self,
+        fraud_check: storage::FraudCheckNew,
+    ) -> CustomResult<storage::FraudCheck, errors::StorageError>;
+
+    async fn find_fraud_check_by_merchant_id_payment_id_attempt_id_optional(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Option<storage::FraudCheck>, errors::StorageError>;
+
+    /// Records the decision of the merchant on a payment attempt left for manual review, failing
+    /// with a not found error if the attempt is not awaiting a review
+    async fn update_fraud_check_review_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+        fraud_check: storage::FraudCheckUpdate,
+    ) -> CustomResult<storage::FraudCheck, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl FraudCheckInterface for Store {
+    async fn insert_fraud_check(
+        &self,
+        fraud_check: storage::FraudCheckNew,
+    ) -> CustomResult<storage::FraudCheck, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        fraud_check
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_fraud_check_by_merchant_id_payment_id_attempt_id_optional(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Option<storage::FraudCheck>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::FraudCheck::find_optional_by_merchant_id_payment_id_attempt_id(
+            &conn,
+            merchant_id,
+            payment_id,
+            attempt_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn update_fraud_check_review_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+        fraud_check: storage::FraudCheckUpdate,
+    ) -> CustomResult<storage::FraudCheck, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::FraudCheck::update_review_by_merchant_id_payment_id_attempt_id(
+            &conn,
+            merchant_id,
+            payment_id,
+            attempt_id,
+            fraud_check,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl FraudCheckInterface for MockDb {
+    async fn insert_fraud_check(
+        &self,
+        fraud_check: storage::FraudCheckNew,
+    ) -> CustomResult<storage::FraudCheck, errors::StorageError> {
+        let mut locked_fraud_checks = self.fraud_checks.lock().await;
+
+        if locked_fraud_checks.iter().any(|f| {
+            f.frm_id == fraud_check.frm_id
+                || (f.merchant_id == fraud_check.merchant_id
+                    && f.payment_id == fraud_check.payment_id
+                    && f.attempt_id == fraud_check.attempt_id)
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "fraud_check",
+                key: Some(fraud_check.frm_id.clone()),
+            })?;
+        }
+
+        let now = common_utils::date_time::now();
+
+        let new_fraud_check = storage::FraudCheck {
+            #[allow(clippy::as_conversions)]
+            id: locked_fraud_checks.len() as i32,
+            frm_id: fraud_check.frm_id,
+            merchant_id: fraud_check.merchant_id,
+            payment_id: fraud_check.payment_id,
+            attempt_id: fraud_check.attempt_id,
+            frm_name: fraud_check.frm_name,
+            frm_reference_id: fraud_check.frm_reference_id,
+            frm_status: fraud_check.frm_status,
+            frm_decision: fraud_check.frm_decision,
+            review_decision: None,
+            created_at: now,
+            modified_at: now,
+            reviewed_at: None,
+        };
+
+        locked_fraud_checks.push(new_fraud_check.clone());
+
+        Ok(new_fraud_check)
+    }
+
+    async fn find_fraud_check_by_merchant_id_payment_id_attempt_id_optional(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Option<storage::FraudCheck>, errors::StorageError> {
+        Ok(self
+            .fraud_checks
+            .lock()
+            .await
+            .iter()
+            .find(|f| {
+                f.merchant_id == merchant_id
+                    && f.payment_id == payment_id
+                    && f.attempt_id == attempt_id
+            })
+            .cloned())
+    }
+
+    async fn update_fraud_check_review_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+        fraud_check: storage::FraudCheckUpdate,
+    ) -> CustomResult<storage::FraudCheck, errors::StorageError> {
+        let mut locked_fraud_checks = self.fraud_checks.lock().await;
+
+        let fraud_check_to_update = locked_fraud_checks
+            .iter_mut()
+            .find(|f| {
+                f.merchant_id == merchant_id
+                    && f.payment_id == payment_id
+                    && f.attempt_id == attempt_id
+                    && f.frm_decision == storage_enums::FrmDecision::ManualReview
+                    && f.review_decision.is_none()
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No fraud check awaiting a review for payment_id = {payment_id}"
+            )))
+            .into_report()?;
+
+        *fraud_check_to_update = fraud_check.apply_changeset(fraud_check_to_update.clone());
+
+        Ok(fraud_check_to_update.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mockdb_fraud_check_is_reviewed_only_once() {
+        let mockdb = MockDb::new(&Default::default()).await;
+        let fraud_check = |frm_decision| storage::FraudCheckNew {
+            frm_id: "frm_1".into(),
+            merchant_id: "merchant_1".into(),
+            payment_id: "pay_1".into(),
+            attempt_id: "pay_1_1".into(),
+            frm_name: "dummyfrm".into(),
+            frm_reference_id: None,
+            frm_status: Some(storage_enums::FraudCheckStatus::ManualReview),
+            frm_decision,
+        };
+        let review = || {
+            mockdb.update_fraud_check_review_by_merchant_id_payment_id_attempt_id(
+                "merchant_1",
+                "pay_1",
+                "pay_1_1",
+                storage::FraudCheckUpdate::ReviewUpdate {
+                    review_decision: storage_enums::FrmDecision::Proceed,
+                },
+            )
+        };
+
+        mockdb
+            .insert_fraud_check(fraud_check(storage_enums::FrmDecision::ManualReview))
+            .await
+            .unwrap();
+
+        let reviewed = review().await.unwrap();
+        assert_eq!(
+            reviewed.review_decision,
+            Some(storage_enums::FrmDecision::Proceed)
+        );
+        assert!(reviewed.reviewed_at.is_some());
+        assert!(review().await.is_err());
+    }
+}
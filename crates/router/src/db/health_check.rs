@@ -0,0 +1,53 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use error_stack::{IntoReport, ResultExt};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+};
+
+#[async_trait::async_trait]
+pub trait HealthCheckInterface {
+    /// Runs a trivial query on the database to which writes are sent
+    async fn health_check_master_db(&self) -> CustomResult<(), errors::StorageError>;
+
+    /// Runs a trivial query on the database from which reads are served
+    async fn health_check_replica_db(&self) -> CustomResult<(), errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl HealthCheckInterface for Store {
+    async fn health_check_master_db(&self) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        diesel::sql_query("SELECT 1")
+            .execute_async(&*conn)
+            .await
+            .into_report()
+            .change_context(errors::StorageError::DatabaseConnectionError)
+            .attach_printable("Failed while running query on master database")?;
+        Ok(())
+    }
+
+    async fn health_check_replica_db(&self) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        diesel::sql_query("SELECT 1")
+            .execute_async(&*conn)
+            .await
+            .into_report()
+            .change_context(errors::StorageError::DatabaseConnectionError)
+            .attach_printable("Failed while running query on replica database")?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheckInterface for MockDb {
+    async fn health_check_master_db(&self) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+
+    async fn health_check_replica_db(&self) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+}
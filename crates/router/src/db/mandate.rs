@@ -15,6 +15,12 @@ pub trait MandateInterface {
         mandate_id: &str,
     ) -> CustomResult<storage::Mandate, errors::StorageError>;
 
+    async fn find_mandate_by_merchant_id_connector_mandate_id(
+        &self,
+        merchant_id: &str,
+        connector_mandate_id: &str,
+    ) -> CustomResult<storage::Mandate, errors::StorageError>;
+
     async fn find_mandate_by_merchant_id_customer_id(
         &self,
         merchant_id: &str,
@@ -28,6 +34,20 @@ pub trait MandateInterface {
         mandate: storage::MandateUpdate,
     ) -> CustomResult<storage::Mandate, errors::StorageError>;
 
+    async fn reserve_mandate_usage_by_merchant_id_mandate_id(
+        &self,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+    ) -> CustomResult<storage::Mandate, errors::StorageError>;
+
+    async fn release_mandate_usage_by_merchant_id_mandate_id(
+        &self,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+    ) -> CustomResult<storage::Mandate, errors::StorageError>;
+
     async fn find_mandates_by_merchant_id(
         &self,
         merchant_id: &str,
@@ -54,6 +74,22 @@ impl MandateInterface for Store {
             .into_report()
     }
 
+    async fn find_mandate_by_merchant_id_connector_mandate_id(
+        &self,
+        merchant_id: &str,
+        connector_mandate_id: &str,
+    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Mandate::find_by_merchant_id_connector_mandate_id(
+            &conn,
+            merchant_id,
+            connector_mandate_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn find_mandate_by_merchant_id_customer_id(
         &self,
         merchant_id: &str,
@@ -79,6 +115,42 @@ impl MandateInterface for Store {
             .into_report()
     }
 
+    async fn reserve_mandate_usage_by_merchant_id_mandate_id(
+        &self,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Mandate::reserve_usage_by_merchant_id_mandate_id(
+            &conn,
+            merchant_id,
+            mandate_id,
+            amount,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn release_mandate_usage_by_merchant_id_mandate_id(
+        &self,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Mandate::release_usage_by_merchant_id_mandate_id(
+            &conn,
+            merchant_id,
+            mandate_id,
+            amount,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn find_mandates_by_merchant_id(
         &self,
         merchant_id: &str,
@@ -115,6 +187,15 @@ impl MandateInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_mandate_by_merchant_id_connector_mandate_id(
+        &self,
+        _merchant_id: &str,
+        _connector_mandate_id: &str,
+    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn find_mandate_by_merchant_id_customer_id(
         &self,
         _merchant_id: &str,
@@ -134,6 +215,26 @@ impl MandateInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn reserve_mandate_usage_by_merchant_id_mandate_id(
+        &self,
+        _merchant_id: &str,
+        _mandate_id: &str,
+        _amount: i64,
+    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn release_mandate_usage_by_merchant_id_mandate_id(
+        &self,
+        _merchant_id: &str,
+        _mandate_id: &str,
+        _amount: i64,
+    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn find_mandates_by_merchant_id(
         &self,
         _merchant_id: &str,
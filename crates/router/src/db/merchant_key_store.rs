@@ -1,7 +1,7 @@
 use error_stack::{IntoReport, ResultExt};
 
 #[cfg(feature = "accounts_cache")]
-use crate::cache::ACCOUNTS_CACHE;
+use crate::cache::{self, ACCOUNTS_CACHE};
 use crate::{
     connection,
     core::errors::{self, CustomResult},
@@ -20,10 +20,11 @@ pub trait MerchantKeyStoreInterface {
         merchant_key_store: merchant_key_store::MerchantKeyStore,
     ) -> CustomResult<merchant_key_store::MerchantKeyStore, errors::StorageError>;
 
-    async fn get_merchant_key_store_by_merchant_id(
+    /// Lists all the versions of the key of a merchant, ordered by version
+    async fn list_merchant_key_stores_by_merchant_id(
         &self,
         merchant_id: &str,
-    ) -> CustomResult<merchant_key_store::MerchantKeyStore, errors::StorageError>;
+    ) -> CustomResult<Vec<merchant_key_store::MerchantKeyStore>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -32,28 +33,48 @@ impl MerchantKeyStoreInterface for Store {
         &self,
         merchant_key_store: merchant_key_store::MerchantKeyStore,
     ) -> CustomResult<merchant_key_store::MerchantKeyStore, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
         let merchant_id = merchant_key_store.merchant_id.clone();
-        merchant_key_store
-            .construct_new()
-            .await
-            .change_context(errors::StorageError::EncryptionError)?
-            .insert(&conn)
-            .await
-            .map_err(Into::into)
-            .into_report()?
-            .convert(self, &merchant_id)
+        let insert_func = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            merchant_key_store
+                .construct_new()
+                .await
+                .change_context(errors::StorageError::EncryptionError)?
+                .insert(&conn)
+                .await
+                .map_err(Into::into)
+                .into_report()?
+                .convert(self, &merchant_id)
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            insert_func().await
+        }
+
+        // A new version of the key has to be visible to every instance before data is
+        // encrypted with it
+        #[cfg(feature = "accounts_cache")]
+        {
+            super::cache::publish_and_redact(
+                self,
+                cache::CacheKind::Accounts(get_key_store_cache_key(&merchant_id).into()),
+                insert_func,
+            )
             .await
-            .change_context(errors::StorageError::DecryptionError)
+        }
     }
-    async fn get_merchant_key_store_by_merchant_id(
+
+    async fn list_merchant_key_stores_by_merchant_id(
         &self,
         merchant_id: &str,
-    ) -> CustomResult<merchant_key_store::MerchantKeyStore, errors::StorageError> {
+    ) -> CustomResult<Vec<merchant_key_store::MerchantKeyStore>, errors::StorageError> {
         let fetch_func = || async {
             let conn = connection::pg_connection_read(self).await?;
 
-            storage_models::merchant_key_store::MerchantKeyStore::find_by_merchant_id(
+            storage_models::merchant_key_store::MerchantKeyStore::find_all_by_merchant_id(
                 &conn,
                 merchant_id,
             )
@@ -61,32 +82,34 @@ impl MerchantKeyStoreInterface for Store {
             .map_err(Into::into)
             .into_report()
         };
+
         #[cfg(not(feature = "accounts_cache"))]
-        {
-            fetch_func()
-                .await?
+        let key_stores = fetch_func().await?;
+
+        #[cfg(feature = "accounts_cache")]
+        let key_stores = super::cache::get_or_populate_in_memory(
+            self,
+            &get_key_store_cache_key(merchant_id),
+            fetch_func,
+            &ACCOUNTS_CACHE,
+        )
+        .await?;
+
+        futures::future::try_join_all(key_stores.into_iter().map(|key_store| async {
+            key_store
                 .convert(self, merchant_id)
                 .await
                 .change_context(errors::StorageError::DecryptionError)
-        }
-
-        #[cfg(feature = "accounts_cache")]
-        {
-            let key_store_cache_key = format!("merchant_key_store_{}", merchant_id);
-            super::cache::get_or_populate_in_memory(
-                self,
-                &key_store_cache_key,
-                fetch_func,
-                &ACCOUNTS_CACHE,
-            )
-            .await?
-            .convert(self, merchant_id)
-            .await
-            .change_context(errors::StorageError::DecryptionError)
-        }
+        }))
+        .await
     }
 }
 
+#[cfg(feature = "accounts_cache")]
+fn get_key_store_cache_key(merchant_id: &str) -> String {
+    format!("merchant_key_stores_{merchant_id}")
+}
+
 #[async_trait::async_trait]
 impl MerchantKeyStoreInterface for MockDb {
     async fn insert_merchant_key_store(
@@ -96,10 +119,10 @@ impl MerchantKeyStoreInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError.into())
     }
-    async fn get_merchant_key_store_by_merchant_id(
+    async fn list_merchant_key_stores_by_merchant_id(
         &self,
         _merchant_id: &str,
-    ) -> CustomResult<merchant_key_store::MerchantKeyStore, errors::StorageError> {
+    ) -> CustomResult<Vec<merchant_key_store::MerchantKeyStore>, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError.into())
     }
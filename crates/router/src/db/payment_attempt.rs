@@ -41,6 +41,13 @@ pub trait PaymentAttemptInterface {
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::PaymentAttempt, errors::StorageError>;
 
+    async fn find_payment_attempts_by_payment_id_merchant_id(
+        &self,
+        payment_id: &str,
+        merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError>;
+
     async fn find_payment_attempt_by_payment_id_merchant_id_attempt_id(
         &self,
         payment_id: &str,
@@ -140,6 +147,43 @@ mod storage {
             .into_report()
         }
 
+        async fn find_payment_attempts_by_payment_id_merchant_id(
+            &self,
+            payment_id: &str,
+            merchant_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            let database_attempts =
+                PaymentAttempt::find_by_payment_id_merchant_id(&conn, payment_id, merchant_id)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()?;
+
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => Ok(database_attempts),
+
+                enums::MerchantStorageScheme::RedisKv => {
+                    // Attempts which are not drained yet are only present in Redis
+                    let key = format!("{merchant_id}_{payment_id}");
+                    let redis_attempts = self
+                        .redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .hscan_and_deserialize::<PaymentAttempt>(&key, "pa_*", None)
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    let mut payment_attempts = db_utils::merge_redis_and_database_records(
+                        redis_attempts,
+                        database_attempts,
+                        |payment_attempt| payment_attempt.attempt_id.clone(),
+                    );
+                    payment_attempts.sort_by_key(|payment_attempt| payment_attempt.created_at);
+                    Ok(payment_attempts)
+                }
+            }
+        }
+
         async fn find_payment_attempt_by_merchant_id_connector_txn_id(
             &self,
             merchant_id: &str,
@@ -244,6 +288,24 @@ impl PaymentAttemptInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_payment_attempts_by_payment_id_merchant_id(
+        &self,
+        payment_id: &str,
+        merchant_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.payment_id == payment_id
+                    && payment_attempt.merchant_id == merchant_id
+            })
+            .cloned()
+            .collect())
+    }
+
     async fn find_payment_attempt_by_merchant_id_connector_txn_id(
         &self,
         _merchant_id: &str,
@@ -455,22 +517,20 @@ mod storage {
                         })
                         .into_report(),
                         Ok(HsetnxReply::KeySet) => {
-                            let conn = connection::pg_connection_write(self).await?;
-
                             //Reverse lookup for attempt_id
-                            ReverseLookupNew {
-                                lookup_id: format!(
-                                    "{}_{}",
-                                    &created_attempt.merchant_id, &created_attempt.attempt_id,
-                                ),
-                                pk_id: key,
-                                sk_id: field,
-                                source: "payment_attempt".to_string(),
-                            }
-                            .insert(&conn)
-                            .await
-                            .map_err(Into::<errors::StorageError>::into)
-                            .into_report()?;
+                            self.insert_reverse_lookup(
+                                ReverseLookupNew {
+                                    lookup_id: format!(
+                                        "{}_{}",
+                                        &created_attempt.merchant_id, &created_attempt.attempt_id,
+                                    ),
+                                    pk_id: key,
+                                    sk_id: field,
+                                    source: "payment_attempt".to_string(),
+                                },
+                                storage_scheme,
+                            )
+                            .await?;
 
                             let redis_entry = kv::TypedSql {
                                 op: kv::DBOperation::Insert {
@@ -536,6 +596,7 @@ mod storage {
                                 this.merchant_id.as_str(),
                                 updated_attempt.attempt_id.as_str(),
                                 connector_transaction_id.as_str(),
+                                storage_scheme,
                             )
                             .await?;
                         }
@@ -547,6 +608,7 @@ mod storage {
                                     this.merchant_id.as_str(),
                                     updated_attempt.attempt_id.as_str(),
                                     connector_transaction_id.as_str(),
+                                    storage_scheme,
                                 )
                                 .await?;
                             }
@@ -562,6 +624,7 @@ mod storage {
                                 this.merchant_id.as_str(),
                                 updated_attempt.attempt_id.as_str(),
                                 preprocessing_id.as_str(),
+                                storage_scheme,
                             )
                             .await?;
                         }
@@ -573,6 +636,7 @@ mod storage {
                                     this.merchant_id.as_str(),
                                     updated_attempt.attempt_id.as_str(),
                                     preprocessing_id.as_str(),
+                                    storage_scheme,
                                 )
                                 .await?;
                             }
@@ -658,6 +722,19 @@ mod storage {
             .into_report()
         }
 
+        async fn find_payment_attempts_by_payment_id_merchant_id(
+            &self,
+            payment_id: &str,
+            merchant_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentAttempt::find_by_payment_id_merchant_id(&conn, payment_id, merchant_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
         async fn find_payment_attempt_by_merchant_id_connector_txn_id(
             &self,
             merchant_id: &str,
@@ -805,19 +882,20 @@ mod storage {
         merchant_id: &str,
         updated_attempt_attempt_id: &str,
         connector_transaction_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<ReverseLookup, errors::StorageError> {
-        let conn = connection::pg_connection_write(store).await?;
         let field = format!("pa_{}", updated_attempt_attempt_id);
-        ReverseLookupNew {
-            lookup_id: format!("{}_{}", merchant_id, connector_transaction_id),
-            pk_id: key.to_owned(),
-            sk_id: field.clone(),
-            source: "payment_attempt".to_string(),
-        }
-        .insert(&conn)
-        .await
-        .map_err(Into::<errors::StorageError>::into)
-        .into_report()
+        store
+            .insert_reverse_lookup(
+                ReverseLookupNew {
+                    lookup_id: format!("{}_{}", merchant_id, connector_transaction_id),
+                    pk_id: key.to_owned(),
+                    sk_id: field.clone(),
+                    source: "payment_attempt".to_string(),
+                },
+                storage_scheme,
+            )
+            .await
     }
 
     #[inline]
@@ -827,18 +905,19 @@ mod storage {
         merchant_id: &str,
         updated_attempt_attempt_id: &str,
         preprocessing_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<ReverseLookup, errors::StorageError> {
-        let conn = connection::pg_connection_write(store).await?;
         let field = format!("pa_{}", updated_attempt_attempt_id);
-        ReverseLookupNew {
-            lookup_id: format!("{}_{}", merchant_id, preprocessing_id),
-            pk_id: key.to_owned(),
-            sk_id: field.clone(),
-            source: "payment_attempt".to_string(),
-        }
-        .insert(&conn)
-        .await
-        .map_err(Into::<errors::StorageError>::into)
-        .into_report()
+        store
+            .insert_reverse_lookup(
+                ReverseLookupNew {
+                    lookup_id: format!("{}_{}", merchant_id, preprocessing_id),
+                    pk_id: key.to_owned(),
+                    sk_id: field.clone(),
+                    source: "payment_attempt".to_string(),
+                },
+                storage_scheme,
+            )
+            .await
     }
 }
@@ -35,6 +35,23 @@ pub trait PaymentIntentInterface {
         pc: &api::PaymentListConstraints,
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError>;
+
+    /// Payment intents are looked up in the database only, intents which have not been drained
+    /// from the KV store yet are not returned
+    async fn find_payment_intents_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError>;
+
+    /// Payment intents are looked up in the database only, intents which have not been drained
+    /// from the KV store yet are not returned
+    async fn find_payment_intents_by_merchant_id_status_created_before(
+        &self,
+        merchant_id: &str,
+        status: enums::IntentStatus,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError>;
 }
 
 #[cfg(feature = "kv_store")]
@@ -236,6 +253,36 @@ mod storage {
                 enums::MerchantStorageScheme::RedisKv => Err(errors::StorageError::KVError.into()),
             }
         }
+
+        async fn find_payment_intents_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+        ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentIntent::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn find_payment_intents_by_merchant_id_status_created_before(
+            &self,
+            merchant_id: &str,
+            status: enums::IntentStatus,
+            created_before: time::PrimitiveDateTime,
+        ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentIntent::find_by_merchant_id_status_created_before(
+                &conn,
+                merchant_id,
+                status,
+                created_before,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
     }
 }
 
@@ -303,6 +350,36 @@ mod storage {
                 .map_err(Into::into)
                 .into_report()
         }
+
+        async fn find_payment_intents_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+        ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentIntent::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn find_payment_intents_by_merchant_id_status_created_before(
+            &self,
+            merchant_id: &str,
+            status: enums::IntentStatus,
+            created_before: time::PrimitiveDateTime,
+        ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            PaymentIntent::find_by_merchant_id_status_created_before(
+                &conn,
+                merchant_id,
+                status,
+                created_before,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
     }
 }
 
@@ -395,4 +472,42 @@ impl PaymentIntentInterface for MockDb {
             .cloned()
             .unwrap())
     }
+
+    async fn find_payment_intents_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError> {
+        Ok(self
+            .payment_intents
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == merchant_id
+                    && payment_intent.customer_id.as_deref() == Some(customer_id)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn find_payment_intents_by_merchant_id_status_created_before(
+        &self,
+        merchant_id: &str,
+        status: enums::IntentStatus,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError> {
+        Ok(self
+            .payment_intents
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == merchant_id
+                    && payment_intent.status == status
+                    && payment_intent.created_at < created_before
+            })
+            .cloned()
+            .collect())
+    }
 }
@@ -0,0 +1,266 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PayoutsInterface {
+    async fn insert_payout(
+        &self,
+        payout: storage::PayoutNew,
+    ) -> CustomResult<storage::Payout, errors::StorageError>;
+
+    async fn find_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<storage::Payout, errors::StorageError>;
+
+    async fn find_optional_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<Option<storage::Payout>, errors::StorageError>;
+
+    async fn update_payout(
+        &self,
+        this: storage::Payout,
+        payout: storage::PayoutUpdate,
+    ) -> CustomResult<storage::Payout, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutsInterface for Store {
+    async fn insert_payout(
+        &self,
+        payout: storage::PayoutNew,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payout.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn find_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Payout::find_by_merchant_id_payout_id(&conn, merchant_id, payout_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_optional_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<Option<storage::Payout>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Payout::find_optional_by_merchant_id_payout_id(&conn, merchant_id, payout_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_payout(
+        &self,
+        this: storage::Payout,
+        payout: storage::PayoutUpdate,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, payout)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutsInterface for MockDb {
+    async fn insert_payout(
+        &self,
+        payout: storage::PayoutNew,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let mut locked_payouts = self.payouts.lock().await;
+
+        if locked_payouts
+            .iter()
+            .any(|p| p.merchant_id == payout.merchant_id && p.payout_id == payout.payout_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "payout_id",
+                key: Some(payout.payout_id.clone()),
+            })?;
+        }
+
+        let now = common_utils::date_time::now();
+
+        let new_payout = storage::Payout {
+            #[allow(clippy::as_conversions)]
+            id: locked_payouts.len() as i32,
+            payout_id: payout.payout_id,
+            merchant_id: payout.merchant_id,
+            customer_id: payout.customer_id,
+            connector: payout.connector,
+            connector_payout_id: payout.connector_payout_id,
+            amount: payout.amount,
+            currency: payout.currency,
+            payout_type: payout.payout_type,
+            status: payout.status,
+            auto_fulfill: payout.auto_fulfill,
+            description: payout.description,
+            business_country: payout.business_country,
+            business_label: payout.business_label,
+            return_url: payout.return_url,
+            metadata: payout.metadata,
+            error_code: payout.error_code,
+            error_message: payout.error_message,
+            created_at: now,
+            modified_at: now,
+        };
+
+        locked_payouts.push(new_payout.clone());
+
+        Ok(new_payout)
+    }
+
+    async fn find_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        self.find_optional_payout_by_merchant_id_payout_id(merchant_id, payout_id)
+            .await?
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No payout available for merchant_id = {merchant_id} and payout_id = {payout_id}"
+            )))
+            .into_report()
+    }
+
+    async fn find_optional_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<Option<storage::Payout>, errors::StorageError> {
+        Ok(self
+            .payouts
+            .lock()
+            .await
+            .iter()
+            .find(|p| p.merchant_id == merchant_id && p.payout_id == payout_id)
+            .cloned())
+    }
+
+    async fn update_payout(
+        &self,
+        this: storage::Payout,
+        payout: storage::PayoutUpdate,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let mut locked_payouts = self.payouts.lock().await;
+
+        let payout_to_update = locked_payouts
+            .iter_mut()
+            .find(|p| p.merchant_id == this.merchant_id && p.payout_id == this.payout_id)
+            .ok_or(errors::StorageError::MockDbError)?;
+
+        *payout_to_update = payout.apply_changeset(payout_to_update.clone());
+
+        Ok(payout_to_update.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(clippy::unwrap_used)]
+    mod mockdb_payouts_interface {
+        use storage_models::{
+            enums::{Currency, PayoutStatus, PayoutType},
+            payouts::{PayoutNew, PayoutUpdate},
+        };
+
+        use crate::db::{payouts::PayoutsInterface, MockDb};
+
+        fn create_payout_new(merchant_id: &str, payout_id: &str) -> PayoutNew {
+            PayoutNew {
+                payout_id: payout_id.into(),
+                merchant_id: merchant_id.into(),
+                customer_id: Some("customer_1".into()),
+                connector: Some("adyen".into()),
+                connector_payout_id: None,
+                amount: 1000,
+                currency: Currency::EUR,
+                payout_type: PayoutType::Card,
+                status: PayoutStatus::RequiresCreation,
+                auto_fulfill: false,
+                description: None,
+                business_country: None,
+                business_label: None,
+                return_url: None,
+                metadata: None,
+                error_code: None,
+                error_message: None,
+            }
+        }
+
+        #[tokio::test]
+        async fn test_insert_payout() {
+            let mockdb = MockDb::new(&Default::default()).await;
+
+            let created_payout = mockdb
+                .insert_payout(create_payout_new("merchant_1", "payout_1"))
+                .await
+                .unwrap();
+
+            let found_payout = mockdb
+                .find_payout_by_merchant_id_payout_id("merchant_1", "payout_1")
+                .await
+                .unwrap();
+
+            assert_eq!(created_payout, found_payout);
+
+            assert!(mockdb
+                .insert_payout(create_payout_new("merchant_1", "payout_1"))
+                .await
+                .is_err());
+        }
+
+        #[tokio::test]
+        async fn test_update_payout_status() {
+            let mockdb = MockDb::new(&Default::default()).await;
+
+            let created_payout = mockdb
+                .insert_payout(create_payout_new("merchant_1", "payout_1"))
+                .await
+                .unwrap();
+
+            let updated_payout = mockdb
+                .update_payout(
+                    created_payout,
+                    PayoutUpdate::StatusUpdate {
+                        status: PayoutStatus::RequiresFulfillment,
+                        connector_payout_id: Some("connector_payout_1".into()),
+                    },
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(updated_payout.status, PayoutStatus::RequiresFulfillment);
+            assert_eq!(
+                updated_payout.connector_payout_id,
+                Some("connector_payout_1".into())
+            );
+
+            let found_payout = mockdb
+                .find_optional_payout_by_merchant_id_payout_id("merchant_1", "payout_1")
+                .await
+                .unwrap();
+
+            assert_eq!(found_payout, Some(updated_payout));
+        }
+    }
+}
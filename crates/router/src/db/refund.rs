@@ -314,8 +314,6 @@ mod storage {
                         })
                         .into_report(),
                         Ok(HsetnxReply::KeySet) => {
-                            let conn = connection::pg_connection_write(self).await?;
-
                             let mut reverse_lookups = vec![
                                 storage_types::ReverseLookupNew {
                                     sk_id: field.clone(),
@@ -353,9 +351,10 @@ mod storage {
                                     source: "refund".to_string(),
                                 })
                             };
-                            storage_types::ReverseLookupNew::batch_insert(reverse_lookups, &conn)
-                                .await
-                                .change_context(errors::StorageError::KVError)?;
+                            for reverse_lookup in reverse_lookups {
+                                self.insert_reverse_lookup(reverse_lookup, storage_scheme)
+                                    .await?;
+                            }
 
                             let redis_entry = kv::TypedSql {
                                 op: kv::DBOperation::Insert {
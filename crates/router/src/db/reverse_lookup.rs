@@ -1,10 +1,10 @@
-use error_stack::IntoReport;
-
-use super::{cache, MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection,
     errors::{self, CustomResult},
-    types::storage::reverse_lookup::{ReverseLookup, ReverseLookupNew},
+    types::storage::{
+        enums,
+        reverse_lookup::{ReverseLookup, ReverseLookupNew},
+    },
 };
 
 #[async_trait::async_trait]
@@ -12,6 +12,7 @@ pub trait ReverseLookupInterface {
     async fn insert_reverse_lookup(
         &self,
         _new: ReverseLookupNew,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<ReverseLookup, errors::StorageError>;
     async fn get_lookup_by_lookup_id(
         &self,
@@ -19,28 +20,137 @@ pub trait ReverseLookupInterface {
     ) -> CustomResult<ReverseLookup, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl ReverseLookupInterface for Store {
-    async fn insert_reverse_lookup(
-        &self,
-        new: ReverseLookupNew,
-    ) -> CustomResult<ReverseLookup, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        new.insert(&conn).await.map_err(Into::into).into_report()
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::IntoReport;
+
+    use super::ReverseLookupInterface;
+    use crate::{
+        connection,
+        db::cache,
+        errors::{self, CustomResult},
+        services::Store,
+        types::storage::{
+            enums,
+            reverse_lookup::{ReverseLookup, ReverseLookupNew},
+        },
+    };
+
+    #[async_trait::async_trait]
+    impl ReverseLookupInterface for Store {
+        async fn insert_reverse_lookup(
+            &self,
+            new: ReverseLookupNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<ReverseLookup, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            new.insert(&conn).await.map_err(Into::into).into_report()
+        }
+
+        async fn get_lookup_by_lookup_id(
+            &self,
+            id: &str,
+        ) -> CustomResult<ReverseLookup, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                ReverseLookup::find_by_lookup_id(id, &conn)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+            };
+            cache::get_or_populate_redis(self, id, database_call).await
+        }
     }
+}
 
-    async fn get_lookup_by_lookup_id(
-        &self,
-        id: &str,
-    ) -> CustomResult<ReverseLookup, errors::StorageError> {
-        let database_call = || async {
-            let conn = connection::pg_connection_read(self).await?;
-            ReverseLookup::find_by_lookup_id(id, &conn)
-                .await
-                .map_err(Into::into)
-                .into_report()
-        };
-        cache::get_or_populate_redis(self, id, database_call).await
+#[cfg(feature = "kv_store")]
+mod storage {
+    use error_stack::IntoReport;
+    use redis_interface::SetnxReply;
+
+    use super::ReverseLookupInterface;
+    use crate::{
+        connection,
+        db::cache,
+        errors::{self, CustomResult},
+        services::Store,
+        types::storage::{
+            enums, kv,
+            reverse_lookup::{ReverseLookup, ReverseLookupNew},
+        },
+        utils::storage_partitioning::PartitionKey,
+    };
+
+    #[async_trait::async_trait]
+    impl ReverseLookupInterface for Store {
+        async fn insert_reverse_lookup(
+            &self,
+            new: ReverseLookupNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<ReverseLookup, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    new.insert(&conn).await.map_err(Into::into).into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let created_lookup = ReverseLookup {
+                        lookup_id: new.lookup_id.clone(),
+                        sk_id: new.sk_id.clone(),
+                        pk_id: new.pk_id.clone(),
+                        source: new.source.clone(),
+                    };
+
+                    // The lookup is stored under the same key that `get_lookup_by_lookup_id`
+                    // caches it with, so that it can be read before the drainer inserts it
+                    match self
+                        .redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .serialize_and_set_key_if_not_exist(
+                            &created_lookup.lookup_id,
+                            &created_lookup,
+                        )
+                        .await
+                    {
+                        Ok(SetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "reverse_lookup",
+                            key: Some(created_lookup.lookup_id),
+                        })
+                        .into_report(),
+                        Ok(SetnxReply::KeySet) => {
+                            let redis_entry = kv::TypedSql {
+                                op: kv::DBOperation::Insert {
+                                    insertable: kv::Insertable::ReverseLookup(new),
+                                },
+                            };
+                            self.push_to_drainer_stream::<ReverseLookup>(
+                                redis_entry,
+                                PartitionKey::CombinationKey {
+                                    combination: &created_lookup.pk_id,
+                                },
+                            )
+                            .await?;
+                            Ok(created_lookup)
+                        }
+                        Err(error) => Err(error.change_context(errors::StorageError::KVError)),
+                    }
+                }
+            }
+        }
+
+        async fn get_lookup_by_lookup_id(
+            &self,
+            id: &str,
+        ) -> CustomResult<ReverseLookup, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                ReverseLookup::find_by_lookup_id(id, &conn)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+            };
+            cache::get_or_populate_redis(self, id, database_call).await
+        }
     }
 }
 
@@ -49,6 +159,7 @@ impl ReverseLookupInterface for MockDb {
     async fn insert_reverse_lookup(
         &self,
         _new: ReverseLookupNew,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<ReverseLookup, errors::StorageError> {
         Err(errors::StorageError::MockDbError.into())
     }
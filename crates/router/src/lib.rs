@@ -51,6 +51,8 @@ pub mod headers {
     pub const AUTHORIZATION: &str = "Authorization";
     pub const CONTENT_TYPE: &str = "Content-Type";
     pub const DATE: &str = "Date";
+    pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+    pub const IDEMPOTENT_REPLAYED: &str = "Idempotent-Replayed";
     pub const NONCE: &str = "nonce";
     pub const TIMESTAMP: &str = "Timestamp";
     pub const TOKEN: &str = "token";
@@ -130,7 +132,8 @@ pub fn mk_app(
             .service(routes::MerchantAccount::server(state.clone()))
             .service(routes::ApiKeys::server(state.clone()))
             .service(routes::Files::server(state.clone()))
-            .service(routes::Disputes::server(state.clone()));
+            .service(routes::Disputes::server(state.clone()))
+            .service(routes::WebhookEvents::server(state.clone()));
     }
 
     #[cfg(feature = "stripe")]
@@ -209,8 +212,12 @@ pub fn get_application_builder(
         .content_type_required(true)
         .error_handler(utils::error_parser::custom_json_error_handler);
 
+    // The raw body of requests carrying an idempotency key is read with the same limit as JSON
+    let payload_cfg = actix_web::web::PayloadConfig::new(request_body_limit);
+
     actix_web::App::new()
         .app_data(json_cfg)
+        .app_data(payload_cfg)
         .wrap(ErrorHandlers::new().handler(
             StatusCode::NOT_FOUND,
             errors::error_handlers::custom_error_handlers,
@@ -221,6 +228,7 @@ pub fn get_application_builder(
         ))
         .wrap(middleware::default_response_headers())
         .wrap(cors::cors())
+        .wrap(middleware::IdempotencyRequestBody)
         .wrap(middleware::RequestId)
         .wrap(router_env::tracing_actix_web::TracingLogger::default())
 }
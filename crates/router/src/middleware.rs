@@ -70,3 +70,87 @@ pub fn default_response_headers() -> actix_web::middleware::DefaultHeaders {
         .add((header::STRICT_TRANSPORT_SECURITY, "max-age=31536000"))
         .add((header::VIA, "HyperSwitch"))
 }
+
+/// Middleware capturing the raw body of requests carrying an idempotency key, so that the request
+/// fingerprint can be computed from the body as it was sent, after the payload extractors of the
+/// route have consumed it.
+pub struct IdempotencyRequestBody;
+
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for IdempotencyRequestBody
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = IdempotencyRequestBodyMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(IdempotencyRequestBodyMiddleware {
+            service: std::rc::Rc::new(service),
+        }))
+    }
+}
+
+pub struct IdempotencyRequestBodyMiddleware<S> {
+    service: std::rc::Rc<S>,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest>
+    for IdempotencyRequestBodyMiddleware<S>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        use actix_web::HttpMessage;
+
+        let service = std::rc::Rc::clone(&self.service);
+        let mut req = req;
+
+        Box::pin(async move {
+            let has_idempotency_key = matches!(
+                crate::services::api::idempotency::get_idempotency_key(req.request()),
+                Ok(Some(_))
+            );
+
+            if has_idempotency_key {
+                let body = req.extract::<actix_web::web::Bytes>().await?;
+                req.extensions_mut()
+                    .insert(crate::services::api::idempotency::RawRequestBody(
+                        body.clone(),
+                    ));
+
+                // The body was consumed above, and is handed back to the route
+                let payload: std::pin::Pin<
+                    Box<
+                        dyn futures::Stream<
+                            Item = Result<actix_web::web::Bytes, actix_web::error::PayloadError>,
+                        >,
+                    >,
+                > = Box::pin(futures::stream::once(futures::future::ready(Ok(body))));
+                req.set_payload(payload.into());
+            }
+
+            service.call(req).await
+        })
+    }
+}
@@ -54,10 +54,12 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Merchant Connector Account", description = "Create and manage merchant connector accounts"),
         (name = "Payments", description = "Create and manage one-time payments, recurring payments and mandates"),
         (name = "Refunds", description = "Create and manage refunds for successful payments"),
+        (name = "Payouts", description = "Create and manage payouts to cards and bank accounts"),
         (name = "Mandates", description = "Manage mandates"),
         (name = "Customers", description = "Create and manage customers"),
         (name = "Payment Methods", description = "Create and manage payment methods of customers"),
         (name = "Disputes", description = "Manage disputes"),
+        (name = "Events", description = "Inspect and resend outgoing webhook events"),
         // (name = "API Key", description = "Create and manage API Keys"),
     ),
     paths(
@@ -65,6 +67,12 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::refunds::refunds_retrieve,
         crate::routes::refunds::refunds_update,
         crate::routes::refunds::refunds_list,
+        crate::routes::payouts::payouts_create,
+        crate::routes::payouts::payouts_retrieve,
+        crate::routes::payouts::payouts_update,
+        crate::routes::payouts::payouts_reverse,
+        crate::routes::payouts::payouts_cancel,
+        crate::routes::payouts::payouts_fulfill,
         // Commenting this out as these are admin apis and not to be used by the merchant
         // crate::routes::admin::merchant_account_create,
         // crate::routes::admin::retrieve_merchant_account,
@@ -83,9 +91,12 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::payments::payments_update,
         crate::routes::payments::payments_confirm,
         crate::routes::payments::payments_capture,
+        crate::routes::payments::payments_retrieve_capture,
         crate::routes::payments::payments_connector_session,
        // crate::routes::payments::payments_redirect_response,
         crate::routes::payments::payments_cancel,
+        crate::routes::payments::payments_frm_fulfillment,
+        crate::routes::payments::payments_frm_review,
         crate::routes::payments::payments_list,
         crate::routes::payment_methods::create_payment_method_api,
         crate::routes::payment_methods::list_payment_method_api,
@@ -102,8 +113,13 @@ Never share your secret api keys. Keep them guarded and secure.
         // crate::routes::api_keys::api_key_update,
         // crate::routes::api_keys::api_key_revoke,
         // crate::routes::api_keys::api_key_list,
+        // crate::routes::api_keys::api_key_rotate,
         crate::routes::disputes::retrieve_disputes_list,
         crate::routes::disputes::retrieve_dispute,
+        crate::routes::webhook_events::list_events,
+        crate::routes::webhook_events::list_events_for_merchant,
+        crate::routes::webhook_events::retrieve_event,
+        crate::routes::webhook_events::retry_event,
     ),
     components(schemas(
         crate::types::api::refunds::RefundRequest,
@@ -137,7 +153,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::ConnectorType,
         api_models::enums::Currency,
         api_models::enums::IntentStatus,
+        api_models::enums::AttemptStatus,
         api_models::enums::CaptureMethod,
+        api_models::enums::CaptureStatus,
         api_models::enums::FutureUsage,
         api_models::enums::AuthenticationType,
         api_models::enums::Connector,
@@ -149,9 +167,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::CardNetwork,
         api_models::enums::DisputeStage,
         api_models::enums::DisputeStatus,
+        api_models::enums::EventType,
+        api_models::enums::EventClass,
+        api_models::enums::EventObjectType,
         api_models::enums::CountryAlpha2,
         api_models::enums::FrmAction,
         api_models::enums::FrmPreferredFlowTypes,
+        api_models::enums::FraudCheckStatus,
         api_models::admin::MerchantConnectorCreate,
         api_models::admin::MerchantConnectorUpdate,
         api_models::admin::PrimaryBusinessDetails,
@@ -161,6 +183,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorDetails,
         api_models::disputes::DisputeResponse,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
+        api_models::fraud_check::FrmFulfillmentRequest,
+        api_models::fraud_check::FrmFulfillmentResponse,
+        api_models::fraud_check::FrmReviewDecision,
+        api_models::fraud_check::FrmReviewRequest,
+        api_models::webhook_events::EventResponse,
+        api_models::webhook_events::EventDeliveryAttemptResponse,
         api_models::payments::AddressDetails,
         api_models::payments::BankDebitData,
         api_models::payments::AliPayRedirection,
@@ -192,6 +220,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsRequest,
         api_models::payments::PaymentsCreateRequest,
         api_models::payments::PaymentsResponse,
+        api_models::payments::PaymentAttemptResponse,
+        api_models::payments::CaptureResponse,
         api_models::payments::PaymentsStartRequest,
         api_models::payments::PaymentRetrieveBody,
         api_models::payments::PaymentsRetrieveRequest,
@@ -236,6 +266,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::RedirectResponse,
         api_models::refunds::RefundListRequest,
         api_models::refunds::RefundListResponse,
+        api_models::payouts::PayoutCreateRequest,
+        api_models::payouts::PayoutUpdateRequest,
+        api_models::payouts::PayoutResponse,
+        api_models::payouts::PayoutMethodData,
+        api_models::payouts::CardPayout,
+        api_models::payouts::BankPayout,
+        api_models::enums::PayoutStatus,
+        api_models::enums::PayoutType,
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
@@ -245,6 +283,7 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::types::api::admin::MerchantDetails,
         crate::types::api::admin::WebhookDetails,
         crate::types::api::api_keys::ApiKeyExpiration,
+        api_models::enums::ApiKeyScope,
         crate::types::api::api_keys::CreateApiKeyRequest,
         crate::types::api::api_keys::CreateApiKeyResponse,
         crate::types::api::api_keys::RetrieveApiKeyResponse,
@@ -16,6 +16,7 @@ pub mod payment_methods;
 pub mod payments;
 pub mod payouts;
 pub mod refunds;
+pub mod webhook_events;
 pub mod webhooks;
 
 #[cfg(feature = "dummy_connector")]
@@ -23,7 +24,7 @@ pub use self::app::DummyConnector;
 pub use self::app::{
     ApiKeys, AppState, Cards, Configs, Customers, Disputes, EphemeralKey, Files, Health, Mandates,
     MerchantAccount, MerchantConnectorAccount, PaymentMethods, Payments, Payouts, Refunds,
-    Webhooks,
+    WebhookEvents, Webhooks,
 };
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
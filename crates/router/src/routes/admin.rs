@@ -403,3 +403,49 @@ pub async fn merchant_account_kv_status(
     )
     .await
 }
+
+/// Merchant Account - Rotate Key
+///
+/// Rotate the key with which the data of the Merchant Account is encrypted
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKeyRotate))]
+pub async fn merchant_key_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKeyRotate;
+    let merchant_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        merchant_id,
+        |state, _, merchant_id| key_rotation::rotate_merchant_key(&*state.store, merchant_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Merchant Account - Key Rotation Status
+///
+/// Retrieve the progress of the latest key rotation of the Merchant Account
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKeyRotationRetrieve))]
+pub async fn merchant_key_rotation_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKeyRotationRetrieve;
+    let merchant_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        merchant_id,
+        |state, _, merchant_id| {
+            key_rotation::retrieve_merchant_key_rotation(&*state.store, merchant_id)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
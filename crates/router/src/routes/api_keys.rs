@@ -45,8 +45,8 @@ pub async fn api_key_create(
             api_keys::create_api_key(
                 &*state.store,
                 &state.conf.api_keys,
-                #[cfg(feature = "kms")]
-                &state.conf.kms,
+                #[cfg(feature = "secrets_management")]
+                &state.conf.secrets_management,
                 payload,
                 merchant_id.clone(),
             )
@@ -181,6 +181,55 @@ pub async fn api_key_revoke(
     .await
 }
 
+/// API Key - Rotate
+///
+/// Issue a new secret for the specified API Key. The previous secret continues to work for a
+/// configured grace period, allowing you to update your servers without downtime. The new
+/// plaintext API Key will be displayed only once, so ensure you store it securely.
+#[utoipa::path(
+    post,
+    path = "/api_keys/{merchant_id}/{key_id}/rotate",
+    params (
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("key_id" = String, Path, description = "The unique identifier for the API Key")
+    ),
+    responses(
+        (status = 200, description = "API Key rotated", body = CreateApiKeyResponse),
+        (status = 404, description = "API Key not found")
+    ),
+    tag = "API Key",
+    operation_id = "Rotate an API Key",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ApiKeyRotate))]
+pub async fn api_key_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::ApiKeyRotate;
+    let (merchant_id, key_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        (&merchant_id, &key_id),
+        |state, _, (merchant_id, key_id)| {
+            api_keys::rotate_api_key(
+                &*state.store,
+                &state.conf.api_keys,
+                #[cfg(feature = "secrets_management")]
+                &state.conf.secrets_management,
+                merchant_id,
+                key_id,
+            )
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
 /// API Key - List
 ///
 /// List all API Keys associated with your merchant account.
@@ -1,23 +1,24 @@
 use actix_web::{web, Scope};
 #[cfg(feature = "email")]
-use external_services::email::{AwsSes, EmailClient};
+use external_services::email::{smtp::SmtpServer, ActiveEmailClient, AwsSes, EmailClient};
 use tokio::sync::oneshot;
 
 #[cfg(feature = "dummy_connector")]
 use super::dummy_connector::*;
 use super::health::*;
 #[cfg(feature = "olap")]
-use super::{admin::*, api_keys::*, disputes::*, files::*};
+use super::{admin::*, api_keys::*, disputes::*, files::*, webhook_events::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers::*, mandates::*, payments::*, payouts::*, refunds::*};
 #[cfg(feature = "oltp")]
 use super::{ephemeral_key::*, payment_methods::*, webhooks::*};
-#[cfg(feature = "kms")]
+#[cfg(feature = "secrets_management")]
 use crate::configs::kms;
 use crate::{
     configs::settings,
+    consts,
     db::{MockDb, StorageImpl, StorageInterface},
-    routes::cards_info::card_iin_info,
+    routes::cards_info::{card_iin_info, cards_info_import},
     services::Store,
 };
 
@@ -28,7 +29,7 @@ pub struct AppState {
     pub conf: settings::Settings,
     #[cfg(feature = "email")]
     pub email_client: Box<dyn EmailClient>,
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "secrets_management")]
     pub kms_secrets: settings::ActiveKmsSecrets,
 }
 
@@ -70,27 +71,32 @@ impl AppState {
             StorageImpl::Mock => Box::new(MockDb::new(&conf).await),
         };
 
-        #[cfg(feature = "kms")]
+        #[cfg(feature = "secrets_management")]
         #[allow(clippy::expect_used)]
         let kms_secrets = kms::KmsDecrypt::decrypt_inner(
             settings::ActiveKmsSecrets {
                 jwekey: conf.jwekey.clone().into(),
             },
-            &conf.kms,
+            &conf.secrets_management,
         )
         .await
-        .expect("Failed while performing KMS decryption");
+        .expect("Failed while decrypting secrets");
 
         #[cfg(feature = "email")]
         #[allow(clippy::expect_used)]
-        let email_client = Box::new(AwsSes::new(&conf.email).await);
+        let email_client: Box<dyn EmailClient> = match conf.email.active_email_client {
+            ActiveEmailClient::AwsSes => Box::new(AwsSes::new(&conf.email).await),
+            ActiveEmailClient::Smtp => {
+                Box::new(SmtpServer::new(&conf.email).expect("Failed to create SMTP email client"))
+            }
+        };
         Self {
             flow_name: String::from("default"),
             store,
             conf,
             #[cfg(feature = "email")]
             email_client,
-            #[cfg(feature = "kms")]
+            #[cfg(feature = "secrets_management")]
             kms_secrets,
         }
     }
@@ -107,6 +113,7 @@ impl Health {
         web::scope("")
             .app_data(web::Data::new(state))
             .service(web::resource("/health").route(web::get().to(health)))
+            .service(web::resource("/health/ready").route(web::get().to(ready)))
     }
 }
 
@@ -133,6 +140,21 @@ impl DummyConnector {
             .service(
                 web::resource("/refunds/{refund_id}")
                     .route(web::get().to(dummy_connector_refund_data)),
+            )
+            .service(
+                web::scope("/frm")
+                    .service(
+                        web::resource("/checkout")
+                            .route(web::post().to(dummy_connector_frm_checkout)),
+                    )
+                    .service(
+                        web::resource("/transaction")
+                            .route(web::post().to(dummy_connector_frm_transaction)),
+                    )
+                    .service(
+                        web::resource("/fulfillment")
+                            .route(web::post().to(dummy_connector_frm_fulfillment)),
+                    ),
             );
         route
     }
@@ -175,6 +197,18 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/capture").route(web::post().to(payments_capture)),
                 )
+                .service(
+                    web::resource("/{payment_id}/captures/{capture_id}")
+                        .route(web::get().to(payments_retrieve_capture)),
+                )
+                .service(
+                    web::resource("/{payment_id}/frm/fulfillment")
+                        .route(web::post().to(payments_frm_fulfillment)),
+                )
+                .service(
+                    web::resource("/{payment_id}/frm/review")
+                        .route(web::post().to(payments_frm_review)),
+                )
                 .service(
                     web::resource("/redirect/{payment_id}/{merchant_id}/{attempt_id}")
                         .route(web::get().to(payments_start)),
@@ -276,10 +310,18 @@ impl Payouts {
         {
             route = route
                 .service(web::resource("/create").route(web::post().to(payouts_create)))
-                .service(web::resource("/retrieve").route(web::get().to(payouts_retrieve)))
-                .service(web::resource("/update").route(web::post().to(payouts_update)))
-                .service(web::resource("/reverse").route(web::post().to(payouts_reverse)))
-                .service(web::resource("/cancel").route(web::post().to(payouts_cancel)));
+                .service(
+                    web::resource("/{payout_id}")
+                        .route(web::get().to(payouts_retrieve))
+                        .route(web::post().to(payouts_update)),
+                )
+                .service(
+                    web::resource("/{payout_id}/reverse").route(web::post().to(payouts_reverse)),
+                )
+                .service(web::resource("/{payout_id}/cancel").route(web::post().to(payouts_cancel)))
+                .service(
+                    web::resource("/{payout_id}/fulfill").route(web::post().to(payouts_fulfill)),
+                );
         }
         route
     }
@@ -319,6 +361,11 @@ impl MerchantAccount {
                     .route(web::post().to(merchant_account_toggle_kv))
                     .route(web::get().to(merchant_account_kv_status)),
             )
+            .service(
+                web::resource("/{id}/keys/rotate")
+                    .route(web::post().to(merchant_key_rotate))
+                    .route(web::get().to(merchant_key_rotation_retrieve)),
+            )
             .service(
                 web::resource("/{id}")
                     .route(web::get().to(retrieve_merchant_account))
@@ -448,6 +495,7 @@ impl ApiKeys {
                     .route(web::post().to(api_key_update))
                     .route(web::delete().to(api_key_revoke)),
             )
+            .service(web::resource("/{key_id}/rotate").route(web::post().to(api_key_rotate)))
     }
 }
 
@@ -473,12 +521,35 @@ impl Disputes {
     }
 }
 
+pub struct WebhookEvents;
+
+#[cfg(feature = "olap")]
+impl WebhookEvents {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/events")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/list").route(web::get().to(list_events)))
+            .service(
+                web::resource("/{merchant_id}/list").route(web::get().to(list_events_for_merchant)),
+            )
+            .service(web::resource("/{event_id}/retry").route(web::post().to(retry_event)))
+            .service(web::resource("/{event_id}").route(web::get().to(retrieve_event)))
+    }
+}
+
 pub struct Cards;
 
 impl Cards {
     pub fn server(state: AppState) -> Scope {
         web::scope("/cards")
             .app_data(web::Data::new(state))
+            .service(
+                web::resource("/import")
+                    .app_data(web::PayloadConfig::new(
+                        consts::CARDS_INFO_IMPORT_BODY_LIMIT,
+                    ))
+                    .route(web::post().to(cards_info_import)),
+            )
             .service(web::resource("/{bin}").route(web::get().to(card_iin_info)))
     }
 }
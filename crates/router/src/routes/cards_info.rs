@@ -51,3 +51,48 @@ pub async fn card_iin_info(
     )
     .await
 }
+
+/// Cards Info - Import
+///
+/// Import a BIN data file into the card information used by the card IIN lookups
+#[utoipa::path(
+    post,
+    path = "/cards/import",
+    params(
+        ("format" = Option<String>, Query, description = "Format of the BIN data file, `json` (default) or `csv`"),
+        ("dry_run" = Option<bool>, Query, description = "Validate the file and report the changes without writing them"),
+        ("provider" = Option<String>, Query, description = "Name of the provider of the BIN data"),
+    ),
+    request_body(content = String, description = "BIN data file, as a JSON array or a CSV file of CardInfoImportRecord"),
+    responses(
+        (status = 200, description = "BIN data file processed", body = CardsInfoImportResponse),
+        (status = 422, description = "BIN data file could not be parsed")
+    ),
+    operation_id = "Import card information",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CardsInfoImport))]
+pub async fn cards_info_import(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<api_models::cards_info::CardsInfoImportParams>,
+    body: String,
+) -> impl Responder {
+    let params = query.into_inner();
+    let payload = api_models::cards_info::CardsInfoImportRequest {
+        format: params.format.unwrap_or_default(),
+        dry_run: params.dry_run,
+        provider: params.provider,
+        data: body,
+    };
+
+    api::server_wrap(
+        Flow::CardsInfoImport,
+        state.as_ref(),
+        &req,
+        payload,
+        |state, _, req| cards_info::import_cards_info(&*state.store, req),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
@@ -87,3 +87,60 @@ pub async fn dummy_connector_refund_data(
     )
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyFrmCheckout))]
+pub async fn dummy_connector_frm_checkout(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyFrmCheckoutRequest>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyFrmCheckout;
+    let payload = json_payload.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, _, req| utils::frm_checkout(state, req),
+        &auth::NoAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyFrmTransaction))]
+pub async fn dummy_connector_frm_transaction(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyFrmTransactionRequest>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyFrmTransaction;
+    let payload = json_payload.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, _, req| utils::frm_transaction(state, req),
+        &auth::NoAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyFrmFulfillment))]
+pub async fn dummy_connector_frm_fulfillment(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyFrmFulfillmentRequest>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyFrmFulfillment;
+    let payload = json_payload.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, _, req| utils::frm_fulfillment(state, req),
+        &auth::NoAuth,
+    )
+    .await
+}
@@ -1,12 +1,15 @@
 use api_models::enums::Currency;
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, pii};
 use masking::Secret;
 use router_env::types::FlowMetric;
 use strum::Display;
 use time::PrimitiveDateTime;
 
 use super::errors::DummyConnectorErrors;
-use crate::services;
+use crate::{
+    services::{self, authentication as auth},
+    types::storage::enums as storage_enums,
+};
 
 #[derive(Debug, Display, Clone, PartialEq, Eq)]
 #[allow(clippy::enum_variant_names)]
@@ -15,10 +18,19 @@ pub enum Flow {
     DummyPaymentRetrieve,
     DummyRefundCreate,
     DummyRefundRetrieve,
+    DummyFrmCheckout,
+    DummyFrmTransaction,
+    DummyFrmFulfillment,
 }
 
 impl FlowMetric for Flow {}
 
+impl auth::ScopedFlow for Flow {
+    fn required_scope(&self) -> Option<storage_enums::ApiKeyScope> {
+        None
+    }
+}
+
 #[allow(dead_code)]
 #[derive(
     Default, serde::Serialize, serde::Deserialize, strum::Display, Clone, PartialEq, Debug, Eq,
@@ -171,5 +183,52 @@ pub struct DummyConnectorRefundRetrieveRequest {
     pub refund_id: String,
 }
 
+#[derive(Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyFrmCheckoutRequest {
+    pub payment_id: String,
+    pub amount: i64,
+    pub currency: Currency,
+    pub email: Option<pii::Email>,
+}
+
+#[derive(Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyFrmTransactionRequest {
+    pub payment_id: String,
+    pub amount: i64,
+    pub currency: Currency,
+    pub email: Option<pii::Email>,
+    pub transaction_status: DummyFrmTransactionStatus,
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DummyFrmTransactionStatus {
+    Success,
+    Failed,
+}
+
+#[derive(Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyFrmFulfillmentRequest {
+    pub payment_id: String,
+    pub fulfillment_id: String,
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DummyFrmDecision {
+    Accept,
+    Reject,
+    Review,
+}
+
+#[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyFrmResponse {
+    pub id: String,
+    pub payment_id: String,
+    pub decision: DummyFrmDecision,
+    pub score: i32,
+    pub reason: Option<String>,
+}
+
 pub type DummyConnectorResponse<T> =
     CustomResult<services::ApplicationResponse<T>, DummyConnectorErrors>;
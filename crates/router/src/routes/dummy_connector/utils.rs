@@ -1,7 +1,7 @@
 use std::{fmt::Debug, sync::Arc};
 
 use app::AppState;
-use common_utils::generate_id;
+use common_utils::{generate_id, pii};
 use error_stack::{report, ResultExt};
 use masking::PeekInterface;
 use rand::Rng;
@@ -190,6 +190,76 @@ pub async fn refund_data(
     Ok(api::ApplicationResponse::Json(refund_data))
 }
 
+pub async fn frm_checkout(
+    _state: &AppState,
+    req: types::DummyFrmCheckoutRequest,
+) -> types::DummyConnectorResponse<types::DummyFrmResponse> {
+    Ok(api::ApplicationResponse::Json(frm_decision(
+        req.payment_id,
+        req.email.as_ref(),
+    )))
+}
+
+pub async fn frm_transaction(
+    _state: &AppState,
+    req: types::DummyFrmTransactionRequest,
+) -> types::DummyConnectorResponse<types::DummyFrmResponse> {
+    let response = match req.transaction_status {
+        types::DummyFrmTransactionStatus::Success => {
+            frm_decision(req.payment_id, req.email.as_ref())
+        }
+        // There is nothing at risk if the payment processor did not authorize the payment
+        types::DummyFrmTransactionStatus::Failed => types::DummyFrmResponse {
+            id: generate_id(20, "dummy_frm_"),
+            payment_id: req.payment_id,
+            decision: types::DummyFrmDecision::Accept,
+            score: 0,
+            reason: None,
+        },
+    };
+    Ok(api::ApplicationResponse::Json(response))
+}
+
+pub async fn frm_fulfillment(
+    _state: &AppState,
+    req: types::DummyFrmFulfillmentRequest,
+) -> types::DummyConnectorResponse<types::DummyFrmResponse> {
+    Ok(api::ApplicationResponse::Json(types::DummyFrmResponse {
+        id: req.fulfillment_id,
+        payment_id: req.payment_id,
+        decision: types::DummyFrmDecision::Accept,
+        score: 0,
+        reason: None,
+    }))
+}
+
+/// Emails starting with `fraud` are rejected and emails starting with `review` are sent for manual
+/// review, every other payment is accepted
+fn frm_decision(payment_id: String, email: Option<&pii::Email>) -> types::DummyFrmResponse {
+    let email = email.map(|email| email.peek().to_lowercase());
+    let (decision, score, reason) = match email.as_deref() {
+        Some(email) if email.starts_with("fraud") => (
+            types::DummyFrmDecision::Reject,
+            95,
+            Some("Customer email is associated with fraudulent payments".to_string()),
+        ),
+        Some(email) if email.starts_with("review") => (
+            types::DummyFrmDecision::Review,
+            60,
+            Some("Customer email requires a manual review".to_string()),
+        ),
+        _ => (types::DummyFrmDecision::Accept, 5, None),
+    };
+
+    types::DummyFrmResponse {
+        id: generate_id(20, "dummy_frm_"),
+        payment_id,
+        decision,
+        score,
+        reason,
+    }
+}
+
 async fn store_data_in_redis(
     redis_conn: Arc<RedisConnectionPool>,
     key: String,
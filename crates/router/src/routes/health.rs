@@ -1,6 +1,8 @@
+use actix_web::web;
 use router_env::{instrument, logger, tracing};
 
-use crate::routes::metrics;
+use super::app;
+use crate::{core::health_check, routes::metrics};
 
 /// .
 // #[logger::instrument(skip_all, name = "name1", level = "warn", fields( key1 = "val1" ))]
@@ -11,3 +13,24 @@ pub async fn health() -> impl actix_web::Responder {
     logger::info!("Health was called");
     actix_web::HttpResponse::Ok().body("health is good")
 }
+
+/// Readiness of the application to serve traffic
+///
+/// Unlike [`health`], which only tells that the application is alive, this checks the components
+/// it depends upon and responds with `503 Service Unavailable` if any of them is unhealthy.
+#[instrument(skip_all)]
+pub async fn ready(state: web::Data<app::AppState>) -> impl actix_web::Responder {
+    metrics::HEALTH_METRIC.add(&metrics::CONTEXT, 1, &[]);
+    logger::info!("Readiness was called");
+
+    let response = health_check::check_readiness(state.get_ref()).await;
+    match response.status {
+        api_models::health_check::HealthStatus::Healthy => {
+            actix_web::HttpResponse::Ok().json(response)
+        }
+        api_models::health_check::HealthStatus::Unhealthy => {
+            logger::error!(readiness_check=?response);
+            actix_web::HttpResponse::ServiceUnavailable().json(response)
+        }
+    }
+}
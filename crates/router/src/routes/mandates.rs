@@ -80,9 +80,7 @@ pub async fn revoke_mandate(
         state.get_ref(),
         &req,
         mandate_id,
-        |state, merchant_account, req| {
-            mandate::revoke_mandate(&*state.store, merchant_account, req)
-        },
+        |state, merchant_account, req| mandate::revoke_mandate(state, merchant_account, req),
         &auth::ApiKeyAuth,
     )
     .await
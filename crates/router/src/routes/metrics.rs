@@ -43,6 +43,7 @@ counter_metric!(CUSTOMER_REDACTED, GLOBAL_METER);
 
 counter_metric!(API_KEY_CREATED, GLOBAL_METER);
 counter_metric!(API_KEY_REVOKED, GLOBAL_METER);
+counter_metric!(API_KEY_ROTATED, GLOBAL_METER);
 
 counter_metric!(MCA_CREATE, GLOBAL_METER);
 
@@ -59,5 +59,6 @@ pub fn track_response_status_code<Q>(response: &ApplicationResponse<Q>) -> i64 {
         | ApplicationResponse::Form(_)
         | ApplicationResponse::FileData(_) => 200,
         ApplicationResponse::JsonForRedirection(_) => 302,
+        ApplicationResponse::IdempotentReplay(response) => response.status_code().into(),
     }
 }
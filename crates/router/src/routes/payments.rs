@@ -7,12 +7,12 @@ use router_env::{instrument, tracing, Flow};
 use crate::{
     self as app,
     core::{
-        errors::http_not_implemented,
+        fraud_check,
         payments::{self, PaymentRedirectFlow},
     },
     services::{api, authentication as auth},
     types::{
-        api::{self as api_types, enums as api_enums, payments as payment_types},
+        api::{self as api_types, payments as payment_types},
         domain,
     },
 };
@@ -42,10 +42,6 @@ pub async fn payments_create(
     let flow = Flow::PaymentsCreate;
     let payload = json_payload.into_inner();
 
-    if let Some(api_enums::CaptureMethod::Scheduled) = payload.capture_method {
-        return http_not_implemented();
-    };
-
     api::server_wrap(
         flow,
         state.get_ref(),
@@ -258,10 +254,6 @@ pub async fn payments_update(
     let flow = Flow::PaymentsUpdate;
     let mut payload = json_payload.into_inner();
 
-    if let Some(api_enums::CaptureMethod::Scheduled) = payload.capture_method {
-        return http_not_implemented();
-    };
-
     let payment_id = path.into_inner();
 
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
@@ -319,10 +311,6 @@ pub async fn payments_confirm(
     let flow = Flow::PaymentsConfirm;
     let mut payload = json_payload.into_inner();
 
-    if let Some(api_enums::CaptureMethod::Scheduled) = payload.capture_method {
-        return http_not_implemented();
-    };
-
     if let Err(err) = helpers::populate_ip_into_browser_info(&req, &mut payload) {
         return api::log_and_return_error_response(err);
     }
@@ -407,6 +395,63 @@ pub async fn payments_capture(
     .await
 }
 
+/// Payments - Retrieve Capture
+///
+/// To retrieve a capture of a payment captured in several parts. A pending capture is synced with the connector when `force_sync` is set
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/captures/{capture_id}",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment"),
+        ("capture_id" = String, Path, description = "The identifier for capture")
+    ),
+    request_body=PaymentRetrieveBody,
+    responses(
+        (status = 200, description = "Gets the capture", body = CaptureResponse),
+        (status = 404, description = "No capture found")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve a Capture of a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip(state), fields(flow = ?Flow::PaymentsCaptureRetrieve))]
+// #[get("/{payment_id}/captures/{capture_id}")]
+pub async fn payments_retrieve_capture(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Query<payment_types::PaymentRetrieveBody>,
+) -> impl Responder {
+    let flow = Flow::PaymentsCaptureRetrieve;
+    let (payment_id, capture_id) = path.into_inner();
+    let payload = payment_types::PaymentsRetrieveRequest {
+        resource_id: payment_types::PaymentIdType::PaymentIntentId(payment_id),
+        merchant_id: json_payload.merchant_id.clone(),
+        force_sync: json_payload.force_sync.unwrap_or(false),
+        capture_id: Some(capture_id),
+        ..Default::default()
+    };
+
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, merchant_account, req| {
+            payments::payments_core::<api_types::PSync, payment_types::CaptureResponse, _, _, _>(
+                state,
+                merchant_account,
+                payments::PaymentStatus,
+                req,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+            )
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
 /// Payments - Session token
 ///
 /// To create the session object or to get session token for wallets
@@ -653,6 +698,86 @@ pub async fn payments_cancel(
     .await
 }
 
+/// Payments - FRM Fulfillment
+///
+/// To report the fulfillment of the order a payment was made for to the FRM connector that checked the payment
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/frm/fulfillment",
+    request_body=FrmFulfillmentRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Fulfillment reported to the FRM connector", body = FrmFulfillmentResponse),
+        (status = 400, description = "Payment was not checked by an FRM connector")
+    ),
+    tag = "Payments",
+    operation_id = "Report the Fulfillment of a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsFrmFulfillment))]
+// #[post("/{payment_id}/frm/fulfillment")]
+pub async fn payments_frm_fulfillment(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<api_types::fraud_check::FrmFulfillmentRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsFrmFulfillment;
+    let mut payload = json_payload.into_inner();
+    payload.payment_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        fraud_check::frm_fulfillment_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Payments - FRM Review
+///
+/// To approve or reject a payment which was left for manual review by the FRM connector. A rejected payment is voided if it was only authorized, and refunded if it was captured
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/frm/review",
+    request_body=FrmReviewRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Review decision applied to the payment", body = PaymentsResponse),
+        (status = 400, description = "Payment is not awaiting a review")
+    ),
+    tag = "Payments",
+    operation_id = "Review a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsFrmReview))]
+// #[post("/{payment_id}/frm/review")]
+pub async fn payments_frm_review(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<api_types::fraud_check::FrmReviewRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsFrmReview;
+    let mut payload = json_payload.into_inner();
+    payload.payment_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        fraud_check::frm_review_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
 /// Payments - List
 ///
 /// To list the payments
@@ -1,42 +1,248 @@
 use actix_web::{
     body::{BoxBody, MessageBody},
-    HttpResponse, Responder,
+    web, HttpRequest, HttpResponse, Responder,
 };
 use router_env::{instrument, tracing, Flow};
 
+use super::app::AppState;
+use crate::{
+    core::payouts::*,
+    services::{api, authentication as auth},
+    types::api::payouts,
+};
+
+/// Payouts - Create
+///
+/// To create a payout to a card or a bank account
+#[utoipa::path(
+    post,
+    path = "/payouts/create",
+    request_body=PayoutCreateRequest,
+    responses(
+        (status = 200, description = "Payout created", body = PayoutResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout",
+    security(("api_key" = []))
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsCreate))]
 // #[post("/create")]
-pub async fn payouts_create() -> impl Responder {
-    let _flow = Flow::PayoutsCreate;
-    http_response("create")
+pub async fn payouts_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payouts::PayoutCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsCreate;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        payouts_create_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
+/// Payouts - Retrieve
+///
+/// To retrieve the properties of a Payout. This may be used to get the status of a previously initiated payout
+#[utoipa::path(
+    get,
+    path = "/payouts/{payout_id}",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout"),
+        ("force_sync" = Option<bool>, Query, description = "Sync with the connector to get the latest status of the payout")
+    ),
+    responses(
+        (status = 200, description = "Payout retrieved", body = PayoutResponse),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout",
+    security(("api_key" = []))
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsRetrieve))]
-// #[get("/retrieve")]
-pub async fn payouts_retrieve() -> impl Responder {
-    let _flow = Flow::PayoutsRetrieve;
-    http_response("retrieve")
+// #[get("/{payout_id}")]
+pub async fn payouts_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query_params: web::Query<payouts::PayoutRetrieveBody>,
+) -> HttpResponse {
+    let payout_request = payouts::PayoutRetrieveRequest {
+        payout_id: path.into_inner(),
+        force_sync: query_params.force_sync,
+    };
+    let flow = Flow::PayoutsRetrieve;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payout_request,
+        payouts_retrieve_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
+/// Payouts - Update
+///
+/// To update the properties of a Payout object. This may include attaching a description or metadata fields
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    request_body=PayoutUpdateRequest,
+    responses(
+        (status = 200, description = "Payout updated", body = PayoutResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Payouts",
+    operation_id = "Update a Payout",
+    security(("api_key" = []))
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsUpdate))]
-// #[post("/update")]
-pub async fn payouts_update() -> impl Responder {
-    let _flow = Flow::PayoutsUpdate;
-    http_response("update")
+// #[post("/{payout_id}")]
+pub async fn payouts_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payouts::PayoutUpdateRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsUpdate;
+    let payout_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, req| {
+            payouts_update_core(state, merchant_account, payout_id.clone(), req)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
+/// Payouts - Reverse
+///
+/// To reverse a payout which has been paid out to the recipient
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/reverse",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout reversed", body = PayoutResponse),
+        (status = 400, description = "Payout cannot be reversed")
+    ),
+    tag = "Payouts",
+    operation_id = "Reverse a Payout",
+    security(("api_key" = []))
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsReverse))]
-// #[post("/reverse")]
-pub async fn payouts_reverse() -> impl Responder {
-    let _flow = Flow::PayoutsReverse;
-    http_response("reverse")
+// #[post("/{payout_id}/reverse")]
+pub async fn payouts_reverse(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payout_request = payouts::PayoutActionRequest {
+        payout_id: path.into_inner(),
+    };
+    let flow = Flow::PayoutsReverse;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payout_request,
+        payouts_reverse_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
+/// Payouts - Cancel
+///
+/// To cancel a payout which has been created at the connector but not fulfilled yet
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/cancel",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout cancelled", body = PayoutResponse),
+        (status = 400, description = "Payout cannot be cancelled")
+    ),
+    tag = "Payouts",
+    operation_id = "Cancel a Payout",
+    security(("api_key" = []))
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsCancel))]
-// #[post("/cancel")]
-pub async fn payouts_cancel() -> impl Responder {
-    let _flow = Flow::PayoutsCancel;
-    http_response("cancel")
+// #[post("/{payout_id}/cancel")]
+pub async fn payouts_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payout_request = payouts::PayoutActionRequest {
+        payout_id: path.into_inner(),
+    };
+    let flow = Flow::PayoutsCancel;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payout_request,
+        payouts_cancel_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Payouts - Fulfill
+///
+/// To fulfill a payout which has been created at the connector, this moves the funds to the recipient
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/fulfill",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout fulfilled", body = PayoutResponse),
+        (status = 400, description = "Payout cannot be fulfilled")
+    ),
+    tag = "Payouts",
+    operation_id = "Fulfill a Payout",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsFulfill))]
+// #[post("/{payout_id}/fulfill")]
+pub async fn payouts_fulfill(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payout_request = payouts::PayoutActionRequest {
+        payout_id: path.into_inner(),
+    };
+    let flow = Flow::PayoutsFulfill;
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payout_request,
+        payouts_fulfill_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsAccounts))]
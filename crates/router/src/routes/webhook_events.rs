@@ -0,0 +1,167 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::webhooks::events,
+    services::{api, authentication as auth},
+    types::api::webhook_events,
+};
+
+/// Events - List Events
+#[utoipa::path(
+    get,
+    path = "/events/list",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of Event Objects to include in the response"),
+        ("event_type" = Option<EventType>, Query, description = "The type of event"),
+        ("event_class" = Option<EventClass>, Query, description = "The class of event"),
+        ("object_id" = Option<String>, Query, description = "The identifier of the object the event was raised for"),
+        ("is_webhook_notified" = Option<bool>, Query, description = "Whether the merchant has acknowledged the webhook of the event"),
+        ("created.lt" = Option<PrimitiveDateTime>, Query, description = "Time less than the event creation time"),
+        ("created.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the event creation time"),
+    ),
+    responses(
+        (status = 200, description = "The event list was retrieved successfully", body = Vec<EventResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Events",
+    operation_id = "List Events",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventsList))]
+pub async fn list_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<webhook_events::EventListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEventsList;
+    let payload = payload.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        events::list_events,
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+    )
+    .await
+}
+
+/// Events - List Events for a Merchant
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}/list",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of Event Objects to include in the response"),
+        ("event_type" = Option<EventType>, Query, description = "The type of event"),
+        ("event_class" = Option<EventClass>, Query, description = "The class of event"),
+        ("object_id" = Option<String>, Query, description = "The identifier of the object the event was raised for"),
+        ("is_webhook_notified" = Option<bool>, Query, description = "Whether the merchant has acknowledged the webhook of the event"),
+        ("created.lt" = Option<PrimitiveDateTime>, Query, description = "Time less than the event creation time"),
+        ("created.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the event creation time"),
+    ),
+    responses(
+        (status = 200, description = "The event list was retrieved successfully", body = Vec<EventResponse>),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Events",
+    operation_id = "List Events for a Merchant",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventsListForMerchant))]
+pub async fn list_events_for_merchant(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    payload: web::Query<webhook_events::EventListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEventsListForMerchant;
+    let merchant_id = path.into_inner();
+    let payload = payload.into_inner();
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        payload,
+        |state, _, req| events::list_events_for_merchant(state, merchant_id.clone(), req),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Events - Retrieve Event
+#[utoipa::path(
+    get,
+    path = "/events/{event_id}",
+    params(
+        ("event_id" = String, Path, description = "The identifier for the event")
+    ),
+    responses(
+        (status = 200, description = "The event was retrieved successfully", body = EventResponse),
+        (status = 404, description = "Event does not exist in our records")
+    ),
+    tag = "Events",
+    operation_id = "Retrieve an Event",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventRetrieve))]
+pub async fn retrieve_event(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEventRetrieve;
+    let event_id = webhook_events::EventId {
+        event_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        event_id,
+        events::retrieve_event,
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+    )
+    .await
+}
+
+/// Events - Retry Event
+///
+/// Resends the webhook of an event to the merchant, and returns the event along with its delivery attempts
+#[utoipa::path(
+    post,
+    path = "/events/{event_id}/retry",
+    params(
+        ("event_id" = String, Path, description = "The identifier for the event")
+    ),
+    responses(
+        (status = 200, description = "The webhook of the event was resent", body = EventResponse),
+        (status = 400, description = "The webhook of the event cannot be resent"),
+        (status = 404, description = "Event does not exist in our records")
+    ),
+    tag = "Events",
+    operation_id = "Retry an Event",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventRetry))]
+pub async fn retry_event(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEventRetry;
+    let event_id = webhook_events::EventId {
+        event_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state.get_ref(),
+        &req,
+        event_id,
+        events::retry_event::<api_models::webhooks::OutgoingWebhook>,
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+    )
+    .await
+}
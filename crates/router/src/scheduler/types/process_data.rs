@@ -60,3 +60,37 @@ impl Default for PaymentMethodsPTMapping {
         }
     }
 }
+
+/// Retries scheduled with exponential backoff: the `n`th retry is scheduled
+/// `initial_delay * multiplier^(n - 1)` seconds after the previous one, capped at `max_delay`
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExponentialBackoffMapping {
+    pub start_after: i32,
+    pub initial_delay: i32,
+    pub multiplier: i32,
+    pub max_delay: i32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutgoingWebhookRetryPTMapping {
+    pub default_mapping: ExponentialBackoffMapping,
+    pub custom_merchant_mapping: HashMap<String, ExponentialBackoffMapping>,
+    pub max_retries_count: i32,
+}
+
+impl Default for OutgoingWebhookRetryPTMapping {
+    fn default() -> Self {
+        Self {
+            custom_merchant_mapping: HashMap::new(),
+            default_mapping: ExponentialBackoffMapping {
+                start_after: 60,
+                initial_delay: 60,
+                multiplier: 2,
+                max_delay: 3600,
+            },
+            max_retries_count: 10,
+        }
+    }
+}
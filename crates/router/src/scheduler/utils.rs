@@ -329,6 +329,33 @@ pub fn get_pm_schedule_time(
     }
 }
 
+pub fn get_outgoing_webhook_retry_schedule_time(
+    mapping: process_data::OutgoingWebhookRetryPTMapping,
+    merchant_name: &str,
+    retry_count: i32,
+) -> Option<i32> {
+    let max_retries_count = mapping.max_retries_count;
+    let mapping = match mapping.custom_merchant_mapping.get(merchant_name) {
+        Some(map) => map.clone(),
+        None => mapping.default_mapping,
+    };
+
+    if retry_count == 0 {
+        Some(mapping.start_after)
+    } else if retry_count > max_retries_count {
+        None
+    } else {
+        let exponent = u32::try_from(retry_count - 1).ok()?;
+        Some(
+            mapping
+                .multiplier
+                .saturating_pow(exponent)
+                .saturating_mul(mapping.initial_delay)
+                .min(mapping.max_delay),
+        )
+    }
+}
+
 fn get_delay<'a>(
     retry_count: i32,
     mut array: impl Iterator<Item = (&'a i32, &'a i32)>,
@@ -8,8 +8,17 @@ use crate::{
     types::storage,
     utils::{OptionExt, StringExt},
 };
+pub mod api_key_last_used_flush;
+pub mod auto_void;
+pub mod customer_redaction;
+pub mod dispute_deadline;
+pub mod email_notification;
+pub mod mandate_expiry;
+pub mod merchant_key_rotation;
+pub mod outgoing_webhook_retry;
 pub mod payment_sync;
 pub mod refund_router;
+pub mod scheduled_capture;
 pub mod tokenized_data;
 
 macro_rules! runners {
@@ -52,7 +61,17 @@ macro_rules! as_item {
 runners! {
     PaymentsSyncWorkflow,
     RefundWorkflowRouter,
-    DeleteTokenizeDataWorkflow
+    DeleteTokenizeDataWorkflow,
+    OutgoingWebhookRetryWorkflow,
+    ScheduledCaptureWorkflow,
+    AutoVoidWorkflow,
+    AutoVoidSweepWorkflow,
+    MandateExpiryWorkflow,
+    ApiKeyLastUsedFlushWorkflow,
+    CustomerRedactionWorkflow,
+    MerchantKeyRotationWorkflow,
+    EmailNotificationWorkflow,
+    DisputeDeadlineWorkflow
 }
 
 pub type WorkflowSelectorFn =
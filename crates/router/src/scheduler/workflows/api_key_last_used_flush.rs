@@ -0,0 +1,41 @@
+use super::{ApiKeyLastUsedFlushWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::api_keys,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for ApiKeyLastUsedFlushWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::ApiKeyLastUsedFlushTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ApiKeyLastUsedFlushTrackingData")?;
+
+        api_keys::flush_api_keys_last_used(db, &tracking_data.redis_key).await?;
+
+        let id = process.id.clone();
+        process
+            .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
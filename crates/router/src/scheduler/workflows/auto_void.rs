@@ -0,0 +1,188 @@
+use router_env::logger;
+
+use super::{payment_sync, AutoVoidSweepWorkflow, AutoVoidWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::{
+        payments::{self as payment_flows, auto_void},
+        webhooks,
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    services,
+    types::{
+        api::{self, PaymentIdTypeExt},
+        domain,
+        storage::{self, enums, ProcessTrackerExt},
+        transformers::ForeignTryInto,
+    },
+    utils::{OptionExt, ValueExt},
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for AutoVoidWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: api::PaymentsRetrieveRequest = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentsRetrieveRequest")?;
+
+        let merchant_id = tracking_data
+            .merchant_id
+            .clone()
+            .get_required_value("merchant_id")?;
+        let payment_id = tracking_data.resource_id.get_payment_intent_id()?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&merchant_id)
+            .await?;
+        let storage_scheme = merchant_account.storage_scheme;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &payment_id,
+                &merchant_id,
+                storage_scheme,
+            )
+            .await?;
+
+        // The payment could have been captured or cancelled since the task was scheduled
+        if payment_intent.status != enums::IntentStatus::RequiresCapture {
+            return process
+                .finish_with_status(db, format!("COMPLETED_BY_PT_{}", payment_intent.status))
+                .await;
+        }
+
+        let cancel_request = api::PaymentsCancelRequest {
+            payment_id: payment_id.clone(),
+            cancellation_reason: Some(auto_void::AUTO_VOID_CANCELLATION_REASON.to_string()),
+            merchant_connector_details: None,
+        };
+        let void_result =
+            payment_flows::payments_core::<api::Void, api::PaymentsResponse, _, _, _>(
+                state,
+                merchant_account.clone(),
+                payment_flows::PaymentCancel,
+                cancel_request,
+                services::AuthFlow::Merchant,
+                payment_flows::CallConnectorAction::Trigger,
+            )
+            .await;
+
+        match void_result {
+            Ok(services::ApplicationResponse::Json(payments_response)) => {
+                trigger_payment_outgoing_webhook(state, merchant_account, payments_response).await;
+
+                let id = process.id.clone();
+                process
+                    .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                    .await?
+            }
+            Ok(_) => Err(errors::ProcessTrackerError::UnexpectedFlow)?,
+            Err(error) => {
+                logger::error!(?error, %payment_id, "Void of the stale authorization failed");
+                let payment_attempt = db
+                    .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                        &payment_id,
+                        &merchant_id,
+                        &payment_intent.active_attempt_id,
+                        storage_scheme,
+                    )
+                    .await?;
+                let connector = payment_attempt
+                    .connector
+                    .ok_or(errors::ProcessTrackerError::MissingRequiredField)?;
+
+                payment_sync::retry_sync_task(db, connector, merchant_id, process).await?
+            }
+        };
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for AutoVoidSweepWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: auto_void::AutoVoidSweepTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AutoVoidSweepTrackingData")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+
+        auto_void::schedule_auto_void_of_pending_authorizations(
+            db,
+            &tracking_data.merchant_id,
+            tracking_data.created_before,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+        let id = process.id.clone();
+        process
+            .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+pub async fn trigger_payment_outgoing_webhook(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    payments_response: api::PaymentsResponse,
+) {
+    let event_type: enums::EventType = match payments_response.status.foreign_try_into() {
+        Ok(event_type) => event_type,
+        Err(_) => return,
+    };
+    let payment_id = match payments_response.payment_id.clone() {
+        Some(payment_id) => payment_id,
+        None => return,
+    };
+
+    let result = webhooks::create_event_and_trigger_outgoing_webhook::<api::OutgoingWebhook>(
+        state.clone(),
+        merchant_account,
+        event_type,
+        enums::EventClass::Payments,
+        None,
+        payment_id,
+        enums::EventObjectType::PaymentDetails,
+        api::OutgoingWebhookContent::PaymentDetails(payments_response),
+    )
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(payment_outgoing_webhook_error=?error);
+    }
+}
@@ -0,0 +1,82 @@
+use error_stack::{IntoReport, ResultExt};
+use router_env::logger;
+
+use super::{CustomerRedactionWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::customers::redaction,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for CustomerRedactionWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::CustomerRedactionTrackingData =
+            process
+                .tracking_data
+                .clone()
+                .parse_value("CustomerRedactionTrackingData")?;
+
+        let redacted_records = redaction::redact_customer_records(
+            state,
+            &tracking_data.merchant_id,
+            &tracking_data.customer_id,
+        )
+        .await?;
+
+        let redacted_records = serde_json::to_value(redacted_records)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while serializing redacted records")?;
+        db.update_customer_redaction_by_redaction_id(
+            &tracking_data.redaction_id,
+            storage::CustomerRedactionUpdate::Completed { redacted_records },
+        )
+        .await?;
+
+        let id = process.id.clone();
+        process
+            .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        // The audit record reflects the last failure, it is completed if a retry succeeds
+        match process
+            .tracking_data
+            .clone()
+            .parse_value::<storage::CustomerRedactionTrackingData>("CustomerRedactionTrackingData")
+        {
+            Ok(tracking_data) => {
+                let result = state
+                    .store
+                    .update_customer_redaction_by_redaction_id(
+                        &tracking_data.redaction_id,
+                        storage::CustomerRedactionUpdate::Failed {
+                            error_message: error.to_string(),
+                        },
+                    )
+                    .await;
+                if let Err(update_error) = result {
+                    logger::error!(?update_error, "Failed to update customer redaction record");
+                }
+            }
+            Err(parse_error) => logger::error!(?parse_error),
+        }
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
@@ -0,0 +1,143 @@
+use common_utils::date_time;
+use error_stack::{IntoReport, ResultExt};
+use router_env::logger;
+
+use super::{DisputeDeadlineWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::disputes::deadline,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, enums, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for DisputeDeadlineWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let mut tracking_data: storage::DisputeDeadlineTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DisputeDeadlineTrackingData")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+        let dispute = db
+            .find_dispute_by_merchant_id_dispute_id(
+                &tracking_data.merchant_id,
+                &tracking_data.dispute_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The dispute could have been accepted, challenged or closed since the task was scheduled
+        if dispute.dispute_status != enums::DisputeStatus::DisputeOpened {
+            return process
+                .finish_with_status(db, format!("COMPLETED_BY_PT_{}", dispute.dispute_status))
+                .await;
+        }
+        let challenge_required_by = match dispute.challenge_required_by {
+            Some(challenge_required_by) => challenge_required_by,
+            None => {
+                return process
+                    .finish_with_status(db, "DEADLINE_NOT_SET".to_string())
+                    .await
+            }
+        };
+
+        let now = date_time::now();
+        if challenge_required_by <= now {
+            deadline::expire_dispute(state, merchant_account, dispute).await?;
+            let id = process.id.clone();
+            return process
+                .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                .await;
+        }
+
+        // The connector extended or shortened the deadline, the reminders are sent again for it
+        if tracking_data.challenge_required_by != Some(challenge_required_by) {
+            tracking_data.challenge_required_by = Some(challenge_required_by);
+            tracking_data.last_reminder_offset = None;
+            tracking_data.evidence_submission_attempted = false;
+        }
+
+        let config = deadline::get_dispute_deadline_config(db, &tracking_data.merchant_id).await;
+
+        if config.is_evidence_submission_due(challenge_required_by, now, &tracking_data) {
+            tracking_data.evidence_submission_attempted = true;
+            // Evidence can only be submitted in the dispute stage, otherwise the merchant is left
+            // to act on the reminders
+            if dispute.dispute_stage == enums::DisputeStage::Dispute {
+                match deadline::submit_attached_evidence(state, merchant_account.clone(), &dispute)
+                    .await
+                {
+                    Ok(true) => {
+                        let id = process.id.clone();
+                        return process
+                            .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                            .await;
+                    }
+                    Ok(false) => {
+                        logger::info!("No evidence attached to the dispute to submit");
+                    }
+                    Err(error) => {
+                        logger::error!(
+                            ?error,
+                            "Automatic submission of the dispute evidence failed"
+                        );
+                    }
+                }
+            }
+        }
+
+        if let Some(reminder_offset) =
+            config.get_due_reminder_offset(challenge_required_by, now, &tracking_data)
+        {
+            deadline::remind_merchant(
+                state,
+                merchant_account,
+                dispute,
+                challenge_required_by,
+                reminder_offset,
+            )
+            .await;
+            tracking_data.last_reminder_offset = Some(reminder_offset);
+        }
+
+        let schedule_time = config.get_schedule_time(challenge_required_by, now, &tracking_data);
+        let tracking_data = serde_json::to_value(&tracking_data)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while serializing dispute deadline tracking data")?;
+        db.update_process(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: Some(schedule_time),
+                tracking_data: Some(tracking_data),
+                business_status: None,
+                status: Some(enums::ProcessTrackerStatus::Pending),
+                updated_at: None,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
@@ -0,0 +1,112 @@
+use common_utils::{date_time, pii};
+use router_env::logger;
+
+use super::{EmailNotificationWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::notifications,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+/// Number of times sending an email is retried before the notification is given up
+const MAX_EMAIL_NOTIFICATION_RETRIES: i32 = 3;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for EmailNotificationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::EmailNotificationTrackingData =
+            process
+                .tracking_data
+                .clone()
+                .parse_value("EmailNotificationTrackingData")?;
+
+        if !notifications::is_notification_relevant(db, &tracking_data).await? {
+            return process
+                .finish_with_status(db, "NOTIFICATION_OUTDATED".to_string())
+                .await;
+        }
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+        let recipient = match notifications::get_merchant_email(&merchant_account) {
+            Some(recipient) => recipient,
+            None => {
+                return process
+                    .finish_with_status(db, "RECIPIENT_NOT_CONFIGURED".to_string())
+                    .await
+            }
+        };
+
+        let (subject, body) = notifications::get_email_contents(
+            &tracking_data.merchant_id,
+            &tracking_data.notification,
+        );
+        if send_email(state, recipient, subject, body).await {
+            let id = process.id.clone();
+            process
+                .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                .await
+        } else if process.retry_count < MAX_EMAIL_NOTIFICATION_RETRIES {
+            let schedule_time = date_time::now().saturating_add(time::Duration::minutes(
+                5 * i64::from(process.retry_count + 1),
+            ));
+            process.retry(db, schedule_time).await
+        } else {
+            process
+                .finish_with_status(db, "RETRIES_EXCEEDED".to_string())
+                .await
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+/// Returns whether the email has been sent
+#[cfg(feature = "email")]
+async fn send_email(
+    state: &AppState,
+    recipient: pii::Email,
+    subject: String,
+    body: String,
+) -> bool {
+    match state
+        .email_client
+        .send_email(recipient, subject, body)
+        .await
+    {
+        Ok(()) => true,
+        Err(error) => {
+            logger::error!(?error, "Failed to send email notification");
+            false
+        }
+    }
+}
+
+/// Returns whether the email has been sent
+#[cfg(not(feature = "email"))]
+async fn send_email(
+    _state: &AppState,
+    _recipient: pii::Email,
+    _subject: String,
+    _body: String,
+) -> bool {
+    logger::error!("Email notifications can only be sent with the `email` feature enabled");
+    false
+}
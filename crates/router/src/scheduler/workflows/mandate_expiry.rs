@@ -0,0 +1,53 @@
+use super::{MandateExpiryWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::mandate,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, enums, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for MandateExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::MandateExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("MandateExpiryTrackingData")?;
+
+        let mandate = db
+            .find_mandate_by_merchant_id_mandate_id(
+                &tracking_data.merchant_id,
+                &tracking_data.mandate_id,
+            )
+            .await?;
+
+        // Mandates which have been revoked or used up in the meantime are left as they are
+        let business_status = if mandate.mandate_status == enums::MandateStatus::Active
+            && mandate::is_mandate_expired(&mandate)
+        {
+            mandate::expire_mandate(state, mandate).await?;
+            format!("COMPLETED_BY_PT_{}", process.id)
+        } else {
+            format!("COMPLETED_BY_PT_{}", mandate.mandate_status)
+        };
+
+        process.finish_with_status(db, business_status).await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
@@ -0,0 +1,84 @@
+use error_stack::{IntoReport, ResultExt};
+
+use super::{MerchantKeyRotationWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::admin::key_rotation,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::{
+        domain::types as domain_types,
+        storage::{self, ProcessTrackerExt},
+    },
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for MerchantKeyRotationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let mut tracking_data: storage::MerchantKeyRotationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("MerchantKeyRotationTrackingData")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+        let key = domain_types::get_merchant_enc_key(db, &tracking_data.merchant_id).await?;
+
+        // The progress is persisted after every batch, so that a retried task resumes from it
+        let mut process = process;
+        loop {
+            let is_completed = key_rotation::reencrypt_next_batch(
+                db,
+                &merchant_account,
+                &key,
+                &mut tracking_data.progress,
+            )
+            .await?;
+
+            let tracking_data_value = serde_json::to_value(&tracking_data)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while serializing key rotation tracking data")?;
+            process = db
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: None,
+                        schedule_time: None,
+                        tracking_data: Some(tracking_data_value),
+                        business_status: None,
+                        status: None,
+                        updated_at: None,
+                    },
+                )
+                .await?;
+
+            if is_completed {
+                break;
+            }
+        }
+
+        let id = process.id.clone();
+        process
+            .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
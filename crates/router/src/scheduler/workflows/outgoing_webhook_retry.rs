@@ -0,0 +1,200 @@
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::{OutgoingWebhookRetryWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::webhooks,
+    db::{get_and_deserialize_key, StorageInterface},
+    errors,
+    routes::AppState,
+    scheduler::{consumer, process_data, utils},
+    types::storage::{self, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for OutgoingWebhookRetryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::OutgoingWebhookTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("OutgoingWebhookTrackingData")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+
+        let request_body = match webhooks::events::find_original_request_body(
+            db,
+            &tracking_data.merchant_id,
+            &tracking_data.event_id,
+        )
+        .await?
+        {
+            Some(request_body) => request_body,
+            None => {
+                logger::warn!(
+                    event_id = %tracking_data.event_id,
+                    "Outgoing webhook payload not found or redacted, retry aborted"
+                );
+                return process
+                    .finish_with_status(db, "RETRY_ABORTED".to_string())
+                    .await;
+            }
+        };
+        // Signed on every retry, so that the signature follows a rotation of the merchant key
+        let outgoing_webhooks_signature =
+            webhooks::sign_outgoing_webhook_payload(&merchant_account, &request_body)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to sign the outgoing webhook")?;
+
+        let delivery_result = webhooks::send_webhook_to_merchant(
+            state,
+            &merchant_account,
+            &tracking_data.event_id,
+            request_body,
+            outgoing_webhooks_signature,
+            false,
+        )
+        .await;
+
+        match delivery_result {
+            Ok(()) => {
+                let id = process.id.clone();
+                process
+                    .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                    .await?
+            }
+            Err(error) if webhooks::is_webhook_delivery_retryable(error.current_context()) => {
+                logger::info!(?error, "Outgoing webhook delivery failed, scheduling retry");
+                retry_outgoing_webhook_task(db, &tracking_data, process).await?
+            }
+            Err(error) => {
+                logger::error!(?error, "Outgoing webhook delivery failed");
+                process
+                    .finish_with_status(db, "RETRY_ABORTED".to_string())
+                    .await?
+            }
+        };
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+pub async fn get_outgoing_webhook_retry_schedule_time(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    retry_count: i32,
+) -> Option<time::PrimitiveDateTime> {
+    let redis_mapping: errors::CustomResult<
+        process_data::OutgoingWebhookRetryPTMapping,
+        errors::RedisError,
+    > = get_and_deserialize_key(
+        db,
+        "pt_mapping_outgoing_webhooks",
+        "OutgoingWebhookRetryPTMapping",
+    )
+    .await;
+    let mapping = match redis_mapping {
+        Ok(x) => x,
+        Err(err) => {
+            logger::info!("Redis Mapping Error: {}", err);
+            process_data::OutgoingWebhookRetryPTMapping::default()
+        }
+    };
+    let time_delta =
+        utils::get_outgoing_webhook_retry_schedule_time(mapping, merchant_id, retry_count);
+
+    utils::get_time_from_delta(time_delta)
+}
+
+pub async fn retry_outgoing_webhook_task(
+    db: &dyn StorageInterface,
+    tracking_data: &storage::OutgoingWebhookTrackingData,
+    pt: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let schedule_time = get_outgoing_webhook_retry_schedule_time(
+        db,
+        &tracking_data.merchant_id,
+        pt.retry_count + 1,
+    )
+    .await;
+
+    match schedule_time {
+        Some(s_time) => pt.retry(db, s_time).await,
+        None => {
+            #[cfg(feature = "email")]
+            crate::core::notifications::notify_merchant(
+                db,
+                &tracking_data.merchant_id,
+                storage::EmailNotification::WebhookEndpointFailing {
+                    event_id: tracking_data.event_id.clone(),
+                    // The delivery attempted before the task was scheduled and one per execution
+                    delivery_attempts: pt.retry_count + 2,
+                    failed_at: common_utils::date_time::now(),
+                },
+            )
+            .await;
+
+            pt.finish_with_status(db, "RETRIES_EXCEEDED".to_string())
+                .await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outgoing_webhook_retry_schedule_time_backs_off_exponentially() {
+        let schedule_time = |retry_count| {
+            utils::get_outgoing_webhook_retry_schedule_time(
+                process_data::OutgoingWebhookRetryPTMapping::default(),
+                "merchant_1",
+                retry_count,
+            )
+        };
+
+        assert_eq!(schedule_time(0), Some(60));
+        assert_eq!(schedule_time(1), Some(60));
+        assert_eq!(schedule_time(2), Some(120));
+        assert_eq!(schedule_time(4), Some(480));
+        assert_eq!(schedule_time(10), Some(3600));
+        assert_eq!(schedule_time(11), None);
+    }
+
+    #[test]
+    fn test_outgoing_webhook_retry_schedule_time_uses_merchant_mapping() {
+        let schedule_time = |retry_count| {
+            let mut mapping = process_data::OutgoingWebhookRetryPTMapping::default();
+            mapping.custom_merchant_mapping.insert(
+                "merchant_1".to_string(),
+                process_data::ExponentialBackoffMapping {
+                    start_after: 10,
+                    initial_delay: 10,
+                    multiplier: 3,
+                    max_delay: 100,
+                },
+            );
+            utils::get_outgoing_webhook_retry_schedule_time(mapping, "merchant_1", retry_count)
+        };
+
+        assert_eq!(schedule_time(0), Some(10));
+        assert_eq!(schedule_time(2), Some(30));
+        assert_eq!(schedule_time(4), Some(100));
+    }
+}
@@ -0,0 +1,124 @@
+use router_env::logger;
+
+use super::{auto_void, payment_sync, ProcessTrackerWorkflow, ScheduledCaptureWorkflow};
+use crate::{
+    core::payments::{self as payment_flows, operations},
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    services,
+    types::{
+        api,
+        storage::{self, enums, ProcessTrackerExt},
+    },
+    utils::{OptionExt, ValueExt},
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for ScheduledCaptureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: api::PaymentsCaptureRequest = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentsCaptureRequest")?;
+
+        let merchant_id = tracking_data
+            .merchant_id
+            .clone()
+            .get_required_value("merchant_id")?;
+        let payment_id = tracking_data
+            .payment_id
+            .clone()
+            .get_required_value("payment_id")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&merchant_id)
+            .await?;
+        let storage_scheme = merchant_account.storage_scheme;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &payment_id,
+                &merchant_id,
+                storage_scheme,
+            )
+            .await?;
+
+        // The payment could have been cancelled or captured manually since the task was scheduled
+        if payment_intent.status != enums::IntentStatus::RequiresCapture {
+            logger::info!(
+                %payment_id,
+                status = %payment_intent.status,
+                "Skipping scheduled capture of a payment which is no longer capturable"
+            );
+            return process
+                .finish_with_status(db, format!("COMPLETED_BY_PT_{}", payment_intent.status))
+                .await;
+        }
+
+        let capture_result =
+            payment_flows::payments_core::<api::Capture, api::PaymentsResponse, _, _, _>(
+                state,
+                merchant_account.clone(),
+                operations::PaymentCapture,
+                tracking_data,
+                services::AuthFlow::Merchant,
+                payment_flows::CallConnectorAction::Trigger,
+            )
+            .await;
+
+        match capture_result {
+            Ok(services::ApplicationResponse::Json(payments_response)) => {
+                logger::info!(
+                    %payment_id,
+                    status = %payments_response.status,
+                    "Scheduled capture completed"
+                );
+                auto_void::trigger_payment_outgoing_webhook(
+                    state,
+                    merchant_account,
+                    payments_response,
+                )
+                .await;
+
+                let id = process.id.clone();
+                process
+                    .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                    .await?
+            }
+            Ok(_) => Err(errors::ProcessTrackerError::UnexpectedFlow)?,
+            Err(error) => {
+                logger::error!(?error, %payment_id, "Scheduled capture failed");
+                let payment_attempt = db
+                    .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                        &payment_id,
+                        &merchant_id,
+                        &payment_intent.active_attempt_id,
+                        storage_scheme,
+                    )
+                    .await?;
+                let connector = payment_attempt
+                    .connector
+                    .ok_or(errors::ProcessTrackerError::MissingRequiredField)?;
+
+                payment_sync::retry_sync_task(db, connector, merchant_id, process).await?
+            }
+        };
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
@@ -6,15 +6,15 @@ pub mod logger;
 use std::sync::{atomic, Arc};
 
 use error_stack::{IntoReport, ResultExt};
-#[cfg(feature = "kms")]
-use external_services::kms;
+#[cfg(feature = "secrets_management")]
+use external_services::secrets_management;
 use redis_interface::{errors as redis_errors, PubsubInterface, RedisValue};
 use tokio::sync::oneshot;
 
 pub use self::{api::*, encryption::*};
 use crate::{
     async_spawn,
-    cache::{CacheKind, ACCOUNTS_CACHE, CONFIG_CACHE},
+    cache::{CacheKind, ACCOUNTS_CACHE, API_KEYS_CACHE, CONFIG_CACHE},
     configs::settings,
     connection::{diesel_make_pg_pool, PgPool},
     consts,
@@ -87,6 +87,10 @@ impl PubSubInterface for redis_interface::RedisConnectionPool {
                     ACCOUNTS_CACHE.invalidate(key.as_ref()).await;
                     key
                 }
+                CacheKind::ApiKeys(key) => {
+                    API_KEYS_CACHE.invalidate(key.as_ref()).await;
+                    key
+                }
             };
 
             self.delete_key(key.as_ref())
@@ -154,8 +158,8 @@ impl Store {
 
         let master_enc_key = get_master_enc_key(
             config,
-            #[cfg(feature = "kms")]
-            &config.kms,
+            #[cfg(feature = "secrets_management")]
+            &config.secrets_management,
         )
         .await;
 
@@ -163,16 +167,16 @@ impl Store {
             master_pool: diesel_make_pg_pool(
                 &config.master_database,
                 test_transaction,
-                #[cfg(feature = "kms")]
-                &config.kms,
+                #[cfg(feature = "secrets_management")]
+                &config.secrets_management,
             )
             .await,
             #[cfg(feature = "olap")]
             replica_pool: diesel_make_pg_pool(
                 &config.replica_database,
                 test_transaction,
-                #[cfg(feature = "kms")]
-                &config.kms,
+                #[cfg(feature = "secrets_management")]
+                &config.secrets_management,
             )
             .await,
             redis_conn,
@@ -233,19 +237,20 @@ impl Store {
 #[allow(clippy::expect_used)]
 async fn get_master_enc_key(
     conf: &crate::configs::settings::Settings,
-    #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
+    #[cfg(feature = "secrets_management")] secrets_management_config: &secrets_management::SecretsManagementConfig,
 ) -> Vec<u8> {
-    #[cfg(feature = "kms")]
+    #[cfg(feature = "secrets_management")]
     let master_enc_key = hex::decode(
-        kms::get_kms_client(kms_config)
+        secrets_management::get_secret_manager(secrets_management_config)
             .await
+            .expect("Failed to initialize secret manager")
             .decrypt(&conf.secrets.master_enc_key)
             .await
             .expect("Failed to decrypt master enc key"),
     )
     .expect("Failed to decode from hex");
 
-    #[cfg(not(feature = "kms"))]
+    #[cfg(not(feature = "secrets_management"))]
     let master_enc_key =
         hex::decode(&conf.secrets.master_enc_key).expect("Failed to decode from hex");
 
@@ -1,4 +1,5 @@
 mod client;
+pub mod idempotency;
 pub(crate) mod request;
 
 use std::{
@@ -10,7 +11,7 @@ use std::{
 };
 
 use actix_web::{body, HttpRequest, HttpResponse, Responder, ResponseError};
-use common_utils::errors::ReportSwitchExt;
+use common_utils::errors::{ErrorSwitch, ReportSwitchExt};
 use error_stack::{report, IntoReport, Report, ResultExt};
 use masking::{ExposeOptionInterface, PeekInterface};
 use router_env::{instrument, tracing, Tag};
@@ -278,7 +279,19 @@ where
                                             req.connector.clone(),
                                         )],
                                     );
-                                    let error = connector_integration.get_error_response(body)?;
+                                    let status_code = body.status_code;
+                                    let error = connector_integration
+                                        .get_error_response(body)
+                                        .map_err(|error| match status_code {
+                                            // The server error is kept in the report, so that
+                                            // the payment can be retried on another connector
+                                            500..=599 => error.attach(
+                                                errors::ApiClientError::from_server_error_status(
+                                                    status_code,
+                                                ),
+                                            ),
+                                            _ => error,
+                                        })?;
                                     router_data.response = Err(error);
 
                                     router_data
@@ -481,6 +494,8 @@ pub enum ApplicationResponse<R> {
     JsonForRedirection(api::RedirectionResponse),
     Form(Box<RedirectionFormData>),
     FileData((Vec<u8>, mime::Mime)),
+    /// Stored response of an earlier request with the same idempotency key
+    IdempotentReplay(idempotency::StoredResponse),
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -544,7 +559,7 @@ pub enum AuthFlow {
 
 #[instrument(skip(request, payload, state, func, api_auth))]
 pub async fn server_wrap_util<'a, 'b, A, U, T, Q, F, Fut, E, OErr>(
-    flow: &'a impl router_env::types::FlowMetric,
+    flow: &'a (impl router_env::types::FlowMetric + auth::ScopedFlow),
     state: &'b A,
     request: &'a HttpRequest,
     payload: T,
@@ -557,18 +572,41 @@ where
     Fut: Future<Output = CustomResult<ApplicationResponse<Q>, E>>,
     Q: Serialize + Debug + 'a,
     T: Debug,
-    A: AppStateInfo,
+    A: AppStateInfo + Sync,
     U: auth::AuthInfo,
     CustomResult<ApplicationResponse<Q>, E>: ReportSwitchExt<ApplicationResponse<Q>, OErr>,
     CustomResult<U, errors::ApiErrorResponse>: ReportSwitchExt<U, OErr>,
-    OErr: ResponseError + Sync + Send + 'static,
+    errors::ApiErrorResponse: ErrorSwitch<OErr>,
+    OErr: ResponseError + error_stack::Context,
 {
     let auth_out = api_auth
-        .authenticate_and_fetch(request.headers(), state)
+        .authenticate_and_fetch_with_scope(request.headers(), state, flow.required_scope())
         .await
         .switch()?;
     let metric_merchant_id = auth_out.get_merchant_id().unwrap_or("").to_string();
 
+    let idempotency_key =
+        ReportSwitchExt::<_, OErr>::switch(idempotency::get_idempotency_key(request))?;
+    if let (Some(idempotency_key), Some(merchant_id)) =
+        (idempotency_key, auth_out.get_merchant_id())
+    {
+        let fingerprint =
+            ReportSwitchExt::<_, OErr>::switch(idempotency::get_request_fingerprint(request))?;
+        let idempotency_check = idempotency::check_idempotency_key(
+            state,
+            request,
+            merchant_id,
+            &idempotency_key,
+            fingerprint,
+        )
+        .await
+        .switch()?;
+
+        if let idempotency::IdempotencyCheck::Replay(response) = idempotency_check {
+            return Ok(ApplicationResponse::IdempotentReplay(response));
+        }
+    }
+
     let output = func(state, auth_out, payload).await.switch();
 
     let status_code = match output.as_ref() {
@@ -590,7 +628,7 @@ where
     fields(request_method, request_url_path)
 )]
 pub async fn server_wrap<'a, 'b, A, T, U, Q, F, Fut, E>(
-    flow: impl router_env::types::FlowMetric,
+    flow: impl router_env::types::FlowMetric + auth::ScopedFlow,
     state: &'b A,
     request: &'a HttpRequest,
     payload: T,
@@ -603,7 +641,7 @@ where
     Q: Serialize + Debug + 'a,
     T: Debug,
     U: auth::AuthInfo,
-    A: AppStateInfo,
+    A: AppStateInfo + Sync,
     ApplicationResponse<Q>: Debug,
     CustomResult<ApplicationResponse<Q>, E>:
         ReportSwitchExt<ApplicationResponse<Q>, api_models::errors::types::ApiErrorResponse>,
@@ -616,6 +654,7 @@ where
     let start_instant = Instant::now();
     logger::info!(tag = ?Tag::BeginRequest, payload = ?payload);
 
+    let mut is_rejected = false;
     let res = match metrics::request::record_request_time_metric(
         server_wrap_util(&flow, state, request, payload, func, api_auth),
         &flow,
@@ -660,8 +699,15 @@ where
         )
         .respond_to(request)
         .map_into_boxed_body(),
-        Err(error) => log_and_return_error_response(error),
+        Ok(ApplicationResponse::IdempotentReplay(response)) => {
+            idempotency::http_response_replayed(response)
+        }
+        Err(error) => {
+            is_rejected = idempotency::is_rejected_request(&error);
+            log_and_return_error_response(error)
+        }
     };
+    let res = idempotency::store_idempotent_response(state, request, res, is_rejected).await;
 
     let response_code = res.status().as_u16();
     let end_instant = Instant::now();
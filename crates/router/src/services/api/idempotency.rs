@@ -0,0 +1,421 @@
+//! Handling of the `Idempotency-Key` header for mutating merchant APIs.
+//!
+//! The first request with a key reserves it in Redis along with a fingerprint of the request.
+//! Once the request completes, its response is stored under the same key, whether it succeeded or
+//! failed, so that retries with an identical request replay the stored response instead of being
+//! processed again.
+
+use actix_web::{
+    body,
+    http::{Method, StatusCode},
+    web, HttpMessage, HttpRequest, HttpResponse,
+};
+use common_utils::crypto::{GenerateDigest, Sha256};
+use error_stack::{IntoReport, Report, ResultExt};
+use redis_interface::SetnxReply;
+use router_env::logger;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResult},
+    headers,
+    routes::app::AppStateInfo,
+};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum IdempotencyRecord {
+    InProgress {
+        fingerprint: String,
+    },
+    Completed {
+        fingerprint: String,
+        response: StoredResponse,
+    },
+}
+
+/// Final response of a request holding an idempotency key, as it was sent to the client
+#[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StoredResponse {
+    status_code: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl StoredResponse {
+    pub fn status_code(&self) -> u16 {
+        self.status_code
+    }
+}
+
+/// Stored in the request extensions while the request holding the idempotency key is processed
+#[derive(Debug)]
+struct IdempotencyContext {
+    redis_key: String,
+    fingerprint: String,
+}
+
+pub enum IdempotencyCheck {
+    /// The key was reserved for this request, which should now be processed
+    Proceed,
+    /// The request was already processed, the stored response should be returned as is
+    Replay(StoredResponse),
+}
+
+/// Returns the idempotency key of the request, if it is a POST request carrying one
+pub fn get_idempotency_key(request: &HttpRequest) -> RouterResult<Option<String>> {
+    if request.method() != Method::POST {
+        return Ok(None);
+    }
+
+    request
+        .headers()
+        .get(headers::IDEMPOTENCY_KEY)
+        .map(|value| {
+            let key = value.to_str().into_report().change_context(
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Idempotency-Key header is not a valid string".to_string(),
+                },
+            )?;
+
+            if key.is_empty() || key.len() > consts::MAX_IDEMPOTENCY_KEY_LENGTH {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Idempotency-Key must be between 1 and {} characters long",
+                        consts::MAX_IDEMPOTENCY_KEY_LENGTH
+                    ),
+                })
+                .into_report()
+            } else {
+                Ok(key.to_string())
+            }
+        })
+        .transpose()
+}
+
+/// Raw body of a request carrying an idempotency key. It is captured by
+/// [`crate::middleware::IdempotencyRequestBody`], as the payload extractors of the route consume the
+/// body before the idempotency key is checked.
+#[derive(Clone, Debug)]
+pub struct RawRequestBody(pub web::Bytes);
+
+/// Fingerprint of the request, used to detect an idempotency key being reused for another request.
+/// The raw body is hashed rather than the deserialized payload, so that fields which are masked
+/// when the payload is logged, such as card numbers, still take part in the fingerprint.
+pub fn get_request_fingerprint(request: &HttpRequest) -> RouterResult<String> {
+    let mut request_signature = format!("{} {}\n", request.method(), request.path()).into_bytes();
+    request_signature.extend_from_slice(
+        &request
+            .extensions()
+            .get::<RawRequestBody>()
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("The raw body of the request was not captured")?
+            .0,
+    );
+
+    Sha256
+        .generate_digest(&request_signature)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate the request fingerprint")
+        .map(hex::encode)
+}
+
+/// Reserves the idempotency key for the request, or determines how a request reusing a key
+/// should be answered
+pub async fn check_idempotency_key<A: AppStateInfo>(
+    state: &A,
+    request: &HttpRequest,
+    merchant_id: &str,
+    idempotency_key: &str,
+    fingerprint: String,
+) -> RouterResult<IdempotencyCheck> {
+    let redis_conn = state.store().get_redis_conn();
+    let redis_key = format!("idempotency_{merchant_id}_{idempotency_key}");
+
+    let reply = redis_conn
+        .serialize_and_set_key_if_not_exist_with_expiry(
+            &redis_key,
+            IdempotencyRecord::InProgress {
+                fingerprint: fingerprint.clone(),
+            },
+            consts::IDEMPOTENCY_KEY_IN_PROGRESS_TTL,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reserve the idempotency key")?;
+
+    match reply {
+        SetnxReply::KeySet => {
+            request.extensions_mut().insert(IdempotencyContext {
+                redis_key,
+                fingerprint,
+            });
+            Ok(IdempotencyCheck::Proceed)
+        }
+        SetnxReply::KeyNotSet => {
+            let record = redis_conn
+                .get_and_deserialize_key::<IdempotencyRecord>(&redis_key, "IdempotencyRecord")
+                .await;
+
+            match record {
+                Ok(IdempotencyRecord::Completed {
+                    fingerprint: stored_fingerprint,
+                    response,
+                }) if stored_fingerprint == fingerprint => Ok(IdempotencyCheck::Replay(response)),
+                Ok(IdempotencyRecord::InProgress {
+                    fingerprint: stored_fingerprint,
+                }) if stored_fingerprint == fingerprint => {
+                    Err(errors::ApiErrorResponse::IdempotencyKeyInUse).into_report()
+                }
+                Ok(_) => Err(errors::ApiErrorResponse::IdempotencyKeyMismatch).into_report(),
+                // The key expired after the reservation attempt, the client can retry right away
+                Err(error)
+                    if matches!(
+                        error.current_context(),
+                        redis_interface::errors::RedisError::NotFound
+                    ) =>
+                {
+                    Err(errors::ApiErrorResponse::IdempotencyKeyInUse).into_report()
+                }
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the idempotency record"),
+            }
+        }
+    }
+}
+
+/// Whether the request failed validation or authentication, before anything was processed.
+/// The idempotency key of such a request is released, so that the corrected request can be sent
+/// with the same key.
+pub fn is_rejected_request<E>(error: &Report<E>) -> bool {
+    error.frames().any(|frame| {
+        frame
+            .downcast_ref::<errors::ApiErrorResponse>()
+            .map_or(false, |error| {
+                matches!(
+                    error,
+                    errors::ApiErrorResponse::Unauthorized
+                        | errors::ApiErrorResponse::InvalidEphemeralKey
+                        | errors::ApiErrorResponse::InvalidJwtToken
+                        | errors::ApiErrorResponse::GenericUnauthorized { .. }
+                        | errors::ApiErrorResponse::InsufficientApiKeyScope { .. }
+                        | errors::ApiErrorResponse::InvalidRequestUrl
+                        | errors::ApiErrorResponse::InvalidHttpMethod
+                        | errors::ApiErrorResponse::MissingRequiredField { .. }
+                        | errors::ApiErrorResponse::MissingRequiredFields { .. }
+                        | errors::ApiErrorResponse::InvalidDataValue { .. }
+                        | errors::ApiErrorResponse::InvalidDataFormat { .. }
+                        | errors::ApiErrorResponse::InvalidRequestData { .. }
+                        | errors::ApiErrorResponse::PreconditionFailed { .. }
+                )
+            })
+    })
+}
+
+/// Stores the final response of a request that reserved an idempotency key, including error and
+/// redirection responses, and returns it to be sent to the client. The key is released instead if
+/// the request was rejected before anything was processed.
+pub async fn store_idempotent_response<A: AppStateInfo>(
+    state: &A,
+    request: &HttpRequest,
+    response: HttpResponse,
+    is_rejected: bool,
+) -> HttpResponse {
+    let context = request.extensions_mut().remove::<IdempotencyContext>();
+    let context = match context {
+        Some(context) => context,
+        None => return response,
+    };
+    let redis_conn = state.store().get_redis_conn();
+
+    if is_rejected {
+        if let Err(error) = redis_conn.delete_key(&context.redis_key).await {
+            logger::error!(?error, "Failed to release the idempotency key");
+        }
+        return response;
+    }
+
+    let (response, response_body) = response.into_parts();
+    let response_body = match body::to_bytes(response_body).await {
+        Ok(response_body) => response_body,
+        Err(error) => {
+            logger::error!(%error, "Failed to read the response body of an idempotent request");
+            // The response cannot be replayed, the key is left to expire so that the request is
+            // not processed again before then
+            return response.map_into_boxed_body();
+        }
+    };
+
+    let stored_response = String::from_utf8(response_body.to_vec()).map(|body| StoredResponse {
+        status_code: response.status().as_u16(),
+        headers: response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.to_string(), value.to_string()))
+            })
+            .collect(),
+        body,
+    });
+
+    let result = match stored_response {
+        Ok(stored_response) => {
+            redis_conn
+                .serialize_and_set_key_with_expiry(
+                    &context.redis_key,
+                    IdempotencyRecord::Completed {
+                        fingerprint: context.fingerprint,
+                        response: stored_response,
+                    },
+                    consts::IDEMPOTENCY_KEY_TTL,
+                )
+                .await
+        }
+        // Only file downloads have binary bodies, which are not made through POST requests
+        Err(_) => redis_conn.delete_key(&context.redis_key).await,
+    };
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to update the idempotency record");
+    }
+
+    response.set_body(response_body).map_into_boxed_body()
+}
+
+/// Rebuilds the stored response of an earlier request with the same idempotency key
+pub fn http_response_replayed(response: StoredResponse) -> HttpResponse {
+    let mut builder =
+        HttpResponse::build(StatusCode::from_u16(response.status_code).unwrap_or(StatusCode::OK));
+    for header in response.headers {
+        builder.append_header(header);
+    }
+
+    builder
+        .insert_header((headers::IDEMPOTENT_REPLAYED, "true"))
+        .body(response.body)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use actix_web::test::TestRequest;
+    use error_stack::report;
+
+    use super::*;
+
+    #[test]
+    fn test_idempotency_key_is_read_only_for_post_requests() {
+        let post_request = TestRequest::post()
+            .insert_header((headers::IDEMPOTENCY_KEY, "key_1"))
+            .to_http_request();
+        let get_request = TestRequest::get()
+            .insert_header((headers::IDEMPOTENCY_KEY, "key_1"))
+            .to_http_request();
+
+        assert_eq!(
+            get_idempotency_key(&post_request).unwrap(),
+            Some("key_1".to_string())
+        );
+        assert_eq!(get_idempotency_key(&get_request).unwrap(), None);
+        assert_eq!(
+            get_idempotency_key(&TestRequest::post().to_http_request()).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_idempotency_key_length_is_validated() {
+        let request = TestRequest::post()
+            .insert_header((
+                headers::IDEMPOTENCY_KEY,
+                "k".repeat(consts::MAX_IDEMPOTENCY_KEY_LENGTH + 1),
+            ))
+            .to_http_request();
+
+        assert!(get_idempotency_key(&request).is_err());
+    }
+
+    fn request_with_body(path: &str, body: &'static str) -> HttpRequest {
+        let request = TestRequest::post().uri(path).to_http_request();
+        request
+            .extensions_mut()
+            .insert(RawRequestBody(web::Bytes::from_static(body.as_bytes())));
+        request
+    }
+
+    #[test]
+    fn test_request_fingerprint_depends_on_path_and_raw_body() {
+        let body = r#"{"amount":100,"card_number":"4242424242424242"}"#;
+        let fingerprint = get_request_fingerprint(&request_with_body("/payments", body)).unwrap();
+
+        assert_eq!(
+            fingerprint,
+            get_request_fingerprint(&request_with_body("/payments", body)).unwrap()
+        );
+        assert_ne!(
+            fingerprint,
+            get_request_fingerprint(&request_with_body(
+                "/payments",
+                r#"{"amount":100,"card_number":"4000000000000002"}"#
+            ))
+            .unwrap()
+        );
+        assert_ne!(
+            fingerprint,
+            get_request_fingerprint(&request_with_body("/refunds", body)).unwrap()
+        );
+        assert!(get_request_fingerprint(&TestRequest::post().to_http_request()).is_err());
+    }
+
+    #[test]
+    fn test_only_validation_and_authentication_errors_release_the_key() {
+        let validation_error: Report<errors::ApiErrorResponse> =
+            report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "amount"
+            });
+        let connector_error: Report<errors::ApiErrorResponse> =
+            report!(errors::ApiErrorResponse::PaymentAuthorizationFailed { data: None });
+
+        assert!(is_rejected_request(&validation_error));
+        assert!(is_rejected_request(
+            &report!(errors::ApiErrorResponse::Unauthorized)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        ));
+        assert!(!is_rejected_request(&connector_error));
+        assert!(!is_rejected_request(&report!(
+            errors::ApiErrorResponse::InternalServerError
+        )));
+    }
+
+    #[actix_rt::test]
+    async fn test_replayed_response_keeps_status_and_headers() {
+        let response = http_response_replayed(StoredResponse {
+            status_code: 400,
+            headers: vec![("content-type".to_string(), "application/json".to_string())],
+            body: r#"{"error":{}}"#.to_string(),
+        });
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            response.headers().get("content-type").unwrap(),
+            "application/json"
+        );
+        assert_eq!(
+            response
+                .headers()
+                .get(headers::IDEMPOTENT_REPLAYED)
+                .unwrap(),
+            "true"
+        );
+        assert_eq!(
+            body::to_bytes(response.into_body()).await.unwrap(),
+            r#"{"error":{}}"#
+        );
+    }
+}
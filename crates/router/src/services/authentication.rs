@@ -3,10 +3,11 @@ use api_models::{payment_methods::PaymentMethodListRequest, payments::PaymentsRe
 use async_trait::async_trait;
 use common_utils::date_time;
 use error_stack::{report, IntoReport, ResultExt};
-#[cfg(feature = "kms")]
-use external_services::kms;
+#[cfg(feature = "secrets_management")]
+use external_services::secrets_management;
 use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
 use masking::{PeekInterface, StrongSecret};
+use router_env::{logger, Flow};
 
 use crate::{
     configs::settings,
@@ -17,7 +18,10 @@ use crate::{
     db::StorageInterface,
     routes::app::AppStateInfo,
     services::api,
-    types::domain,
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+    },
     utils::OptionExt,
 };
 
@@ -38,7 +42,7 @@ impl AuthInfo for domain::MerchantAccount {
 }
 
 #[async_trait]
-pub trait AuthenticateAndFetch<T, A>
+pub trait AuthenticateAndFetch<T, A>: Sync
 where
     A: AppStateInfo,
     T: AuthInfo,
@@ -48,6 +52,83 @@ where
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<T>;
+
+    /// Authenticates the request for a flow which requires the given API key scope. Only
+    /// authentication methods which can be restricted to scopes need to override this.
+    async fn authenticate_and_fetch_with_scope(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+        _required_scope: Option<storage_enums::ApiKeyScope>,
+    ) -> RouterResult<T>
+    where
+        A: Sync,
+    {
+        self.authenticate_and_fetch(request_headers, state).await
+    }
+}
+
+/// Flows which API keys restricted to a set of scopes can be allowed to access
+pub trait ScopedFlow {
+    /// The scope an API key requires to access the flow. Flows returning `None` can only be
+    /// accessed with API keys having full access.
+    fn required_scope(&self) -> Option<storage_enums::ApiKeyScope>;
+}
+
+impl ScopedFlow for Flow {
+    fn required_scope(&self) -> Option<storage_enums::ApiKeyScope> {
+        use storage_enums::ApiKeyScope;
+
+        match self {
+            Self::PaymentsRetrieve | Self::PaymentsCaptureRetrieve | Self::PaymentsList => {
+                Some(ApiKeyScope::PaymentsRead)
+            }
+            Self::PaymentsCreate
+            | Self::PaymentsUpdate
+            | Self::PaymentsConfirm
+            | Self::PaymentsCapture
+            | Self::PaymentsCancel
+            | Self::PaymentsSessionToken
+            | Self::PaymentsStart
+            | Self::PaymentsRedirect
+            | Self::PaymentsFrmFulfillment
+            | Self::PaymentsFrmReview => Some(ApiKeyScope::PaymentsWrite),
+            Self::RefundsRetrieve | Self::RefundsList => Some(ApiKeyScope::RefundsRead),
+            Self::RefundsCreate | Self::RefundsUpdate => Some(ApiKeyScope::RefundsWrite),
+            Self::CustomersRetrieve => Some(ApiKeyScope::CustomersRead),
+            Self::CustomersCreate
+            | Self::CustomersUpdate
+            | Self::CustomersDelete
+            | Self::EphemeralKeyCreate
+            | Self::EphemeralKeyDelete => Some(ApiKeyScope::CustomersWrite),
+            Self::PaymentMethodsRetrieve
+            | Self::PaymentMethodsList
+            | Self::CustomerPaymentMethodsList => Some(ApiKeyScope::PaymentMethodsRead),
+            Self::PaymentMethodsCreate
+            | Self::PaymentMethodsUpdate
+            | Self::PaymentMethodsDelete
+            | Self::ValidatePaymentMethod => Some(ApiKeyScope::PaymentMethodsWrite),
+            Self::MandatesRetrieve | Self::MandatesList | Self::CustomersGetMandates => {
+                Some(ApiKeyScope::MandatesRead)
+            }
+            Self::MandatesRevoke => Some(ApiKeyScope::MandatesWrite),
+            Self::DisputesRetrieve
+            | Self::DisputesList
+            | Self::RetrieveDisputeEvidence
+            | Self::RetrieveFile => Some(ApiKeyScope::DisputesRead),
+            Self::DisputesEvidenceSubmit
+            | Self::AttachDisputeEvidence
+            | Self::CreateFile
+            | Self::DeleteFile => Some(ApiKeyScope::DisputesWrite),
+            Self::PayoutsRetrieve | Self::PayoutsAccounts => Some(ApiKeyScope::PayoutsRead),
+            Self::PayoutsCreate
+            | Self::PayoutsUpdate
+            | Self::PayoutsReverse
+            | Self::PayoutsCancel
+            | Self::PayoutsFulfill => Some(ApiKeyScope::PayoutsWrite),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -78,6 +159,16 @@ where
         &self,
         request_headers: &HeaderMap,
         state: &A,
+    ) -> RouterResult<domain::MerchantAccount> {
+        self.authenticate_and_fetch_with_scope(request_headers, state, None)
+            .await
+    }
+
+    async fn authenticate_and_fetch_with_scope(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+        required_scope: Option<storage_enums::ApiKeyScope>,
     ) -> RouterResult<domain::MerchantAccount> {
         let api_key = get_api_key(request_headers)
             .change_context(errors::ApiErrorResponse::Unauthorized)?
@@ -89,20 +180,18 @@ where
         }
 
         let api_key = api_keys::PlaintextApiKey::from(api_key);
-        let hash_key = {
-            let config = state.conf();
-            api_keys::get_hash_key(
-                &config.api_keys,
-                #[cfg(feature = "kms")]
-                &config.kms,
-            )
-            .await?
-        };
-        let hashed_api_key = api_key.keyed_hash(hash_key.peek());
+        let config = state.conf();
+        let hash_key = api_keys::get_hash_key(
+            &config.api_keys,
+            #[cfg(feature = "secrets_management")]
+            &config.secrets_management,
+        )
+        .await?;
+        let hashed_api_key: storage::HashedApiKey = api_key.keyed_hash(hash_key.peek()).into();
 
         let stored_api_key = state
             .store()
-            .find_api_key_by_hash_optional(hashed_api_key.into())
+            .find_api_key_by_hash_optional(hashed_api_key.clone())
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
             .attach_printable("Failed to retrieve API key")?
@@ -118,6 +207,27 @@ where
                 .attach_printable("API key has expired");
         }
 
+        // The secret a key had before it was rotated is only accepted during the grace period
+        if stored_api_key.hashed_api_key != hashed_api_key
+            && stored_api_key
+                .previous_key_expires_at
+                .map(|expires_at| expires_at < date_time::now())
+                .unwrap_or(true)
+        {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("Grace period of the rotated API key has ended");
+        }
+
+        if let Some(scopes) = stored_api_key.scopes.as_ref() {
+            check_api_key_scope(scopes, required_scope)?;
+        }
+
+        if let Err(error) =
+            api_keys::record_api_key_usage(&*state.store(), &config.api_keys, &stored_api_key).await
+        {
+            logger::error!(?error, "Failed to record the use of the API key");
+        }
+
         state
             .store()
             .find_merchant_account_by_merchant_id(&stored_api_key.merchant_id)
@@ -132,24 +242,45 @@ where
     }
 }
 
+fn check_api_key_scope(
+    scopes: &[storage_enums::ApiKeyScope],
+    required_scope: Option<storage_enums::ApiKeyScope>,
+) -> RouterResult<()> {
+    match required_scope {
+        Some(required_scope) if scopes.contains(&required_scope) => Ok(()),
+        Some(required_scope) => Err(report!(
+            errors::ApiErrorResponse::InsufficientApiKeyScope {
+                message: format!(
+                    "The API key used does not have the `{required_scope}` scope required for this request"
+                ),
+            }
+        )),
+        None => Err(report!(errors::ApiErrorResponse::InsufficientApiKeyScope {
+            message: "This request can only be made with an API key having full access".to_string(),
+        })),
+    }
+}
+
 static ADMIN_API_KEY: tokio::sync::OnceCell<StrongSecret<String>> =
     tokio::sync::OnceCell::const_new();
 
 pub async fn get_admin_api_key(
     secrets: &settings::Secrets,
-    #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
+    #[cfg(feature = "secrets_management")] secrets_management_config: &secrets_management::SecretsManagementConfig,
 ) -> RouterResult<&'static StrongSecret<String>> {
     ADMIN_API_KEY
         .get_or_try_init(|| async {
-            #[cfg(feature = "kms")]
-            let admin_api_key = kms::get_kms_client(kms_config)
+            #[cfg(feature = "secrets_management")]
+            let admin_api_key = secrets_management::get_secret_manager(secrets_management_config)
                 .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to initialize secret manager")?
                 .decrypt(&secrets.kms_encrypted_admin_api_key)
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Failed to KMS decrypt admin API key")?;
+                .attach_printable("Failed to decrypt admin API key")?;
 
-            #[cfg(not(feature = "kms"))]
+            #[cfg(not(feature = "secrets_management"))]
             let admin_api_key = secrets.admin_api_key.clone();
 
             Ok(StrongSecret::new(admin_api_key))
@@ -176,8 +307,8 @@ where
 
         let admin_api_key = get_admin_api_key(
             &conf.secrets,
-            #[cfg(feature = "kms")]
-            &conf.kms,
+            #[cfg(feature = "secrets_management")]
+            &conf.secrets_management,
         )
         .await?;
 
@@ -413,19 +544,21 @@ static JWT_SECRET: tokio::sync::OnceCell<StrongSecret<String>> = tokio::sync::On
 
 pub async fn get_jwt_secret(
     secrets: &settings::Secrets,
-    #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
+    #[cfg(feature = "secrets_management")] secrets_management_config: &secrets_management::SecretsManagementConfig,
 ) -> RouterResult<&'static StrongSecret<String>> {
     JWT_SECRET
         .get_or_try_init(|| async {
-            #[cfg(feature = "kms")]
-            let jwt_secret = kms::get_kms_client(kms_config)
+            #[cfg(feature = "secrets_management")]
+            let jwt_secret = secrets_management::get_secret_manager(secrets_management_config)
                 .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to initialize secret manager")?
                 .decrypt(&secrets.kms_encrypted_jwt_secret)
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Failed to KMS decrypt JWT secret")?;
+                .attach_printable("Failed to decrypt JWT secret")?;
 
-            #[cfg(not(feature = "kms"))]
+            #[cfg(not(feature = "secrets_management"))]
             let jwt_secret = secrets.jwt_secret.clone();
 
             Ok(StrongSecret::new(jwt_secret))
@@ -440,8 +573,8 @@ where
     let conf = state.conf();
     let secret = get_jwt_secret(
         &conf.secrets,
-        #[cfg(feature = "kms")]
-        &conf.kms,
+        #[cfg(feature = "secrets_management")]
+        &conf.secrets_management,
     )
     .await?
     .peek()
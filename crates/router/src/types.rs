@@ -11,7 +11,7 @@ pub mod domain;
 pub mod storage;
 pub mod transformers;
 
-use std::marker::PhantomData;
+use std::{collections::HashMap, marker::PhantomData};
 
 pub use api_models::enums::Connector;
 use common_utils::{pii, pii::Email};
@@ -41,11 +41,21 @@ pub type PaymentsSessionRouterData =
 pub type RefundsRouterData<F> = RouterData<F, RefundsData, RefundsResponseData>;
 pub type RefundExecuteRouterData = RouterData<api::Execute, RefundsData, RefundsResponseData>;
 pub type RefundSyncRouterData = RouterData<api::RSync, RefundsData, RefundsResponseData>;
+pub type PayoutsRouterData<F> = RouterData<F, PayoutsData, PayoutsResponseData>;
+pub type FrmCheckoutRouterData =
+    RouterData<api::fraud_check::Checkout, FraudCheckCheckoutData, FraudCheckResponseData>;
+pub type FrmTransactionRouterData =
+    RouterData<api::fraud_check::Transaction, FraudCheckTransactionData, FraudCheckResponseData>;
+pub type FrmFulfillmentRouterData =
+    RouterData<api::fraud_check::Fulfillment, FraudCheckFulfillmentData, FraudCheckResponseData>;
 pub type TokenizationRouterData =
     RouterData<api::PaymentMethodToken, PaymentMethodTokenizationData, PaymentsResponseData>;
 pub type ConnectorCustomerRouterData =
     RouterData<api::CreateConnectorCustomer, ConnectorCustomerData, PaymentsResponseData>;
 
+pub type MandateRevokeRouterData =
+    RouterData<api::mandates::MandateRevoke, MandateRevokeRequestData, MandateRevokeResponseData>;
+
 pub type RefreshTokenRouterData =
     RouterData<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
 
@@ -76,6 +86,10 @@ pub type ConnectorCustomerResponseRouterData<R> = ResponseRouterData<
 
 pub type RefundsResponseRouterData<F, R> =
     ResponseRouterData<F, R, RefundsData, RefundsResponseData>;
+pub type PayoutsResponseRouterData<F, R> =
+    ResponseRouterData<F, R, PayoutsData, PayoutsResponseData>;
+pub type FrmResponseRouterData<F, R, Request> =
+    ResponseRouterData<F, R, Request, FraudCheckResponseData>;
 
 pub type PaymentsAuthorizeType =
     dyn services::ConnectorIntegration<api::Authorize, PaymentsAuthorizeData, PaymentsResponseData>;
@@ -126,6 +140,39 @@ pub type RefundExecuteType =
 pub type RefundSyncType =
     dyn services::ConnectorIntegration<api::RSync, RefundsData, RefundsResponseData>;
 
+pub type PayoutCreateType =
+    dyn services::ConnectorIntegration<api::PoCreate, PayoutsData, PayoutsResponseData>;
+pub type PayoutFulfillType =
+    dyn services::ConnectorIntegration<api::PoFulfill, PayoutsData, PayoutsResponseData>;
+pub type PayoutCancelType =
+    dyn services::ConnectorIntegration<api::PoCancel, PayoutsData, PayoutsResponseData>;
+pub type PayoutSyncType =
+    dyn services::ConnectorIntegration<api::PoSync, PayoutsData, PayoutsResponseData>;
+pub type PayoutReverseType =
+    dyn services::ConnectorIntegration<api::PoReverse, PayoutsData, PayoutsResponseData>;
+
+pub type FrmCheckoutType = dyn services::ConnectorIntegration<
+    api::fraud_check::Checkout,
+    FraudCheckCheckoutData,
+    FraudCheckResponseData,
+>;
+pub type FrmTransactionType = dyn services::ConnectorIntegration<
+    api::fraud_check::Transaction,
+    FraudCheckTransactionData,
+    FraudCheckResponseData,
+>;
+pub type FrmFulfillmentType = dyn services::ConnectorIntegration<
+    api::fraud_check::Fulfillment,
+    FraudCheckFulfillmentData,
+    FraudCheckResponseData,
+>;
+
+pub type MandateRevokeType = dyn services::ConnectorIntegration<
+    api::mandates::MandateRevoke,
+    MandateRevokeRequestData,
+    MandateRevokeResponseData,
+>;
+
 pub type RefreshTokenType =
     dyn services::ConnectorIntegration<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
 
@@ -242,6 +289,14 @@ pub struct PaymentsCaptureData {
     pub connector_transaction_id: String,
     pub payment_amount: i64,
     pub connector_meta: Option<serde_json::Value>,
+    pub multiple_capture_data: Option<MultipleCaptureRequestData>,
+}
+
+/// Details of a single capture made against an authorization that is captured in several parts
+#[derive(Debug, Clone, Default)]
+pub struct MultipleCaptureRequestData {
+    pub capture_sequence: i16,
+    pub capture_reference: String,
 }
 
 #[derive(Debug, Clone)]
@@ -306,6 +361,15 @@ pub struct PaymentsSyncData {
     pub capture_method: Option<storage_enums::CaptureMethod>,
     pub connector_meta: Option<serde_json::Value>,
     pub mandate_id: Option<api_models::payments::MandateIds>,
+    pub sync_type: SyncRequestType,
+}
+
+#[derive(Debug, Default, Clone)]
+pub enum SyncRequestType {
+    /// Sync the captures with the given connector capture ids
+    MultipleCaptureSync(Vec<String>),
+    #[default]
+    SinglePaymentSync,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -358,7 +422,7 @@ pub struct AccessToken {
     pub expires: i64,
 }
 
-#[derive(serde::Serialize, Debug, Clone)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 pub struct MandateReference {
     pub connector_mandate_id: Option<String>,
     pub payment_method_id: Option<String>,
@@ -400,6 +464,17 @@ pub enum PaymentsResponseData {
         pre_processing_id: String,
         connector_metadata: Option<serde_json::Value>,
     },
+    MultipleCaptureResponse {
+        /// Sync status of each capture, keyed by the connector capture id
+        capture_sync_response_list: HashMap<String, CaptureSyncResponse>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct CaptureSyncResponse {
+    pub status: storage_enums::CaptureStatus,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -463,6 +538,63 @@ pub struct RefundsResponseData {
     // pub amount_received: Option<i32>, // Calculation for amount received not in place yet
 }
 
+#[derive(Debug, Clone)]
+pub struct PayoutsData {
+    pub payout_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub payout_type: storage_enums::PayoutType,
+    /// Details of the card or bank account, only available while creating the payout
+    pub payout_method_data: Option<api::PayoutMethodData>,
+    /// Reference of the payout at the connector, available once the payout is created
+    pub connector_payout_id: Option<String>,
+    pub email: Option<Email>,
+    pub name: Option<Secret<String>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PayoutsResponseData {
+    pub connector_payout_id: String,
+    pub status: storage_enums::PayoutStatus,
+}
+
+#[derive(Debug, Clone)]
+pub struct FraudCheckCheckoutData {
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub email: Option<Email>,
+    pub browser_info: Option<BrowserInformation>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FraudCheckTransactionData {
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub email: Option<Email>,
+    /// Status of the payment attempt that was authorized by the payment processor
+    pub attempt_status: storage_enums::AttemptStatus,
+    pub connector: Option<String>,
+    pub connector_transaction_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FraudCheckFulfillmentData {
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub fulfillment_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct FraudCheckResponseData {
+    /// Reference of the fraud check at the FRM connector
+    pub frm_reference_id: String,
+    pub status: api_models::enums::FraudCheckStatus,
+    pub score: Option<i32>,
+    pub reason: Option<String>,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum Redirection {
     Redirect,
@@ -481,6 +613,17 @@ pub struct AcceptDisputeResponse {
     pub connector_status: Option<String>,
 }
 
+#[derive(Debug, Clone)]
+pub struct MandateRevokeRequestData {
+    pub mandate_id: String,
+    pub connector_mandate_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MandateRevokeResponseData {
+    pub mandate_status: storage_enums::MandateStatus,
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct SubmitEvidenceRequestData {
     pub dispute_id: String,
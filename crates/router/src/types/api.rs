@@ -6,10 +6,13 @@ pub mod disputes;
 pub mod enums;
 pub mod ephemeral_key;
 pub mod files;
+pub mod fraud_check;
 pub mod mandates;
 pub mod payment_methods;
 pub mod payments;
+pub mod payouts;
 pub mod refunds;
+pub mod webhook_events;
 pub mod webhooks;
 
 use std::{fmt::Debug, str::FromStr};
@@ -18,7 +21,7 @@ use error_stack::{report, IntoReport, ResultExt};
 
 pub use self::{
     admin::*, api_keys::*, configs::*, customers::*, disputes::*, files::*, payment_methods::*,
-    payments::*, refunds::*, webhooks::*,
+    payments::*, payouts::*, refunds::*, webhooks::*,
 };
 use super::ErrorResponse;
 use crate::{
@@ -110,7 +113,9 @@ pub trait Connector:
     + ConnectorAccessToken
     + Dispute
     + FileUpload
+    + Payouts
     + ConnectorTransactionId
+    + mandates::ConnectorMandateRevoke
 {
 }
 
@@ -128,7 +133,9 @@ impl<
             + ConnectorAccessToken
             + Dispute
             + FileUpload
-            + ConnectorTransactionId,
+            + Payouts
+            + ConnectorTransactionId
+            + mandates::ConnectorMandateRevoke,
     > Connector for T
 {
 }
@@ -0,0 +1,99 @@
+use std::{fmt::Debug, str::FromStr};
+
+pub use api_models::fraud_check::{
+    FrmFulfillmentRequest, FrmFulfillmentResponse, FrmReviewDecision, FrmReviewRequest,
+};
+use error_stack::{IntoReport, ResultExt};
+
+#[cfg(feature = "dummy_connector")]
+use crate::connector;
+use crate::{
+    configs::settings::Connectors,
+    core::errors::{self, CustomResult},
+    services,
+    types::{self, api::enums as api_enums},
+};
+
+#[derive(Debug, Clone)]
+pub struct Checkout;
+
+#[derive(Debug, Clone)]
+pub struct Transaction;
+
+#[derive(Debug, Clone)]
+pub struct Fulfillment;
+
+pub trait FraudCheckCheckout:
+    services::ConnectorIntegration<
+    Checkout,
+    types::FraudCheckCheckoutData,
+    types::FraudCheckResponseData,
+>
+{
+}
+
+pub trait FraudCheckTransaction:
+    services::ConnectorIntegration<
+    Transaction,
+    types::FraudCheckTransactionData,
+    types::FraudCheckResponseData,
+>
+{
+}
+
+pub trait FraudCheckFulfillment:
+    services::ConnectorIntegration<
+    Fulfillment,
+    types::FraudCheckFulfillmentData,
+    types::FraudCheckResponseData,
+>
+{
+}
+
+/// Fraud and risk management connectors are not payment processors, and hence only implement the
+/// fraud check flows instead of [`super::Connector`]
+pub trait FraudCheck:
+    super::ConnectorCommon
+    + FraudCheckCheckout
+    + FraudCheckTransaction
+    + FraudCheckFulfillment
+    + Send
+    + Debug
+{
+}
+
+type BoxedFraudCheckConnector = Box<&'static (dyn FraudCheck + Sync)>;
+
+#[derive(Clone)]
+pub struct FraudCheckConnectorData {
+    pub connector: BoxedFraudCheckConnector,
+    pub connector_name: api_enums::FrmConnectors,
+}
+
+impl FraudCheckConnectorData {
+    pub fn get_connector_by_name(
+        connectors: &Connectors,
+        name: &str,
+    ) -> CustomResult<Self, errors::ApiErrorResponse> {
+        let connector_name = api_enums::FrmConnectors::from_str(name)
+            .into_report()
+            .change_context(errors::ConnectorError::InvalidConnectorName)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("unable to parse FRM connector name {name:?}"))?;
+        let connector = Self::convert_connector(connectors, connector_name)?;
+        Ok(Self {
+            connector,
+            connector_name,
+        })
+    }
+
+    fn convert_connector(
+        _connectors: &Connectors,
+        connector_name: api_enums::FrmConnectors,
+    ) -> CustomResult<BoxedFraudCheckConnector, errors::ApiErrorResponse> {
+        match connector_name {
+            #[cfg(feature = "dummy_connector")]
+            api_enums::FrmConnectors::DummyFrm => Ok(Box::new(&connector::DummyFrm)),
+        }
+    }
+}
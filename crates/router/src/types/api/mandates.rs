@@ -10,8 +10,9 @@ use crate::{
     },
     newtype,
     routes::AppState,
+    services,
     types::{
-        api, domain,
+        self, api, domain,
         storage::{self, enums as storage_enums},
         transformers::ForeignInto,
     },
@@ -22,6 +23,18 @@ newtype!(
     derives = (Default, Debug, Deserialize, Serialize)
 );
 
+#[derive(Debug, Clone)]
+pub struct MandateRevoke;
+
+pub trait ConnectorMandateRevoke:
+    services::ConnectorIntegration<
+    MandateRevoke,
+    types::MandateRevokeRequestData,
+    types::MandateRevokeResponseData,
+>
+{
+}
+
 #[async_trait::async_trait]
 pub(crate) trait MandateResponseExt: Sized {
     async fn from_db_mandate(
@@ -1,9 +1,9 @@
 pub use api_models::payments::{
-    AcceptanceType, Address, AddressDetails, Amount, AuthenticationForStartResponse, Card,
-    CustomerAcceptance, MandateData, MandateTxnType, MandateType, MandateValidationFields,
-    NextActionType, OnlineMandate, PayLaterData, PaymentIdType, PaymentListConstraints,
-    PaymentListResponse, PaymentMethodData, PaymentMethodDataResponse, PaymentOp,
-    PaymentRetrieveBody, PaymentRetrieveBodyWithCredentials, PaymentsCancelRequest,
+    AcceptanceType, Address, AddressDetails, Amount, AuthenticationForStartResponse,
+    CaptureResponse, Card, CustomerAcceptance, MandateData, MandateTxnType, MandateType,
+    MandateValidationFields, NextActionType, OnlineMandate, PayLaterData, PaymentIdType,
+    PaymentListConstraints, PaymentListResponse, PaymentMethodData, PaymentMethodDataResponse,
+    PaymentOp, PaymentRetrieveBody, PaymentRetrieveBodyWithCredentials, PaymentsCancelRequest,
     PaymentsCaptureRequest, PaymentsRedirectRequest, PaymentsRedirectionResponse, PaymentsRequest,
     PaymentsResponse, PaymentsResponseForm, PaymentsRetrieveRequest, PaymentsSessionRequest,
     PaymentsSessionResponse, PaymentsStartRequest, PgRedirectResponse, PhoneDetails,
@@ -154,6 +154,11 @@ pub trait PaymentVoid:
 pub trait PaymentCapture:
     api::ConnectorIntegration<Capture, types::PaymentsCaptureData, types::PaymentsResponseData>
 {
+    /// Whether the connector can capture an authorization in several parts, which is required
+    /// for payments made with the `manual_multiple` capture method
+    fn supports_multiple_captures(&self) -> bool {
+        false
+    }
 }
 
 pub trait PaymentSession:
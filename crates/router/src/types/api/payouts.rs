@@ -0,0 +1,51 @@
+pub use api_models::payouts::{
+    BankPayout, CardPayout, PayoutActionRequest, PayoutCreateRequest, PayoutMethodData,
+    PayoutResponse, PayoutRetrieveBody, PayoutRetrieveRequest, PayoutUpdateRequest,
+};
+
+use crate::{services, types};
+
+#[derive(Debug, Clone)]
+pub struct PoCreate;
+
+#[derive(Debug, Clone)]
+pub struct PoFulfill;
+
+#[derive(Debug, Clone)]
+pub struct PoCancel;
+
+#[derive(Debug, Clone)]
+pub struct PoSync;
+
+#[derive(Debug, Clone)]
+pub struct PoReverse;
+
+pub trait PayoutCreate:
+    services::ConnectorIntegration<PoCreate, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait PayoutFulfill:
+    services::ConnectorIntegration<PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait PayoutCancel:
+    services::ConnectorIntegration<PoCancel, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait PayoutSync:
+    services::ConnectorIntegration<PoSync, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait PayoutReverse:
+    services::ConnectorIntegration<PoReverse, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait Payouts:
+    super::ConnectorCommon + PayoutCreate + PayoutFulfill + PayoutCancel + PayoutSync + PayoutReverse
+{
+}
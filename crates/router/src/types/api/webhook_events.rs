@@ -0,0 +1,9 @@
+pub use api_models::webhook_events::{
+    EventDeliveryAttemptResponse, EventListConstraints, EventResponse,
+};
+use masking::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Deserialize, Serialize)]
+pub struct EventId {
+    pub event_id: String,
+}
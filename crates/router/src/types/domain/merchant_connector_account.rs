@@ -1,5 +1,5 @@
 use common_utils::{
-    crypto::{Encryptable, GcmAes256},
+    crypto::Encryptable,
     date_time,
     errors::{CustomResult, ValidationError},
     pii,
@@ -11,10 +11,7 @@ use storage_models::{
     merchant_connector_account::MerchantConnectorAccountUpdateInternal,
 };
 
-use super::{
-    behaviour,
-    types::{self, TypeEncryption},
-};
+use super::{behaviour, types};
 use crate::db::StorageInterface;
 
 #[derive(Clone, Debug)]
@@ -100,10 +97,9 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             id: Some(other.id),
             merchant_id: other.merchant_id,
             connector_name: other.connector_name,
-            connector_account_details: Encryptable::decrypt(
+            connector_account_details: types::decrypt_with_key_version(
                 other.connector_account_details,
                 &key,
-                GcmAes256,
             )
             .await
             .change_context(ValidationError::InvalidValue {
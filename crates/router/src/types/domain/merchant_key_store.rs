@@ -18,6 +18,7 @@ pub struct MerchantKeyStore {
     pub key: Encryptable<Secret<Vec<u8>>>,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub key_version: i32,
 }
 
 #[async_trait::async_trait]
@@ -29,6 +30,7 @@ impl super::behaviour::Conversion for MerchantKeyStore {
             key: self.key.into(),
             merchant_id: self.merchant_id,
             created_at: self.created_at,
+            key_version: self.key_version,
         })
     }
 
@@ -49,6 +51,7 @@ impl super::behaviour::Conversion for MerchantKeyStore {
                 })?,
             merchant_id: item.merchant_id,
             created_at: item.created_at,
+            key_version: item.key_version,
         })
     }
 
@@ -57,6 +60,7 @@ impl super::behaviour::Conversion for MerchantKeyStore {
             merchant_id: self.merchant_id,
             key: self.key.into(),
             created_at: date_time::now(),
+            key_version: self.key_version,
         })
     }
 }
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use common_utils::{
     crypto,
@@ -7,8 +9,11 @@ use common_utils::{
 use error_stack::{IntoReport, ResultExt};
 use masking::{ExposeInterface, PeekInterface, Secret};
 use router_env::{instrument, tracing};
-use storage_models::encryption::Encryption;
+use storage_models::encryption::{
+    add_key_version, split_key_version, Encryption, INITIAL_KEY_VERSION,
+};
 
+use super::merchant_key_store::MerchantKeyStore;
 use crate::routes::metrics::{request, DECRYPTION_TIME, ENCRYPTION_TIME};
 
 #[async_trait]
@@ -134,17 +139,72 @@ impl<
     }
 }
 
+/// Versions of the data encryption key of a merchant. Data is encrypted with the latest version
+/// of the key and decrypted with the version it has been encrypted with.
+#[derive(Clone, Debug)]
+pub struct MerchantEncryptionKeys {
+    latest_version: i32,
+    keys: HashMap<i32, Secret<Vec<u8>>>,
+}
+
+impl MerchantEncryptionKeys {
+    pub fn new(key_version: i32, key: Vec<u8>) -> Self {
+        Self {
+            latest_version: key_version,
+            keys: HashMap::from([(key_version, key.into())]),
+        }
+    }
+
+    pub fn from_key_stores(key_stores: Vec<MerchantKeyStore>) -> Option<Self> {
+        let latest_version = key_stores
+            .iter()
+            .map(|key_store| key_store.key_version)
+            .max()?;
+        let keys = key_stores
+            .into_iter()
+            .map(|key_store| (key_store.key_version, key_store.key.into_inner()))
+            .collect();
+        Some(Self {
+            latest_version,
+            keys,
+        })
+    }
+
+    pub fn get_latest_version(&self) -> i32 {
+        self.latest_version
+    }
+
+    fn get_key(&self, key_version: i32) -> Option<&[u8]> {
+        self.keys.get(&key_version).map(|key| key.peek().as_slice())
+    }
+
+    fn get_latest_key(&self) -> CustomResult<&[u8], errors::CryptoError> {
+        self.get_key(self.latest_version)
+            .ok_or(errors::CryptoError::EncodingFailed)
+            .into_report()
+            .attach_printable("Latest merchant key is missing")
+    }
+
+    /// Whether the data has been encrypted with the latest version of the key
+    pub fn is_latest_version<T: Clone>(&self, data: &crypto::Encryptable<T>) -> bool {
+        split_key_version(data.get_encrypted())
+            .map_or(false, |(key_version, _)| key_version == self.latest_version)
+    }
+}
+
 pub async fn get_merchant_enc_key(
     db: &dyn crate::db::StorageInterface,
     merchant_id: impl AsRef<str>,
-) -> CustomResult<Vec<u8>, crate::core::errors::StorageError> {
+) -> CustomResult<MerchantEncryptionKeys, crate::core::errors::StorageError> {
     let merchant_id = merchant_id.as_ref();
-    let key = db
-        .get_merchant_key_store_by_merchant_id(merchant_id)
-        .await?
-        .key
-        .into_inner();
-    Ok(key.expose())
+    let key_stores = db
+        .list_merchant_key_stores_by_merchant_id(merchant_id)
+        .await?;
+    MerchantEncryptionKeys::from_key_stores(key_stores)
+        .ok_or(crate::core::errors::StorageError::ValueNotFound(format!(
+            "merchant key store for {merchant_id}"
+        )))
+        .into_report()
 }
 
 pub trait Lift<U> {
@@ -196,23 +256,29 @@ impl<U, V: Lift<U> + Lift<U, SelfWrapper<U> = V> + Send> AsyncLift<U> for V {
 #[inline]
 pub async fn encrypt<E: Clone, S>(
     inner: Secret<E, S>,
-    key: &[u8],
+    key: &MerchantEncryptionKeys,
 ) -> CustomResult<crypto::Encryptable<Secret<E, S>>, errors::CryptoError>
 where
     S: masking::Strategy<E>,
     crypto::Encryptable<Secret<E, S>>: TypeEncryption<E, crypto::GcmAes256, S>,
 {
-    request::record_operation_time(
-        crypto::Encryptable::encrypt(inner, key, crypto::GcmAes256),
+    let (inner, encrypted_data) = request::record_operation_time(
+        crypto::Encryptable::encrypt(inner, key.get_latest_key()?, crypto::GcmAes256),
         &ENCRYPTION_TIME,
     )
-    .await
+    .await?
+    .deconstruct();
+
+    Ok(crypto::Encryptable::new(
+        inner,
+        add_key_version(key.get_latest_version(), encrypted_data),
+    ))
 }
 
 #[inline]
 pub async fn encrypt_optional<E: Clone, S>(
     inner: Option<Secret<E, S>>,
-    key: &[u8],
+    key: &MerchantEncryptionKeys,
 ) -> CustomResult<Option<crypto::Encryptable<Secret<E, S>>>, errors::CryptoError>
 where
     Secret<E, S>: Send,
@@ -222,18 +288,117 @@ where
     inner.async_map(|f| encrypt(f, key)).await.transpose()
 }
 
+/// Encrypts data again with the latest version of the key
+#[inline]
+pub async fn reencrypt_optional<E: Clone, S>(
+    inner: Option<crypto::Encryptable<Secret<E, S>>>,
+    key: &MerchantEncryptionKeys,
+) -> CustomResult<Option<crypto::Encryptable<Secret<E, S>>>, errors::CryptoError>
+where
+    Secret<E, S>: Send,
+    S: masking::Strategy<E>,
+    crypto::Encryptable<Secret<E, S>>: TypeEncryption<E, crypto::GcmAes256, S>,
+{
+    encrypt_optional(inner.map(crypto::Encryptable::into_inner), key).await
+}
+
 #[inline]
 pub async fn decrypt<T: Clone, S: masking::Strategy<T>>(
     inner: Option<Encryption>,
-    key: &[u8],
+    key: &MerchantEncryptionKeys,
 ) -> CustomResult<Option<crypto::Encryptable<Secret<T, S>>>, errors::CryptoError>
 where
     crypto::Encryptable<Secret<T, S>>: TypeEncryption<T, crypto::GcmAes256, S>,
 {
     request::record_operation_time(
-        inner.async_map(|item| crypto::Encryptable::decrypt(item, key, crypto::GcmAes256)),
+        inner.async_map(|item| decrypt_with_key_version(item, key)),
         &DECRYPTION_TIME,
     )
     .await
     .transpose()
 }
+
+/// Decrypts data with the version of the key recorded in it. Data which does not record a
+/// version has been encrypted with the initial version of the key.
+pub async fn decrypt_with_key_version<T: Clone, S: masking::Strategy<T>>(
+    encrypted_data: Encryption,
+    key: &MerchantEncryptionKeys,
+) -> CustomResult<crypto::Encryptable<Secret<T, S>>, errors::CryptoError>
+where
+    crypto::Encryptable<Secret<T, S>>: TypeEncryption<T, crypto::GcmAes256, S>,
+{
+    let versioned_data =
+        split_key_version(encrypted_data.get_inner()).and_then(|(key_version, data)| {
+            Some((key.get_key(key_version)?, Encryption::new(data.to_vec())))
+        });
+    if let Some((versioned_key, data)) = versioned_data {
+        if let Ok(decrypted) =
+            crypto::Encryptable::decrypt(data, versioned_key, crypto::GcmAes256).await
+        {
+            // The version is kept, so that the data can be written back as it is
+            return Ok(crypto::Encryptable::new(
+                decrypted.into_inner(),
+                encrypted_data.into_inner(),
+            ));
+        }
+    }
+
+    let initial_key = key
+        .get_key(INITIAL_KEY_VERSION)
+        .ok_or(errors::CryptoError::DecodingFailed)
+        .into_report()
+        .attach_printable("Merchant key used to encrypt the data is missing")?;
+    crypto::Encryptable::decrypt(encrypted_data, initial_key, crypto::GcmAes256).await
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_decrypt_with_key_version() {
+        let initial_key = vec![1; 32];
+        let rotated_key = vec![2; 32];
+        let legacy_keys = MerchantEncryptionKeys::new(INITIAL_KEY_VERSION, initial_key.clone());
+        let keys = MerchantEncryptionKeys {
+            latest_version: 2,
+            keys: HashMap::from([
+                (INITIAL_KEY_VERSION, initial_key.clone().into()),
+                (2, rotated_key.into()),
+            ]),
+        };
+
+        // Data encrypted before the keys were versioned
+        let legacy_data: crypto::Encryptable<Secret<String>> = crypto::Encryptable::encrypt(
+            Secret::new("legacy".to_string()),
+            &initial_key,
+            crypto::GcmAes256,
+        )
+        .await
+        .expect("Failed to encrypt legacy data");
+        let initial_data = encrypt(Secret::<String>::new("initial".to_string()), &legacy_keys)
+            .await
+            .expect("Failed to encrypt initial data");
+        let rotated_data = encrypt(Secret::<String>::new("rotated".to_string()), &keys)
+            .await
+            .expect("Failed to encrypt rotated data");
+
+        assert!(!keys.is_latest_version(&legacy_data));
+        assert!(!keys.is_latest_version(&initial_data));
+        assert!(keys.is_latest_version(&rotated_data));
+
+        for (data, expected) in [
+            (legacy_data, "legacy"),
+            (initial_data, "initial"),
+            (rotated_data, "rotated"),
+        ] {
+            let decrypted: crypto::Encryptable<Secret<String>> =
+                decrypt_with_key_version(data.into(), &keys)
+                    .await
+                    .expect("Failed to decrypt data");
+            assert_eq!(decrypted.peek(), expected);
+        }
+    }
+}
@@ -1,21 +1,28 @@
 pub mod address;
 pub mod api_keys;
+pub mod capture;
 pub mod cards_info;
 pub mod configs;
 pub mod connector_response;
+pub mod customer_redaction;
 pub mod customers;
 pub mod dispute;
+pub mod email_notification;
 pub mod enums;
 pub mod ephemeral_key;
+pub mod event_delivery_attempt;
 pub mod events;
 pub mod file;
+pub mod fraud_check;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_store;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payouts;
 pub mod process_tracker;
 pub mod reverse_lookup;
 
@@ -26,8 +33,10 @@ pub mod refund;
 pub mod kv;
 
 pub use self::{
-    address::*, api_keys::*, cards_info::*, configs::*, connector_response::*, customers::*,
-    dispute::*, events::*, file::*, locker_mock_up::*, mandate::*, merchant_account::*,
-    merchant_connector_account::*, payment_attempt::*, payment_intent::*, payment_method::*,
-    process_tracker::*, refund::*, reverse_lookup::*,
+    address::*, api_keys::*, capture::*, cards_info::*, configs::*, connector_response::*,
+    customer_redaction::*, customers::*, dispute::*, email_notification::*,
+    event_delivery_attempt::*, events::*, file::*, fraud_check::*, locker_mock_up::*, mandate::*,
+    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_attempt::*,
+    payment_intent::*, payment_method::*, payouts::*, process_tracker::*, refund::*,
+    reverse_lookup::*,
 };
@@ -1,3 +1,6 @@
 pub use storage_models::address::{Address, AddressNew, AddressUpdateInternal};
 
 pub use crate::types::domain::AddressUpdate;
+
+#[cfg(feature = "kv_store")]
+impl crate::utils::storage_partitioning::KvStorePartition for Address {}
@@ -1 +1,3 @@
-pub use storage_models::api_keys::{ApiKey, ApiKeyNew, ApiKeyUpdate, HashedApiKey};
+pub use storage_models::api_keys::{
+    ApiKey, ApiKeyLastUsed, ApiKeyLastUsedFlushTrackingData, ApiKeyNew, ApiKeyUpdate, HashedApiKey,
+};
@@ -1 +1 @@
-pub use storage_models::cards_info::CardInfo;
+pub use storage_models::cards_info::{CardInfo, CardInfoNew, CardInfoUpdate};
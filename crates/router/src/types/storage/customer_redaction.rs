@@ -0,0 +1,4 @@
+pub use storage_models::customer_redaction::{
+    CustomerRedaction, CustomerRedactionNew, CustomerRedactionTrackingData,
+    CustomerRedactionUpdate, RedactedRecords,
+};
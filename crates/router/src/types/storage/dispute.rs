@@ -2,11 +2,16 @@ use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::errors::CustomResult;
 use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
 use error_stack::{IntoReport, ResultExt};
-pub use storage_models::dispute::{Dispute, DisputeNew, DisputeUpdate};
+pub use storage_models::dispute::{
+    Dispute, DisputeDeadlineTrackingData, DisputeNew, DisputeUpdate, DisputeUpdateInternal,
+};
 use storage_models::{errors, schema::dispute::dsl};
 
 use crate::{connection::PgPooledConn, logger, types::transformers::ForeignInto};
 
+#[cfg(feature = "kv_store")]
+impl crate::utils::storage_partitioning::KvStorePartition for Dispute {}
+
 #[async_trait::async_trait]
 pub trait DisputeDbExt: Sized {
     async fn filter_by_constraints(
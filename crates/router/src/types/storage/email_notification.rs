@@ -0,0 +1 @@
+pub use storage_models::email_notification::{EmailNotification, EmailNotificationTrackingData};
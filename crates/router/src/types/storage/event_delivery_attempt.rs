@@ -0,0 +1 @@
+pub use storage_models::event_delivery_attempt::{EventDeliveryAttempt, EventDeliveryAttemptNew};
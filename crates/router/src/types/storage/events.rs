@@ -1 +1,64 @@
-pub use storage_models::events::{Event, EventNew, EventUpdate};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use error_stack::{IntoReport, ResultExt};
+pub use storage_models::events::{Event, EventNew, EventUpdate, OutgoingWebhookTrackingData};
+use storage_models::{errors, schema::events::dsl};
+
+use crate::{connection::PgPooledConn, logger, types::transformers::ForeignInto};
+
+#[async_trait::async_trait]
+pub trait EventDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        event_list_constraints: api_models::webhook_events::EventListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl EventDbExt for Event {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        event_list_constraints: api_models::webhook_events::EventListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(event_type) = event_list_constraints.event_type {
+            let storage_event_type: storage_models::enums::EventType = event_type.foreign_into();
+            filter = filter.filter(dsl::event_type.eq(storage_event_type));
+        }
+        if let Some(event_class) = event_list_constraints.event_class {
+            let storage_event_class: storage_models::enums::EventClass = event_class.foreign_into();
+            filter = filter.filter(dsl::event_class.eq(storage_event_class));
+        }
+        if let Some(object_id) = event_list_constraints.object_id {
+            filter = filter.filter(dsl::primary_object_id.eq(object_id));
+        }
+        if let Some(is_webhook_notified) = event_list_constraints.is_webhook_notified {
+            filter = filter.filter(dsl::is_webhook_notified.eq(is_webhook_notified));
+        }
+        if let Some(created_lt) = event_list_constraints.created_lt {
+            filter = filter.filter(dsl::created_at.lt(created_lt));
+        }
+        if let Some(created_gt) = event_list_constraints.created_gt {
+            filter = filter.filter(dsl::created_at.gt(created_gt));
+        }
+        if let Some(limit) = event_list_constraints.limit {
+            filter = filter.limit(limit);
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        filter
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error filtering records by predicate")
+    }
+}
@@ -0,0 +1 @@
+pub use storage_models::fraud_check::{FraudCheck, FraudCheckNew, FraudCheckUpdate};
@@ -1,4 +1,5 @@
 pub use storage_models::kv::{
-    DBOperation, Insertable, PaymentAttemptUpdateMems, PaymentIntentUpdateMems, RefundUpdateMems,
-    TypedSql, Updateable,
+    AddressUpdateMems, ConnectorResponseUpdateMems, DBOperation, Deletable, DisputeUpdateMems,
+    Insertable, PaymentAttemptUpdateMems, PaymentIntentUpdateMems, RefundUpdateMems, TypedSql,
+    Updateable,
 };
@@ -3,7 +3,8 @@ use common_utils::errors::CustomResult;
 use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
 use error_stack::{IntoReport, ResultExt};
 pub use storage_models::mandate::{
-    Mandate, MandateNew, MandateUpdate, MandateUpdateInternal, SingleUseMandate,
+    Mandate, MandateExpiryTrackingData, MandateNew, MandateUpdate, MandateUpdateInternal,
+    SingleUseMandate,
 };
 use storage_models::{errors, schema::mandate::dsl};
 
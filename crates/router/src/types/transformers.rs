@@ -194,6 +194,8 @@ impl ForeignFrom<storage_enums::MandateAmountData> for api_models::payments::Man
             currency: from.currency.foreign_into(),
             start_date: from.start_date,
             end_date: from.end_date,
+            max_amount_per_payment: from.max_amount_per_payment,
+            usage_limit: from.usage_limit,
             metadata: from.metadata,
         }
     }
@@ -206,6 +208,8 @@ impl ForeignFrom<api_models::payments::MandateAmountData> for storage_enums::Man
             currency: from.currency.foreign_into(),
             start_date: from.start_date,
             end_date: from.end_date,
+            max_amount_per_payment: from.max_amount_per_payment,
+            usage_limit: from.usage_limit,
             metadata: from.metadata,
         }
     }
@@ -219,6 +223,7 @@ impl ForeignTryFrom<api_enums::IntentStatus> for storage_enums::EventType {
             api_enums::IntentStatus::Succeeded => Ok(Self::PaymentSucceeded),
             api_enums::IntentStatus::Failed => Ok(Self::PaymentFailed),
             api_enums::IntentStatus::Processing => Ok(Self::PaymentProcessing),
+            api_enums::IntentStatus::Cancelled => Ok(Self::PaymentCancelled),
             api_enums::IntentStatus::RequiresMerchantAction
             | api_enums::IntentStatus::RequiresCustomerAction => Ok(Self::ActionRequired),
             _ => Err(errors::ValidationError::IncorrectValueProvided {
@@ -242,6 +247,44 @@ impl ForeignTryFrom<storage_enums::RefundStatus> for storage_enums::EventType {
     }
 }
 
+impl ForeignTryFrom<storage_enums::PayoutStatus> for storage_enums::EventType {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(value: storage_enums::PayoutStatus) -> Result<Self, Self::Error> {
+        match value {
+            storage_enums::PayoutStatus::RequiresFulfillment => Ok(Self::PayoutInitiated),
+            storage_enums::PayoutStatus::Pending => Ok(Self::PayoutProcessing),
+            storage_enums::PayoutStatus::Success => Ok(Self::PayoutSuccess),
+            storage_enums::PayoutStatus::Failed => Ok(Self::PayoutFailed),
+            storage_enums::PayoutStatus::Cancelled => Ok(Self::PayoutCancelled),
+            storage_enums::PayoutStatus::Reversed => Ok(Self::PayoutReversed),
+            storage_enums::PayoutStatus::RequiresCreation => {
+                Err(errors::ValidationError::IncorrectValueProvided {
+                    field_name: "payout_status",
+                })
+            }
+        }
+    }
+}
+
+impl ForeignTryFrom<storage_enums::MandateStatus> for storage_enums::EventType {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(value: storage_enums::MandateStatus) -> Result<Self, Self::Error> {
+        match value {
+            storage_enums::MandateStatus::Active => Ok(Self::MandateActive),
+            storage_enums::MandateStatus::Revoked => Ok(Self::MandateRevoked),
+            storage_enums::MandateStatus::Expired => Ok(Self::MandateExpired),
+            storage_enums::MandateStatus::Used => Ok(Self::MandateUsed),
+            storage_enums::MandateStatus::Inactive | storage_enums::MandateStatus::Pending => {
+                Err(errors::ValidationError::IncorrectValueProvided {
+                    field_name: "mandate_status",
+                })
+            }
+        }
+    }
+}
+
 impl ForeignTryFrom<storage_enums::DisputeStatus> for storage_enums::EventType {
     type Error = errors::ValidationError;
 
@@ -274,6 +317,64 @@ impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enum
     }
 }
 
+impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::MandateStatus {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(
+        value: api_models::webhooks::IncomingWebhookEvent,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            api_models::webhooks::IncomingWebhookEvent::MandateActive => Ok(Self::Active),
+            api_models::webhooks::IncomingWebhookEvent::MandateRevoked => Ok(Self::Revoked),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event_type",
+            }),
+        }
+    }
+}
+
+impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::PayoutStatus {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(
+        value: api_models::webhooks::IncomingWebhookEvent,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            api_models::webhooks::IncomingWebhookEvent::PayoutSuccess => Ok(Self::Success),
+            api_models::webhooks::IncomingWebhookEvent::PayoutFailure => Ok(Self::Failed),
+            api_models::webhooks::IncomingWebhookEvent::PayoutCancelled => Ok(Self::Cancelled),
+            api_models::webhooks::IncomingWebhookEvent::PayoutReversed => Ok(Self::Reversed),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event_type",
+            }),
+        }
+    }
+}
+
+impl ForeignFrom<api_enums::EventType> for storage_enums::EventType {
+    fn foreign_from(event_type: api_enums::EventType) -> Self {
+        frunk::labelled_convert_from(event_type)
+    }
+}
+
+impl ForeignFrom<storage_enums::EventClass> for api_enums::EventClass {
+    fn foreign_from(event_class: storage_enums::EventClass) -> Self {
+        frunk::labelled_convert_from(event_class)
+    }
+}
+
+impl ForeignFrom<api_enums::EventClass> for storage_enums::EventClass {
+    fn foreign_from(event_class: api_enums::EventClass) -> Self {
+        frunk::labelled_convert_from(event_class)
+    }
+}
+
+impl ForeignFrom<storage_enums::EventObjectType> for api_enums::EventObjectType {
+    fn foreign_from(object_type: storage_enums::EventObjectType) -> Self {
+        frunk::labelled_convert_from(object_type)
+    }
+}
+
 impl ForeignFrom<storage_enums::EventType> for api_enums::EventType {
     fn foreign_from(event_type: storage_enums::EventType) -> Self {
         frunk::labelled_convert_from(event_type)
@@ -298,6 +399,30 @@ impl ForeignFrom<storage_enums::RefundStatus> for api_enums::RefundStatus {
     }
 }
 
+impl ForeignFrom<storage_enums::PayoutStatus> for api_enums::PayoutStatus {
+    fn foreign_from(status: storage_enums::PayoutStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<api_enums::PayoutStatus> for storage_enums::PayoutStatus {
+    fn foreign_from(status: api_enums::PayoutStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<api_enums::PayoutType> for storage_enums::PayoutType {
+    fn foreign_from(payout_type: api_enums::PayoutType) -> Self {
+        frunk::labelled_convert_from(payout_type)
+    }
+}
+
+impl ForeignFrom<storage_enums::PayoutType> for api_enums::PayoutType {
+    fn foreign_from(payout_type: storage_enums::PayoutType) -> Self {
+        frunk::labelled_convert_from(payout_type)
+    }
+}
+
 impl ForeignFrom<api_enums::CaptureMethod> for storage_enums::CaptureMethod {
     fn foreign_from(capture_method: api_enums::CaptureMethod) -> Self {
         frunk::labelled_convert_from(capture_method)
@@ -392,6 +517,24 @@ impl ForeignFrom<storage_models::enums::PaymentMethodType>
     }
 }
 
+impl ForeignFrom<api_enums::ApiKeyScope> for storage_enums::ApiKeyScope {
+    fn foreign_from(scope: api_enums::ApiKeyScope) -> Self {
+        frunk::labelled_convert_from(scope)
+    }
+}
+
+impl ForeignFrom<storage_enums::ApiKeyScope> for api_enums::ApiKeyScope {
+    fn foreign_from(scope: storage_enums::ApiKeyScope) -> Self {
+        frunk::labelled_convert_from(scope)
+    }
+}
+
+impl ForeignFrom<api_enums::FraudCheckStatus> for storage_enums::FraudCheckStatus {
+    fn foreign_from(status: api_enums::FraudCheckStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
 impl
     ForeignFrom<(
         storage_models::api_keys::ApiKey,
@@ -415,6 +558,9 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: api_key
+                .scopes
+                .map(|scopes| scopes.into_iter().map(ForeignInto::foreign_into).collect()),
         }
     }
 }
@@ -431,6 +577,10 @@ impl ForeignFrom<storage_models::api_keys::ApiKey>
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: api_key
+                .scopes
+                .map(|scopes| scopes.into_iter().map(ForeignInto::foreign_into).collect()),
+            last_used: api_key.last_used,
         }
     }
 }
@@ -444,6 +594,9 @@ impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            scopes: api_key
+                .scopes
+                .map(|scopes| scopes.into_iter().map(ForeignInto::foreign_into).collect()),
         }
     }
 }
@@ -472,6 +625,42 @@ impl ForeignFrom<storage_enums::DisputeStage> for api_enums::DisputeStage {
     }
 }
 
+impl ForeignFrom<storage_enums::CaptureStatus> for api_enums::CaptureStatus {
+    fn foreign_from(status: storage_enums::CaptureStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<storage_enums::AttemptStatus> for storage_enums::CaptureStatus {
+    fn foreign_from(status: storage_enums::AttemptStatus) -> Self {
+        match status {
+            storage_enums::AttemptStatus::Charged
+            | storage_enums::AttemptStatus::PartialCharged => Self::Charged,
+            storage_enums::AttemptStatus::AuthenticationFailed
+            | storage_enums::AttemptStatus::RouterDeclined
+            | storage_enums::AttemptStatus::AuthorizationFailed
+            | storage_enums::AttemptStatus::CaptureFailed
+            | storage_enums::AttemptStatus::VoidFailed
+            | storage_enums::AttemptStatus::Voided
+            | storage_enums::AttemptStatus::Failure => Self::Failed,
+            storage_enums::AttemptStatus::Started
+            | storage_enums::AttemptStatus::AuthenticationPending
+            | storage_enums::AttemptStatus::AuthenticationSuccessful
+            | storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::Authorizing
+            | storage_enums::AttemptStatus::CodInitiated
+            | storage_enums::AttemptStatus::VoidInitiated
+            | storage_enums::AttemptStatus::CaptureInitiated
+            | storage_enums::AttemptStatus::AutoRefunded
+            | storage_enums::AttemptStatus::Unresolved
+            | storage_enums::AttemptStatus::Pending
+            | storage_enums::AttemptStatus::PaymentMethodAwaited
+            | storage_enums::AttemptStatus::ConfirmationAwaited
+            | storage_enums::AttemptStatus::DeviceDataCollectionPending => Self::Pending,
+        }
+    }
+}
+
 impl ForeignFrom<storage_enums::DisputeStatus> for api_enums::DisputeStatus {
     fn foreign_from(status: storage_enums::DisputeStatus) -> Self {
         frunk::labelled_convert_from(status)
@@ -546,6 +735,100 @@ impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponsePaym
     }
 }
 
+impl ForeignFrom<storage::PaymentAttempt> for api_models::payments::PaymentAttemptResponse {
+    fn foreign_from(payment_attempt: storage::PaymentAttempt) -> Self {
+        Self {
+            attempt_id: payment_attempt.attempt_id,
+            status: payment_attempt.status.foreign_into(),
+            amount: payment_attempt.amount,
+            connector: payment_attempt.connector,
+            connector_transaction_id: payment_attempt.connector_transaction_id,
+            error_code: payment_attempt.error_code,
+            error_message: payment_attempt.error_message,
+            created_at: payment_attempt.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::Capture> for api_models::payments::CaptureResponse {
+    fn foreign_from(capture: storage::Capture) -> Self {
+        Self {
+            capture_id: capture.capture_id,
+            status: capture.status.foreign_into(),
+            amount: capture.amount,
+            currency: capture.currency.map(ForeignInto::foreign_into),
+            connector: capture.connector,
+            authorized_attempt_id: capture.authorized_attempt_id,
+            capture_sequence: capture.capture_sequence,
+            connector_capture_id: capture.connector_capture_id,
+            error_code: capture.error_code,
+            error_message: capture.error_message,
+            created_at: capture.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::Event> for api_models::webhook_events::EventResponse {
+    fn foreign_from(event: storage::Event) -> Self {
+        Self {
+            event_id: event.event_id,
+            merchant_id: event.merchant_id,
+            event_type: event.event_type.foreign_into(),
+            event_class: event.event_class.foreign_into(),
+            is_webhook_notified: event.is_webhook_notified,
+            object_id: event.primary_object_id,
+            object_type: event.primary_object_type.foreign_into(),
+            intent_reference_id: event.intent_reference_id,
+            created_at: event.created_at,
+            delivery_attempts: None,
+        }
+    }
+}
+
+impl ForeignFrom<storage::EventDeliveryAttempt>
+    for api_models::webhook_events::EventDeliveryAttemptResponse
+{
+    fn foreign_from(delivery_attempt: storage::EventDeliveryAttempt) -> Self {
+        Self {
+            attempt_id: delivery_attempt.attempt_id,
+            request_body: delivery_attempt.request_body,
+            response_status_code: delivery_attempt
+                .response_status_code
+                .and_then(|status_code| u16::try_from(status_code).ok()),
+            response_body: delivery_attempt.response_body,
+            latency_ms: delivery_attempt.latency_ms,
+            error_message: delivery_attempt.error_message,
+            is_manual_retry: delivery_attempt.is_manual_retry,
+            created_at: delivery_attempt.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::Payout> for api_models::payouts::PayoutResponse {
+    fn foreign_from(payout: storage::Payout) -> Self {
+        Self {
+            payout_id: payout.payout_id,
+            merchant_id: payout.merchant_id,
+            amount: payout.amount,
+            currency: payout.currency.foreign_into(),
+            customer_id: payout.customer_id,
+            connector: payout.connector,
+            connector_payout_id: payout.connector_payout_id,
+            payout_type: payout.payout_type.foreign_into(),
+            status: payout.status.foreign_into(),
+            auto_fulfill: payout.auto_fulfill,
+            description: payout.description,
+            return_url: payout.return_url,
+            business_country: payout.business_country,
+            business_label: payout.business_label,
+            metadata: payout.metadata,
+            error_code: payout.error_code,
+            error_message: payout.error_message,
+            created_at: payout.created_at,
+        }
+    }
+}
+
 impl ForeignFrom<storage::FileMetadata> for api_models::files::FileMetadataResponse {
     fn foreign_from(file_metadata: storage::FileMetadata) -> Self {
         Self {
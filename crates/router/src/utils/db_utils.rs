@@ -11,6 +11,31 @@ pub fn generate_hscan_pattern_for_refund(sk: &str) -> String {
         .join("_")
 }
 
+#[cfg(feature = "kv_store")]
+/// Merges the records of a lookup on Redis with the ones of the same lookup on the database.
+/// Records which are not drained to the database yet are only present in Redis, and a record
+/// present in both is taken from Redis as it has the latest updates.
+pub fn merge_redis_and_database_records<T, K, F>(
+    redis_records: Vec<T>,
+    database_records: Vec<T>,
+    get_key: F,
+) -> Vec<T>
+where
+    K: Eq + std::hash::Hash,
+    F: Fn(&T) -> K,
+{
+    let redis_keys = redis_records
+        .iter()
+        .map(&get_key)
+        .collect::<std::collections::HashSet<_>>();
+
+    database_records
+        .into_iter()
+        .filter(|record| !redis_keys.contains(&get_key(record)))
+        .chain(redis_records)
+        .collect()
+}
+
 // The first argument should be a future while the second argument should be a closure that returns a future for a database call
 pub async fn try_redis_get_else_try_database_get<F, RFut, DFut, T>(
     redis_fut: RFut,
@@ -33,3 +58,26 @@ where
         },
     }
 }
+
+#[cfg(all(test, feature = "kv_store"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_redis_and_database_records() {
+        let redis_records = vec![("attempt_2", "charged"), ("attempt_3", "started")];
+        let database_records = vec![("attempt_1", "failure"), ("attempt_2", "authorized")];
+
+        let merged =
+            merge_redis_and_database_records(redis_records, database_records, |record| record.0);
+
+        assert_eq!(
+            merged,
+            vec![
+                ("attempt_1", "failure"),
+                ("attempt_2", "charged"),
+                ("attempt_3", "started")
+            ]
+        );
+    }
+}
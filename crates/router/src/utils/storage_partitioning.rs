@@ -13,6 +13,12 @@ pub(crate) enum PartitionKey<'a> {
         merchant_id: &'a str,
         payment_id: &'a str,
     },
+    AddressId {
+        address_id: &'a str,
+    },
+    CombinationKey {
+        combination: &'a str,
+    },
 }
 
 impl<'a> std::fmt::Display for PartitionKey<'a> {
@@ -22,6 +28,8 @@ impl<'a> std::fmt::Display for PartitionKey<'a> {
                 merchant_id,
                 payment_id,
             } => f.write_str(&format!("mid_{merchant_id}_pid_{payment_id}")),
+            PartitionKey::AddressId { address_id } => f.write_str(&format!("add_{address_id}")),
+            PartitionKey::CombinationKey { combination } => f.write_str(combination),
         }
     }
 }
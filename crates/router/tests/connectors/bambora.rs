@@ -106,6 +106,7 @@ async fn should_sync_authorized_payment() {
                 encoded_data: None,
                 capture_method: Some(storage_models::enums::CaptureMethod::Manual),
                 connector_meta: None,
+                sync_type: types::SyncRequestType::SinglePaymentSync,
             }),
             None,
         )
@@ -219,6 +220,7 @@ async fn should_sync_auto_captured_payment() {
                 encoded_data: None,
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 connector_meta: None,
+                sync_type: types::SyncRequestType::SinglePaymentSync,
             }),
             None,
         )
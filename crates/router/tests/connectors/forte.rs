@@ -151,6 +151,7 @@ async fn should_sync_authorized_payment() {
                 capture_method: None,
                 connector_meta: None,
                 mandate_id: None,
+                sync_type: types::SyncRequestType::SinglePaymentSync,
             }),
             get_default_payment_info(),
         )
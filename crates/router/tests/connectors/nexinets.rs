@@ -121,6 +121,7 @@ async fn should_sync_authorized_payment() {
                 capture_method: None,
                 connector_meta,
                 mandate_id: None,
+                sync_type: types::SyncRequestType::SinglePaymentSync,
             }),
             None,
         )
@@ -138,6 +138,7 @@ async fn should_sync_authorized_payment() {
                 encoded_data: None,
                 capture_method: None,
                 connector_meta,
+                sync_type: types::SyncRequestType::SinglePaymentSync,
             }),
             get_default_payment_info(),
         )
@@ -330,6 +331,7 @@ async fn should_sync_auto_captured_payment() {
                 encoded_data: None,
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 connector_meta,
+                sync_type: types::SyncRequestType::SinglePaymentSync,
             }),
             get_default_payment_info(),
         )
@@ -421,6 +421,7 @@ pub trait ConnectorActions: Connector {
             Ok(types::PaymentsResponseData::ConnectorCustomerResponse { .. }) => None,
             Ok(types::PaymentsResponseData::PreProcessingResponse { .. }) => None,
             Ok(types::PaymentsResponseData::ThreeDSEnrollmentResponse { .. }) => None,
+            Ok(types::PaymentsResponseData::MultipleCaptureResponse { .. }) => None,
             Err(_) => None,
         }
     }
@@ -574,6 +575,7 @@ impl Default for PaymentSyncType {
             encoded_data: None,
             capture_method: None,
             connector_meta: None,
+            sync_type: types::SyncRequestType::SinglePaymentSync,
         };
         Self(data)
     }
@@ -610,6 +612,7 @@ pub fn get_connector_transaction_id(
         Ok(types::PaymentsResponseData::PreProcessingResponse { .. }) => None,
         Ok(types::PaymentsResponseData::ConnectorCustomerResponse { .. }) => None,
         Ok(types::PaymentsResponseData::ThreeDSEnrollmentResponse { .. }) => None,
+        Ok(types::PaymentsResponseData::MultipleCaptureResponse { .. }) => None,
         Err(_) => None,
     }
 }
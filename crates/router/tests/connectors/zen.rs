@@ -100,6 +100,7 @@ async fn should_sync_authorized_payment() {
                 capture_method: None,
                 connector_meta: None,
                 mandate_id: None,
+                sync_type: types::SyncRequestType::SinglePaymentSync,
             }),
             None,
         )
@@ -213,6 +214,7 @@ async fn should_sync_auto_captured_payment() {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 connector_meta: None,
                 mandate_id: None,
+                sync_type: types::SyncRequestType::SinglePaymentSync,
             }),
             None,
         )
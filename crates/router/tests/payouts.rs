@@ -1,36 +1,17 @@
 #![allow(clippy::unwrap_used)]
 
+use utils::{mk_service, AppClient};
+
 mod utils;
 
 #[actix_web::test]
-async fn payouts_todo() {
-    utils::setup().await;
+async fn payout_create_fail_invalid_api_key() {
+    let app = mk_service().await;
+    let client = AppClient::guest();
 
-    let client = awc::Client::default();
-    let mut response;
-    let mut response_body;
-    let get_endpoints = vec!["retrieve", "accounts"];
-    let post_endpoints = vec!["create", "update", "reverse", "cancel"];
+    let user_client = client.user("321");
 
-    for endpoint in get_endpoints {
-        response = client
-            .get(format!("http://127.0.0.1:8080/payouts/{endpoint}"))
-            .send()
-            .await
-            .unwrap();
-        response_body = response.body().await;
-        println!("{endpoint} =:= {response:?} : {response_body:?}");
-        assert_eq!(response.status(), awc::http::StatusCode::OK);
-    }
+    let payout: serde_json::Value = user_client.create_payout(&app, 1000).await;
 
-    for endpoint in post_endpoints {
-        response = client
-            .post(format!("http://127.0.0.1:8080/payouts/{endpoint}"))
-            .send()
-            .await
-            .unwrap();
-        response_body = response.body().await;
-        println!("{endpoint} =:= {response:?} : {response_body:?}");
-        assert_eq!(response.status(), awc::http::StatusCode::OK);
-    }
+    assert_eq!(payout["error"]["message"], "Access forbidden, invalid API key was used. Please create your new API key from the Dashboard Settings section.");
 }
@@ -149,6 +149,19 @@ impl AppClient<User> {
             .to_request();
         call_and_read_body_json(app, request).await
     }
+
+    pub async fn create_payout<T: DeserializeOwned, S, B>(&self, app: &S, amount: i64) -> T
+    where
+        S: Service<Request, Response = ServiceResponse<B>, Error = actix_web::Error>,
+        B: MessageBody,
+    {
+        let request = TestRequest::post()
+            .uri("/payouts/create")
+            .append_header(("api-key".to_owned(), self.state.authkey.clone()))
+            .set_json(mk_payout(amount))
+            .to_request();
+        call_and_read_body_json(app, request).await
+    }
 }
 
 impl<T> AppClient<T> {
@@ -342,6 +355,27 @@ fn mk_refund(payment_id: &str, amount: usize) -> Value {
     })
 }
 
+fn mk_payout(amount: i64) -> Value {
+    json!({
+      "amount": amount,
+      "currency": "EUR",
+      "connector": "adyen",
+      "payout_type": "card",
+      "payout_method_data": {
+        "card": {
+          "card_number": "4111111111111111",
+          "expiry_month": "03",
+          "expiry_year": "2030",
+          "card_holder_name": "John Doe"
+        }
+      },
+      "description": "Seller payout",
+      "metadata": {
+        "udf1": "value1"
+      }
+    })
+}
+
 pub struct HNil;
 
 impl<'de> Deserialize<'de> for HNil {
@@ -62,6 +62,10 @@ pub enum Flow {
     MerchantsAccountUpdate,
     /// Merchants account delete flow.
     MerchantsAccountDelete,
+    /// Merchant key rotate flow.
+    MerchantKeyRotate,
+    /// Merchant key rotation retrieve flow.
+    MerchantKeyRotationRetrieve,
     /// Merchant Connectors create flow.
     MerchantConnectorsCreate,
     /// Merchant Connectors retrieve flow.
@@ -120,6 +124,8 @@ pub enum Flow {
     PaymentsConfirm,
     /// Payments capture flow.
     PaymentsCapture,
+    /// Payments capture retrieve flow.
+    PaymentsCaptureRetrieve,
     /// Payments cancel flow.
     PaymentsCancel,
     /// Payments Session Token flow
@@ -128,6 +134,10 @@ pub enum Flow {
     PaymentsStart,
     /// Payments list flow.
     PaymentsList,
+    /// Payments FRM fulfillment flow.
+    PaymentsFrmFulfillment,
+    /// Payments FRM review flow.
+    PaymentsFrmReview,
     /// Payouts create flow
     PayoutsCreate,
     /// Payouts retrieve flow.
@@ -138,6 +148,8 @@ pub enum Flow {
     PayoutsReverse,
     /// Payouts cancel flow.
     PayoutsCancel,
+    /// Payouts fulfill flow.
+    PayoutsFulfill,
     /// Payouts accounts flow.
     PayoutsAccounts,
     /// Payments Redirect flow.
@@ -164,12 +176,16 @@ pub enum Flow {
     ApiKeyRevoke,
     /// API Key list flow
     ApiKeyList,
+    /// API Key rotate flow
+    ApiKeyRotate,
     /// Dispute Retrieve flow
     DisputesRetrieve,
     /// Dispute List flow
     DisputesList,
     /// Cards Info flow
     CardsInfo,
+    /// Cards Info import flow
+    CardsInfoImport,
     /// Create File flow
     CreateFile,
     /// Delete File flow
@@ -184,6 +200,14 @@ pub enum Flow {
     AttachDisputeEvidence,
     /// Retrieve Dispute Evidence flow
     RetrieveDisputeEvidence,
+    /// Webhook Events list flow
+    WebhookEventsList,
+    /// Webhook Events list for a merchant flow
+    WebhookEventsListForMerchant,
+    /// Webhook Event retrieve flow
+    WebhookEventRetrieve,
+    /// Webhook Event retry flow
+    WebhookEventRetry,
     /// Request to compatibility layer
     CompatibilityLayerRequest,
 }
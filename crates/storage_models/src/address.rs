@@ -1,9 +1,10 @@
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{encryption::Encryption, enums, schema::address};
 
-#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize, router_derive::DebugAsDisplay)]
 #[diesel(table_name = address)]
 pub struct AddressNew {
     pub address_id: String,
@@ -24,7 +25,7 @@ pub struct AddressNew {
     pub modified_at: PrimitiveDateTime,
 }
 
-#[derive(Clone, Debug, Identifiable, Queryable, frunk::LabelledGeneric)]
+#[derive(Clone, Debug, Identifiable, Queryable, frunk::LabelledGeneric, Serialize, Deserialize)]
 #[diesel(table_name = address)]
 pub struct Address {
     pub id: i32,
@@ -46,7 +47,7 @@ pub struct Address {
     pub merchant_id: String,
 }
 
-#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[derive(Clone, Debug, AsChangeset, Serialize, Deserialize, router_derive::DebugAsDisplay)]
 #[diesel(table_name = address)]
 pub struct AddressUpdateInternal {
     pub city: Option<String>,
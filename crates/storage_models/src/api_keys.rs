@@ -1,9 +1,9 @@
 use diesel::{AsChangeset, AsExpression, Identifiable, Insertable, Queryable};
 use time::PrimitiveDateTime;
 
-use crate::schema::api_keys;
+use crate::{enums as storage_enums, schema::api_keys};
 
-#[derive(Debug, Clone, Identifiable, Queryable)]
+#[derive(Debug, Clone, Identifiable, Queryable, serde::Serialize, serde::Deserialize)]
 #[diesel(table_name = api_keys, primary_key(key_id))]
 pub struct ApiKey {
     pub key_id: String,
@@ -15,6 +15,13 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    /// The scopes the key is restricted to, `None` for keys with full access
+    #[diesel(deserialize_as = super::OptionalDieselArray<storage_enums::ApiKeyScope>)]
+    pub scopes: Option<Vec<storage_enums::ApiKeyScope>>,
+    /// The hash of the secret the key had before it was last rotated
+    pub previous_hashed_api_key: Option<HashedApiKey>,
+    /// The time until which the secret the key had before it was last rotated remains valid
+    pub previous_key_expires_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Debug, Insertable)]
@@ -29,6 +36,7 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub scopes: Option<Vec<storage_enums::ApiKeyScope>>,
 }
 
 #[derive(Debug)]
@@ -38,10 +46,17 @@ pub enum ApiKeyUpdate {
         description: Option<String>,
         expires_at: Option<Option<PrimitiveDateTime>>,
         last_used: Option<PrimitiveDateTime>,
+        scopes: Option<Vec<storage_enums::ApiKeyScope>>,
     },
     LastUsedUpdate {
         last_used: PrimitiveDateTime,
     },
+    RotateUpdate {
+        hashed_api_key: HashedApiKey,
+        prefix: String,
+        previous_hashed_api_key: HashedApiKey,
+        previous_key_expires_at: PrimitiveDateTime,
+    },
 }
 
 #[derive(Debug, AsChangeset)]
@@ -51,6 +66,11 @@ pub(crate) struct ApiKeyUpdateInternal {
     pub description: Option<String>,
     pub expires_at: Option<Option<PrimitiveDateTime>>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub scopes: Option<Vec<storage_enums::ApiKeyScope>>,
+    pub hashed_api_key: Option<HashedApiKey>,
+    pub prefix: Option<String>,
+    pub previous_hashed_api_key: Option<HashedApiKey>,
+    pub previous_key_expires_at: Option<PrimitiveDateTime>,
 }
 
 impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
@@ -61,23 +81,66 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
                 description,
                 expires_at,
                 last_used,
+                scopes,
             } => Self {
                 name,
                 description,
                 expires_at,
                 last_used,
+                scopes,
+                hashed_api_key: None,
+                prefix: None,
+                previous_hashed_api_key: None,
+                previous_key_expires_at: None,
             },
             ApiKeyUpdate::LastUsedUpdate { last_used } => Self {
                 last_used: Some(last_used),
                 name: None,
                 description: None,
                 expires_at: None,
+                scopes: None,
+                hashed_api_key: None,
+                prefix: None,
+                previous_hashed_api_key: None,
+                previous_key_expires_at: None,
+            },
+            ApiKeyUpdate::RotateUpdate {
+                hashed_api_key,
+                prefix,
+                previous_hashed_api_key,
+                previous_key_expires_at,
+            } => Self {
+                hashed_api_key: Some(hashed_api_key),
+                prefix: Some(prefix),
+                previous_hashed_api_key: Some(previous_hashed_api_key),
+                previous_key_expires_at: Some(previous_key_expires_at),
+                name: None,
+                description: None,
+                expires_at: None,
+                last_used: None,
+                scopes: None,
             },
         }
     }
 }
 
-#[derive(Debug, Clone, AsExpression, PartialEq)]
+/// Last used time of an API key, collected in Redis until it is written to the database
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ApiKeyLastUsed {
+    pub merchant_id: String,
+    pub key_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_used: PrimitiveDateTime,
+}
+
+/// Tracking data of the process tracker task writing the last used times of API keys collected
+/// in a Redis hash to the database
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ApiKeyLastUsedFlushTrackingData {
+    pub redis_key: String,
+}
+
+#[derive(Debug, Clone, AsExpression, PartialEq, serde::Serialize, serde::Deserialize)]
 #[diesel(sql_type = diesel::sql_types::Text)]
 pub struct HashedApiKey(String);
 
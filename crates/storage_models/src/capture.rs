@@ -0,0 +1,113 @@
+use common_utils::custom_serde;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::captures};
+
+/// A single capture against an authorization made with
+/// [`storage_enums::CaptureMethod::ManualMultiple`].
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = captures)]
+#[serde(deny_unknown_fields)]
+pub struct CaptureNew {
+    pub capture_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub authorized_attempt_id: String,
+    pub capture_sequence: i16,
+    pub status: storage_enums::CaptureStatus,
+    pub amount: i64,
+    pub currency: Option<storage_enums::Currency>,
+    pub connector: String,
+    pub connector_capture_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = captures)]
+pub struct Capture {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub capture_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub authorized_attempt_id: String,
+    pub capture_sequence: i16,
+    pub status: storage_enums::CaptureStatus,
+    pub amount: i64,
+    pub currency: Option<storage_enums::Currency>,
+    pub connector: String,
+    pub connector_capture_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone)]
+pub enum CaptureUpdate {
+    ResponseUpdate {
+        status: storage_enums::CaptureStatus,
+        connector_capture_id: Option<String>,
+    },
+    ErrorUpdate {
+        status: storage_enums::CaptureStatus,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = captures)]
+pub struct CaptureUpdateInternal {
+    status: Option<storage_enums::CaptureStatus>,
+    connector_capture_id: Option<String>,
+    error_code: Option<String>,
+    error_message: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<CaptureUpdate> for CaptureUpdateInternal {
+    fn from(capture_update: CaptureUpdate) -> Self {
+        match capture_update {
+            CaptureUpdate::ResponseUpdate {
+                status,
+                connector_capture_id,
+            } => Self {
+                status: Some(status),
+                connector_capture_id,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            CaptureUpdate::ErrorUpdate {
+                status,
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(status),
+                error_code,
+                error_message,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl CaptureUpdate {
+    pub fn apply_changeset(self, source: Capture) -> Capture {
+        let update = CaptureUpdateInternal::from(self);
+        Capture {
+            status: update.status.unwrap_or(source.status),
+            connector_capture_id: update.connector_capture_id.or(source.connector_capture_id),
+            error_code: update.error_code.or(source.error_code),
+            error_message: update.error_message.or(source.error_message),
+            modified_at: update.modified_at.unwrap_or(source.modified_at),
+            ..source
+        }
+    }
+}
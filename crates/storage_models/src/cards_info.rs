@@ -1,4 +1,4 @@
-use diesel::{Identifiable, Queryable};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use time::PrimitiveDateTime;
 
 use crate::schema::cards_info;
@@ -19,3 +19,82 @@ pub struct CardInfo {
     pub last_updated: Option<PrimitiveDateTime>,
     pub last_updated_provider: Option<String>,
 }
+
+#[derive(Clone, Debug, Insertable)]
+#[diesel(table_name = cards_info)]
+pub struct CardInfoNew {
+    pub card_iin: String,
+    pub card_issuer: Option<String>,
+    pub card_network: Option<String>,
+    pub card_type: Option<String>,
+    pub card_subtype: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub bank_code_id: Option<String>,
+    pub bank_code: Option<String>,
+    pub country_code: Option<String>,
+    pub date_created: PrimitiveDateTime,
+    pub last_updated: Option<PrimitiveDateTime>,
+    pub last_updated_provider: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum CardInfoUpdate {
+    Update {
+        card_issuer: Option<String>,
+        card_network: Option<String>,
+        card_type: Option<String>,
+        card_subtype: Option<String>,
+        card_issuing_country: Option<String>,
+        bank_code_id: Option<String>,
+        bank_code: Option<String>,
+        country_code: Option<String>,
+        last_updated_provider: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset)]
+#[diesel(table_name = cards_info)]
+// Imported BIN data replaces the stored record as a whole, so missing values are written as
+// NULL instead of being skipped
+#[diesel(treat_none_as_null = true)]
+pub struct CardInfoUpdateInternal {
+    card_issuer: Option<String>,
+    card_network: Option<String>,
+    card_type: Option<String>,
+    card_subtype: Option<String>,
+    card_issuing_country: Option<String>,
+    bank_code_id: Option<String>,
+    bank_code: Option<String>,
+    country_code: Option<String>,
+    last_updated: Option<PrimitiveDateTime>,
+    last_updated_provider: Option<String>,
+}
+
+impl From<CardInfoUpdate> for CardInfoUpdateInternal {
+    fn from(card_info_update: CardInfoUpdate) -> Self {
+        match card_info_update {
+            CardInfoUpdate::Update {
+                card_issuer,
+                card_network,
+                card_type,
+                card_subtype,
+                card_issuing_country,
+                bank_code_id,
+                bank_code,
+                country_code,
+                last_updated_provider,
+            } => Self {
+                card_issuer,
+                card_network,
+                card_type,
+                card_subtype,
+                card_issuing_country,
+                bank_code_id,
+                bank_code,
+                country_code,
+                last_updated: Some(common_utils::date_time::now()),
+                last_updated_provider,
+            },
+        }
+    }
+}
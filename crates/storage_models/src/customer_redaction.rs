@@ -0,0 +1,106 @@
+use common_utils::custom_serde;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::customer_redaction};
+
+/// Audit record of the redaction of a customer and of the records linked to it
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = customer_redaction)]
+pub struct CustomerRedactionNew {
+    pub redaction_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub status: storage_enums::CustomerRedactionStatus,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = customer_redaction)]
+pub struct CustomerRedaction {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub redaction_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub status: storage_enums::CustomerRedactionStatus,
+    pub redacted_records: Option<serde_json::Value>,
+    pub error_message: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+/// Number of records of each kind redacted along with a customer
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RedactedRecords {
+    pub addresses: usize,
+    pub payment_intents: usize,
+    pub payment_attempts: usize,
+    pub mandates: usize,
+    pub disputes: usize,
+    pub files: usize,
+    pub event_delivery_attempts: usize,
+}
+
+#[derive(Debug)]
+pub enum CustomerRedactionUpdate {
+    Completed { redacted_records: serde_json::Value },
+    Failed { error_message: String },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = customer_redaction)]
+pub struct CustomerRedactionUpdateInternal {
+    status: Option<storage_enums::CustomerRedactionStatus>,
+    redacted_records: Option<serde_json::Value>,
+    error_message: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+    completed_at: Option<PrimitiveDateTime>,
+}
+
+impl CustomerRedactionUpdate {
+    pub fn apply_changeset(self, source: CustomerRedaction) -> CustomerRedaction {
+        let update: CustomerRedactionUpdateInternal = self.into();
+        CustomerRedaction {
+            status: update.status.unwrap_or(source.status),
+            redacted_records: update.redacted_records.or(source.redacted_records),
+            error_message: update.error_message.or(source.error_message),
+            modified_at: update.modified_at.unwrap_or(source.modified_at),
+            completed_at: update.completed_at.or(source.completed_at),
+            ..source
+        }
+    }
+}
+
+impl From<CustomerRedactionUpdate> for CustomerRedactionUpdateInternal {
+    fn from(customer_redaction_update: CustomerRedactionUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match customer_redaction_update {
+            CustomerRedactionUpdate::Completed { redacted_records } => Self {
+                status: Some(storage_enums::CustomerRedactionStatus::Completed),
+                redacted_records: Some(redacted_records),
+                modified_at: Some(now),
+                completed_at: Some(now),
+                ..Default::default()
+            },
+            CustomerRedactionUpdate::Failed { error_message } => Self {
+                status: Some(storage_enums::CustomerRedactionStatus::Failed),
+                error_message: Some(error_message),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Tracking data of the process tracker task redacting the records linked to a customer
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CustomerRedactionTrackingData {
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub redaction_id: String,
+}
@@ -1,12 +1,12 @@
 use common_utils::custom_serde;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use masking::Secret;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::dispute};
 
-#[derive(Clone, Debug, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize, router_derive::DebugAsDisplay)]
 #[diesel(table_name = dispute)]
 #[serde(deny_unknown_fields)]
 pub struct DisputeNew {
@@ -29,10 +29,10 @@ pub struct DisputeNew {
     pub evidence: Option<Secret<serde_json::Value>>,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Identifiable, Queryable)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Identifiable, Queryable)]
 #[diesel(table_name = dispute)]
 pub struct Dispute {
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing, default)]
     pub id: i32,
     pub dispute_id: String,
     pub amount: String,
@@ -57,7 +57,7 @@ pub struct Dispute {
     pub evidence: Secret<serde_json::Value>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum DisputeUpdate {
     Update {
         dispute_stage: storage_enums::DisputeStage,
@@ -129,3 +129,36 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
         }
     }
 }
+
+impl DisputeUpdateInternal {
+    pub fn create_dispute(self, source: Dispute) -> Dispute {
+        Dispute {
+            dispute_stage: self.dispute_stage.unwrap_or(source.dispute_stage),
+            dispute_status: self.dispute_status.unwrap_or(source.dispute_status),
+            connector_status: self.connector_status.unwrap_or(source.connector_status),
+            connector_reason: self.connector_reason.or(source.connector_reason),
+            connector_reason_code: self.connector_reason_code.or(source.connector_reason_code),
+            challenge_required_by: self.challenge_required_by.or(source.challenge_required_by),
+            connector_updated_at: self.connector_updated_at.or(source.connector_updated_at),
+            modified_at: self.modified_at.unwrap_or(source.modified_at),
+            evidence: self.evidence.unwrap_or(source.evidence),
+            ..source
+        }
+    }
+}
+
+/// Tracking data of the process tracker task reminding the merchant of the evidence deadline of a
+/// dispute, and expiring the dispute once the deadline has passed
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DisputeDeadlineTrackingData {
+    pub merchant_id: String,
+    pub dispute_id: String,
+    /// The deadline the reminders have been sent for, reminders are sent again if it changes
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    /// Offset before the deadline, in seconds, of the last reminder sent
+    pub last_reminder_offset: Option<i64>,
+    /// Whether the attached evidence has already been submitted automatically
+    #[serde(default)]
+    pub evidence_submission_attempted: bool,
+}
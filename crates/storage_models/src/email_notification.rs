@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+/// Transactional emails sent to the primary email of a merchant
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EmailNotification {
+    MerchantAccountCreated,
+    ApiKeyExpiringSoon {
+        key_id: String,
+        key_name: String,
+        #[serde(with = "common_utils::custom_serde::iso8601")]
+        expires_at: PrimitiveDateTime,
+    },
+    DisputeOpened {
+        dispute_id: String,
+        payment_id: String,
+        amount: String,
+        currency: String,
+        #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+        challenge_required_by: Option<PrimitiveDateTime>,
+    },
+    DisputeEvidenceDue {
+        dispute_id: String,
+        payment_id: String,
+        #[serde(with = "common_utils::custom_serde::iso8601")]
+        challenge_required_by: PrimitiveDateTime,
+        /// Offset before the deadline, in seconds, the reminder is sent at
+        #[serde(default)]
+        reminder_offset: i64,
+    },
+    WebhookEndpointFailing {
+        /// The event whose delivery retries were exhausted
+        event_id: String,
+        delivery_attempts: i32,
+        /// Time at which the delivery retries were exhausted, at most one notification is sent
+        /// per merchant per day
+        #[serde(with = "common_utils::custom_serde::iso8601")]
+        failed_at: PrimitiveDateTime,
+    },
+}
+
+/// Tracking data of the process tracker task sending an email notification to a merchant
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EmailNotificationTrackingData {
+    pub merchant_id: String,
+    pub notification: EmailNotification,
+}
@@ -5,6 +5,36 @@ use diesel::{
     sql_types, AsExpression,
 };
 
+/// Version of the merchant keys created before the keys were versioned. Data encrypted with
+/// these keys does not record the version of the key.
+pub const INITIAL_KEY_VERSION: i32 = 1;
+
+/// Marks data which is prefixed with the version of the merchant key it has been encrypted with
+const KEY_VERSION_MARKER: [u8; 4] = *b"kver";
+const KEY_VERSION_LENGTH: usize = std::mem::size_of::<i32>();
+
+/// Prefixes data encrypted with a merchant key with the version of that key
+pub fn add_key_version(key_version: i32, encrypted_data: Vec<u8>) -> Vec<u8> {
+    [
+        KEY_VERSION_MARKER.as_slice(),
+        &key_version.to_be_bytes(),
+        &encrypted_data,
+    ]
+    .concat()
+}
+
+/// Splits data prefixed by [`add_key_version`] into the version of the key and the encrypted
+/// data. Data encrypted without a version can start with the marker by chance, so decryption
+/// with the returned version is expected to fail in rare cases.
+pub fn split_key_version(data: &[u8]) -> Option<(i32, &[u8])> {
+    let data = data.strip_prefix(&KEY_VERSION_MARKER)?;
+    let key_version = data.get(..KEY_VERSION_LENGTH)?.try_into().ok()?;
+    Some((
+        i32::from_be_bytes(key_version),
+        data.get(KEY_VERSION_LENGTH..)?,
+    ))
+}
+
 #[derive(Debug, AsExpression, Clone, serde::Serialize, serde::Deserialize)]
 #[diesel(sql_type = diesel::sql_types::Binary)]
 #[repr(transparent)]
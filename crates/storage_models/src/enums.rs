@@ -2,16 +2,17 @@
 pub mod diesel_exports {
     pub use super::{
         DbAttemptStatus as AttemptStatus, DbAuthenticationType as AuthenticationType,
-        DbCaptureMethod as CaptureMethod, DbConnectorType as ConnectorType,
-        DbCountryAlpha2 as CountryAlpha2, DbCurrency as Currency, DbDisputeStage as DisputeStage,
+        DbCaptureMethod as CaptureMethod, DbCaptureStatus as CaptureStatus,
+        DbConnectorType as ConnectorType, DbCountryAlpha2 as CountryAlpha2, DbCurrency as Currency,
+        DbCustomerRedactionStatus as CustomerRedactionStatus, DbDisputeStage as DisputeStage,
         DbDisputeStatus as DisputeStatus, DbEventClass as EventClass,
         DbEventObjectType as EventObjectType, DbEventType as EventType,
         DbFutureUsage as FutureUsage, DbIntentStatus as IntentStatus,
         DbMandateStatus as MandateStatus, DbMandateType as MandateType,
         DbMerchantStorageScheme as MerchantStorageScheme,
-        DbPaymentMethodIssuerCode as PaymentMethodIssuerCode,
-        DbProcessTrackerStatus as ProcessTrackerStatus, DbRefundStatus as RefundStatus,
-        DbRefundType as RefundType,
+        DbPaymentMethodIssuerCode as PaymentMethodIssuerCode, DbPayoutStatus as PayoutStatus,
+        DbPayoutType as PayoutType, DbProcessTrackerStatus as ProcessTrackerStatus,
+        DbRefundStatus as RefundStatus, DbRefundType as RefundType,
     };
 }
 
@@ -109,6 +110,34 @@ pub enum CaptureMethod {
     Scheduled,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "pg_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CaptureStatus {
+    /// The capture has been created but not yet sent to the connector
+    #[default]
+    Started,
+    /// The connector has settled the capture
+    Charged,
+    /// The connector has accepted the capture but not yet settled it
+    Pending,
+    /// The capture was declined or could not be processed
+    Failed,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -275,6 +304,7 @@ pub enum Currency {
     serde::Serialize,
     strum::Display,
     strum::EnumString,
+    frunk::LabelledGeneric,
 )]
 #[router_derive::diesel_enum(storage_type = "pg_enum")]
 #[serde(rename_all = "snake_case")]
@@ -283,6 +313,8 @@ pub enum EventClass {
     Payments,
     Refunds,
     Disputes,
+    Payouts,
+    Mandates,
 }
 
 #[derive(
@@ -295,6 +327,7 @@ pub enum EventClass {
     serde::Serialize,
     strum::Display,
     strum::EnumString,
+    frunk::LabelledGeneric,
 )]
 #[router_derive::diesel_enum(storage_type = "pg_enum")]
 #[serde(rename_all = "snake_case")]
@@ -303,6 +336,8 @@ pub enum EventObjectType {
     PaymentDetails,
     RefundDetails,
     DisputeDetails,
+    PayoutDetails,
+    MandateDetails,
 }
 
 #[derive(
@@ -324,6 +359,7 @@ pub enum EventType {
     PaymentSucceeded,
     PaymentFailed,
     PaymentProcessing,
+    PaymentCancelled,
     ActionRequired,
     RefundSucceeded,
     RefundFailed,
@@ -334,6 +370,17 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    DisputeEvidenceDue,
+    PayoutInitiated,
+    PayoutProcessing,
+    PayoutSuccess,
+    PayoutFailed,
+    PayoutCancelled,
+    PayoutReversed,
+    MandateExpired,
+    MandateUsed,
+    MandateActive,
+    MandateRevoked,
 }
 
 #[derive(
@@ -623,6 +670,8 @@ pub struct MandateAmountData {
     pub currency: Currency,
     pub start_date: Option<PrimitiveDateTime>,
     pub end_date: Option<PrimitiveDateTime>,
+    pub max_amount_per_payment: Option<i64>,
+    pub usage_limit: Option<i32>,
     pub metadata: Option<pii::SecretSerdeValue>,
 }
 
@@ -648,6 +697,10 @@ pub enum MandateStatus {
     Inactive,
     Pending,
     Revoked,
+    /// The mandate is past its end date
+    Expired,
+    /// The usage or amount limits of the mandate have been exhausted
+    Used,
 }
 
 #[derive(
@@ -701,6 +754,32 @@ pub enum PaymentMethodType {
     WeChatPay,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "pg_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CustomerRedactionStatus {
+    /// The customer record has been redacted and the redaction of the linked records has been
+    /// scheduled
+    #[default]
+    Scheduled,
+    /// All the records linked to the customer have been redacted
+    Completed,
+    /// The redaction of the linked records could not be completed
+    Failed,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -868,3 +947,133 @@ pub enum DisputeStatus {
     DisputeWon,
     DisputeLost,
 }
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "pg_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutStatus {
+    #[default]
+    RequiresCreation,
+    RequiresFulfillment,
+    Pending,
+    Success,
+    Failed,
+    Cancelled,
+    Reversed,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "pg_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutType {
+    #[default]
+    Card,
+    Bank,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ApiKeyScope {
+    PaymentsRead,
+    PaymentsWrite,
+    RefundsRead,
+    RefundsWrite,
+    CustomersRead,
+    CustomersWrite,
+    PaymentMethodsRead,
+    PaymentMethodsWrite,
+    MandatesRead,
+    MandatesWrite,
+    DisputesRead,
+    DisputesWrite,
+    PayoutsRead,
+    PayoutsWrite,
+}
+
+/// Outcome of a fraud check performed by an FRM connector
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FraudCheckStatus {
+    Fraud,
+    ManualReview,
+    Pending,
+    Legit,
+    TransactionFailure,
+}
+
+/// Action taken on a payment based on its fraud check
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmDecision {
+    /// The payment can continue as usual
+    Proceed,
+    /// The payment is left for the merchant to review once it is authorized
+    ManualReview,
+    /// The payment must not be completed
+    Decline,
+}
@@ -1,4 +1,4 @@
-#[derive(Debug, thiserror::Error)]
+#[derive(Copy, Clone, Debug, thiserror::Error)]
 pub enum DatabaseError {
     #[error("An error occurred when obtaining database connection")]
     DatabaseConnectionError,
@@ -14,3 +14,17 @@ pub enum DatabaseError {
     #[error("An unknown error occurred")]
     Others,
 }
+
+impl From<diesel::result::Error> for DatabaseError {
+    fn from(error: diesel::result::Error) -> Self {
+        match error {
+            diesel::result::Error::DatabaseError(
+                diesel::result::DatabaseErrorKind::UniqueViolation,
+                _,
+            ) => Self::UniqueViolation,
+            diesel::result::Error::NotFound => Self::NotFound,
+            diesel::result::Error::QueryBuilderError(_) => Self::QueryGenerationFailed,
+            _ => Self::Others,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use common_utils::custom_serde;
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::event_delivery_attempt;
+
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = event_delivery_attempt)]
+#[serde(deny_unknown_fields)]
+pub struct EventDeliveryAttemptNew {
+    pub attempt_id: String,
+    pub event_id: String,
+    pub merchant_id: String,
+    pub request_body: String,
+    pub response_status_code: Option<i16>,
+    pub response_body: Option<String>,
+    pub latency_ms: Option<i64>,
+    pub error_message: Option<String>,
+    pub is_manual_retry: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = event_delivery_attempt)]
+pub struct EventDeliveryAttempt {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub attempt_id: String,
+    pub event_id: String,
+    pub merchant_id: String,
+    pub request_body: String,
+    pub response_status_code: Option<i16>,
+    pub response_body: Option<String>,
+    pub latency_ms: Option<i64>,
+    pub error_message: Option<String>,
+    pub is_manual_retry: bool,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
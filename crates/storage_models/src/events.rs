@@ -16,6 +16,7 @@ pub struct EventNew {
     pub intent_reference_id: Option<String>,
     pub primary_object_id: String,
     pub primary_object_type: storage_enums::EventObjectType,
+    pub merchant_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -43,6 +44,16 @@ pub struct Event {
     pub primary_object_type: storage_enums::EventObjectType,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub merchant_id: Option<String>,
+}
+
+/// Tracking data of the process tracker task retrying the delivery of an outgoing webhook. The
+/// payload holds customer details, it is read from the delivery attempts of the event when the
+/// webhook is retried instead of being copied here.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OutgoingWebhookTrackingData {
+    pub merchant_id: String,
+    pub event_id: String,
 }
 
 impl From<EventUpdate> for EventUpdateInternal {
@@ -44,6 +44,9 @@ pub enum FileMetadataUpdate {
         available: bool,
         connector_label: Option<String>,
     },
+    RedactionUpdate {
+        file_name: Option<String>,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -53,6 +56,7 @@ pub struct FileMetadataUpdateInternal {
     file_upload_provider: Option<common_enums::FileUploadProvider>,
     available: bool,
     connector_label: Option<String>,
+    file_name: Option<String>,
 }
 
 impl From<FileMetadataUpdate> for FileMetadataUpdateInternal {
@@ -68,6 +72,13 @@ impl From<FileMetadataUpdate> for FileMetadataUpdateInternal {
                 file_upload_provider,
                 available,
                 connector_label,
+                file_name: None,
+            },
+            // Redacted files are no longer retrievable
+            FileMetadataUpdate::RedactionUpdate { file_name } => Self {
+                available: false,
+                file_name,
+                ..Default::default()
             },
         }
     }
@@ -0,0 +1,85 @@
+use common_utils::custom_serde;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::fraud_check};
+
+/// Outcome of the fraud check of a payment attempt, and the decision of the merchant on it if
+/// the payment was left for manual review
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = fraud_check)]
+pub struct FraudCheckNew {
+    pub frm_id: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub frm_name: String,
+    pub frm_reference_id: Option<String>,
+    /// `None` if the FRM connector could not be reached or returned an error
+    pub frm_status: Option<storage_enums::FraudCheckStatus>,
+    pub frm_decision: storage_enums::FrmDecision,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = fraud_check)]
+pub struct FraudCheck {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub frm_id: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub frm_name: String,
+    pub frm_reference_id: Option<String>,
+    pub frm_status: Option<storage_enums::FraudCheckStatus>,
+    pub frm_decision: storage_enums::FrmDecision,
+    /// `Proceed` if the merchant approved the payment, `Decline` if the merchant rejected it
+    pub review_decision: Option<storage_enums::FrmDecision>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub reviewed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum FraudCheckUpdate {
+    ReviewUpdate {
+        review_decision: storage_enums::FrmDecision,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = fraud_check)]
+pub struct FraudCheckUpdateInternal {
+    review_decision: Option<storage_enums::FrmDecision>,
+    modified_at: Option<PrimitiveDateTime>,
+    reviewed_at: Option<PrimitiveDateTime>,
+}
+
+impl FraudCheckUpdate {
+    pub fn apply_changeset(self, source: FraudCheck) -> FraudCheck {
+        let update: FraudCheckUpdateInternal = self.into();
+        FraudCheck {
+            review_decision: update.review_decision.or(source.review_decision),
+            modified_at: update.modified_at.unwrap_or(source.modified_at),
+            reviewed_at: update.reviewed_at.or(source.reviewed_at),
+            ..source
+        }
+    }
+}
+
+impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
+    fn from(fraud_check_update: FraudCheckUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match fraud_check_update {
+            FraudCheckUpdate::ReviewUpdate { review_decision } => Self {
+                review_decision: Some(review_decision),
+                modified_at: Some(now),
+                reviewed_at: Some(now),
+            },
+        }
+    }
+}
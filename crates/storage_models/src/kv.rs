@@ -2,10 +2,14 @@ use error_stack::{IntoReport, ResultExt};
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    address::{Address, AddressNew, AddressUpdateInternal},
+    connector_response::{ConnectorResponse, ConnectorResponseNew, ConnectorResponseUpdate},
+    dispute::{Dispute, DisputeNew, DisputeUpdate},
     errors,
     payment_attempt::{PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate},
     payment_intent::{PaymentIntent, PaymentIntentNew, PaymentIntentUpdate},
     refund::{Refund, RefundNew, RefundUpdate},
+    reverse_lookup::ReverseLookupNew,
 };
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -13,7 +17,7 @@ use crate::{
 pub enum DBOperation {
     Insert { insertable: Insertable },
     Update { updatable: Updateable },
-    Delete,
+    Delete { deletable: Deletable },
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -39,6 +43,10 @@ pub enum Insertable {
     PaymentIntent(PaymentIntentNew),
     PaymentAttempt(PaymentAttemptNew),
     Refund(RefundNew),
+    ConnectorResponse(ConnectorResponseNew),
+    Address(AddressNew),
+    Dispute(DisputeNew),
+    ReverseLookup(ReverseLookupNew),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -47,6 +55,29 @@ pub enum Updateable {
     PaymentIntentUpdate(PaymentIntentUpdateMems),
     PaymentAttemptUpdate(PaymentAttemptUpdateMems),
     RefundUpdate(RefundUpdateMems),
+    ConnectorResponseUpdate(ConnectorResponseUpdateMems),
+    AddressUpdate(AddressUpdateMems),
+    DisputeUpdate(DisputeUpdateMems),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "table", content = "data")]
+pub enum Deletable {
+    ConnectorResponse {
+        merchant_id: String,
+        payment_id: String,
+        attempt_id: String,
+    },
+    Address {
+        address_id: String,
+    },
+    Dispute {
+        merchant_id: String,
+        dispute_id: String,
+    },
+    ReverseLookup {
+        lookup_id: String,
+    },
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -66,3 +97,21 @@ pub struct RefundUpdateMems {
     pub orig: Refund,
     pub update_data: RefundUpdate,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConnectorResponseUpdateMems {
+    pub orig: ConnectorResponse,
+    pub update_data: ConnectorResponseUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddressUpdateMems {
+    pub orig: Address,
+    pub update_data: AddressUpdateInternal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DisputeUpdateMems {
+    pub orig: Dispute,
+    pub update_data: DisputeUpdate,
+}
@@ -1,16 +1,21 @@
 pub mod address;
 pub mod api_keys;
+pub mod capture;
 pub mod cards_info;
 pub mod configs;
 pub mod connector_response;
+pub mod customer_redaction;
 pub mod customers;
 pub mod dispute;
+pub mod email_notification;
 pub mod encryption;
 pub mod enums;
 pub mod ephemeral_key;
 pub mod errors;
+pub mod event_delivery_attempt;
 pub mod events;
 pub mod file;
+pub mod fraud_check;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -21,6 +26,7 @@ pub mod merchant_key_store;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payouts;
 pub mod process_tracker;
 pub mod query;
 pub mod refund;
@@ -30,6 +30,9 @@ pub struct Mandate {
     pub end_date: Option<PrimitiveDateTime>,
     pub metadata: Option<pii::SecretSerdeValue>,
     pub connector_mandate_ids: Option<pii::SecretSerdeValue>,
+    pub max_amount_per_payment: Option<i64>,
+    pub usage_limit: Option<i32>,
+    pub usage_count: i32,
 }
 
 #[derive(
@@ -58,6 +61,9 @@ pub struct MandateNew {
     pub end_date: Option<PrimitiveDateTime>,
     pub metadata: Option<pii::SecretSerdeValue>,
     pub connector_mandate_ids: Option<pii::SecretSerdeValue>,
+    pub max_amount_per_payment: Option<i64>,
+    pub usage_limit: Option<i32>,
+    pub usage_count: i32,
 }
 
 #[derive(Debug)]
@@ -69,8 +75,20 @@ pub enum MandateUpdate {
         amount_captured: Option<i64>,
     },
     ConnectorReferenceUpdate {
+        connector_mandate_id: Option<String>,
         connector_mandate_ids: Option<pii::SecretSerdeValue>,
     },
+    RedactionUpdate {
+        customer_ip_address: Option<Secret<String, pii::IpAddress>>,
+        customer_user_agent: Option<String>,
+    },
+}
+
+/// Tracking data of the process tracker task expiring a mandate at its end date
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct MandateExpiryTrackingData {
+    pub merchant_id: String,
+    pub mandate_id: String,
 }
 
 #[derive(Clone, Eq, PartialEq, Copy, Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -84,7 +102,10 @@ pub struct SingleUseMandate {
 pub struct MandateUpdateInternal {
     mandate_status: Option<storage_enums::MandateStatus>,
     amount_captured: Option<i64>,
+    connector_mandate_id: Option<String>,
     connector_mandate_ids: Option<pii::SecretSerdeValue>,
+    customer_ip_address: Option<Secret<String, pii::IpAddress>>,
+    customer_user_agent: Option<String>,
 }
 
 impl From<MandateUpdate> for MandateUpdateInternal {
@@ -92,18 +113,26 @@ impl From<MandateUpdate> for MandateUpdateInternal {
         match mandate_update {
             MandateUpdate::StatusUpdate { mandate_status } => Self {
                 mandate_status: Some(mandate_status),
-                connector_mandate_ids: None,
-                amount_captured: None,
+                ..Default::default()
             },
             MandateUpdate::CaptureAmountUpdate { amount_captured } => Self {
-                mandate_status: None,
                 amount_captured,
-                connector_mandate_ids: None,
+                ..Default::default()
             },
             MandateUpdate::ConnectorReferenceUpdate {
-                connector_mandate_ids: connector_mandate_id,
+                connector_mandate_id,
+                connector_mandate_ids,
+            } => Self {
+                connector_mandate_id,
+                connector_mandate_ids,
+                ..Default::default()
+            },
+            MandateUpdate::RedactionUpdate {
+                customer_ip_address,
+                customer_user_agent,
             } => Self {
-                connector_mandate_ids: connector_mandate_id,
+                customer_ip_address,
+                customer_user_agent,
                 ..Default::default()
             },
         }
@@ -14,12 +14,13 @@ use crate::{encryption::Encryption, schema::merchant_key_store};
     router_derive::DebugAsDisplay,
 )]
 #[diesel(table_name = merchant_key_store)]
-#[diesel(primary_key(merchant_id))]
+#[diesel(primary_key(merchant_id, key_version))]
 pub struct MerchantKeyStore {
     pub merchant_id: String,
     pub key: Encryption,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub key_version: i32,
 }
 
 #[derive(
@@ -30,6 +31,7 @@ pub struct MerchantKeyStoreNew {
     pub merchant_id: String,
     pub key: Encryption,
     pub created_at: PrimitiveDateTime,
+    pub key_version: i32,
 }
 
 #[derive(
@@ -40,3 +42,30 @@ pub struct MerchantKeyStoreUpdateInternal {
     pub merchant_id: String,
     pub key: Encryption,
 }
+
+/// Tracking data of the re-encryption of the data of a merchant with its latest key, which also
+/// records the progress of the re-encryption so that it can be resumed
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MerchantKeyRotationTrackingData {
+    pub merchant_id: String,
+    pub key_version: i32,
+    #[serde(default)]
+    pub progress: ReencryptionProgress,
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct ReencryptionProgress {
+    /// Whether the merchant account and its connector accounts have been re-encrypted
+    pub merchant_accounts_completed: bool,
+    /// Whether all the customers have been re-encrypted
+    pub customers_completed: bool,
+    /// Whether all the addresses have been re-encrypted
+    pub addresses_completed: bool,
+    /// `id` of the last customer processed, customers are processed in batches ordered by `id`
+    pub last_customer_row_id: Option<i32>,
+    /// `id` of the last address processed, addresses are processed in batches ordered by `id`
+    pub last_address_row_id: Option<i32>,
+    pub merchant_connector_accounts: usize,
+    pub customers: usize,
+    pub addresses: usize,
+}
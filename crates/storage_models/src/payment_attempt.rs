@@ -111,6 +111,8 @@ pub enum PaymentAttemptUpdate {
         payment_method_type: Option<storage_enums::PaymentMethodType>,
         payment_experience: Option<storage_enums::PaymentExperience>,
         business_sub_label: Option<String>,
+        capture_method: Option<storage_enums::CaptureMethod>,
+        capture_on: Option<PrimitiveDateTime>,
     },
     UpdateTrackers {
         payment_token: Option<String>,
@@ -134,6 +136,8 @@ pub enum PaymentAttemptUpdate {
         payment_experience: Option<storage_enums::PaymentExperience>,
         business_sub_label: Option<String>,
         straight_through_algorithm: Option<serde_json::Value>,
+        capture_method: Option<storage_enums::CaptureMethod>,
+        capture_on: Option<PrimitiveDateTime>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -174,6 +178,10 @@ pub enum PaymentAttemptUpdate {
         connector_metadata: Option<serde_json::Value>,
         preprocessing_step_id: Option<String>,
     },
+    RedactionUpdate {
+        payment_method_data: Option<serde_json::Value>,
+        browser_info: Option<serde_json::Value>,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -201,6 +209,8 @@ pub struct PaymentAttemptUpdateInternal {
     business_sub_label: Option<String>,
     straight_through_algorithm: Option<serde_json::Value>,
     preprocessing_step_id: Option<String>,
+    capture_method: Option<storage_enums::CaptureMethod>,
+    capture_on: Option<PrimitiveDateTime>,
 }
 
 impl PaymentAttemptUpdate {
@@ -224,9 +234,12 @@ impl PaymentAttemptUpdate {
             modified_at: common_utils::date_time::now(),
             payment_token: pa_update.payment_token.or(source.payment_token),
             connector_metadata: pa_update.connector_metadata.or(source.connector_metadata),
+            payment_method_data: pa_update.payment_method_data.or(source.payment_method_data),
             preprocessing_step_id: pa_update
                 .preprocessing_step_id
                 .or(source.preprocessing_step_id),
+            capture_method: pa_update.capture_method.or(source.capture_method),
+            capture_on: pa_update.capture_on.or(source.capture_on),
             ..source
         }
     }
@@ -247,6 +260,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_method_type,
                 payment_experience,
                 business_sub_label,
+                capture_method,
+                capture_on,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -260,6 +275,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_method_type,
                 payment_experience,
                 business_sub_label,
+                capture_method,
+                capture_on,
                 ..Default::default()
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
@@ -283,6 +300,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_experience,
                 business_sub_label,
                 straight_through_algorithm,
+                capture_method,
+                capture_on,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -298,6 +317,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_experience,
                 business_sub_label,
                 straight_through_algorithm,
+                capture_method,
+                capture_on,
                 ..Default::default()
             },
             PaymentAttemptUpdate::VoidUpdate {
@@ -390,6 +411,15 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 preprocessing_step_id,
                 ..Default::default()
             },
+            PaymentAttemptUpdate::RedactionUpdate {
+                payment_method_data,
+                browser_info,
+            } => Self {
+                payment_method_data,
+                browser_info,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
@@ -130,6 +130,9 @@ pub enum PaymentIntentUpdate {
         status: storage_enums::IntentStatus,
         active_attempt_id: String,
     },
+    RedactionUpdate {
+        description: Option<String>,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -145,6 +148,7 @@ pub struct PaymentIntentUpdateInternal {
     pub setup_future_usage: Option<storage_enums::FutureUsage>,
     pub off_session: Option<bool>,
     pub metadata: Option<pii::SecretSerdeValue>,
+    pub description: Option<String>,
     pub client_secret: Option<Option<String>>,
     pub billing_address_id: Option<String>,
     pub shipping_address_id: Option<String>,
@@ -171,6 +175,7 @@ impl PaymentIntentUpdate {
                 .or(source.setup_future_usage),
             off_session: internal_update.off_session.or(source.off_session),
             metadata: internal_update.metadata.or(source.metadata),
+            description: internal_update.description.or(source.description),
             client_secret: internal_update
                 .client_secret
                 .unwrap_or(source.client_secret),
@@ -289,6 +294,11 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 active_attempt_id: Some(active_attempt_id),
                 ..Default::default()
             },
+            PaymentIntentUpdate::RedactionUpdate { description } => Self {
+                description,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
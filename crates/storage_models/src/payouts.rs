@@ -0,0 +1,143 @@
+use common_utils::{custom_serde, pii};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payouts};
+
+#[derive(Clone, Debug, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payouts)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutNew {
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub connector: Option<String>,
+    pub connector_payout_id: Option<String>,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub payout_type: storage_enums::PayoutType,
+    pub status: storage_enums::PayoutStatus,
+    pub auto_fulfill: bool,
+    pub description: Option<String>,
+    pub business_country: Option<storage_enums::CountryAlpha2>,
+    pub business_label: Option<String>,
+    pub return_url: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = payouts)]
+pub struct Payout {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub connector: Option<String>,
+    pub connector_payout_id: Option<String>,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub payout_type: storage_enums::PayoutType,
+    pub status: storage_enums::PayoutStatus,
+    pub auto_fulfill: bool,
+    pub description: Option<String>,
+    pub business_country: Option<storage_enums::CountryAlpha2>,
+    pub business_label: Option<String>,
+    pub return_url: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PayoutUpdate {
+    Update {
+        description: Option<String>,
+        return_url: Option<String>,
+        metadata: Option<pii::SecretSerdeValue>,
+    },
+    StatusUpdate {
+        status: storage_enums::PayoutStatus,
+        connector_payout_id: Option<String>,
+    },
+    ErrorUpdate {
+        status: storage_enums::PayoutStatus,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payouts)]
+pub struct PayoutUpdateInternal {
+    connector_payout_id: Option<String>,
+    status: Option<storage_enums::PayoutStatus>,
+    description: Option<String>,
+    return_url: Option<String>,
+    metadata: Option<pii::SecretSerdeValue>,
+    error_code: Option<String>,
+    error_message: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<PayoutUpdate> for PayoutUpdateInternal {
+    fn from(payout_update: PayoutUpdate) -> Self {
+        match payout_update {
+            PayoutUpdate::Update {
+                description,
+                return_url,
+                metadata,
+            } => Self {
+                description,
+                return_url,
+                metadata,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PayoutUpdate::StatusUpdate {
+                status,
+                connector_payout_id,
+            } => Self {
+                status: Some(status),
+                connector_payout_id,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PayoutUpdate::ErrorUpdate {
+                status,
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(status),
+                error_code,
+                error_message,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl PayoutUpdate {
+    pub fn apply_changeset(self, source: Payout) -> Payout {
+        let update = PayoutUpdateInternal::from(self);
+        Payout {
+            connector_payout_id: update.connector_payout_id.or(source.connector_payout_id),
+            status: update.status.unwrap_or(source.status),
+            description: update.description.or(source.description),
+            return_url: update.return_url.or(source.return_url),
+            metadata: update.metadata.or(source.metadata),
+            error_code: update.error_code.or(source.error_code),
+            error_message: update.error_message.or(source.error_message),
+            modified_at: update.modified_at.unwrap_or(source.modified_at),
+            ..source
+        }
+    }
+}
@@ -1,12 +1,16 @@
 pub mod address;
 pub mod api_keys;
+pub mod capture;
 pub mod cards_info;
 pub mod configs;
 pub mod connector_response;
+pub mod customer_redaction;
 pub mod customers;
 pub mod dispute;
+pub mod event_delivery_attempt;
 pub mod events;
 pub mod file;
+pub mod fraud_check;
 pub mod generics;
 pub mod locker_mock_up;
 pub mod mandate;
@@ -16,6 +20,7 @@ pub mod merchant_key_store;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
 pub mod reverse_lookup;
@@ -1,5 +1,7 @@
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use error_stack::report;
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
@@ -47,6 +49,31 @@ impl Address {
         }
     }
 
+    /// Updates the address only if it has not been modified since `modified_at`, returns a not
+    /// found error otherwise
+    #[instrument(skip(conn))]
+    pub async fn update_by_address_id_modified_at(
+        conn: &PgPooledConn,
+        address_id: String,
+        modified_at: PrimitiveDateTime,
+        address: AddressUpdateInternal,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::address_id
+                .eq(address_id)
+                .and(dsl::modified_at.eq(modified_at)),
+            address,
+        )
+        .await?
+        .first()
+        .cloned()
+        .ok_or_else(|| {
+            report!(errors::DatabaseError::NotFound)
+                .attach_printable("Address doesn't exist or has been modified")
+        })
+    }
+
     #[instrument(skip(conn))]
     pub async fn delete_by_address_id(
         conn: &PgPooledConn,
@@ -95,4 +122,43 @@ impl Address {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+            None,
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
+
+    /// Lists the addresses of a merchant in batches ordered by `id`, starting after the row with
+    /// the given `id`
+    #[instrument(skip(conn))]
+    pub async fn list_by_merchant_id_after_row_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        last_row_id: Option<i32>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.gt(last_row_id.unwrap_or_default())),
+            Some(limit),
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
 }
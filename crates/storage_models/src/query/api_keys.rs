@@ -94,7 +94,9 @@ impl ApiKey {
     ) -> StorageResult<Option<Self>> {
         generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
             conn,
-            dsl::hashed_api_key.eq(hashed_api_key),
+            dsl::hashed_api_key
+                .eq(hashed_api_key.clone())
+                .or(dsl::previous_hashed_api_key.eq(hashed_api_key)),
         )
         .await
     }
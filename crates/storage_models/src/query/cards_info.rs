@@ -1,6 +1,19 @@
-use diesel::associations::HasTable;
+use diesel::{associations::HasTable, ExpressionMethods, Table};
+use router_env::{instrument, tracing};
 
-use crate::{cards_info::CardInfo, query::generics, PgPooledConn, StorageResult};
+use crate::{
+    cards_info::{CardInfo, CardInfoNew, CardInfoUpdate, CardInfoUpdateInternal},
+    query::generics,
+    schema::cards_info::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl CardInfoNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<CardInfo> {
+        generics::generic_insert(conn, self).await
+    }
+}
 
 impl CardInfo {
     pub async fn find_by_iin(conn: &PgPooledConn, card_iin: &str) -> StorageResult<Option<Self>> {
@@ -10,4 +23,32 @@ impl CardInfo {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_iins(
+        conn: &PgPooledConn,
+        card_iins: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(conn, dsl::card_iin.eq_any(card_iins), None, None, None)
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update_by_iin(
+        conn: &PgPooledConn,
+        card_iin: &str,
+        card_info_update: CardInfoUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_by_id::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            card_iin.to_owned(),
+            CardInfoUpdateInternal::from(card_info_update),
+        )
+        .await
+    }
 }
@@ -58,4 +58,22 @@ impl ConnectorResponse {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_payment_id_merchant_id_attempt_id(
+        conn: &PgPooledConn,
+        payment_id: &str,
+        merchant_id: &str,
+        attempt_id: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()).and(
+                dsl::payment_id
+                    .eq(payment_id.to_owned())
+                    .and(dsl::attempt_id.eq(attempt_id.to_owned())),
+            ),
+        )
+        .await
+    }
 }
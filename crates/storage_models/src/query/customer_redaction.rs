@@ -0,0 +1,52 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    customer_redaction::{
+        CustomerRedaction, CustomerRedactionNew, CustomerRedactionUpdate,
+        CustomerRedactionUpdateInternal,
+    },
+    schema::customer_redaction::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl CustomerRedactionNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<CustomerRedaction> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl CustomerRedaction {
+    #[instrument(skip(conn))]
+    pub async fn find_by_redaction_id(
+        conn: &PgPooledConn,
+        redaction_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::redaction_id.eq(redaction_id.to_owned()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update_by_redaction_id(
+        conn: &PgPooledConn,
+        redaction_id: &str,
+        customer_redaction_update: CustomerRedactionUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::redaction_id.eq(redaction_id.to_owned()),
+            CustomerRedactionUpdateInternal::from(customer_redaction_update),
+        )
+        .await
+    }
+}
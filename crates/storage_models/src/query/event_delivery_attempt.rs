@@ -0,0 +1,66 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    event_delivery_attempt::{EventDeliveryAttempt, EventDeliveryAttemptNew},
+    schema::{event_delivery_attempt::dsl, events},
+    PgPooledConn, StorageResult,
+};
+
+impl EventDeliveryAttemptNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<EventDeliveryAttempt> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl EventDeliveryAttempt {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_event_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::event_id.eq(event_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    /// Replaces the request body of the attempts to deliver the events of the given objects, such
+    /// as payments or mandates. Returns the number of attempts updated.
+    #[instrument(skip(conn))]
+    pub async fn update_request_body_by_merchant_id_primary_object_ids(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        primary_object_ids: Vec<String>,
+        request_body: String,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::request_body.ne(request_body.clone()))
+                .and(
+                    dsl::event_id.eq_any(
+                        events::table
+                            .filter(
+                                events::merchant_id
+                                    .eq(merchant_id.to_owned())
+                                    .and(events::primary_object_id.eq_any(primary_object_ids)),
+                            )
+                            .select(events::event_id),
+                    ),
+                ),
+            dsl::request_body.eq(request_body),
+        )
+        .await
+    }
+}
@@ -0,0 +1,65 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    enums as storage_enums,
+    fraud_check::{FraudCheck, FraudCheckNew, FraudCheckUpdate, FraudCheckUpdateInternal},
+    schema::fraud_check::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl FraudCheckNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<FraudCheck> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl FraudCheck {
+    #[instrument(skip(conn))]
+    pub async fn find_optional_by_merchant_id_payment_id_attempt_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned()))
+                .and(dsl::attempt_id.eq(attempt_id.to_owned())),
+        )
+        .await
+    }
+
+    /// Records the decision of the merchant on a payment attempt left for manual review. Fails
+    /// with a not found error if the attempt was not left for manual review, or if it has been
+    /// reviewed already, so that concurrent reviews cannot both be applied.
+    #[instrument(skip(conn))]
+    pub async fn update_review_by_merchant_id_payment_id_attempt_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+        fraud_check_update: FraudCheckUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned()))
+                .and(dsl::attempt_id.eq(attempt_id.to_owned()))
+                .and(dsl::frm_decision.eq(storage_enums::FrmDecision::ManualReview))
+                .and(dsl::review_decision.is_null()),
+            FraudCheckUpdateInternal::from(fraud_check_update),
+        )
+        .await
+    }
+}
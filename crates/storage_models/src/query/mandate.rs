@@ -1,9 +1,16 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+use diesel::{
+    associations::HasTable, sql_types, BoolExpressionMethods, ExpressionMethods,
+    NullableExpressionMethods, Table,
+};
 use error_stack::report;
 use router_env::{instrument, tracing};
 
 use super::generics;
-use crate::{errors, mandate::*, schema::mandate::dsl, PgPooledConn, StorageResult};
+use crate::{enums, errors, mandate::*, schema::mandate::dsl, PgPooledConn, StorageResult};
+
+diesel::sql_function! {
+    fn coalesce(x: sql_types::Nullable<sql_types::BigInt>, y: sql_types::BigInt) -> sql_types::BigInt;
+}
 
 impl MandateNew {
     #[instrument(skip(conn))]
@@ -27,6 +34,20 @@ impl Mandate {
         .await
     }
 
+    pub async fn find_by_merchant_id_connector_mandate_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector_mandate_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector_mandate_id.eq(connector_mandate_id.to_owned())),
+        )
+        .await
+    }
+
     pub async fn find_by_merchant_id_customer_id(
         conn: &PgPooledConn,
         merchant_id: &str,
@@ -70,4 +91,78 @@ impl Mandate {
                 .attach_printable("Error while updating mandate")
         })
     }
+
+    /// Reserves a usage of the mandate and the amount of a payment in a single statement, before
+    /// the payment is made, so that concurrent payments cannot both pass the checks against the
+    /// same usage count and captured amount. No row is updated if the mandate is no longer active,
+    /// its usage limit is reached or the amount would exceed the mandate amount.
+    pub async fn reserve_usage_by_merchant_id_mandate_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::mandate_id.eq(mandate_id.to_owned()))
+                .and(dsl::mandate_status.eq(enums::MandateStatus::Active))
+                .and(
+                    dsl::mandate_type
+                        .eq(enums::MandateType::MultiUse)
+                        .or(dsl::usage_count.eq(0)),
+                )
+                .and(
+                    dsl::usage_limit
+                        .is_null()
+                        .or(dsl::usage_count.nullable().lt(dsl::usage_limit)),
+                )
+                .and(
+                    dsl::mandate_amount
+                        .is_null()
+                        .or((coalesce(dsl::amount_captured, 0) + amount)
+                            .nullable()
+                            .le(dsl::mandate_amount)),
+                ),
+            (
+                dsl::usage_count.eq(dsl::usage_count + 1),
+                dsl::amount_captured.eq((coalesce(dsl::amount_captured, 0) + amount).nullable()),
+            ),
+        )
+        .await?
+        .first()
+        .cloned()
+        .ok_or_else(|| {
+            report!(errors::DatabaseError::NotFound)
+                .attach_printable("Mandate not found or it can no longer be used for the amount")
+        })
+    }
+
+    /// Releases a usage of the mandate and the amount reserved for a payment which failed.
+    pub async fn release_usage_by_merchant_id_mandate_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::mandate_id.eq(mandate_id.to_owned()))
+                .and(dsl::usage_count.gt(0)),
+            (
+                dsl::usage_count.eq(dsl::usage_count - 1),
+                dsl::amount_captured.eq((coalesce(dsl::amount_captured, 0) - amount).nullable()),
+            ),
+        )
+        .await?
+        .first()
+        .cloned()
+        .ok_or_else(|| {
+            report!(errors::DatabaseError::NotFound)
+                .attach_printable("Mandate not found or it has no reserved usage")
+        })
+    }
 }
@@ -17,13 +17,16 @@ impl MerchantKeyStoreNew {
 
 impl MerchantKeyStore {
     #[instrument(skip(conn))]
-    pub async fn find_by_merchant_id(
+    pub async fn find_all_by_merchant_id(
         conn: &PgPooledConn,
         merchant_id: &str,
-    ) -> StorageResult<Self> {
-        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
             conn,
             dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(dsl::key_version.asc()),
         )
         .await
     }
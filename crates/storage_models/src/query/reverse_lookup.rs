@@ -30,4 +30,13 @@ impl ReverseLookup {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_lookup_id(lookup_id: &str, conn: &PgPooledConn) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::lookup_id.eq(lookup_id.to_owned()),
+        )
+        .await
+    }
 }
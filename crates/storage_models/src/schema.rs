@@ -50,6 +50,40 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        scopes -> Nullable<Array<Nullable<Text>>>,
+        #[max_length = 128]
+        previous_hashed_api_key -> Nullable<Varchar>,
+        previous_key_expires_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    captures (id) {
+        id -> Int4,
+        #[max_length = 64]
+        capture_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        authorized_attempt_id -> Varchar,
+        capture_sequence -> Int2,
+        status -> CaptureStatus,
+        amount -> Int8,
+        currency -> Nullable<Currency>,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 128]
+        connector_capture_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -112,6 +146,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    customer_redaction (id) {
+        id -> Int4,
+        #[max_length = 64]
+        redaction_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        status -> CustomerRedactionStatus,
+        redacted_records -> Nullable<Jsonb>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        completed_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -175,6 +230,28 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    event_delivery_attempt (id) {
+        id -> Int4,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        #[max_length = 64]
+        event_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        request_body -> Text,
+        response_status_code -> Nullable<Int2>,
+        response_body -> Nullable<Text>,
+        latency_ms -> Nullable<Int8>,
+        error_message -> Nullable<Text>,
+        is_manual_retry -> Bool,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -192,6 +269,8 @@ diesel::table! {
         primary_object_id -> Varchar,
         primary_object_type -> EventObjectType,
         created_at -> Timestamp,
+        #[max_length = 64]
+        merchant_id -> Nullable<Varchar>,
     }
 }
 
@@ -220,6 +299,36 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    fraud_check (id) {
+        id -> Int4,
+        #[max_length = 64]
+        frm_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        #[max_length = 64]
+        frm_name -> Varchar,
+        #[max_length = 255]
+        frm_reference_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        frm_status -> Nullable<Varchar>,
+        #[max_length = 32]
+        frm_decision -> Varchar,
+        #[max_length = 32]
+        review_decision -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        reviewed_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -293,6 +402,9 @@ diesel::table! {
         end_date -> Nullable<Timestamp>,
         metadata -> Nullable<Jsonb>,
         connector_mandate_ids -> Nullable<Jsonb>,
+        max_amount_per_payment -> Nullable<Int8>,
+        usage_limit -> Nullable<Int4>,
+        usage_count -> Int4,
     }
 }
 
@@ -366,11 +478,12 @@ diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
 
-    merchant_key_store (merchant_id) {
+    merchant_key_store (merchant_id, key_version) {
         #[max_length = 255]
         merchant_id -> Varchar,
         key -> Bytea,
         created_at -> Timestamp,
+        key_version -> Int4,
     }
 }
 
@@ -519,6 +632,43 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payouts (id) {
+        id -> Int4,
+        #[max_length = 64]
+        payout_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        connector -> Nullable<Varchar>,
+        #[max_length = 128]
+        connector_payout_id -> Nullable<Varchar>,
+        amount -> Int8,
+        currency -> Currency,
+        payout_type -> PayoutType,
+        status -> PayoutStatus,
+        auto_fulfill -> Bool,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        business_country -> Nullable<CountryAlpha2>,
+        #[max_length = 64]
+        business_label -> Nullable<Varchar>,
+        #[max_length = 255]
+        return_url -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        #[max_length = 64]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -608,13 +758,17 @@ diesel::table! {
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
+    captures,
     cards_info,
     configs,
     connector_response,
+    customer_redaction,
     customers,
     dispute,
+    event_delivery_attempt,
     events,
     file_metadata,
+    fraud_check,
     locker_mock_up,
     mandate,
     merchant_account,
@@ -623,6 +777,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_attempt,
     payment_intent,
     payment_methods,
+    payouts,
     process_tracker,
     refund,
     reverse_lookup,
@@ -0,0 +1,49 @@
+CREATE TYPE "PayoutStatus" AS ENUM (
+    'requires_creation',
+    'requires_fulfillment',
+    'pending',
+    'success',
+    'failed',
+    'cancelled',
+    'reversed'
+);
+
+CREATE TYPE "PayoutType" AS ENUM ('card', 'bank');
+
+CREATE TABLE payouts (
+    id SERIAL PRIMARY KEY,
+    payout_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64),
+    connector VARCHAR(64),
+    connector_payout_id VARCHAR(128),
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    payout_type "PayoutType" NOT NULL,
+    status "PayoutStatus" NOT NULL,
+    auto_fulfill BOOLEAN NOT NULL DEFAULT FALSE,
+    description VARCHAR(255),
+    business_country "CountryAlpha2",
+    business_label VARCHAR(64),
+    return_url VARCHAR(255),
+    metadata JSONB,
+    error_code VARCHAR(64),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX payouts_merchant_id_payout_id_index ON payouts (merchant_id, payout_id);
+
+CREATE INDEX payouts_status_index ON payouts (status);
+
+ALTER TYPE "EventClass" ADD VALUE 'payouts';
+
+ALTER TYPE "EventObjectType" ADD VALUE 'payout_details';
+
+ALTER TYPE "EventType" ADD VALUE 'payout_initiated';
+ALTER TYPE "EventType" ADD VALUE 'payout_processing';
+ALTER TYPE "EventType" ADD VALUE 'payout_success';
+ALTER TYPE "EventType" ADD VALUE 'payout_failed';
+ALTER TYPE "EventType" ADD VALUE 'payout_cancelled';
+ALTER TYPE "EventType" ADD VALUE 'payout_reversed';
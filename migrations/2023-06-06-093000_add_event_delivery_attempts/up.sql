@@ -0,0 +1,21 @@
+ALTER TABLE events ADD COLUMN merchant_id VARCHAR(64);
+
+CREATE INDEX events_merchant_id_created_at_index ON events (merchant_id, created_at);
+
+CREATE TABLE event_delivery_attempt (
+    id SERIAL PRIMARY KEY,
+    attempt_id VARCHAR(64) NOT NULL,
+    event_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    request_body TEXT NOT NULL,
+    response_status_code SMALLINT,
+    response_body TEXT,
+    latency_ms BIGINT,
+    error_message TEXT,
+    is_manual_retry BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX event_delivery_attempt_attempt_id_index ON event_delivery_attempt (attempt_id);
+
+CREATE INDEX event_delivery_attempt_event_id_index ON event_delivery_attempt (event_id);
@@ -0,0 +1,4 @@
+ALTER TABLE mandate
+DROP COLUMN IF EXISTS max_amount_per_payment,
+DROP COLUMN IF EXISTS usage_limit,
+DROP COLUMN IF EXISTS usage_count;
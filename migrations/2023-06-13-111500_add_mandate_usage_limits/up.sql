@@ -0,0 +1,16 @@
+ALTER TABLE mandate
+ADD COLUMN IF NOT EXISTS max_amount_per_payment BIGINT,
+ADD COLUMN IF NOT EXISTS usage_limit INTEGER,
+ADD COLUMN IF NOT EXISTS usage_count INTEGER NOT NULL DEFAULT 0;
+
+ALTER TYPE "MandateStatus" ADD VALUE 'expired';
+
+ALTER TYPE "MandateStatus" ADD VALUE 'used';
+
+ALTER TYPE "EventClass" ADD VALUE 'mandates';
+
+ALTER TYPE "EventObjectType" ADD VALUE 'mandate_details';
+
+ALTER TYPE "EventType" ADD VALUE 'mandate_expired';
+
+ALTER TYPE "EventType" ADD VALUE 'mandate_used';
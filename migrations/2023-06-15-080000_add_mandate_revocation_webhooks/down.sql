@@ -0,0 +1 @@
+DROP INDEX IF EXISTS mandate_merchant_id_connector_mandate_id_index;
@@ -0,0 +1,5 @@
+CREATE INDEX IF NOT EXISTS mandate_merchant_id_connector_mandate_id_index ON mandate (merchant_id, connector_mandate_id);
+
+ALTER TYPE "EventType" ADD VALUE 'mandate_active';
+
+ALTER TYPE "EventType" ADD VALUE 'mandate_revoked';
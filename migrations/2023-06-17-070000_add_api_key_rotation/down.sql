@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS api_keys_previous_hashed_api_key_index;
+
+ALTER TABLE api_keys
+DROP COLUMN IF EXISTS previous_hashed_api_key,
+DROP COLUMN IF EXISTS previous_key_expires_at;
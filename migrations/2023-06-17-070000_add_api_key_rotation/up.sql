@@ -0,0 +1,5 @@
+ALTER TABLE api_keys
+ADD COLUMN IF NOT EXISTS previous_hashed_api_key VARCHAR(128),
+ADD COLUMN IF NOT EXISTS previous_key_expires_at TIMESTAMP;
+
+CREATE UNIQUE INDEX IF NOT EXISTS api_keys_previous_hashed_api_key_index ON api_keys (previous_hashed_api_key);
@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS payment_intent_merchant_id_customer_id_index;
+
+DROP TABLE customer_redaction;
+
+DROP TYPE "CustomerRedactionStatus";
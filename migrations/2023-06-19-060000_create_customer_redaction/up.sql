@@ -0,0 +1,30 @@
+CREATE TYPE "CustomerRedactionStatus" AS ENUM (
+    'scheduled',
+    'completed',
+    'failed'
+);
+
+CREATE TABLE customer_redaction (
+    id SERIAL PRIMARY KEY,
+    redaction_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    status "CustomerRedactionStatus" NOT NULL,
+    redacted_records JSONB,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    completed_at TIMESTAMP
+);
+
+CREATE UNIQUE INDEX customer_redaction_redaction_id_index ON customer_redaction (redaction_id);
+
+CREATE INDEX customer_redaction_merchant_id_customer_id_index ON customer_redaction (
+    merchant_id,
+    customer_id
+);
+
+CREATE INDEX payment_intent_merchant_id_customer_id_index ON payment_intent (
+    merchant_id,
+    customer_id
+);
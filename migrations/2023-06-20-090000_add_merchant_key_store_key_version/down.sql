@@ -0,0 +1,14 @@
+-- Merchant data may already be encrypted with the keys of later versions, which would become
+-- undecryptable if they were dropped along with the key version
+DO $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM merchant_key_store WHERE key_version <> 1) THEN
+        RAISE EXCEPTION 'merchant_key_store holds rotated keys, which may be needed to decrypt merchant data';
+    END IF;
+END $$;
+
+ALTER TABLE merchant_key_store DROP CONSTRAINT merchant_key_store_pkey;
+
+ALTER TABLE merchant_key_store ADD PRIMARY KEY (merchant_id);
+
+ALTER TABLE merchant_key_store DROP COLUMN IF EXISTS key_version;
@@ -0,0 +1,6 @@
+ALTER TABLE merchant_key_store
+ADD COLUMN IF NOT EXISTS key_version INTEGER NOT NULL DEFAULT 1;
+
+ALTER TABLE merchant_key_store DROP CONSTRAINT merchant_key_store_pkey;
+
+ALTER TABLE merchant_key_store ADD PRIMARY KEY (merchant_id, key_version);
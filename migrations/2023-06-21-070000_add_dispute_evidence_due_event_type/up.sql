@@ -0,0 +1 @@
+ALTER TYPE "EventType" ADD VALUE 'dispute_evidence_due';
@@ -0,0 +1,29 @@
+-- Backfill the merchant of existing events from their primary object. Identifiers are only unique
+-- per merchant, so events whose object identifier is shared by several merchants are left unset.
+UPDATE events SET merchant_id = payment_intent.merchant_id
+FROM payment_intent
+WHERE events.merchant_id IS NULL
+    AND events.primary_object_type = 'payment_details'
+    AND payment_intent.payment_id = events.primary_object_id
+    AND (SELECT COUNT(*) FROM payment_intent AS other WHERE other.payment_id = events.primary_object_id) = 1;
+
+UPDATE events SET merchant_id = refund.merchant_id
+FROM refund
+WHERE events.merchant_id IS NULL
+    AND events.primary_object_type = 'refund_details'
+    AND refund.refund_id = events.primary_object_id
+    AND (SELECT COUNT(*) FROM refund AS other WHERE other.refund_id = events.primary_object_id) = 1;
+
+UPDATE events SET merchant_id = dispute.merchant_id
+FROM dispute
+WHERE events.merchant_id IS NULL
+    AND events.primary_object_type = 'dispute_details'
+    AND dispute.dispute_id = events.primary_object_id
+    AND (SELECT COUNT(*) FROM dispute AS other WHERE other.dispute_id = events.primary_object_id) = 1;
+
+UPDATE events SET merchant_id = payouts.merchant_id
+FROM payouts
+WHERE events.merchant_id IS NULL
+    AND events.primary_object_type = 'payout_details'
+    AND payouts.payout_id = events.primary_object_id
+    AND (SELECT COUNT(*) FROM payouts AS other WHERE other.payout_id = events.primary_object_id) = 1;
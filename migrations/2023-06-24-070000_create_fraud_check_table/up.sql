@@ -0,0 +1,23 @@
+CREATE TABLE fraud_check (
+    id SERIAL PRIMARY KEY,
+    frm_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    frm_name VARCHAR(64) NOT NULL,
+    frm_reference_id VARCHAR(255),
+    frm_status VARCHAR(32),
+    frm_decision VARCHAR(32) NOT NULL,
+    review_decision VARCHAR(32),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    reviewed_at TIMESTAMP
+);
+
+CREATE UNIQUE INDEX fraud_check_frm_id_index ON fraud_check (frm_id);
+
+CREATE UNIQUE INDEX fraud_check_merchant_id_payment_id_attempt_id_index ON fraud_check (
+    merchant_id,
+    payment_id,
+    attempt_id
+);